@@ -1,7 +1,7 @@
 //! Plugin-based LSP installation helpers for CLI
 
 use mill_lang_common::lsp::get_cache_dir;
-use mill_plugin_api::{iter_plugins, LanguagePlugin, LspInstaller};
+use mill_plugin_api::{iter_plugins, LanguagePlugin, LspInstaller, PluginDiscovery};
 use std::path::{Path, PathBuf};
 use tracing::{debug, info};
 
@@ -61,15 +61,19 @@ pub async fn install_lsp(language: &str) -> Result<PathBuf, String> {
         .map_err(|e| format!("Installation failed: {}", e))
 }
 
-/// Get list of all languages with LSP installer support
+/// Get list of all languages with LSP installer support, sorted by name
 pub fn list_supported_languages() -> Vec<(&'static str, String)> {
-    iter_plugins()
-        .filter_map(|desc| {
-            // Create plugin instance to check for LSP installer
-            let plugin = (desc.factory)();
+    let mut plugins = PluginDiscovery::new();
+    for descriptor in iter_plugins() {
+        plugins.register_lazy(descriptor);
+    }
+    plugins
+        .sorted_by_name()
+        .into_iter()
+        .filter_map(|plugin| {
             plugin
                 .lsp_installer()
-                .map(|installer| (desc.name, installer.lsp_name().to_string()))
+                .map(|installer| (plugin.metadata().name, installer.lsp_name().to_string()))
         })
         .collect()
 }
@@ -406,6 +410,7 @@ mod tests {
         assert!(names.contains(&"rust"));
         assert!(names.contains(&"typescript"));
         assert!(names.contains(&"python"));
+        assert!(names.windows(2).all(|pair| pair[0] <= pair[1]));
     }
 
     #[test]
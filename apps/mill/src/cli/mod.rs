@@ -73,7 +73,11 @@ pub enum Commands {
     /// Remove AI from config
     Unlink,
     /// Check client configuration and diagnose potential problems
-    Doctor,
+    Doctor {
+        /// Fail when a language server needed by a registered plugin is missing
+        #[arg(long)]
+        strict: bool,
+    },
     /// Install LSP server for a specific language
     InstallLsp {
         /// Language name (e.g., "rust", "typescript", "python")
@@ -347,8 +351,8 @@ pub async fn run() {
         Commands::Unlink => {
             handle_unlink().await;
         }
-        Commands::Doctor => {
-            handle_doctor().await;
+        Commands::Doctor { strict } => {
+            handle_doctor(strict).await;
         }
         Commands::InstallLsp { language } => {
             handle_install_lsp(&language).await;
@@ -764,7 +768,7 @@ async fn handle_status() {
 }
 
 /// Handle the doctor command
-async fn handle_doctor() {
+async fn handle_doctor(strict: bool) {
     use mill_client::formatting::Formatter;
     use mill_foundation::core::utils::system;
 
@@ -857,6 +861,17 @@ async fn handle_doctor() {
         }
     }
 
+    // 3. Report the client's TLS settings and environment-interpolated values
+    mill_client::commands::doctor::report_client_settings().await;
+
+    // 4. Check the LSP servers needed by the linked plugins
+    let checks = mill_client::commands::doctor::plugin_lsp_checks();
+    if let Err(e) = mill_client::commands::doctor::report_plugin_lsps(&checks, strict) {
+        println!("\n  {}", fmt.error(&e.to_string()));
+        process::exit(1);
+    }
+    println!();
+
     println!("{}", fmt.success("Doctor's checkup complete"));
 }
 
@@ -2,7 +2,8 @@
 
 use dashmap::DashMap;
 use mill_foundation::protocol::{CacheStats, ImportGraph};
-use std::path::PathBuf;
+use mill_plugin_api::Symbol;
+use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 use tracing::{debug, trace};
 
@@ -26,6 +27,16 @@ pub struct CachedEntry {
     pub file_size: u64,
 }
 
+/// Symbols listed by a language plugin, valid for one file version
+#[derive(Debug, Clone)]
+struct CachedSymbols {
+    symbols: Vec<Symbol>,
+    /// Modification time of the file the symbols were listed from
+    modified_time: SystemTime,
+    /// When the symbols were cached, used to evict the oldest entries
+    cached_at: SystemTime,
+}
+
 /// Cache configuration settings
 #[derive(Debug, Clone)]
 pub struct CacheSettings {
@@ -101,6 +112,8 @@ impl Default for CacheSettings {
 pub struct AstCache {
     /// Cache storage mapping file paths to cached entries
     cache: DashMap<PathBuf, CachedEntry>,
+    /// Symbol lists keyed by file path, bounded by the same settings
+    symbols: DashMap<PathBuf, CachedSymbols>,
     /// Cache statistics
     stats: DashMap<String, u64>,
     /// Cache configuration
@@ -117,6 +130,7 @@ impl AstCache {
     pub fn with_settings(settings: CacheSettings) -> Self {
         let cache = Self {
             cache: DashMap::new(),
+            symbols: DashMap::new(),
             stats: DashMap::new(),
             settings: settings.clone(),
         };
@@ -259,6 +273,67 @@ impl AstCache {
         Ok(())
     }
 
+    /// Get the cached symbols of a file if they were listed from the version
+    /// modified at `modified_time`
+    pub fn get_symbols(&self, file_path: &Path, modified_time: SystemTime) -> Option<Vec<Symbol>> {
+        if !self.settings.enabled {
+            return None;
+        }
+
+        let cached = self
+            .symbols
+            .get(file_path)
+            .filter(|entry| entry.modified_time == modified_time)
+            .map(|entry| entry.symbols.clone());
+        self.increment_stat(if cached.is_some() { "hits" } else { "misses" });
+        cached
+    }
+
+    /// Cache the symbols listed from the version of a file modified at
+    /// `modified_time`
+    pub fn insert_symbols(
+        &self,
+        file_path: PathBuf,
+        modified_time: SystemTime,
+        symbols: Vec<Symbol>,
+    ) {
+        if !self.settings.enabled {
+            return;
+        }
+
+        if !self.symbols.contains_key(&file_path) && self.symbols.len() >= self.settings.max_entries
+        {
+            self.evict_oldest_symbols();
+        }
+
+        self.symbols.insert(
+            file_path,
+            CachedSymbols {
+                symbols,
+                modified_time,
+                cached_at: SystemTime::now(),
+            },
+        );
+        self.increment_stat("inserts");
+    }
+
+    /// Evict the oldest 10% of symbol entries when the cache is full
+    fn evict_oldest_symbols(&self) {
+        let mut entries: Vec<(PathBuf, SystemTime)> = self
+            .symbols
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().cached_at))
+            .collect();
+        entries.sort_by_key(|(_, cached_at)| *cached_at);
+
+        let evict_count = (self.settings.max_entries / 10).max(1);
+        for (path, _) in entries.iter().take(evict_count) {
+            if self.symbols.remove(path).is_some() {
+                self.increment_stat("evictions");
+            }
+        }
+    }
+
     /// Evict least recently used entries when cache is full
     fn evict_lru(&self) {
         // Simple eviction strategy: remove oldest cached entries
@@ -286,7 +361,8 @@ impl AstCache {
 
     /// Invalidate a cached entry
     pub fn invalidate(&self, file_path: &PathBuf) {
-        if self.cache.remove(file_path).is_some() {
+        let removed_symbols = self.symbols.remove(file_path).is_some();
+        if self.cache.remove(file_path).is_some() || removed_symbols {
             self.increment_stat("invalidations");
             debug!("Invalidated cache entry for: {}", file_path.display());
         }
@@ -296,6 +372,7 @@ impl AstCache {
     pub fn clear(&self) {
         let count = self.cache.len();
         self.cache.clear();
+        self.symbols.clear();
         debug!("Cleared {} cached entries", count);
     }
 
@@ -343,7 +420,7 @@ impl AstCache {
     /// Perform cache maintenance (remove entries for files that no longer exist)
     pub fn maintenance(&self) {
         let mut removed_count = 0;
-        let paths_to_remove: Vec<PathBuf> = self
+        let mut paths_to_remove: Vec<PathBuf> = self
             .cache
             .iter()
             .filter_map(|entry| {
@@ -355,6 +432,14 @@ impl AstCache {
                 }
             })
             .collect();
+        paths_to_remove.extend(
+            self.symbols
+                .iter()
+                .map(|entry| entry.key().clone())
+                .filter(|path| !path.exists()),
+        );
+        paths_to_remove.sort();
+        paths_to_remove.dedup();
 
         for path in paths_to_remove {
             self.invalidate(&path);
@@ -508,4 +593,45 @@ mod tests {
         assert_eq!(stats.misses, 0);
         assert_eq!(stats.hit_ratio(), 100.0);
     }
+
+    #[test]
+    fn test_symbol_cache_is_bounded_and_keyed_by_modification_time() {
+        let cache = AstCache::with_settings(CacheSettings {
+            enabled: true,
+            max_entries: 2,
+            ttl_seconds: 3600,
+            max_size_bytes: 1024,
+        });
+        let modified = SystemTime::now();
+        let symbol = Symbol {
+            name: "load".to_string(),
+            kind: mill_plugin_api::SymbolKind::Function,
+            location: mill_plugin_api::SourceLocation { line: 0, column: 0 },
+            end_location: None,
+            documentation: None,
+            is_test: false,
+            parent: None,
+        };
+
+        cache.insert_symbols(PathBuf::from("a.rs"), modified, vec![symbol.clone()]);
+        assert_eq!(
+            cache.get_symbols(Path::new("a.rs"), modified),
+            Some(vec![symbol.clone()])
+        );
+        assert_eq!(
+            cache.get_symbols(
+                Path::new("a.rs"),
+                modified + std::time::Duration::from_secs(1)
+            ),
+            None
+        );
+
+        cache.insert_symbols(PathBuf::from("b.rs"), modified, vec![]);
+        cache.insert_symbols(PathBuf::from("c.rs"), modified, vec![]);
+        assert_eq!(cache.symbols.len(), 2);
+        assert!(cache.get_symbols(Path::new("c.rs"), modified).is_some());
+
+        cache.invalidate(&PathBuf::from("c.rs"));
+        assert_eq!(cache.get_symbols(Path::new("c.rs"), modified), None);
+    }
 }
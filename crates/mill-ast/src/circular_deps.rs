@@ -0,0 +1,523 @@
+//! Circular dependency detection over a `DependencyGraph`
+//!
+//! Cycles are found by first computing the strongly connected components (SCCs)
+//! of the graph and then searching each SCC for its shortest simple cycles.
+//! An SCC can contain many nodes even when the actual loop is short, so the
+//! reported cycle is the tightest loop while the full SCC membership is kept
+//! alongside it for context.
+
+use crate::parser::DependencyGraph;
+use petgraph::algo::tarjan_scc;
+use petgraph::graph::NodeIndex;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Options controlling how cycles are reported
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleDetectionOptions {
+    /// Report the shortest simple cycles inside each SCC instead of the whole SCC
+    pub minimal_cycles: bool,
+    /// Maximum number of distinct minimal cycles to report per SCC
+    pub max_cycles_per_scc: usize,
+}
+
+impl Default for CycleDetectionOptions {
+    fn default() -> Self {
+        Self {
+            minimal_cycles: true,
+            max_cycles_per_scc: 1,
+        }
+    }
+}
+
+/// A single circular dependency
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cycle {
+    /// Modules forming the loop, in import order (the last imports the first)
+    pub modules: Vec<String>,
+    /// Every module of the strongly connected component containing the loop
+    pub scc_members: Vec<String>,
+}
+
+impl Cycle {
+    /// Number of modules in the reported loop
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    /// Whether the reported loop is empty
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+}
+
+/// Summary statistics for a cycle report
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CircularDependenciesSummary {
+    /// Number of reported cycles
+    pub total_cycles: usize,
+    /// Number of distinct modules taking part in any SCC with a cycle
+    pub total_modules_in_cycles: usize,
+    /// Size of the largest reported cycle
+    pub largest_cycle_size: usize,
+}
+
+/// Threshold at which a cycle report should fail a CI run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FailOn {
+    /// Fail when any cycle is found
+    AnyCycle,
+    /// Fail when a reported cycle has at least this many modules
+    CycleSizeGte(usize),
+    /// Fail when at least this many cycles are reported
+    TotalCyclesGte(usize),
+}
+
+/// Result of circular dependency detection
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CircularDependenciesResult {
+    pub cycles: Vec<Cycle>,
+    pub summary: CircularDependenciesSummary,
+}
+
+impl CircularDependenciesResult {
+    /// Whether the report crosses the `fail_on` threshold
+    ///
+    /// The result itself is unchanged, so callers can still report every
+    /// cycle before exiting with a failure status.
+    pub fn exceeds(&self, fail_on: &FailOn) -> bool {
+        match *fail_on {
+            FailOn::AnyCycle => self.summary.total_cycles > 0,
+            FailOn::CycleSizeGte(size) => {
+                self.summary.total_cycles > 0 && self.summary.largest_cycle_size >= size
+            }
+            FailOn::TotalCyclesGte(count) => {
+                self.summary.total_cycles > 0 && self.summary.total_cycles >= count
+            }
+        }
+    }
+
+    /// Render the result as a self-contained HTML page
+    ///
+    /// Each cycle gets a collapsible section with its modules, import chain,
+    /// severity, and a suggested import to break. Styles are inlined so the
+    /// page can be attached as a CI artifact without external assets.
+    pub fn to_html(&self) -> String {
+        let mut html = String::from(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Circular Dependencies</title>\n<style>\n",
+        );
+        html.push_str(HTML_STYLE);
+        html.push_str("</style>\n</head>\n<body>\n<h1>Circular Dependencies</h1>\n");
+
+        html.push_str(&format!(
+            "<ul class=\"summary\">\n<li>Cycles: <strong>{}</strong></li>\n\
+             <li>Modules in cycles: <strong>{}</strong></li>\n\
+             <li>Largest cycle: <strong>{}</strong></li>\n</ul>\n",
+            self.summary.total_cycles,
+            self.summary.total_modules_in_cycles,
+            self.summary.largest_cycle_size
+        ));
+
+        if self.cycles.is_empty() {
+            html.push_str("<p>No circular dependencies found.</p>\n");
+        }
+
+        for (index, cycle) in self.cycles.iter().enumerate() {
+            let severity = cycle_severity(cycle);
+            html.push_str(&format!(
+                "<details class=\"cycle\" id=\"cycle-{}\">\n\
+                 <summary>Cycle {} <span class=\"severity {}\">{}</span> ({} modules)</summary>\n",
+                index + 1,
+                index + 1,
+                severity,
+                severity,
+                cycle.len()
+            ));
+
+            html.push_str("<h3>Modules</h3>\n<ul>\n");
+            for module in &cycle.modules {
+                html.push_str(&format!("<li><code>{}</code></li>\n", escape_html(module)));
+            }
+            html.push_str("</ul>\n");
+
+            let chain: Vec<String> = cycle
+                .modules
+                .iter()
+                .chain(cycle.modules.first())
+                .map(|module| format!("<code>{}</code>", escape_html(module)))
+                .collect();
+            html.push_str(&format!(
+                "<h3>Import chain</h3>\n<p>{}</p>\n",
+                chain.join(" &rarr; ")
+            ));
+
+            if let (Some(first), Some(last)) = (cycle.modules.first(), cycle.modules.last()) {
+                html.push_str(&format!(
+                    "<h3>Suggested break</h3>\n<p>Remove the import of <code>{}</code> from <code>{}</code></p>\n",
+                    escape_html(first),
+                    escape_html(last)
+                ));
+            }
+            html.push_str("</details>\n");
+        }
+
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+const HTML_STYLE: &str = "body { font-family: sans-serif; margin: 2em; color: #222; }
+.summary { list-style: none; padding: 0; }
+.cycle { border: 1px solid #ccc; border-radius: 4px; margin: 0.5em 0; padding: 0.5em 1em; }
+.cycle summary { cursor: pointer; font-weight: bold; }
+.severity { border-radius: 3px; padding: 0 0.4em; color: #fff; font-size: 0.85em; }
+.severity.low { background: #6a737d; }
+.severity.medium { background: #d97706; }
+.severity.high { background: #c0392b; }
+code { background: #f4f4f4; padding: 0 0.2em; }
+";
+
+/// Longer loops touch more modules and are harder to untangle
+fn cycle_severity(cycle: &Cycle) -> &'static str {
+    match cycle.len() {
+        0..=2 => "low",
+        3..=4 => "medium",
+        _ => "high",
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Find circular dependencies in a dependency graph
+///
+/// With `options.minimal_cycles` set (the default), each SCC contributes its
+/// shortest simple cycles; otherwise each SCC is reported as one cycle.
+pub fn find_circular_dependencies(
+    graph: &DependencyGraph,
+    options: &CycleDetectionOptions,
+) -> CircularDependenciesResult {
+    let mut cycles = Vec::new();
+    let mut modules_in_cycles = HashSet::new();
+
+    for scc in tarjan_scc(&graph.graph) {
+        let is_cycle = scc.len() > 1 || graph.graph.contains_edge(scc[0], scc[0]);
+        if !is_cycle {
+            continue;
+        }
+
+        let mut scc_members: Vec<String> = scc.iter().map(|&n| graph.graph[n].clone()).collect();
+        scc_members.sort();
+        modules_in_cycles.extend(scc_members.iter().cloned());
+
+        if !options.minimal_cycles {
+            cycles.push(Cycle {
+                modules: scc_members.clone(),
+                scc_members,
+            });
+            continue;
+        }
+
+        for cycle in shortest_cycles_in_scc(graph, &scc, options.max_cycles_per_scc.max(1)) {
+            cycles.push(Cycle {
+                modules: cycle.into_iter().map(|n| graph.graph[n].clone()).collect(),
+                scc_members: scc_members.clone(),
+            });
+        }
+    }
+
+    cycles.sort_by(|a, b| {
+        a.len()
+            .cmp(&b.len())
+            .then_with(|| a.modules.cmp(&b.modules))
+    });
+
+    let summary = CircularDependenciesSummary {
+        total_cycles: cycles.len(),
+        total_modules_in_cycles: modules_in_cycles.len(),
+        largest_cycle_size: cycles.iter().map(Cycle::len).max().unwrap_or(0),
+    };
+
+    CircularDependenciesResult { cycles, summary }
+}
+
+/// Find up to `limit` distinct shortest simple cycles within one SCC
+///
+/// Runs a BFS from every SCC node (restricted to the SCC) back to itself, which
+/// yields the shortest cycle through that node. Cycles are deduplicated by
+/// rotating them to start at their smallest node.
+fn shortest_cycles_in_scc(
+    graph: &DependencyGraph,
+    scc: &[NodeIndex],
+    limit: usize,
+) -> Vec<Vec<NodeIndex>> {
+    let members: HashSet<NodeIndex> = scc.iter().copied().collect();
+    let mut candidates: Vec<Vec<NodeIndex>> = Vec::new();
+    let mut seen = HashSet::new();
+
+    for &start in scc {
+        if let Some(cycle) = shortest_cycle_through(graph, start, &members) {
+            let canonical = canonicalize(&cycle);
+            if seen.insert(canonical.clone()) {
+                candidates.push(canonical);
+            }
+        }
+    }
+
+    candidates.sort_by(|a, b| {
+        a.len().cmp(&b.len()).then_with(|| {
+            let names_a: Vec<&String> = a.iter().map(|&n| &graph.graph[n]).collect();
+            let names_b: Vec<&String> = b.iter().map(|&n| &graph.graph[n]).collect();
+            names_a.cmp(&names_b)
+        })
+    });
+    candidates.truncate(limit);
+    candidates
+}
+
+/// BFS for the shortest path from `start` back to `start` staying inside `members`
+fn shortest_cycle_through(
+    graph: &DependencyGraph,
+    start: NodeIndex,
+    members: &HashSet<NodeIndex>,
+) -> Option<Vec<NodeIndex>> {
+    if graph.graph.contains_edge(start, start) {
+        return Some(vec![start]);
+    }
+
+    let mut parent: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        for next in graph.graph.neighbors(node) {
+            if !members.contains(&next) {
+                continue;
+            }
+            if next == start {
+                let mut path = vec![node];
+                let mut current = node;
+                while current != start {
+                    current = parent[&current];
+                    path.push(current);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if let Entry::Vacant(entry) = parent.entry(next) {
+                entry.insert(node);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Rotate a cycle so that it starts at its smallest node index
+fn canonicalize(cycle: &[NodeIndex]) -> Vec<NodeIndex> {
+    let pivot = cycle
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, n)| n.index())
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    cycle[pivot..]
+        .iter()
+        .chain(cycle[..pivot].iter())
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mill_foundation::protocol::{ImportInfo, ImportType, SourceLocation};
+    use petgraph::Graph;
+
+    fn import(module: &str) -> ImportInfo {
+        ImportInfo {
+            module_path: module.to_string(),
+            import_type: ImportType::EsModule,
+            named_imports: vec![],
+            default_import: None,
+            namespace_import: None,
+            type_only: false,
+            location: SourceLocation {
+                start_line: 0,
+                start_column: 0,
+                end_line: 0,
+                end_column: 0,
+            },
+        }
+    }
+
+    fn graph_from_edges(nodes: &[&str], edges: &[(&str, &str)]) -> DependencyGraph {
+        let mut graph = Graph::new();
+        let mut file_nodes = HashMap::new();
+        for name in nodes {
+            file_nodes.insert(name.to_string(), graph.add_node(name.to_string()));
+        }
+        for (from, to) in edges {
+            graph.add_edge(file_nodes[*from], file_nodes[*to], import(to));
+        }
+        DependencyGraph {
+            graph,
+            file_nodes,
+            circular_dependencies: vec![],
+            root: None,
+            languages: HashMap::new(),
+            import_targets: HashSet::new(),
+        }
+    }
+
+    #[test]
+    fn test_minimal_cycle_excludes_non_cycle_scc_node() {
+        // a <-> b is the tight loop; c is in the same SCC via b -> c -> a
+        // but a path through c is longer than the direct loop.
+        let graph = graph_from_edges(
+            &["a", "b", "c", "d"],
+            &[("a", "b"), ("b", "a"), ("b", "c"), ("c", "a"), ("a", "d")],
+        );
+
+        let result = find_circular_dependencies(&graph, &CycleDetectionOptions::default());
+
+        assert_eq!(result.cycles.len(), 1);
+        let cycle = &result.cycles[0];
+        assert_eq!(cycle.modules.len(), 2);
+        assert!(cycle.modules.contains(&"a".to_string()));
+        assert!(cycle.modules.contains(&"b".to_string()));
+        assert!(!cycle.modules.contains(&"c".to_string()));
+        assert_eq!(cycle.scc_members, vec!["a", "b", "c"]);
+        assert_eq!(result.summary.total_modules_in_cycles, 3);
+        assert_eq!(result.summary.largest_cycle_size, 2);
+    }
+
+    #[test]
+    fn test_whole_scc_reported_when_minimal_disabled() {
+        let graph = graph_from_edges(
+            &["a", "b", "c"],
+            &[("a", "b"), ("b", "a"), ("b", "c"), ("c", "a")],
+        );
+        let options = CycleDetectionOptions {
+            minimal_cycles: false,
+            ..Default::default()
+        };
+
+        let result = find_circular_dependencies(&graph, &options);
+
+        assert_eq!(result.cycles.len(), 1);
+        assert_eq!(result.cycles[0].modules, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_multiple_minimal_cycles_per_scc() {
+        let graph = graph_from_edges(
+            &["a", "b", "c"],
+            &[("a", "b"), ("b", "a"), ("b", "c"), ("c", "b")],
+        );
+        let options = CycleDetectionOptions {
+            max_cycles_per_scc: 5,
+            ..Default::default()
+        };
+
+        let result = find_circular_dependencies(&graph, &options);
+
+        assert_eq!(result.cycles.len(), 2);
+        assert!(result.cycles.iter().all(|c| c.len() == 2));
+    }
+
+    #[test]
+    fn test_self_import_and_acyclic_graph() {
+        let graph = graph_from_edges(&["a", "b"], &[("a", "a"), ("a", "b")]);
+        let result = find_circular_dependencies(&graph, &CycleDetectionOptions::default());
+        assert_eq!(result.cycles.len(), 1);
+        assert_eq!(result.cycles[0].modules, vec!["a"]);
+
+        let acyclic = graph_from_edges(&["a", "b"], &[("a", "b")]);
+        let result = find_circular_dependencies(&acyclic, &CycleDetectionOptions::default());
+        assert!(result.cycles.is_empty());
+        assert_eq!(result.summary, CircularDependenciesSummary::default());
+    }
+
+    #[test]
+    fn test_exceeds_each_threshold_against_two_cycles() {
+        // One 2-module and one 3-module cycle
+        let graph = graph_from_edges(
+            &["a", "b", "x", "y", "z"],
+            &[("a", "b"), ("b", "a"), ("x", "y"), ("y", "z"), ("z", "x")],
+        );
+        let result = find_circular_dependencies(&graph, &CycleDetectionOptions::default());
+        assert_eq!(result.summary.total_cycles, 2);
+
+        assert!(result.exceeds(&FailOn::AnyCycle));
+        assert!(result.exceeds(&FailOn::CycleSizeGte(3)));
+        assert!(!result.exceeds(&FailOn::CycleSizeGte(4)));
+        assert!(result.exceeds(&FailOn::TotalCyclesGte(2)));
+        assert!(!result.exceeds(&FailOn::TotalCyclesGte(3)));
+
+        let acyclic = graph_from_edges(&["a", "b"], &[("a", "b")]);
+        let clean = find_circular_dependencies(&acyclic, &CycleDetectionOptions::default());
+        assert!(!clean.exceeds(&FailOn::AnyCycle));
+        assert!(!clean.exceeds(&FailOn::CycleSizeGte(0)));
+        assert!(!clean.exceeds(&FailOn::TotalCyclesGte(0)));
+    }
+
+    #[test]
+    fn test_fail_on_deserializes_from_tool_arguments() {
+        let parse = |value: serde_json::Value| serde_json::from_value::<FailOn>(value).unwrap();
+        assert_eq!(parse(serde_json::json!("anyCycle")), FailOn::AnyCycle);
+        assert_eq!(
+            parse(serde_json::json!({ "cycleSizeGte": 4 })),
+            FailOn::CycleSizeGte(4)
+        );
+        assert_eq!(
+            parse(serde_json::json!({ "totalCyclesGte": 2 })),
+            FailOn::TotalCyclesGte(2)
+        );
+    }
+
+    #[test]
+    fn test_to_html_lists_cycles_and_escapes_paths() {
+        let graph = graph_from_edges(
+            &["src/<a>.ts", "src/b&c.ts", "x", "y", "z"],
+            &[
+                ("src/<a>.ts", "src/b&c.ts"),
+                ("src/b&c.ts", "src/<a>.ts"),
+                ("x", "y"),
+                ("y", "z"),
+                ("z", "x"),
+            ],
+        );
+        let result = find_circular_dependencies(&graph, &CycleDetectionOptions::default());
+
+        let html = result.to_html();
+
+        assert_eq!(html.matches("<details class=\"cycle\"").count(), 2);
+        assert!(html.contains("Cycles: <strong>2</strong>"));
+        assert!(html.contains("Modules in cycles: <strong>5</strong>"));
+        assert!(html.contains("Largest cycle: <strong>3</strong>"));
+        assert!(html.contains("src/&lt;a&gt;.ts"));
+        assert!(html.contains("src/b&amp;c.ts"));
+        assert!(!html.contains("src/<a>.ts"));
+        assert!(!html.contains("<link"));
+    }
+}
@@ -0,0 +1,217 @@
+//! Unused symbol detection over a set of source files
+//!
+//! Every file is parsed with the language plugin to collect its top-level
+//! functions and types, then all files are scanned for whole-identifier
+//! occurrences of each name (string literals and comments are skipped). A
+//! symbol whose only occurrence is its own definition is reported as unused.
+//!
+//! The scan is name-based, so two symbols sharing a name keep each other
+//! alive. That errs on the side of not reporting live code.
+
+use mill_plugin_api::identifier_scanner::{find_identifier_occurrences, LexicalSyntax};
+use mill_plugin_api::{LanguagePlugin, SymbolKind};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tracing::debug;
+
+/// Options controlling which symbols are reported
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedSymbolOptions {
+    /// Also report `pub`/`public`/`export` items, which may be used from
+    /// outside the analyzed files
+    pub include_public: bool,
+}
+
+/// A top-level symbol that is never referenced
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedSymbol {
+    /// File defining the symbol
+    pub file: PathBuf,
+    /// Symbol name
+    pub name: String,
+    /// Symbol kind
+    pub kind: SymbolKind,
+    /// Line of the definition (0-based)
+    pub line: usize,
+    /// Whether the symbol is exported from its module
+    pub is_public: bool,
+}
+
+/// Find top-level functions and types that are never referenced, leaving out
+/// public items
+pub async fn find_unused_symbols(
+    files: &[(PathBuf, String)],
+    plugin: &dyn LanguagePlugin,
+) -> Vec<UnusedSymbol> {
+    find_unused_symbols_with_options(files, plugin, &UnusedSymbolOptions::default()).await
+}
+
+/// Find top-level functions and types that are never referenced
+///
+/// Files the plugin fails to parse contribute no symbols but are still
+/// scanned for references. Test functions and `main` are never reported.
+pub async fn find_unused_symbols_with_options(
+    files: &[(PathBuf, String)],
+    plugin: &dyn LanguagePlugin,
+    options: &UnusedSymbolOptions,
+) -> Vec<UnusedSymbol> {
+    let syntax = LexicalSyntax::for_language(plugin.metadata().name);
+
+    let mut candidates = Vec::new();
+    for (path, content) in files {
+        let symbols = match plugin.parse_symbols_only(content).await {
+            Ok(symbols) => symbols,
+            Err(e) => {
+                debug!(file = %path.display(), error = %e, "Skipping unparsable file");
+                continue;
+            }
+        };
+        let lines: Vec<&str> = content.lines().collect();
+
+        for symbol in symbols {
+            if !is_reportable_kind(&symbol.kind) || symbol.is_test || symbol.name == "main" {
+                continue;
+            }
+            // Symbol lines are 0-based and may point at a doc comment or
+            // attribute above the declaration, so the declaration is the first
+            // occurrence of the name from that line on
+            let Some(line) = find_identifier_occurrences(content, &symbol.name, syntax)
+                .iter()
+                .map(|occurrence| occurrence.line - 1)
+                .find(|&line| line >= symbol.location.line)
+            else {
+                continue;
+            };
+            let Some(definition) = lines.get(line) else {
+                continue;
+            };
+            // Nested items (methods, items in inline modules) are indented
+            if definition.starts_with(char::is_whitespace) {
+                continue;
+            }
+            candidates.push(UnusedSymbol {
+                file: path.clone(),
+                is_public: is_public(definition),
+                name: symbol.name,
+                kind: symbol.kind,
+                line,
+            });
+        }
+    }
+
+    candidates
+        .into_iter()
+        .filter(|candidate| options.include_public || !candidate.is_public)
+        .filter(|candidate| !is_referenced(candidate, files, syntax))
+        .collect()
+}
+
+fn is_reportable_kind(kind: &SymbolKind) -> bool {
+    matches!(
+        kind,
+        SymbolKind::Function
+            | SymbolKind::Class
+            | SymbolKind::Struct
+            | SymbolKind::Enum
+            | SymbolKind::Interface
+    )
+}
+
+/// Whether the declaration line starts with a visibility or export keyword
+fn is_public(definition: &str) -> bool {
+    ["pub ", "pub(", "public ", "export "]
+        .iter()
+        .any(|keyword| definition.starts_with(keyword))
+}
+
+/// Whether `symbol` occurs anywhere besides its own definition
+///
+/// The definition is the occurrence of the name on the symbol's line in its
+/// own file.
+fn is_referenced(
+    symbol: &UnusedSymbol,
+    files: &[(PathBuf, String)],
+    syntax: LexicalSyntax,
+) -> bool {
+    files.iter().any(|(path, content)| {
+        let occurrences = find_identifier_occurrences(content, &symbol.name, syntax);
+        if path != &symbol.file {
+            return !occurrences.is_empty();
+        }
+        // Occurrence lines are 1-based, symbol lines 0-based
+        let has_definition = occurrences
+            .iter()
+            .any(|occurrence| occurrence.line == symbol.line + 1);
+        let definitions = usize::from(has_definition);
+        occurrences.len() > definitions
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mill_lang_rust::RustPlugin;
+
+    fn fixture() -> Vec<(PathBuf, String)> {
+        vec![
+            (
+                PathBuf::from("src/main.rs"),
+                "mod util;\n\nuse util::helper;\n\nfn main() {\n    println!(\"{}\", helper());\n}\n"
+                    .to_string(),
+            ),
+            (
+                PathBuf::from("src/util.rs"),
+                "pub fn helper() -> u32 {\n    used_inside()\n}\n\nfn used_inside() -> u32 {\n    1\n}\n\n// dead() is only mentioned in comments and strings\nfn dead() -> &'static str {\n    \"dead\"\n}\n\npub fn exported() {}\n"
+                    .to_string(),
+            ),
+        ]
+    }
+
+    fn names(unused: &[UnusedSymbol]) -> Vec<&str> {
+        unused.iter().map(|symbol| symbol.name.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_reports_dead_private_function() {
+        let files = fixture();
+        let unused = find_unused_symbols(&files, &RustPlugin::default()).await;
+
+        assert_eq!(names(&unused), vec!["dead"]);
+        assert_eq!(unused[0].file, PathBuf::from("src/util.rs"));
+        assert_eq!(unused[0].line, 9);
+        assert!(!unused[0].is_public);
+    }
+
+    #[tokio::test]
+    async fn test_include_public_reports_unreferenced_exports() {
+        let files = fixture();
+        let options = UnusedSymbolOptions {
+            include_public: true,
+        };
+        let unused =
+            find_unused_symbols_with_options(&files, &RustPlugin::default(), &options).await;
+
+        assert_eq!(names(&unused), vec!["dead", "exported"]);
+        assert!(unused[1].is_public);
+    }
+
+    #[tokio::test]
+    async fn test_reports_documented_item_on_the_last_line() {
+        let files = vec![(
+            PathBuf::from("src/lib.rs"),
+            "fn used() {}\n\npub fn entry() {\n    used();\n}\n\n/// Never called\n#[inline]\npub fn last() {}"
+                .to_string(),
+        )];
+        let options = UnusedSymbolOptions {
+            include_public: true,
+        };
+        let unused =
+            find_unused_symbols_with_options(&files, &RustPlugin::default(), &options).await;
+
+        assert_eq!(names(&unused), vec!["entry", "last"]);
+        assert_eq!(unused[1].line, 8);
+        assert!(unused[1].is_public);
+    }
+}
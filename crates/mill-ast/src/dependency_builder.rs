@@ -0,0 +1,276 @@
+//! Project-wide `DependencyGraph` construction from language plugins
+//!
+//! Walks a directory (honoring `.gitignore`), asks the plugin matching each
+//! file for its detailed imports, and resolves every import to a file of the
+//! project. Nodes are paths relative to the walked root with `/` separators;
+//! edges carry the `ImportInfo` of the import, including the imported names.
+//! Imports the plugin classifies as external, or that don't resolve to a
+//! walked file, are left out of the graph.
+
+use crate::error::{AstError, AstResult};
+use crate::parser::{detect_cycles, DependencyGraph};
+use ignore::WalkBuilder;
+use mill_foundation::protocol::ImportInfo;
+use mill_plugin_api::{LanguagePlugin, PluginDiscovery};
+use petgraph::Graph;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+/// Build the dependency graph of every plugin-handled file under `root`
+///
+/// Files that can't be read or whose imports can't be analyzed still become
+/// nodes, just without outgoing edges.
+pub fn build_dependency_graph(
+    root: &Path,
+    plugins: &PluginDiscovery,
+) -> AstResult<DependencyGraph> {
+    if !root.is_dir() {
+        return Err(AstError::analysis(format!(
+            "Not a directory: {}",
+            root.display()
+        )));
+    }
+
+    let files: Vec<(PathBuf, &dyn LanguagePlugin)> = WalkBuilder::new(root)
+        .git_ignore(true)
+        .require_git(false)
+        .build()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_some_and(|ft| ft.is_file()))
+        .filter_map(|entry| {
+            let plugin = entry
+                .path()
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .and_then(|ext| plugins.find_by_extension(ext))?;
+            Some((entry.into_path(), plugin))
+        })
+        .collect();
+    let known: HashSet<PathBuf> = files.iter().map(|(path, _)| path.clone()).collect();
+
+    let mut graph = Graph::new();
+    let mut file_nodes = HashMap::new();
+    for (path, _) in &files {
+        let name = relative_name(root, path);
+        file_nodes.insert(name.clone(), graph.add_node(name));
+    }
+
+    for (path, plugin) in &files {
+        let source = match std::fs::read_to_string(path) {
+            Ok(source) => source,
+            Err(e) => {
+                debug!(file = %path.display(), error = %e, "Skipping unreadable file");
+                continue;
+            }
+        };
+        let imports = match plugin.analyze_detailed_imports(&source, Some(path)) {
+            Ok(imports) => imports,
+            Err(e) => {
+                debug!(file = %path.display(), error = %e, "Skipping file with unanalyzable imports");
+                continue;
+            }
+        };
+
+        let source_node = file_nodes[&relative_name(root, path)];
+        let resolver = ImportResolver {
+            root,
+            file: path,
+            separator: plugin.metadata().module_separator,
+            extensions: plugin.metadata().extensions,
+            known: &known,
+            on_disk: false,
+        };
+        for import in imports.imports {
+            if imports
+                .metadata
+                .external_dependencies
+                .contains(&import.module_path)
+            {
+                continue;
+            }
+            let Some(target) = resolver.resolve(&import) else {
+                debug!(
+                    file = %path.display(),
+                    module = %import.module_path,
+                    "Skipping unresolved import"
+                );
+                continue;
+            };
+            if target != *path {
+                let target_node = file_nodes[&relative_name(root, &target)];
+                graph.add_edge(source_node, target_node, import);
+            }
+        }
+    }
+
+    let languages = files
+        .iter()
+        .filter_map(|(path, plugin)| {
+            let ext = path.extension()?.to_str()?;
+            Some((ext.to_string(), *plugin.metadata()))
+        })
+        .collect();
+    let circular_dependencies = detect_cycles(&graph, &file_nodes);
+    Ok(DependencyGraph {
+        graph,
+        file_nodes,
+        circular_dependencies,
+        root: Some(root.to_path_buf()),
+        languages,
+        import_targets: HashSet::new(),
+    })
+}
+
+pub(crate) fn relative_name(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Maps the imports of one file to files of the project
+pub(crate) struct ImportResolver<'a> {
+    pub(crate) root: &'a Path,
+    pub(crate) file: &'a Path,
+    pub(crate) separator: &'a str,
+    pub(crate) extensions: &'a [&'a str],
+    pub(crate) known: &'a HashSet<PathBuf>,
+    /// Also accept files that exist on disk but are not in `known`
+    pub(crate) on_disk: bool,
+}
+
+impl ImportResolver<'_> {
+    pub(crate) fn resolve(&self, import: &ImportInfo) -> Option<PathBuf> {
+        let module = import.module_path.as_str();
+        let source_dir = self.file.parent()?;
+
+        // Path-style imports (`./utils`, `../lib/api`)
+        if module.starts_with("./") || module.starts_with("../") {
+            return self.file_for(&source_dir.join(module));
+        }
+
+        let (bases, segments) = self.anchor(module, source_dir)?;
+
+        // A `use a::b` style import names its last segment separately, so try
+        // the imported name as one more segment before the module path alone
+        let mut candidates = Vec::new();
+        for named in &import.named_imports {
+            let mut with_name = segments.clone();
+            with_name.push(named.name.as_str());
+            candidates.push(with_name);
+        }
+        candidates.push(segments);
+
+        for segments in candidates {
+            for len in (1..=segments.len()).rev() {
+                for base in &bases {
+                    let path = segments[..len]
+                        .iter()
+                        .fold(base.clone(), |path, segment| path.join(segment));
+                    if let Some(found) = self.file_for(&path) {
+                        return Some(found);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Split a module path into the directories it is relative to and the
+    /// remaining segments
+    fn anchor<'m>(
+        &self,
+        module: &'m str,
+        source_dir: &Path,
+    ) -> Option<(Vec<PathBuf>, Vec<&'m str>)> {
+        // Python-style relative imports: `.sibling`, `..package.module`
+        if self.separator == "." && module.starts_with('.') {
+            let rest = module.trim_start_matches('.');
+            let levels = module.len() - rest.len();
+            let base = source_dir.ancestors().nth(levels - 1)?.to_path_buf();
+            let segments = rest.split('.').filter(|s| !s.is_empty()).collect();
+            return Some((vec![base], segments));
+        }
+
+        let mut segments: Vec<&str> = module.split(self.separator).collect();
+        let bases = match segments.first().copied() {
+            Some("crate") => {
+                segments.remove(0);
+                vec![self.crate_root(source_dir)]
+            }
+            Some("self") | Some("super") => {
+                let mut dir = self.module_dir();
+                while segments.first() == Some(&"super") {
+                    segments.remove(0);
+                    dir = dir.parent()?.to_path_buf();
+                }
+                if segments.first() == Some(&"self") {
+                    segments.remove(0);
+                }
+                vec![dir]
+            }
+            _ => vec![self.root.to_path_buf(), source_dir.to_path_buf()],
+        };
+        Some((bases, segments))
+    }
+
+    /// Directory holding the crate root file (`lib.rs` or `main.rs`)
+    fn crate_root(&self, source_dir: &Path) -> PathBuf {
+        source_dir
+            .ancestors()
+            .take_while(|dir| dir.starts_with(self.root))
+            .find(|dir| {
+                self.known.contains(&dir.join("lib.rs"))
+                    || self.known.contains(&dir.join("main.rs"))
+            })
+            .unwrap_or(source_dir)
+            .to_path_buf()
+    }
+
+    /// Directory holding the child modules of the current file
+    fn module_dir(&self) -> PathBuf {
+        let parent = self.file.parent().unwrap_or(self.root);
+        match self.file.file_stem().and_then(|stem| stem.to_str()) {
+            Some("mod") | Some("lib") | Some("main") | Some("__init__") | Some("index") | None => {
+                parent.to_path_buf()
+            }
+            Some(stem) => parent.join(stem),
+        }
+    }
+
+    /// The project file a module path without extension refers to
+    fn file_for(&self, path: &Path) -> Option<PathBuf> {
+        let path = normalize(path);
+        let mut candidates = vec![path.clone()];
+        for ext in self.extensions {
+            let mut with_ext = path.clone().into_os_string();
+            with_ext.push(".");
+            with_ext.push(ext);
+            candidates.push(with_ext.into());
+        }
+        for index in ["mod", "__init__", "index"] {
+            for ext in self.extensions {
+                candidates.push(path.join(format!("{}.{}", index, ext)));
+            }
+        }
+        candidates.into_iter().find(|candidate| {
+            self.known.contains(candidate) || (self.on_disk && candidate.is_file())
+        })
+    }
+}
+
+/// Resolve `.` and `..` components without touching the file system
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
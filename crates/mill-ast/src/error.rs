@@ -61,7 +61,8 @@ impl From<AstError> for MillError {
 impl From<mill_plugin_api::PluginApiError> for AstError {
     fn from(err: mill_plugin_api::PluginApiError) -> Self {
         match err {
-            mill_plugin_api::PluginApiError::Parse { message, .. } => Self::Parse { message },
+            // Carried as the core parse error so its position and snippet survive
+            err @ mill_plugin_api::PluginApiError::Parse { .. } => Self::Core(err.into()),
             mill_plugin_api::PluginApiError::Manifest { message } => Self::Analysis { message },
             mill_plugin_api::PluginApiError::NotSupported { operation } => {
                 Self::UnsupportedSyntax { feature: operation }
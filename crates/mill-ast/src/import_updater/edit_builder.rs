@@ -2,12 +2,15 @@ use crate::error::AstResult;
 use crate::import_updater::{
     file_scanner,
     path_resolver::ImportPathResolver,
+    reference_cache::ReferenceScanCache,
     reference_finder::{create_text_edits_from_references, find_inline_crate_references},
 };
 use mill_foundation::protocol::{EditPlan, EditPlanMetadata};
+use mill_plugin_api::ReferenceKind;
 use std::path::{Path, PathBuf};
 use tracing::{debug, info, warn};
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn build_import_update_plan(
     old_path: &Path,
     new_path: &Path,
@@ -16,6 +19,7 @@ pub(crate) async fn build_import_update_plan(
     rename_info: Option<&serde_json::Value>,
     dry_run: bool,
     scan_scope: Option<mill_plugin_api::ScanScope>,
+    reference_cache: &ReferenceScanCache,
 ) -> AstResult<mill_foundation::protocol::EditPlan> {
     let resolver = ImportPathResolver::with_plugins(project_root, plugins.to_vec());
 
@@ -132,8 +136,14 @@ pub(crate) async fn build_import_update_plan(
                     // Find module references using the capability trait
                     // This works for any language plugin that implements ModuleReferenceScanner
                     if let Some(scanner) = plugin.module_reference_scanner() {
-                        if let Ok(refs) = scanner.scan_references(&content, module_name, scope) {
-                            if !refs.is_empty() {
+                        if let Ok(refs) = reference_cache.scan(
+                            plugin.metadata().name,
+                            scanner,
+                            &content,
+                            module_name,
+                            scope,
+                        ) {
+                            if refs.iter().any(|r| r.kind != ReferenceKind::Comment) {
                                 debug!(
                                     file = ?file_path,
                                     references = refs.len(),
@@ -233,14 +243,26 @@ pub(crate) async fn build_import_update_plan(
         // PRIORITY 2: directory renames (for path-based imports)
         // PRIORITY 3: file renames (for path-based imports)
         if let Some(scope) = scan_scope {
+            // Starts of the references the scanner already turned into edits
+            let mut scanned: Vec<(usize, usize)> = Vec::new();
             // Use find_module_references for precise edits (works for both file and directory renames)
             // Use capability trait for language-agnostic module reference scanning
             if let Some(scanner) = plugin.module_reference_scanner() {
-                let refs = scanner
-                    .scan_references(&content, old_module_name, scope)
+                let refs = reference_cache
+                    .scan(
+                        plugin.metadata().name,
+                        scanner,
+                        &content,
+                        old_module_name,
+                        scope,
+                    )
                     .ok();
 
-                if let Some(refs) = refs {
+                if let Some(mut refs) = refs {
+                    // Comment mentions are prose, not imports; comment updates
+                    // are a separate, opt-in pass
+                    refs.retain(|r| r.kind != ReferenceKind::Comment);
+                    scanned.extend(refs.iter().map(|r| (r.line, r.column)));
                     if !refs.is_empty() {
                         let edits = create_text_edits_from_references(
                             &refs,
@@ -263,8 +285,10 @@ pub(crate) async fn build_import_update_plan(
             // This catches references like `old_crate::module::function()`
             // that are NOT in import statements
             if old_crate_name != new_crate_name {
-                let inline_refs =
+                let mut inline_refs =
                     find_inline_crate_references(&content, &file_path, &old_crate_name);
+                // Qualified paths the scanner reported must not be edited twice
+                inline_refs.retain(|r| !scanned.contains(&(r.line, r.column)));
 
                 if !inline_refs.is_empty() {
                     debug!(
@@ -3,6 +3,7 @@
 pub mod edit_builder;
 pub mod file_scanner;
 pub mod path_resolver;
+pub mod reference_cache;
 pub mod reference_finder;
 
 #[cfg(test)]
@@ -13,10 +14,14 @@ use std::path::Path;
 
 pub use file_scanner::find_project_files;
 pub use path_resolver::ImportPathResolver;
+pub use reference_cache::ReferenceScanCache;
 
 /// Update import paths in all affected files after a file/directory rename
 ///
-/// Returns an EditPlan that can be applied via FileService.apply_edit_plan()
+/// Returns an EditPlan that can be applied via FileService.apply_edit_plan().
+/// Module reference scans go through `reference_cache`, so files unchanged
+/// since an earlier call are not re-scanned.
+#[allow(clippy::too_many_arguments)]
 pub async fn update_imports_for_rename(
     old_path: &Path,
     new_path: &Path,
@@ -25,6 +30,7 @@ pub async fn update_imports_for_rename(
     rename_info: Option<&serde_json::Value>,
     dry_run: bool,
     scan_scope: Option<mill_plugin_api::ScanScope>,
+    reference_cache: &ReferenceScanCache,
 ) -> AstResult<mill_foundation::protocol::EditPlan> {
     edit_builder::build_import_update_plan(
         old_path,
@@ -34,6 +40,7 @@ pub async fn update_imports_for_rename(
         rename_info,
         dry_run,
         scan_scope,
+        reference_cache,
     )
     .await
 }
@@ -0,0 +1,209 @@
+//! Cache for module reference scans
+//!
+//! Plugin scanners re-scan the whole file on every call. This cache wraps
+//! [`ModuleReferenceScanner::scan_references`] and keys results by the plugin
+//! that scanned, the length and hash of the file content, the module name and
+//! the scan scope, so repeated queries against unchanged files skip the scan.
+//! An edited file hashes differently and misses the cache.
+
+use dashmap::DashMap;
+use mill_plugin_api::{ModuleReference, ModuleReferenceScanner, PluginResult, ScanScope};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tracing::trace;
+
+/// Entries kept before the cache is cleared
+const MAX_ENTRIES: usize = 10_000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ScanKey {
+    plugin: String,
+    content_len: usize,
+    content_hash: u64,
+    module_name: String,
+    scope: ScanScope,
+}
+
+/// Thread-safe cache of module reference scan results
+#[derive(Debug, Default)]
+pub struct ReferenceScanCache {
+    entries: DashMap<ScanKey, Vec<ModuleReference>>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl ReferenceScanCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scan `content` for references to `module_name`, reusing a cached result
+    ///
+    /// `plugin` names the plugin `scanner` belongs to, so the same file scanned
+    /// by different plugins is cached separately. Failed scans are not cached.
+    pub fn scan(
+        &self,
+        plugin: &str,
+        scanner: &dyn ModuleReferenceScanner,
+        content: &str,
+        module_name: &str,
+        scope: ScanScope,
+    ) -> PluginResult<Vec<ModuleReference>> {
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        let key = ScanKey {
+            plugin: plugin.to_string(),
+            content_len: content.len(),
+            content_hash: hasher.finish(),
+            module_name: module_name.to_string(),
+            scope,
+        };
+
+        if let Some(refs) = self.entries.get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            trace!(module_name = %module_name, "Reference scan cache hit");
+            return Ok(refs.clone());
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let refs = scanner.scan_references(content, module_name, scope)?;
+        if self.entries.len() >= MAX_ENTRIES {
+            self.entries.clear();
+        }
+        self.entries.insert(key, refs.clone());
+        Ok(refs)
+    }
+
+    /// Number of scans answered from the cache
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Number of scans that ran the plugin scanner
+    pub fn misses(&self) -> usize {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Number of cached scan results
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache holds no scan results
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drop all cached scan results
+    pub fn clear(&self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mill_plugin_api::ReferenceKind;
+
+    /// Scanner that counts how often it runs
+    #[derive(Default)]
+    struct CountingScanner {
+        calls: AtomicUsize,
+    }
+
+    impl ModuleReferenceScanner for CountingScanner {
+        fn scan_references(
+            &self,
+            content: &str,
+            module_name: &str,
+            _scope: ScanScope,
+        ) -> PluginResult<Vec<ModuleReference>> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            Ok(content
+                .lines()
+                .enumerate()
+                .filter_map(|(i, line)| {
+                    line.find(module_name).map(|column| ModuleReference {
+                        line: i + 1,
+                        column,
+                        length: module_name.len(),
+                        text: module_name.to_string(),
+                        kind: ReferenceKind::Declaration,
+                    })
+                })
+                .collect())
+        }
+    }
+
+    #[test]
+    fn test_reference_scan_cache_hits_until_content_changes() {
+        let cache = ReferenceScanCache::new();
+        let scanner = CountingScanner::default();
+        let content = "use utils::helper;\n";
+
+        let first = cache
+            .scan(
+                "test",
+                &scanner,
+                content,
+                "utils",
+                ScanScope::AllUseStatements,
+            )
+            .unwrap();
+        let second = cache
+            .scan(
+                "test",
+                &scanner,
+                content,
+                "utils",
+                ScanScope::AllUseStatements,
+            )
+            .unwrap();
+        assert_eq!(scanner.calls.load(Ordering::Relaxed), 1);
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+        assert_eq!(first.len(), 1);
+        assert_eq!(second.len(), 1);
+
+        // A different scope is a different query
+        cache
+            .scan(
+                "test",
+                &scanner,
+                content,
+                "utils",
+                ScanScope::QualifiedPaths,
+            )
+            .unwrap();
+        assert_eq!(scanner.calls.load(Ordering::Relaxed), 2);
+
+        let edited = "use std::fmt;\nuse utils::helper;\n";
+        let refs = cache
+            .scan(
+                "test",
+                &scanner,
+                edited,
+                "utils",
+                ScanScope::AllUseStatements,
+            )
+            .unwrap();
+        assert_eq!(scanner.calls.load(Ordering::Relaxed), 3);
+        assert_eq!(cache.misses(), 3);
+        assert_eq!(refs[0].line, 2);
+    }
+
+    #[test]
+    fn test_reference_scan_cache_separates_plugins() {
+        let cache = ReferenceScanCache::new();
+        let scanner = CountingScanner::default();
+        let content = "import utils\n";
+
+        cache
+            .scan("python", &scanner, content, "utils", ScanScope::All)
+            .unwrap();
+        cache
+            .scan("typescript", &scanner, content, "utils", ScanScope::All)
+            .unwrap();
+        assert_eq!(scanner.calls.load(Ordering::Relaxed), 2);
+        assert_eq!(cache.len(), 2);
+    }
+}
@@ -31,6 +31,7 @@ pub(crate) fn create_text_edits_from_references(
                     mill_plugin_api::ReferenceKind::Declaration => "import",
                     mill_plugin_api::ReferenceKind::QualifiedPath => "qualified path",
                     mill_plugin_api::ReferenceKind::StringLiteral => "string literal",
+                    mill_plugin_api::ReferenceKind::Comment => "comment",
                 },
                 old_module_name,
                 new_module_name
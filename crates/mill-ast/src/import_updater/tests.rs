@@ -220,3 +220,45 @@ async fn test_get_all_imported_files() {
         "Should include types.ts"
     );
 }
+
+#[tokio::test]
+async fn test_move_leaves_comment_mentions_alone() {
+    use mill_plugin_api::{LanguagePlugin, ScanScope};
+    use std::fs;
+
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join("src/lib.rs"), "mod app;\nmod utils;\n").unwrap();
+    fs::write(root.join("src/utils.rs"), "pub fn helper() {}\n").unwrap();
+    let app = root.join("src/app.rs");
+    fs::write(
+        &app,
+        "// utils holds the shared helpers\nuse crate::utils::helper;\n\npub fn run() {\n    helper();\n}\n",
+    )
+    .unwrap();
+
+    let plugins: Vec<Arc<dyn LanguagePlugin>> =
+        vec![Arc::new(mill_lang_rust::RustPlugin::default())];
+    let plan = update_imports_for_rename(
+        &root.join("src/utils.rs"),
+        &root.join("src/tools.rs"),
+        &root,
+        &plugins,
+        None,
+        true,
+        Some(ScanScope::All),
+        &ReferenceScanCache::new(),
+    )
+    .await
+    .unwrap();
+
+    let app_path = app.to_string_lossy().to_string();
+    let app_lines: Vec<u32> = plan
+        .edits
+        .iter()
+        .filter(|edit| edit.file_path.as_deref() == Some(app_path.as_str()))
+        .map(|edit| edit.location.start_line)
+        .collect();
+    assert_eq!(app_lines, vec![1], "{:#?}", plan.edits);
+}
@@ -7,7 +7,10 @@
 
 pub mod analyzer;
 pub mod cache;
+pub mod circular_deps;
 pub mod complexity;
+pub mod dead_code;
+pub mod dependency_builder;
 pub mod error;
 pub mod import_updater;
 pub mod package_extractor; // Now language-agnostic using capability-based dispatch
@@ -21,11 +24,24 @@ pub use analyzer::plan_refactor;
 // Cache
 pub use cache::{AstCache, CacheKey, CacheSettings, CachedEntry};
 
+// Circular dependency detection
+pub use circular_deps::{
+    find_circular_dependencies, CircularDependenciesResult, CircularDependenciesSummary, Cycle,
+    CycleDetectionOptions, FailOn,
+};
+
+// Unused symbol detection
+pub use dead_code::{
+    find_unused_symbols, find_unused_symbols_with_options, UnusedSymbol, UnusedSymbolOptions,
+};
+
 // Error types
 pub use error::{AstError, AstResult};
 
 // Import utilities
-pub use import_updater::{find_project_files, update_imports_for_rename, ImportPathResolver};
+pub use import_updater::{
+    find_project_files, update_imports_for_rename, ImportPathResolver, ReferenceScanCache,
+};
 
 // Parser
 pub use parser::{build_dependency_graph, build_import_graph, DependencyGraph};
@@ -2,6 +2,7 @@ use crate::error::AstError;
 use crate::package_extractor::ExtractModuleToPackageParams;
 use futures::stream::StreamExt;
 use mill_foundation::protocol::{EditLocation, EditType, TextEdit};
+use mill_lang_common::normalize_module_path;
 use mill_plugin_api::LanguagePlugin;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -246,12 +247,12 @@ pub(crate) async fn add_import_update_edits(
         .filter(|f| !located_files_set.contains(f))
         .collect();
 
-    let module_path = params.module_path.clone();
+    let patterns_to_match = import_prefixes(&params.module_path);
     let target_package_name = params.target_package_name.clone();
 
     let new_edits = futures::stream::iter(files_to_scan)
         .map(|file_path| {
-            let module_path = module_path.clone();
+            let patterns_to_match = patterns_to_match.clone();
             let target_package_name = target_package_name.clone();
 
             async move {
@@ -276,13 +277,6 @@ pub(crate) async fn add_import_update_edits(
                     );
 
                     for import_path in import_paths {
-                        let module_path_normalized = module_path.replace('.', "::");
-                        let patterns_to_match = [
-                            format!("crate::{}", module_path_normalized),
-                            format!("self::{}", module_path_normalized),
-                            module_path_normalized.clone(),
-                        ];
-
                         let is_match = patterns_to_match
                             .iter()
                             .any(|pattern| import_path.starts_with(pattern));
@@ -410,3 +404,15 @@ pub(crate) async fn add_import_update_edits(
     edits.extend(new_edits);
     Ok(())
 }
+
+/// Prefixes of the imports that refer to `module_path`
+///
+/// The module path may use `.`, `/` or `::`; imports are matched as `a::b`.
+pub(crate) fn import_prefixes(module_path: &str) -> [String; 3] {
+    let module_path = normalize_module_path(module_path, "::");
+    [
+        format!("crate::{}", module_path),
+        format!("self::{}", module_path),
+        module_path,
+    ]
+}
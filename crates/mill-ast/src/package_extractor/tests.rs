@@ -410,3 +410,19 @@ version = "0.1.0"
         "Should NOT have workspace Cargo.toml edit when is_workspace_member=false"
     );
 }
+
+#[test]
+fn test_import_prefixes_accept_any_module_separator() {
+    for module_path in ["services.planner", "services/planner", "services::planner"] {
+        assert_eq!(
+            edits::import_prefixes(module_path),
+            [
+                "crate::services::planner".to_string(),
+                "self::services::planner".to_string(),
+                "services::planner".to_string(),
+            ],
+            "{}",
+            module_path
+        );
+    }
+}
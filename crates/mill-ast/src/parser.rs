@@ -3,10 +3,11 @@ use crate::error::AstResult;
 use mill_foundation::protocol::{
     ImportGraph, ImportGraphMetadata, ImportInfo, ImportType, NamedImport, SourceLocation,
 };
+use mill_plugin_api::LanguageMetadata;
 use petgraph::graph::NodeIndex;
 use petgraph::{Direction, Graph};
 use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 /// Build import graph for a source file
 pub fn build_import_graph(source: &str, path: &Path) -> AstResult<ImportGraph> {
     // Note: Only Rust and TypeScript supported after language reduction
@@ -288,6 +289,9 @@ pub fn build_dependency_graph(import_graphs: &[ImportGraph]) -> DependencyGraph
         graph,
         file_nodes,
         circular_dependencies,
+        root: None,
+        languages: HashMap::new(),
+        import_targets: HashSet::new(),
     }
 }
 /// Dependency graph structure
@@ -295,8 +299,114 @@ pub struct DependencyGraph {
     pub graph: Graph<String, ImportInfo>,
     pub file_nodes: HashMap<String, NodeIndex>,
     pub circular_dependencies: Vec<Vec<String>>,
+    /// Directory the node names are relative to, if the graph was built from one
+    pub root: Option<PathBuf>,
+    /// Metadata of the plugin handling each file extension, used by
+    /// `update_module` to resolve imports
+    pub languages: HashMap<String, LanguageMetadata>,
+    /// Nodes added by `update_module` only because a module imports them;
+    /// they are pruned once nothing does
+    pub import_targets: HashSet<String>,
 }
 impl DependencyGraph {
+    /// Replace the imports of a single module
+    ///
+    /// Removes the module's outgoing edges and adds one edge per import in
+    /// `new_imports`. The module is added if it isn't in the graph yet. Edges
+    /// of other modules are left alone; `circular_dependencies` is recomputed.
+    ///
+    /// `path` is named like the graph's nodes, relative to the project root
+    /// for graphs from [`crate::dependency_builder::build_dependency_graph`].
+    /// Imports are resolved to files the same way that builder does, with the
+    /// module separator and extensions in `languages` for the file's
+    /// extension. When the graph has a `root`, an import may also resolve to a
+    /// file created since the build: its node is added as an import target
+    /// and pruned again once no module imports it.
+    ///
+    /// Returns the imports that resolve to no file, such as external packages
+    /// and missing modules.
+    pub fn update_module(&mut self, path: &Path, new_imports: &[ImportInfo]) -> Vec<ImportInfo> {
+        let name = path.to_string_lossy().replace('\\', "/");
+        self.import_targets.remove(&name);
+        let node = self.node_for(&name);
+
+        let old_targets = self.get_imports(&name);
+        while let Some(edge) = self.graph.first_edge(node, Direction::Outgoing) {
+            self.graph.remove_edge(edge);
+        }
+
+        let root = self.root.clone().unwrap_or_default();
+        let known: HashSet<PathBuf> = self.file_nodes.keys().map(|name| root.join(name)).collect();
+        let file = root.join(&name);
+        let language = Path::new(&name)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| self.languages.get(ext))
+            .copied();
+        let resolver = language.map(|metadata| crate::dependency_builder::ImportResolver {
+            root: &root,
+            file: &file,
+            separator: metadata.module_separator,
+            extensions: metadata.extensions,
+            known: &known,
+            on_disk: self.root.is_some(),
+        });
+
+        let mut unresolved = Vec::new();
+        for import in new_imports {
+            let Some(target) = resolver
+                .as_ref()
+                .and_then(|resolver| resolver.resolve(import))
+            else {
+                tracing::debug!(
+                    module = %name,
+                    import = %import.module_path,
+                    "Skipping unresolved import"
+                );
+                unresolved.push(import.clone());
+                continue;
+            };
+            let target = crate::dependency_builder::relative_name(&root, &target);
+            if target == name {
+                continue;
+            }
+            if !self.file_nodes.contains_key(&target) {
+                self.import_targets.insert(target.clone());
+            }
+            let target = self.node_for(&target);
+            self.graph.add_edge(node, target, import.clone());
+        }
+
+        for target in old_targets {
+            if self.import_targets.contains(&target) && self.get_importers(&target).is_empty() {
+                self.remove_node(&target);
+            }
+        }
+
+        self.circular_dependencies = detect_cycles(&self.graph, &self.file_nodes);
+        unresolved
+    }
+    /// Get the node of a module, adding it if needed
+    fn node_for(&mut self, name: &str) -> NodeIndex {
+        if let Some(&node) = self.file_nodes.get(name) {
+            return node;
+        }
+        let node = self.graph.add_node(name.to_string());
+        self.file_nodes.insert(name.to_string(), node);
+        node
+    }
+    /// Remove the node of a module, keeping `file_nodes` valid
+    fn remove_node(&mut self, name: &str) {
+        let Some(node) = self.file_nodes.remove(name) else {
+            return;
+        };
+        self.import_targets.remove(name);
+        self.graph.remove_node(node);
+        // petgraph moves the last node into the freed index
+        if let Some(moved) = self.graph.node_weight(node) {
+            self.file_nodes.insert(moved.clone(), node);
+        }
+    }
     /// Get all files that import the given file
     pub fn get_importers(&self, file_path: &str) -> Vec<String> {
         if let Some(&node) = self.file_nodes.get(file_path) {
@@ -356,7 +466,7 @@ fn resolve_import_path(
     None
 }
 /// Detect circular dependencies in the graph
-fn detect_cycles(
+pub(crate) fn detect_cycles(
     graph: &Graph<String, ImportInfo>,
     path_to_node: &HashMap<String, NodeIndex>,
 ) -> Vec<Vec<String>> {
@@ -465,5 +575,80 @@ mod tests {
         let importers = dep_graph.get_importers("b.ts");
         assert_eq!(importers.len(), 0);
     }
+    fn import(module_path: &str) -> ImportInfo {
+        ImportInfo {
+            module_path: module_path.to_string(),
+            import_type: ImportType::EsModule,
+            named_imports: vec![],
+            default_import: None,
+            namespace_import: None,
+            type_only: false,
+            location: SourceLocation {
+                start_line: 0,
+                start_column: 0,
+                end_line: 0,
+                end_column: 0,
+            },
+        }
+    }
+    fn graph_of(modules: &[(&str, &[&str])]) -> DependencyGraph {
+        let mut graph = build_dependency_graph(&[]);
+        graph.languages.insert(
+            "ts".to_string(),
+            mill_lang_typescript::TypeScriptPlugin::METADATA,
+        );
+        // Register every module first so relative imports resolve to them
+        for (module, _) in modules {
+            graph.update_module(Path::new(module), &[]);
+        }
+        for (module, imports) in modules {
+            let imports: Vec<ImportInfo> = imports.iter().map(|m| import(m)).collect();
+            assert!(graph.update_module(Path::new(module), &imports).is_empty());
+        }
+        graph
+    }
+    fn sorted(mut names: Vec<String>) -> Vec<String> {
+        names.sort();
+        names
+    }
+    #[test]
+    fn test_update_module_only_changes_its_edges() {
+        let mut graph = graph_of(&[
+            ("src/a.ts", &["./b"]),
+            ("src/b.ts", &["./c"]),
+            ("src/c.ts", &[]),
+            ("src/d.ts", &["./c"]),
+        ]);
+        assert_eq!(graph.get_imports("src/a.ts"), vec!["src/b.ts"]);
+
+        let unresolved =
+            graph.update_module(Path::new("src/a.ts"), &[import("./c"), import("lodash")]);
+
+        assert_eq!(graph.get_imports("src/a.ts"), vec!["src/c.ts"]);
+        assert!(graph.get_importers("src/b.ts").is_empty());
+        assert_eq!(graph.get_imports("src/b.ts"), vec!["src/c.ts"]);
+        assert_eq!(graph.get_imports("src/d.ts"), vec!["src/c.ts"]);
+        assert_eq!(
+            sorted(graph.get_importers("src/c.ts")),
+            vec!["src/a.ts", "src/b.ts", "src/d.ts"]
+        );
+        // External packages are not modules of the graph
+        assert_eq!(unresolved, vec![import("lodash")]);
+        assert!(!graph.file_nodes.contains_key("lodash"));
+        assert_eq!(graph.graph.node_count(), 4);
+    }
+    #[test]
+    fn test_update_module_introduces_cycle() {
+        let mut graph = graph_of(&[("src/a.ts", &["./b"]), ("src/b.ts", &[])]);
+        assert!(graph.circular_dependencies.is_empty());
+
+        graph.update_module(Path::new("src/b.ts"), &[import("./a")]);
+
+        assert_eq!(graph.circular_dependencies.len(), 1);
+        assert_eq!(
+            sorted(graph.circular_dependencies[0].clone()),
+            vec!["src/a.ts", "src/b.ts"]
+        );
+    }
     // test_parse_named_imports_enhanced removed - see mill-lang-typescript plugin tests
 }
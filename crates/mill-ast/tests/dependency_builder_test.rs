@@ -0,0 +1,206 @@
+//! Integration test for building a project dependency graph from plugins
+
+use mill_ast::dependency_builder::build_dependency_graph;
+use mill_ast::{find_circular_dependencies, CycleDetectionOptions};
+use mill_lang_rust::RustPlugin;
+use mill_lang_typescript::TypeScriptPlugin;
+use mill_plugin_api::{LanguagePlugin, PluginDiscovery};
+use mill_test_support::harness::TestWorkspace;
+use std::sync::Arc;
+
+#[test]
+fn test_rust_cycle_is_built_and_detected() {
+    let dir = TestWorkspace::with_files(&[
+        (
+            "Cargo.toml",
+            "[package]\nname = \"fixture\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\nserde = \"1\"\n",
+        ),
+        ("src/lib.rs", "mod a;\nmod b;\nmod c;\n"),
+        (
+            "src/a.rs",
+            "use crate::b::from_b;\nuse serde::Serialize;\n\npub fn from_a() -> u32 {\n    from_b()\n}\n",
+        ),
+        (
+            "src/b.rs",
+            "use crate::a;\n\npub fn from_b() -> u32 {\n    a::from_a()\n}\n",
+        ),
+        (
+            "src/c.rs",
+            "use std::fmt;\nuse super::b::from_b;\n\npub fn from_c() -> u32 {\n    from_b()\n}\n",
+        ),
+    ]);
+    let mut plugins = PluginDiscovery::new();
+    plugins.register(Arc::new(RustPlugin::default()));
+
+    let graph = build_dependency_graph(dir.path(), &plugins).unwrap();
+
+    // External crates and std are not nodes
+    let mut nodes: Vec<&String> = graph.file_nodes.keys().collect();
+    nodes.sort();
+    assert_eq!(nodes, ["src/a.rs", "src/b.rs", "src/c.rs", "src/lib.rs"]);
+
+    assert_eq!(graph.get_imports("src/a.rs"), vec!["src/b.rs"]);
+    assert_eq!(graph.get_imports("src/b.rs"), vec!["src/a.rs"]);
+    assert_eq!(graph.get_imports("src/c.rs"), vec!["src/b.rs"]);
+
+    // Edges record the imported symbols
+    let a = graph.file_nodes["src/a.rs"];
+    let edge = graph.graph.edges(a).next().unwrap();
+    let names: Vec<&str> = edge
+        .weight()
+        .named_imports
+        .iter()
+        .map(|named| named.name.as_str())
+        .collect();
+    assert_eq!(names, ["from_b"]);
+
+    let result = find_circular_dependencies(&graph, &CycleDetectionOptions::default());
+    assert_eq!(result.cycles.len(), 1);
+    let mut cycle = result.cycles[0].modules.clone();
+    cycle.sort();
+    assert_eq!(cycle, ["src/a.rs", "src/b.rs"]);
+}
+
+#[test]
+fn test_update_module_resolves_like_the_builder() {
+    let dir = TestWorkspace::with_files(&[
+        ("src/lib.rs", "mod a;\nmod b;\nmod net;\n"),
+        ("src/a.rs", "pub fn from_a() {}\n"),
+        ("src/b.rs", "pub fn from_b() {}\n"),
+        ("src/net/mod.rs", "mod b;\n"),
+        ("src/net/b.rs", "pub fn from_net() {}\n"),
+    ]);
+    let plugin = Arc::new(RustPlugin::default());
+    let mut plugins = PluginDiscovery::new();
+    plugins.register(plugin.clone());
+    let mut graph = build_dependency_graph(dir.path(), &plugins).unwrap();
+    assert!(graph.get_imports("src/a.rs").is_empty());
+
+    let a = dir.path().join("src/a.rs");
+    let imports = plugin
+        .analyze_detailed_imports("use crate::b::from_b;\nuse std::fmt;\n", Some(&a))
+        .unwrap();
+    let unresolved = graph.update_module(std::path::Path::new("src/a.rs"), &imports.imports);
+
+    assert!(graph
+        .get_imports("src/a.rs")
+        .contains(&"src/b.rs".to_string()));
+    let unresolved: Vec<&str> = unresolved
+        .iter()
+        .map(|import| import.module_path.as_str())
+        .collect();
+    assert_eq!(unresolved, ["std"]);
+    assert!(!graph
+        .get_imports("src/a.rs")
+        .contains(&"src/net/b.rs".to_string()));
+    assert!(!graph.file_nodes.contains_key("crate::b"));
+}
+
+#[test]
+fn test_update_module_resolves_with_the_plugin_metadata() {
+    let dir = TestWorkspace::with_files(&[
+        ("src/lib.rs", "mod a;\nmod b;\n"),
+        ("src/a.rs", "pub fn from_a() {}\n"),
+        ("src/b.rs", "pub fn from_b() {}\n"),
+        ("src/b.js", "export function fromB() {}\n"),
+    ]);
+    let plugin = Arc::new(RustPlugin::default());
+    let mut plugins = PluginDiscovery::new();
+    plugins.register(plugin.clone());
+    plugins.register(Arc::new(TypeScriptPlugin::default()));
+    let mut graph = build_dependency_graph(dir.path(), &plugins).unwrap();
+
+    // A Rust import never resolves to the JavaScript file of the same name
+    let a = dir.path().join("src/a.rs");
+    let imports = plugin
+        .analyze_detailed_imports("use crate::b::from_b;\n", Some(&a))
+        .unwrap();
+    graph.update_module(std::path::Path::new("src/a.rs"), &imports.imports);
+
+    assert_eq!(graph.get_imports("src/a.rs"), vec!["src/b.rs"]);
+}
+
+#[test]
+fn test_update_module_matches_a_full_rebuild() {
+    let dir = TestWorkspace::with_files(&[
+        (
+            "Cargo.toml",
+            "[package]\nname = \"fixture\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\nserde = \"1\"\n",
+        ),
+        ("src/lib.rs", "mod a;\nmod b;\nmod c;\n"),
+        ("src/a.rs", "use crate::b::from_b;\n\npub fn from_a() {}\n"),
+        ("src/b.rs", "pub fn from_b() {}\n"),
+        ("src/c.rs", "pub fn from_c() {}\n"),
+    ]);
+    let plugin = Arc::new(RustPlugin::default());
+    let mut plugins = PluginDiscovery::new();
+    plugins.register(plugin.clone());
+    let mut graph = build_dependency_graph(dir.path(), &plugins).unwrap();
+
+    // External, std and missing modules are imported alongside a local one
+    let a = dir.path().join("src/a.rs");
+    let source =
+        "use crate::c::from_c;\nuse crate::missing::gone;\nuse serde::Serialize;\nuse std::fmt;\n\npub fn from_a() {}\n";
+    std::fs::write(&a, source).unwrap();
+    let imports = plugin.analyze_detailed_imports(source, Some(&a)).unwrap();
+    graph.update_module(std::path::Path::new("src/a.rs"), &imports.imports);
+
+    let rebuilt = build_dependency_graph(dir.path(), &plugins).unwrap();
+    assert_eq!(edges(&graph), edges(&rebuilt));
+    let mut nodes: Vec<&String> = graph.file_nodes.keys().collect();
+    let mut rebuilt_nodes: Vec<&String> = rebuilt.file_nodes.keys().collect();
+    nodes.sort();
+    rebuilt_nodes.sort();
+    assert_eq!(nodes, rebuilt_nodes);
+    assert_eq!(graph.get_imports("src/a.rs"), vec!["src/c.rs"]);
+}
+
+#[test]
+fn test_update_module_prunes_orphaned_import_targets() {
+    let dir = TestWorkspace::with_files(&[
+        ("src/lib.rs", "mod a;\nmod b;\n"),
+        ("src/a.rs", "pub fn from_a() {}\n"),
+        ("src/b.rs", "pub fn from_b() {}\n"),
+    ]);
+    let plugin = Arc::new(RustPlugin::default());
+    let mut plugins = PluginDiscovery::new();
+    plugins.register(plugin.clone());
+    let mut graph = build_dependency_graph(dir.path(), &plugins).unwrap();
+
+    // A file created after the build becomes a node once it is imported
+    std::fs::write(dir.path().join("src/fresh.rs"), "pub fn from_fresh() {}\n").unwrap();
+    let a = dir.path().join("src/a.rs");
+    let imports = plugin
+        .analyze_detailed_imports(
+            "use crate::b::from_b;\nuse crate::fresh::from_fresh;\n",
+            Some(&a),
+        )
+        .unwrap();
+    let unresolved = graph.update_module(std::path::Path::new("src/a.rs"), &imports.imports);
+    assert!(unresolved.is_empty());
+    let mut imported = graph.get_imports("src/a.rs");
+    imported.sort();
+    assert_eq!(imported, ["src/b.rs", "src/fresh.rs"]);
+
+    // Dropping the imports prunes the import target, not the module
+    graph.update_module(std::path::Path::new("src/a.rs"), &[]);
+
+    assert!(!graph.file_nodes.contains_key("src/fresh.rs"));
+    assert!(graph.file_nodes.contains_key("src/b.rs"));
+    assert_eq!(graph.graph.node_count(), graph.file_nodes.len());
+    for (name, &node) in &graph.file_nodes {
+        assert_eq!(&graph.graph[node], name);
+    }
+}
+
+/// Every edge of a graph as a sorted `(importer, imported)` list
+fn edges(graph: &mill_ast::DependencyGraph) -> Vec<(String, String)> {
+    let mut edges: Vec<(String, String)> = graph
+        .graph
+        .edge_indices()
+        .filter_map(|edge| graph.graph.edge_endpoints(edge))
+        .map(|(from, to)| (graph.graph[from].clone(), graph.graph[to].clone()))
+        .collect();
+    edges.sort();
+    edges
+}
@@ -8,12 +8,40 @@ use tracing::{debug, info, warn};
 /// Client configuration for connecting to mill server
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientConfig {
-    /// WebSocket server URL (e.g., "ws://localhost:3000")
+    /// WebSocket server URL (e.g., "ws://localhost:3000"), as written in the
+    /// config file; may contain `${VAR}` references expanded by [`Self::get_url`]
     pub url: Option<String>,
-    /// JWT authentication token
+    /// JWT authentication token, as written in the config file; may contain
+    /// `${VAR}` references expanded by [`Self::get_token`]
     pub token: Option<String>,
     /// Request timeout in milliseconds
     pub timeout_ms: Option<u64>,
+    /// Interval between keep-alive pings in milliseconds (0 disables pings)
+    pub keep_alive_interval_ms: Option<u64>,
+    /// How long to wait for a pong before treating the connection as lost
+    pub pong_timeout_ms: Option<u64>,
+    /// Upper bound for the delay between reconnection attempts in milliseconds
+    pub reconnect_max_backoff_ms: Option<u64>,
+    /// Give up reconnecting after this many attempts (unlimited when unset)
+    pub reconnect_max_attempts: Option<u32>,
+    /// Skip TLS certificate verification for `wss://` URLs (insecure)
+    #[serde(default)]
+    pub accept_invalid_certs: bool,
+    /// PEM file with extra CA certificates trusted for `wss://` URLs; may
+    /// contain `${VAR}` references expanded by [`Self::get_custom_ca_path`]
+    pub custom_ca_path: Option<PathBuf>,
+    /// Fields whose file value contains `${VAR}` references
+    #[serde(skip)]
+    pub interpolated_fields: Vec<InterpolatedField>,
+}
+
+/// A config field expanded from environment variables when read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterpolatedField {
+    /// Config field name, e.g. `token`
+    pub field: &'static str,
+    /// Environment variables referenced by the field
+    pub variables: Vec<String>,
 }
 
 impl Default for ClientConfig {
@@ -21,7 +49,14 @@ impl Default for ClientConfig {
         Self {
             url: None,
             token: None,
-            timeout_ms: Some(30000), // 30 seconds default
+            timeout_ms: Some(30000),               // 30 seconds default
+            keep_alive_interval_ms: Some(30000),   // 30 seconds default
+            pong_timeout_ms: Some(10000),          // 10 seconds default
+            reconnect_max_backoff_ms: Some(30000), // 30 seconds default
+            reconnect_max_attempts: None,
+            accept_invalid_certs: false,
+            custom_ca_path: None,
+            interpolated_fields: Vec::new(),
         }
     }
 }
@@ -38,21 +73,19 @@ impl ClientConfig {
     }
 
     /// Load configuration from file, falling back to defaults and environment variables
+    ///
+    /// Every variable in [`ENV_VARS`] is applied; one that does not parse is an error.
     pub async fn load() -> ClientResult<Self> {
         ConfigBuilder::new()
             .from_file_if_exists(Self::default_config_path()?)
             .await?
-            .with_env_overrides()
+            .from_env()?
             .build()
     }
 
     /// Create a client config with environment variable overrides
     pub async fn with_env_overrides() -> ClientResult<Self> {
-        ConfigBuilder::new()
-            .from_file_if_exists(Self::default_config_path()?)
-            .await?
-            .with_env_overrides()
-            .build()
+        Self::load().await
     }
 
     /// Create configuration from all available sources
@@ -99,13 +132,46 @@ impl ClientConfig {
             .await
             .map_err(|e| ClientError::ConfigError(format!("Failed to read config file: {}", e)))?;
 
-        let config: Self = serde_json::from_str(&content)
-            .map_err(|e| ClientError::ConfigError(format!("Failed to parse config file: {}", e)))?;
+        let config = Self::parse_file_content(&content)?;
 
         config.validate()?;
         Ok(config)
     }
 
+    /// Parse the JSON of a config file and record its environment references
+    ///
+    /// Fails when a referenced variable is unset and has no default. Values
+    /// keep their `${VAR}` templates, so saving the config writes them back
+    /// unchanged; they are expanded when read through the getters.
+    fn parse_file_content(content: &str) -> ClientResult<Self> {
+        let mut config: Self = serde_json::from_str(content)
+            .map_err(|e| ClientError::ConfigError(format!("Failed to parse config file: {}", e)))?;
+        config.interpolated_fields = config.env_references()?;
+        Ok(config)
+    }
+
+    /// Fields that reference environment variables, with the variable names
+    ///
+    /// Fails on malformed references and on unset variables without a default.
+    fn env_references(&self) -> ClientResult<Vec<InterpolatedField>> {
+        let ca_path = self.custom_ca_path.as_ref().and_then(|path| path.to_str());
+        let mut interpolated = Vec::new();
+        for (field, value) in [
+            ("url", self.url.as_deref()),
+            ("token", self.token.as_deref()),
+            ("custom_ca_path", ca_path),
+        ] {
+            let Some(value) = value else {
+                continue;
+            };
+            let (_, variables) = expand_env_vars(field, value, &|name| std::env::var(name).ok())?;
+            if !variables.is_empty() {
+                interpolated.push(InterpolatedField { field, variables });
+            }
+        }
+        Ok(interpolated)
+    }
+
     /// Save configuration to the default config file
     pub async fn save(&self) -> ClientResult<()> {
         let config_path = Self::default_config_path()?;
@@ -154,10 +220,14 @@ impl ClientConfig {
     }
 
     /// Validate the configuration
+    ///
+    /// The URL and custom CA path are expanded first, so a reference to an
+    /// unset environment variable in either fails validation. The token is
+    /// only expanded when read.
     pub fn validate(&self) -> ClientResult<()> {
         // Validate URL format if provided
-        if let Some(ref url) = self.url {
-            if let Err(e) = url::Url::parse(url) {
+        if let Some(url) = self.get_url_opt()? {
+            if let Err(e) = url::Url::parse(&url) {
                 return Err(ClientError::ConfigError(format!(
                     "Invalid URL format: {}",
                     e
@@ -165,6 +235,21 @@ impl ClientConfig {
             }
         }
 
+        if let Some(path) = self.get_custom_ca_path()? {
+            if !path.is_file() {
+                return Err(ClientError::ConfigError(format!(
+                    "Custom CA file not found: {}",
+                    path.display()
+                )));
+            }
+        }
+
+        if self.pong_timeout_ms == Some(0) {
+            return Err(ClientError::ConfigError(
+                "Pong timeout cannot be zero".to_string(),
+            ));
+        }
+
         // Validate timeout is reasonable
         if let Some(timeout) = self.timeout_ms {
             if timeout == 0 {
@@ -183,11 +268,18 @@ impl ClientConfig {
         Ok(())
     }
 
-    /// Get the URL, returning an error if not configured
-    pub fn get_url(&self) -> ClientResult<&str> {
+    /// Get the URL with environment references expanded, returning an error
+    /// if not configured or a referenced variable is unset
+    pub fn get_url(&self) -> ClientResult<String> {
+        self.get_url_opt()?
+            .ok_or_else(|| ClientError::ConfigError("No server URL configured".to_string()))
+    }
+
+    fn get_url_opt(&self) -> ClientResult<Option<String>> {
         self.url
             .as_deref()
-            .ok_or_else(|| ClientError::ConfigError("No server URL configured".to_string()))
+            .map(|url| expand_from_env("url", url))
+            .transpose()
     }
 
     /// Get the timeout in milliseconds
@@ -195,14 +287,74 @@ impl ClientConfig {
         self.timeout_ms.unwrap_or(30000)
     }
 
+    /// Get the keep-alive ping interval in milliseconds (0 when disabled)
+    pub fn get_keep_alive_interval_ms(&self) -> u64 {
+        self.keep_alive_interval_ms.unwrap_or(30000)
+    }
+
+    /// Get the pong timeout in milliseconds
+    pub fn get_pong_timeout_ms(&self) -> u64 {
+        self.pong_timeout_ms.unwrap_or(10000)
+    }
+
+    /// Get the maximum reconnect backoff in milliseconds
+    pub fn get_reconnect_max_backoff_ms(&self) -> u64 {
+        self.reconnect_max_backoff_ms.unwrap_or(30000)
+    }
+
+    /// Get the maximum number of reconnection attempts, if limited
+    pub fn get_reconnect_max_attempts(&self) -> Option<u32> {
+        self.reconnect_max_attempts
+    }
+
+    /// Whether TLS certificate verification is disabled
+    pub fn accepts_invalid_certs(&self) -> bool {
+        self.accept_invalid_certs
+    }
+
+    /// Get the path of the extra CA certificates, if configured, with
+    /// environment references expanded
+    pub fn get_custom_ca_path(&self) -> ClientResult<Option<PathBuf>> {
+        match self.custom_ca_path.as_ref() {
+            Some(path) => match path.to_str() {
+                Some(raw) => Ok(Some(PathBuf::from(expand_from_env("custom_ca_path", raw)?))),
+                None => Ok(Some(path.clone())),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Describe the effective TLS settings for display
+    pub fn tls_summary(&self) -> String {
+        let verification = if self.accept_invalid_certs {
+            "disabled (accepting invalid certificates)"
+        } else {
+            "enabled"
+        };
+        let ca = self
+            .custom_ca_path
+            .as_ref()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| "<system roots only>".to_string());
+
+        format!(
+            "Certificate verification: {}\nCustom CA: {}",
+            verification, ca
+        )
+    }
+
     /// Check if authentication token is available
     pub fn has_token(&self) -> bool {
         self.token.is_some()
     }
 
-    /// Get the authentication token
-    pub fn get_token(&self) -> Option<&str> {
-        self.token.as_deref()
+    /// Get the authentication token with environment references expanded,
+    /// returning an error if a referenced variable is unset
+    pub fn get_token(&self) -> ClientResult<Option<String>> {
+        self.token
+            .as_deref()
+            .map(|token| expand_from_env("token", token))
+            .transpose()
     }
 
     /// Set the URL
@@ -262,6 +414,164 @@ impl ClientConfig {
     }
 }
 
+/// Expand a config value's environment references from the process environment
+fn expand_from_env(field: &str, value: &str) -> ClientResult<String> {
+    expand_env_vars(field, value, &|name| std::env::var(name).ok()).map(|(expanded, _)| expanded)
+}
+
+/// Expand `${VAR}` and `${VAR:-default}` references in a config value
+///
+/// Returns the expanded value and the names of the referenced variables.
+fn expand_env_vars(
+    field: &str,
+    value: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> ClientResult<(String, Vec<String>)> {
+    let mut expanded = String::with_capacity(value.len());
+    let mut variables = Vec::new();
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        expanded.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find('}').ok_or_else(|| {
+            ClientError::ConfigError(format!("Unterminated '${{' in config field '{}'", field))
+        })?;
+        let reference = &after[..end];
+        let (name, default) = match reference.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (reference, None),
+        };
+        if name.is_empty() {
+            return Err(ClientError::ConfigError(format!(
+                "Empty variable name in config field '{}'",
+                field
+            )));
+        }
+
+        // Like the shell, `:-` also replaces a variable that is set but empty
+        let resolved = match default {
+            Some(default) => Some(
+                lookup(name)
+                    .filter(|v| !v.is_empty())
+                    .unwrap_or_else(|| default.to_string()),
+            ),
+            None => lookup(name),
+        };
+        match resolved {
+            Some(resolved) => expanded.push_str(&resolved),
+            None => {
+                return Err(ClientError::ConfigError(format!(
+                    "Environment variable '{}' referenced by config field '{}' is not set",
+                    name, field
+                )))
+            }
+        }
+        variables.push(name.to_string());
+        rest = &after[end + 1..];
+    }
+    expanded.push_str(rest);
+
+    Ok((expanded, variables))
+}
+
+/// An environment variable read by [`ConfigBuilder::from_env`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnvVar {
+    /// Variable name, e.g. `TYPEMILL_URL`
+    pub name: &'static str,
+    /// What the variable sets and the format it expects
+    pub description: &'static str,
+}
+
+/// Every environment variable recognized by the client configuration
+pub const ENV_VARS: &[EnvVar] = &[
+    EnvVar {
+        name: "TYPEMILL_URL",
+        description: "WebSocket server URL (ws:// or wss://)",
+    },
+    EnvVar {
+        name: "TYPEMILL_TOKEN",
+        description: "JWT authentication token",
+    },
+    EnvVar {
+        name: "TYPEMILL_TIMEOUT",
+        description: "Request timeout in milliseconds",
+    },
+    EnvVar {
+        name: "TYPEMILL_KEEP_ALIVE_INTERVAL_MS",
+        description: "Interval between keep-alive pings in milliseconds (0 disables pings)",
+    },
+    EnvVar {
+        name: "TYPEMILL_PONG_TIMEOUT_MS",
+        description: "How long to wait for a pong in milliseconds",
+    },
+    EnvVar {
+        name: "TYPEMILL_RECONNECT_MAX_BACKOFF_MS",
+        description: "Upper bound for the delay between reconnection attempts in milliseconds",
+    },
+    EnvVar {
+        name: "TYPEMILL_RECONNECT_MAX_ATTEMPTS",
+        description: "Number of reconnection attempts before giving up",
+    },
+    EnvVar {
+        name: "TYPEMILL_ACCEPT_INVALID_CERTS",
+        description: "Skip TLS certificate verification (true/false, insecure)",
+    },
+    EnvVar {
+        name: "TYPEMILL_CUSTOM_CA_PATH",
+        description: "PEM file with extra CA certificates trusted for wss:// URLs",
+    },
+];
+
+/// Set variables that look like misspellings of a recognized one
+///
+/// Returns each unknown name with the recognized variable it is closest to.
+/// Names too far from every recognized variable are not reported, so
+/// unrelated `TYPEMILL_*` settings read elsewhere stay quiet.
+pub fn env_var_typos<'a>(names: impl IntoIterator<Item = &'a str>) -> Vec<(String, &'static str)> {
+    names
+        .into_iter()
+        .filter(|name| !ENV_VARS.iter().any(|var| var.name == *name))
+        .filter_map(|name| {
+            ENV_VARS
+                .iter()
+                .map(|var| (edit_distance(name, var.name), var.name))
+                .filter(|(distance, _)| *distance <= 2)
+                .min()
+                .map(|(_, suggestion)| (name.to_string(), suggestion))
+        })
+        .collect()
+}
+
+/// Levenshtein distance between two names
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+fn parse_env_value<T: std::str::FromStr>(
+    name: &str,
+    value: &str,
+    expected: &str,
+) -> ClientResult<T> {
+    value.trim().parse().map_err(|_| {
+        ClientError::ConfigError(format!(
+            "Invalid value '{}' for {}: expected {}",
+            value, name, expected
+        ))
+    })
+}
+
 /// Configuration builder for fluent API
 pub struct ConfigBuilder {
     config: ClientConfig,
@@ -291,9 +601,7 @@ impl ConfigBuilder {
                 ClientError::ConfigError(format!("Failed to read config file: {}", e))
             })?;
 
-            self.config = serde_json::from_str(&content).map_err(|e| {
-                ClientError::ConfigError(format!("Failed to parse config file: {}", e))
-            })?;
+            self.config = ClientConfig::parse_file_content(&content)?;
         } else {
             debug!("No config file found at {}, using defaults", path.display());
         }
@@ -310,8 +618,7 @@ impl ConfigBuilder {
             .await
             .map_err(|e| ClientError::ConfigError(format!("Failed to read config file: {}", e)))?;
 
-        self.config = serde_json::from_str(&content)
-            .map_err(|e| ClientError::ConfigError(format!("Failed to parse config file: {}", e)))?;
+        self.config = ClientConfig::parse_file_content(&content)?;
 
         Ok(self)
     }
@@ -344,6 +651,64 @@ impl ConfigBuilder {
         self
     }
 
+    /// Apply every variable in [`ENV_VARS`] that is set in the environment
+    ///
+    /// Unlike [`ConfigBuilder::with_env_overrides`], values that do not parse
+    /// are an error rather than being ignored. Unknown variables that look
+    /// like a typo of a recognized one are logged with the suggested name.
+    pub fn from_env(self) -> ClientResult<Self> {
+        self.apply_env_vars(std::env::vars())
+    }
+
+    fn apply_env_vars(
+        mut self,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> ClientResult<Self> {
+        let vars: Vec<(String, String)> = vars.into_iter().collect();
+        for (name, suggestion) in env_var_typos(vars.iter().map(|(name, _)| name.as_str())) {
+            warn!(
+                variable = %name,
+                suggestion,
+                "Unknown environment variable, did you mean {}?",
+                suggestion
+            );
+        }
+
+        for (name, value) in &vars {
+            let config = &mut self.config;
+            match name.as_str() {
+                "TYPEMILL_URL" => config.url = Some(value.clone()),
+                "TYPEMILL_TOKEN" => config.token = Some(value.clone()),
+                "TYPEMILL_TIMEOUT" => {
+                    config.timeout_ms = Some(parse_env_value(name, value, "milliseconds")?)
+                }
+                "TYPEMILL_KEEP_ALIVE_INTERVAL_MS" => {
+                    config.keep_alive_interval_ms =
+                        Some(parse_env_value(name, value, "milliseconds")?)
+                }
+                "TYPEMILL_PONG_TIMEOUT_MS" => {
+                    config.pong_timeout_ms = Some(parse_env_value(name, value, "milliseconds")?)
+                }
+                "TYPEMILL_RECONNECT_MAX_BACKOFF_MS" => {
+                    config.reconnect_max_backoff_ms =
+                        Some(parse_env_value(name, value, "milliseconds")?)
+                }
+                "TYPEMILL_RECONNECT_MAX_ATTEMPTS" => {
+                    config.reconnect_max_attempts =
+                        Some(parse_env_value(name, value, "a number of attempts")?)
+                }
+                "TYPEMILL_ACCEPT_INVALID_CERTS" => {
+                    config.accept_invalid_certs = parse_env_value(name, value, "true or false")?
+                }
+                "TYPEMILL_CUSTOM_CA_PATH" => config.custom_ca_path = Some(PathBuf::from(value)),
+                _ => continue,
+            }
+            debug!(variable = %name, "Using value from environment variable");
+        }
+
+        Ok(self)
+    }
+
     /// Set the URL
     pub fn with_url(mut self, url: String) -> Self {
         self.config.url = Some(url);
@@ -362,6 +727,39 @@ impl ConfigBuilder {
         self
     }
 
+    /// Set the keep-alive ping interval and pong timeout in milliseconds
+    ///
+    /// An interval of 0 disables keep-alive pings.
+    pub fn with_keep_alive(mut self, interval_ms: u64, pong_timeout_ms: u64) -> Self {
+        self.config.keep_alive_interval_ms = Some(interval_ms);
+        self.config.pong_timeout_ms = Some(pong_timeout_ms);
+        self
+    }
+
+    /// Set the upper bound for the delay between reconnection attempts
+    pub fn with_reconnect_max_backoff_ms(mut self, max_backoff_ms: u64) -> Self {
+        self.config.reconnect_max_backoff_ms = Some(max_backoff_ms);
+        self
+    }
+
+    /// Give up reconnecting after `max_attempts` attempts
+    pub fn with_reconnect_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.config.reconnect_max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Skip TLS certificate verification (insecure, for development only)
+    pub fn with_accept_invalid_certs(mut self, accept_invalid_certs: bool) -> Self {
+        self.config.accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
+    /// Trust the CA certificates in the PEM file at `path` for `wss://` URLs
+    pub fn with_custom_ca_path(mut self, path: PathBuf) -> Self {
+        self.config.custom_ca_path = Some(path);
+        self
+    }
+
     /// Apply overrides from another config
     pub fn with_overrides(mut self, url: Option<String>, token: Option<String>) -> Self {
         if let Some(url) = url {
@@ -479,6 +877,13 @@ mod tests {
             url: Some("ws://file:3000".to_string()),
             token: Some("file-token".to_string()),
             timeout_ms: Some(30000),
+            keep_alive_interval_ms: None,
+            pong_timeout_ms: None,
+            reconnect_max_backoff_ms: None,
+            reconnect_max_attempts: None,
+            accept_invalid_certs: false,
+            custom_ca_path: None,
+            interpolated_fields: Vec::new(),
         };
         file_config.save_to_path(&config_path).await.unwrap();
 
@@ -585,6 +990,13 @@ mod tests {
             url: Some("ws://file:7000".to_string()),
             token: Some("file-token".to_string()),
             timeout_ms: Some(60000),
+            keep_alive_interval_ms: None,
+            pong_timeout_ms: None,
+            reconnect_max_backoff_ms: None,
+            reconnect_max_attempts: None,
+            accept_invalid_certs: false,
+            custom_ca_path: None,
+            interpolated_fields: Vec::new(),
         };
         file_config.save_to_path(&config_path).await.unwrap();
 
@@ -624,6 +1036,78 @@ mod tests {
         env::remove_var("TYPEMILL_TIMEOUT");
     }
 
+    #[serial]
+    #[tokio::test]
+    async fn test_load_rejects_invalid_env_value() {
+        env::set_var("TYPEMILL_RECONNECT_MAX_ATTEMPTS", "many");
+
+        let err = ClientConfig::load().await.err().unwrap();
+
+        env::remove_var("TYPEMILL_RECONNECT_MAX_ATTEMPTS");
+        assert!(
+            err.to_string().contains("TYPEMILL_RECONNECT_MAX_ATTEMPTS"),
+            "{}",
+            err
+        );
+    }
+
+    fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_config_builder_from_env_parses_all_types() {
+        let config = ConfigBuilder::new()
+            .apply_env_vars(env(&[
+                ("TYPEMILL_URL", "wss://env:4000"),
+                ("TYPEMILL_TOKEN", "env-token"),
+                ("TYPEMILL_TIMEOUT", "25000"),
+                ("TYPEMILL_RECONNECT_MAX_ATTEMPTS", "5"),
+                ("TYPEMILL_ACCEPT_INVALID_CERTS", "true"),
+                ("TYPEMILL_CUSTOM_CA_PATH", "/etc/mill/ca.pem"),
+                ("TYPEMILL_PERF", "1"),
+                ("HOME", "/root"),
+            ]))
+            .unwrap()
+            .config;
+
+        assert_eq!(config.url.as_deref(), Some("wss://env:4000"));
+        assert_eq!(config.token.as_deref(), Some("env-token"));
+        assert_eq!(config.timeout_ms, Some(25000));
+        assert_eq!(config.reconnect_max_attempts, Some(5));
+        assert!(config.accept_invalid_certs);
+        assert_eq!(
+            config.custom_ca_path,
+            Some(PathBuf::from("/etc/mill/ca.pem"))
+        );
+
+        let err = ConfigBuilder::new()
+            .apply_env_vars(env(&[("TYPEMILL_TIMEOUT", "soon")]))
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("TYPEMILL_TIMEOUT"), "{}", err);
+    }
+
+    #[test]
+    fn test_env_var_typos_suggest_recognized_name() {
+        let typos = env_var_typos([
+            "TYPEMILL_URLS",
+            "TYPEMIL_TOKEN",
+            "TYPEMILL_URL",
+            "TYPEMILL_PERF",
+            "PATH",
+        ]);
+        assert_eq!(
+            typos,
+            vec![
+                ("TYPEMILL_URLS".to_string(), "TYPEMILL_URL"),
+                ("TYPEMIL_TOKEN".to_string(), "TYPEMILL_TOKEN"),
+            ]
+        );
+    }
+
     #[test]
     fn test_config_builder_validation_errors() {
         // Test that builder validates on build
@@ -654,6 +1138,39 @@ mod tests {
         assert_eq!(config.timeout_ms, Some(20000));
     }
 
+    #[test]
+    fn test_config_tls_settings() {
+        // Older config files without TLS fields keep verification on
+        let config: ClientConfig =
+            serde_json::from_str(r#"{"url": "wss://mill.example:3000"}"#).unwrap();
+        assert!(!config.accepts_invalid_certs());
+        assert_eq!(config.get_custom_ca_path().unwrap(), None);
+
+        let ca_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/test_ca.pem");
+        let config: ClientConfig = serde_json::from_value(serde_json::json!({
+            "url": "wss://mill.example:3000",
+            "accept_invalid_certs": true,
+            "custom_ca_path": ca_path,
+        }))
+        .unwrap();
+        assert!(config.accepts_invalid_certs());
+        assert_eq!(config.get_custom_ca_path().unwrap(), Some(ca_path.clone()));
+        assert!(config.validate().is_ok());
+
+        let config = ConfigBuilder::new()
+            .with_accept_invalid_certs(true)
+            .with_custom_ca_path(ca_path.clone())
+            .build()
+            .unwrap();
+        assert!(config.accepts_invalid_certs());
+        assert!(config.tls_summary().contains("disabled"));
+
+        let missing = ConfigBuilder::new()
+            .with_custom_ca_path(PathBuf::from("/nonexistent/ca.pem"))
+            .build();
+        assert!(missing.is_err());
+    }
+
     #[test]
     fn test_config_missing_items_and_suggestions() {
         let config = ClientConfig::new();
@@ -676,6 +1193,113 @@ mod tests {
         assert!(config.is_complete());
     }
 
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "MILL_TEST_HOST" => Some("example.com".to_string()),
+            "MILL_TEST_TOKEN" => Some("secret".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_env_interpolation() {
+        let (expanded, variables) = expand_env_vars(
+            "url",
+            "wss://${MILL_TEST_HOST}:3000/${MILL_TEST_HOST}",
+            &lookup,
+        )
+        .unwrap();
+        assert_eq!(expanded, "wss://example.com:3000/example.com");
+        assert_eq!(variables, vec!["MILL_TEST_HOST", "MILL_TEST_HOST"]);
+
+        let (unchanged, variables) =
+            expand_env_vars("url", "ws://localhost:3000", &lookup).unwrap();
+        assert_eq!(unchanged, "ws://localhost:3000");
+        assert!(variables.is_empty());
+    }
+
+    #[test]
+    fn test_env_interpolation_default_fallback() {
+        let (expanded, _) =
+            expand_env_vars("url", "ws://${MILL_TEST_UNSET:-localhost}:3000", &lookup).unwrap();
+        assert_eq!(expanded, "ws://localhost:3000");
+
+        // A set variable wins over the default
+        let (expanded, _) =
+            expand_env_vars("token", "${MILL_TEST_TOKEN:-fallback}", &lookup).unwrap();
+        assert_eq!(expanded, "secret");
+    }
+
+    #[test]
+    fn test_env_interpolation_missing_variable() {
+        let err = expand_env_vars("token", "${MILL_TEST_UNSET}", &lookup).unwrap_err();
+        assert!(matches!(err, ClientError::ConfigError(_)));
+        assert!(err.to_string().contains("MILL_TEST_UNSET"));
+        assert!(err.to_string().contains("token"));
+
+        assert!(expand_env_vars("token", "${MILL_TEST_TOKEN", &lookup).is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_unset_variables() {
+        let config: ClientConfig =
+            serde_json::from_str(r#"{"url": "ws://${MILL_TEST_VALIDATE_HOST}:3000"}"#).unwrap();
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("MILL_TEST_VALIDATE_HOST"));
+
+        let config: ClientConfig =
+            serde_json::from_str(r#"{"custom_ca_path": "${MILL_TEST_VALIDATE_CA}/ca.pem"}"#)
+                .unwrap();
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("MILL_TEST_VALIDATE_CA"));
+    }
+
+    #[serial]
+    #[tokio::test]
+    async fn test_config_file_values_interpolated() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("interpolated.json");
+        std::fs::write(
+            &config_path,
+            r#"{"url": "ws://${MILL_TEST_CONFIG_HOST:-localhost}:3000", "token": "${MILL_TEST_CONFIG_TOKEN}"}"#,
+        )
+        .unwrap();
+
+        // An unset variable without a default fails the load
+        let err = ClientConfig::load_from_path(&config_path)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ClientError::ConfigError(_)));
+        assert!(err.to_string().contains("MILL_TEST_CONFIG_TOKEN"));
+
+        env::set_var("MILL_TEST_CONFIG_TOKEN", "from-env");
+        let config = ClientConfig::load_from_path(&config_path).await.unwrap();
+        assert_eq!(config.get_url().unwrap(), "ws://localhost:3000");
+        assert_eq!(config.get_token().unwrap().as_deref(), Some("from-env"));
+        env::remove_var("MILL_TEST_CONFIG_TOKEN");
+        assert_eq!(
+            config.interpolated_fields,
+            vec![
+                InterpolatedField {
+                    field: "url",
+                    variables: vec!["MILL_TEST_CONFIG_HOST".to_string()],
+                },
+                InterpolatedField {
+                    field: "token",
+                    variables: vec!["MILL_TEST_CONFIG_TOKEN".to_string()],
+                },
+            ]
+        );
+
+        // Saving writes the templates back, not the expanded values
+        let saved_path = temp_dir.path().join("saved.json");
+        config.save_to_path(&saved_path).await.unwrap();
+        let saved = std::fs::read_to_string(&saved_path).unwrap();
+        assert!(saved.contains("ws://${MILL_TEST_CONFIG_HOST:-localhost}:3000"));
+        assert!(saved.contains("${MILL_TEST_CONFIG_TOKEN}"));
+        assert!(!saved.contains("from-env"));
+    }
+
     #[tokio::test]
     async fn test_config_methods_delegated_to_builder() {
         let temp_dir = TempDir::new().unwrap();
@@ -686,6 +1310,13 @@ mod tests {
             url: Some("ws://delegate:3000".to_string()),
             token: None,
             timeout_ms: Some(30000),
+            keep_alive_interval_ms: None,
+            pong_timeout_ms: None,
+            reconnect_max_backoff_ms: None,
+            reconnect_max_attempts: None,
+            accept_invalid_certs: false,
+            custom_ca_path: None,
+            interpolated_fields: Vec::new(),
         };
         file_config.save_to_path(&config_path).await.unwrap();
 
@@ -1,10 +1,19 @@
 use super::{utils, Command, CommandContext, GlobalArgs};
 use crate::error::{ClientError, ClientResult};
-use crate::websocket::MCPResponse;
+use crate::websocket::{MCPResponse, ReconnectBackoff};
 use async_trait::async_trait;
 use serde_json::Value;
 use std::fs;
-use std::io::{self, Read};
+use std::future::Future;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// Delay before the first retry of a failed call, doubled on every retry
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Upper bound on the delay between retries
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(10);
 
 /// Call command for direct MCP tool invocation
 pub struct CallCommand {
@@ -22,6 +31,12 @@ pub struct CallCommand {
     pub params_file: Option<String>,
     /// Read parameters from stdin
     pub params_stdin: bool,
+    /// Path of the single response value to print
+    pub select: Option<String>,
+    /// Write the output to this file instead of stdout
+    pub output_file: Option<String>,
+    /// Re-attempt the call this many times on retryable errors
+    pub retries: u32,
 }
 
 /// Output format options
@@ -46,6 +61,9 @@ impl CallCommand {
             format: OutputFormat::default(),
             params_file: None,
             params_stdin: false,
+            select: None,
+            output_file: None,
+            retries: 0,
         }
     }
 
@@ -74,11 +92,29 @@ impl CallCommand {
         self
     }
 
+    pub fn with_select(mut self, path: String) -> Self {
+        self.select = Some(path);
+        self
+    }
+
+    pub fn with_output_file(mut self, path: String) -> Self {
+        self.output_file = Some(path);
+        self
+    }
+
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
     /// Execute the tool call
     async fn execute_tool_call(&self, ctx: &CommandContext) -> ClientResult<()> {
         // Validate tool name
         utils::validate_tool_name(&self.tool)?;
 
+        // Reject a malformed selection before doing any work
+        let selection = self.select.as_deref().map(parse_select_path).transpose()?;
+
         // Get parameters from various sources
         let params = self.resolve_parameters(ctx).await?;
 
@@ -92,15 +128,24 @@ impl CallCommand {
             println!();
         }
 
-        // Connect to server
-        let client = ctx
-            .connect_client(self.url.clone(), self.token.clone())
-            .await?;
-
-        // Execute the tool call
+        // Connect and execute the tool call; on timeout the client cancels it
+        // server-side. Each retry opens a fresh connection.
         ctx.display_info(&format!("Calling tool '{}'...", self.tool));
 
-        let response = match client.call_tool(&self.tool, params).await {
+        let backoff = ReconnectBackoff::new(RETRY_BASE_DELAY, RETRY_MAX_DELAY, Some(self.retries));
+        let attempt = || async {
+            let client = ctx
+                .connect_client(self.url.clone(), self.token.clone())
+                .await?;
+            let response = client.call_tool(&self.tool, params.clone()).await;
+            let _ = client.disconnect().await;
+            response
+        };
+        let on_retry = |error: &ClientError, delay: Duration| {
+            ctx.display_warning(&format!("{}; retrying in {} ms", error, delay.as_millis()));
+        };
+
+        let response = match call_with_retries(backoff, attempt, on_retry).await {
             Ok(response) => {
                 ctx.display_success("Tool call completed");
                 response
@@ -111,11 +156,24 @@ impl CallCommand {
             }
         };
 
-        // Disconnect
-        let _ = client.disconnect().await;
-
         // Format and display output
-        self.display_response(ctx, &response)?;
+        let output = match selection {
+            Some(segments) => self.render_selection(&response, &segments)?,
+            None => self.render_response(ctx, &response)?,
+        };
+
+        match self.output_file {
+            Some(ref path) => {
+                write_output_file(Path::new(path), &output)?;
+                ctx.display_info(&format!("Output written to {}", ctx.formatter.path(path)));
+            }
+            None => {
+                if self.format == OutputFormat::Pretty && self.select.is_none() {
+                    println!();
+                }
+                println!("{}", output);
+            }
+        }
 
         Ok(())
     }
@@ -169,38 +227,56 @@ impl CallCommand {
         }
     }
 
-    /// Display the response in the specified format
-    fn display_response(&self, ctx: &CommandContext, response: &MCPResponse) -> ClientResult<()> {
+    /// Render the response in the specified format
+    fn render_response(
+        &self,
+        ctx: &CommandContext,
+        response: &MCPResponse,
+    ) -> ClientResult<String> {
         match self.format {
-            OutputFormat::Pretty => {
-                println!();
-                println!("{}", ctx.formatter.mcp_response(response)?);
-            }
-            OutputFormat::Json => {
-                let json = serde_json::to_string_pretty(response).map_err(|e| {
-                    ClientError::SerializationError(format!("Failed to serialize response: {}", e))
-                })?;
-                println!("{}", json);
-            }
+            OutputFormat::Pretty => ctx.formatter.mcp_response(response),
+            OutputFormat::Json => serde_json::to_string_pretty(response).map_err(|e| {
+                ClientError::SerializationError(format!("Failed to serialize response: {}", e))
+            }),
             OutputFormat::Raw => {
                 if let Some(ref error) = response.error {
                     eprintln!("Error {}: {}", error.code, error.message);
                     std::process::exit(1);
                 } else if let Some(ref result) = response.result {
-                    let output = serde_json::to_string(result).map_err(|e| {
+                    serde_json::to_string(result).map_err(|e| {
                         ClientError::SerializationError(format!(
                             "Failed to serialize result: {}",
                             e
                         ))
-                    })?;
-                    println!("{}", output);
+                    })
                 } else {
-                    println!("null");
+                    Ok("null".to_string())
                 }
             }
         }
+    }
 
-        Ok(())
+    /// Render only the selected value of the response
+    ///
+    /// Strings are rendered bare, everything else as compact JSON.
+    fn render_selection(
+        &self,
+        response: &MCPResponse,
+        segments: &[PathSegment],
+    ) -> ClientResult<String> {
+        let value = serde_json::to_value(response).map_err(|e| {
+            ClientError::SerializationError(format!("Failed to serialize response: {}", e))
+        })?;
+        let path = self.select.as_deref().unwrap_or_default();
+        let selected = select_value(&value, segments)
+            .ok_or_else(|| ClientError::request(format!("No value at path '{}'", path)))?;
+
+        match selected {
+            Value::String(s) => Ok(s.clone()),
+            other => serde_json::to_string(other).map_err(|e| {
+                ClientError::SerializationError(format!("Failed to serialize value: {}", e))
+            }),
+        }
     }
 
     /// Show examples of common tool calls
@@ -247,6 +323,7 @@ impl CallCommand {
         println!("  • Use --format raw for result-only output");
         println!("  • Read params from file: --params-file params.json");
         println!("  • Read params from stdin: --params-stdin");
+        println!("  • Write output to a file: --output-file result.json");
         println!("  • Override server: --url ws://localhost:3000");
         println!();
 
@@ -401,10 +478,144 @@ impl Clone for CallCommand {
             format: self.format.clone(),
             params_file: self.params_file.clone(),
             params_stdin: self.params_stdin,
+            select: self.select.clone(),
+            output_file: self.output_file.clone(),
+            retries: self.retries,
         }
     }
 }
 
+/// Run `attempt` until it succeeds or fails with a non-retryable error
+///
+/// Retryable errors are re-attempted after the delays handed out by
+/// `backoff`; once it is exhausted the last error is returned. `on_retry` is
+/// told about each failure that will be retried and the delay before it.
+async fn call_with_retries<T, F, Fut>(
+    mut backoff: ReconnectBackoff,
+    mut attempt: F,
+    mut on_retry: impl FnMut(&ClientError, Duration),
+) -> ClientResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ClientResult<T>>,
+{
+    let mut rng = fastrand::Rng::new();
+    loop {
+        match attempt().await {
+            Err(e) if e.is_retryable() => match backoff.next_delay(&mut rng) {
+                Some(delay) => {
+                    on_retry(&e, delay);
+                    tokio::time::sleep(delay).await;
+                }
+                None => return Err(e),
+            },
+            result => return result,
+        }
+    }
+}
+
+/// Write `output` to `path`, replacing any existing file atomically
+///
+/// The content goes to a temporary file in the same directory, which is then
+/// renamed over `path`, so readers never see a partial file. ANSI color codes
+/// are stripped. If any step fails the temporary file is removed.
+fn write_output_file(path: &Path, output: &str) -> ClientResult<()> {
+    let io_error =
+        |e: io::Error| ClientError::IoError(format!("Failed to write '{}': {}", path.display(), e));
+
+    let file_name = path.file_name().ok_or_else(|| {
+        ClientError::IoError(format!("Output path '{}' is not a file", path.display()))
+    })?;
+    let temp_path = path.with_file_name(format!(
+        ".{}.{}.tmp",
+        file_name.to_string_lossy(),
+        uuid::Uuid::new_v4()
+    ));
+
+    let written = fs::File::create(&temp_path).and_then(|mut file| {
+        writeln!(file, "{}", console::strip_ansi_codes(output))?;
+        file.sync_all()
+    });
+    if let Err(e) = written.and_then(|()| fs::rename(&temp_path, path)) {
+        let _ = fs::remove_file(&temp_path);
+        return Err(io_error(e));
+    }
+
+    Ok(())
+}
+
+/// One step of a `--select` path
+#[derive(Debug, Clone, PartialEq)]
+enum PathSegment {
+    /// Object field, written `.name`
+    Key(String),
+    /// Array element, written `[0]`
+    Index(usize),
+}
+
+/// Parse a dotted/bracket path such as `result.symbols[0].name`
+fn parse_select_path(path: &str) -> ClientResult<Vec<PathSegment>> {
+    let invalid =
+        |reason: &str| ClientError::request(format!("Invalid path '{}': {}", path, reason));
+
+    let mut segments = Vec::new();
+    let mut chars = path.chars().peekable();
+    let mut expect_key = true;
+
+    while let Some(c) = chars.peek().copied() {
+        match c {
+            '[' => {
+                chars.next();
+                let mut digits = String::new();
+                loop {
+                    match chars.next() {
+                        Some(']') => break,
+                        Some(c) => digits.push(c),
+                        None => return Err(invalid("unclosed '['")),
+                    }
+                }
+                let index = digits
+                    .parse()
+                    .map_err(|_| invalid(&format!("'[{}' is not an array index", digits)))?;
+                segments.push(PathSegment::Index(index));
+                expect_key = false;
+            }
+            '.' if !expect_key => {
+                chars.next();
+                expect_key = true;
+            }
+            '.' => return Err(invalid("empty field name")),
+            _ if expect_key => {
+                let mut key = String::new();
+                while let Some(c) = chars.next_if(|c| *c != '.' && *c != '[') {
+                    if c == ']' {
+                        return Err(invalid("unmatched ']'"));
+                    }
+                    key.push(c);
+                }
+                segments.push(PathSegment::Key(key));
+                expect_key = false;
+            }
+            _ => return Err(invalid(&format!("unexpected '{}'", c))),
+        }
+    }
+
+    if expect_key {
+        return Err(invalid("empty field name"));
+    }
+    Ok(segments)
+}
+
+/// Walk `value` along the parsed path
+fn select_value<'a>(value: &'a Value, segments: &[PathSegment]) -> Option<&'a Value> {
+    segments
+        .iter()
+        .try_fold(value, |current, segment| match segment {
+            PathSegment::Key(key) => current.get(key),
+            PathSegment::Index(index) => current.get(index),
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -437,6 +648,149 @@ mod tests {
         assert_eq!(OutputFormat::default(), OutputFormat::Pretty);
     }
 
+    #[test]
+    fn test_select_nested_object() {
+        let value = serde_json::json!({"result": {"file": {"path": "src/lib.rs"}}});
+        let segments = parse_select_path("result.file.path").unwrap();
+        assert_eq!(
+            select_value(&value, &segments),
+            Some(&serde_json::json!("src/lib.rs"))
+        );
+    }
+
+    #[test]
+    fn test_select_array_index() {
+        let value = serde_json::json!({"result": {"symbols": [{"name": "a"}, {"name": "b"}]}});
+        let segments = parse_select_path("result.symbols[1].name").unwrap();
+        assert_eq!(
+            segments,
+            vec![
+                PathSegment::Key("result".to_string()),
+                PathSegment::Key("symbols".to_string()),
+                PathSegment::Index(1),
+                PathSegment::Key("name".to_string()),
+            ]
+        );
+        assert_eq!(
+            select_value(&value, &segments),
+            Some(&serde_json::json!("b"))
+        );
+    }
+
+    #[test]
+    fn test_select_missing_path() {
+        let value = serde_json::json!({"result": {"symbols": []}});
+        for path in ["result.symbols[0]", "result.missing", "result.symbols.name"] {
+            let segments = parse_select_path(path).unwrap();
+            assert_eq!(select_value(&value, &segments), None, "{}", path);
+        }
+    }
+
+    #[test]
+    fn test_select_invalid_path() {
+        for path in [
+            "",
+            "result.",
+            ".result",
+            "result..name",
+            "items[x]",
+            "items[0",
+        ] {
+            assert!(
+                matches!(parse_select_path(path), Err(ClientError::RequestError(_))),
+                "{}",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn test_write_output_file_replaces_atomically() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("result.json");
+        fs::write(&path, "old").unwrap();
+
+        write_output_file(&path, "\u{1b}[32m{\"ok\": true}\u{1b}[0m").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{\"ok\": true}\n");
+        // Only the target remains; the temporary file was renamed over it
+        let entries: Vec<_> = fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+    }
+
+    #[test]
+    fn test_write_output_file_failure_leaves_nothing() {
+        let dir = tempfile::TempDir::new().unwrap();
+        // Renaming a file over a non-empty directory fails
+        let path = dir.path().join("taken");
+        fs::create_dir(&path).unwrap();
+        fs::write(path.join("inner"), "").unwrap();
+
+        let result = write_output_file(&path, "{}");
+
+        assert!(matches!(result, Err(ClientError::IoError(_))));
+        let entries: Vec<_> = fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1, "temporary file was left behind");
+    }
+
+    fn no_delay(retries: u32) -> ReconnectBackoff {
+        ReconnectBackoff::new(Duration::ZERO, Duration::ZERO, Some(retries))
+    }
+
+    #[tokio::test]
+    async fn test_retries_exhausted_returns_last_error() {
+        let mut attempts = 0;
+        let result: ClientResult<()> = call_with_retries(
+            no_delay(2),
+            || {
+                attempts += 1;
+                let message = format!("attempt {}", attempts);
+                async move { Err(ClientError::connection(message)) }
+            },
+            |_, _| {},
+        )
+        .await;
+
+        assert_eq!(attempts, 3);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Connection error: attempt 3"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retries_stop_on_success_and_skip_non_retryable() {
+        let mut attempts = 0;
+        let result = call_with_retries(
+            no_delay(5),
+            || {
+                attempts += 1;
+                let outcome = if attempts < 2 {
+                    Err(ClientError::timeout("slow"))
+                } else {
+                    Ok(attempts)
+                };
+                async move { outcome }
+            },
+            |_, _| {},
+        )
+        .await;
+        assert_eq!(result.unwrap(), 2);
+
+        let mut attempts = 0;
+        let result: ClientResult<()> = call_with_retries(
+            no_delay(5),
+            || {
+                attempts += 1;
+                async { Err(ClientError::authentication("bad token")) }
+            },
+            |_, _| {},
+        )
+        .await;
+        assert!(matches!(result, Err(ClientError::AuthError(_))));
+        assert_eq!(attempts, 1);
+    }
+
     #[test]
     fn test_call_command_clone() {
         let cmd = CallCommand::new("test".to_string(), Some("{}".to_string()));
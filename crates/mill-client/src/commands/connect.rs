@@ -1,10 +1,10 @@
 use super::{utils, Command, CommandContext, GlobalArgs};
 use crate::error::{ClientError, ClientResult};
 use crate::websocket::{ConnectionState, WebSocketClient};
+use crate::SessionReport;
 use async_trait::async_trait;
 use std::io::{self, Write};
 use std::time::{Duration, Instant};
-use tokio::time::sleep;
 
 /// Connect command for persistent WebSocket sessions
 pub struct ConnectCommand {
@@ -116,6 +116,14 @@ impl ConnectCommand {
         // Show connection info
         self.show_connection_info(ctx, &client).await?;
 
+        let mut report = SessionReport::new(
+            self.url
+                .clone()
+                .or_else(|| ctx.config.url.clone())
+                .unwrap_or_default(),
+            self.token.is_some() || ctx.config.token.is_some(),
+        );
+
         // Main session loop
         while session_active {
             // Check session timeout
@@ -134,19 +142,9 @@ impl ConnectCommand {
             ) {
                 if self.auto_reconnect {
                     ctx.display_warning("Connection lost, attempting to reconnect...");
-                    if let Err(e) = client.connect().await {
-                        ctx.display_error(&ClientError::ConnectionError(format!(
-                            "Reconnection failed: {}",
-                            e
-                        )));
-                        if !ctx
-                            .interactive
-                            .confirm("Continue trying to reconnect?", Some(true))?
-                        {
-                            break;
-                        }
-                        sleep(Duration::from_secs(2)).await;
-                        continue;
+                    if let Err(e) = client.reconnect(&mut report).await {
+                        ctx.display_error(&e);
+                        break;
                     }
                     ctx.display_success("Reconnected successfully");
                 } else {
@@ -202,7 +200,8 @@ impl ConnectCommand {
 
         // Disconnect and show session summary
         let _ = client.disconnect().await;
-        self.show_session_summary(ctx, &stats)?;
+        report.end_session();
+        self.show_session_summary(ctx, &stats, &report)?;
 
         Ok(())
     }
@@ -574,7 +573,12 @@ impl ConnectCommand {
     }
 
     /// Show session summary on exit
-    fn show_session_summary(&self, ctx: &CommandContext, stats: &SessionStats) -> ClientResult<()> {
+    fn show_session_summary(
+        &self,
+        ctx: &CommandContext,
+        stats: &SessionStats,
+        report: &SessionReport,
+    ) -> ClientResult<()> {
         println!();
         ctx.formatter.header("📋 Session Summary");
         println!();
@@ -592,6 +596,15 @@ impl ConnectCommand {
                 .key_value("Commands executed", &stats.commands_executed.to_string())
         );
 
+        let reconnections = report.connection_info.reconnection_attempts;
+        if reconnections > 0 {
+            println!(
+                "  {}",
+                ctx.formatter
+                    .key_value("Reconnection attempts", &reconnections.to_string())
+            );
+        }
+
         if stats.commands_executed > 0 {
             let success_rate =
                 (stats.successful_calls as f64 / stats.commands_executed as f64) * 100.0;
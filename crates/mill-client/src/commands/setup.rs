@@ -145,7 +145,7 @@ impl SetupCommand {
             loop {
                 match ctx
                     .interactive
-                    .optional_input("Authentication token", ctx.config.get_token())
+                    .optional_input("Authentication token", ctx.config.token.as_deref())
                 {
                     Ok(Some(token)) => {
                         // Basic token validation
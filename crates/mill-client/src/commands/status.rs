@@ -3,8 +3,26 @@ use crate::client_config::ClientConfig;
 use crate::error::{ClientError, ClientResult};
 use crate::websocket::{ConnectionState, WebSocketClient};
 use async_trait::async_trait;
+use serde::Serialize;
 use std::time::Duration;
 
+/// Machine-readable result of a status check, printed by `status --json`
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct StatusReport {
+    /// Whether the server accepted a connection and answered a ping
+    pub reachable: bool,
+    /// The server URL that was checked
+    pub server_url: Option<String>,
+    /// Whether the connection is authenticated
+    pub authenticated: bool,
+    /// Ping round-trip time in milliseconds
+    pub latency_ms: Option<u64>,
+    /// Version reported by the server, if any
+    pub server_version: Option<String>,
+    /// Why the server is not reachable
+    pub error: Option<String>,
+}
+
 /// Status command for health checking and diagnostics
 pub struct StatusCommand {
     /// Server URL override
@@ -13,6 +31,8 @@ pub struct StatusCommand {
     pub token: Option<String>,
     /// Show detailed information
     pub verbose: bool,
+    /// Print a JSON status report instead of formatted text
+    pub json: bool,
 }
 
 impl StatusCommand {
@@ -21,6 +41,7 @@ impl StatusCommand {
             url,
             token,
             verbose: false,
+            json: false,
         }
     }
 
@@ -29,6 +50,72 @@ impl StatusCommand {
         self
     }
 
+    pub fn with_json(mut self, json: bool) -> Self {
+        self.json = json;
+        self
+    }
+
+    /// Check reachability and print the result as JSON
+    ///
+    /// Returns a connection error when the server is not reachable so the
+    /// exit code reflects the result.
+    async fn run_json_status(&self, ctx: &CommandContext) -> ClientResult<()> {
+        let report = self.collect_report(ctx).await;
+
+        let json = serde_json::to_string_pretty(&report)
+            .map_err(|e| ClientError::SerializationError(e.to_string()))?;
+        println!("{}", json);
+
+        if report.reachable {
+            Ok(())
+        } else {
+            Err(ClientError::ConnectionError(
+                report
+                    .error
+                    .unwrap_or_else(|| "Server not reachable".to_string()),
+            ))
+        }
+    }
+
+    /// Connect to the server and collect a status report without printing
+    async fn collect_report(&self, ctx: &CommandContext) -> StatusReport {
+        let mut report = StatusReport {
+            server_url: self.url.clone().or_else(|| ctx.config.url.clone()),
+            ..Default::default()
+        };
+
+        if report.server_url.is_none() {
+            report.error = Some("No server URL configured".to_string());
+            return report;
+        }
+
+        let client = match ctx.create_client(self.url.clone(), self.token.clone()) {
+            Ok(client) => client,
+            Err(e) => {
+                report.error = Some(e.to_string());
+                return report;
+            }
+        };
+
+        match self.test_connection(&client).await {
+            Ok((ping_time, state)) => {
+                report.reachable = true;
+                report.authenticated = matches!(state, ConnectionState::Authenticated);
+                report.latency_ms = Some(ping_time.as_millis() as u64);
+                report.server_version = client
+                    .get_capabilities()
+                    .await
+                    .ok()
+                    .as_ref()
+                    .and_then(server_version);
+            }
+            Err(e) => report.error = Some(e.to_string()),
+        }
+
+        let _ = client.disconnect().await;
+        report
+    }
+
     /// Run comprehensive status check
     async fn run_status_check(&self, ctx: &CommandContext) -> ClientResult<()> {
         ctx.formatter.header("🔍 TypeMill Client Status");
@@ -444,6 +531,15 @@ impl StatusCommand {
     }
 }
 
+/// Read the server version from a capabilities response
+fn server_version(capabilities: &serde_json::Value) -> Option<String> {
+    capabilities
+        .pointer("/serverInfo/version")
+        .or_else(|| capabilities.get("version"))
+        .and_then(|version| version.as_str())
+        .map(str::to_string)
+}
+
 impl Default for StatusCommand {
     fn default() -> Self {
         Self::new(None, None)
@@ -453,6 +549,15 @@ impl Default for StatusCommand {
 #[async_trait]
 impl Command for StatusCommand {
     async fn execute(&self, global_args: &GlobalArgs) -> ClientResult<()> {
+        if self.json {
+            // Formatting is never applied to JSON output
+            let mut global_args = global_args.clone();
+            global_args.no_color = true;
+            global_args.no_emoji = true;
+            let ctx = CommandContext::new(global_args).await?;
+            return self.run_json_status(&ctx).await;
+        }
+
         let ctx = CommandContext::new(global_args.clone()).await?;
         self.run_status_check(&ctx).await
     }
@@ -500,5 +605,53 @@ mod tests {
         assert_eq!(cmd.name(), "status");
         assert!(cmd.url.is_none());
         assert!(cmd.token.is_none());
+        assert!(!cmd.json);
+    }
+
+    #[test]
+    fn test_status_report_reachable_json() {
+        let report = StatusReport {
+            reachable: true,
+            server_url: Some("ws://localhost:3000".to_string()),
+            authenticated: true,
+            latency_ms: Some(12),
+            server_version: server_version(&serde_json::json!({
+                "serverInfo": { "name": "mill", "version": "0.1.0" }
+            })),
+            error: None,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&report).unwrap(),
+            serde_json::json!({
+                "reachable": true,
+                "server_url": "ws://localhost:3000",
+                "authenticated": true,
+                "latency_ms": 12,
+                "server_version": "0.1.0",
+                "error": null
+            })
+        );
+    }
+
+    #[test]
+    fn test_status_report_unreachable_json() {
+        let report = StatusReport {
+            server_url: Some("ws://localhost:1".to_string()),
+            error: Some("Failed to connect: Connection refused".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(&report).unwrap(),
+            serde_json::json!({
+                "reachable": false,
+                "server_url": "ws://localhost:1",
+                "authenticated": false,
+                "latency_ms": null,
+                "server_version": null,
+                "error": "Failed to connect: Connection refused"
+            })
+        );
     }
 }
@@ -83,6 +83,72 @@ impl ClientError {
     pub fn io(message: impl Into<String>) -> Self {
         Self::IoError(message.into())
     }
+
+    /// Process exit code used when the CLI fails with this error
+    ///
+    /// | Code | Error                |
+    /// |------|----------------------|
+    /// | 2    | `ConfigError`        |
+    /// | 3    | `ConnectionError`    |
+    /// | 4    | `AuthError`          |
+    /// | 5    | `TimeoutError`       |
+    /// | 6    | `RequestError`       |
+    /// | 7    | `SerializationError` |
+    /// | 8    | `IoError`            |
+    /// | 9    | `TransportError`     |
+    /// | 10   | `ProtocolError`      |
+    /// | 11   | `MillError` (core)   |
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::ConfigError(_) => 2,
+            Self::ConnectionError(_) => 3,
+            Self::AuthError(_) => 4,
+            Self::TimeoutError(_) => 5,
+            Self::RequestError(_) => 6,
+            Self::SerializationError(_) => 7,
+            Self::IoError(_) => 8,
+            Self::TransportError(_) => 9,
+            Self::ProtocolError(_) => 10,
+            Self::Core(_) => 11,
+        }
+    }
+
+    /// Stable name of the error category
+    pub fn error_type(&self) -> &'static str {
+        match self {
+            Self::ConfigError(_) => "ConfigError",
+            Self::ConnectionError(_) => "ConnectionError",
+            Self::AuthError(_) => "AuthError",
+            Self::TimeoutError(_) => "TimeoutError",
+            Self::RequestError(_) => "RequestError",
+            Self::SerializationError(_) => "SerializationError",
+            Self::IoError(_) => "IoError",
+            Self::TransportError(_) => "TransportError",
+            Self::ProtocolError(_) => "ProtocolError",
+            Self::Core(_) => "MillError",
+        }
+    }
+
+    /// Whether the same request may succeed if attempted again
+    ///
+    /// Connection, timeout and transport failures are transient; everything
+    /// else (bad configuration, rejected credentials, invalid requests) fails
+    /// the same way on every attempt.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::ConnectionError(_) | Self::TimeoutError(_) | Self::TransportError(_)
+        )
+    }
+
+    /// Structured representation for machine-readable error output
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "error_type": self.error_type(),
+            "message": self.to_string(),
+            "exit_code": self.exit_code(),
+        })
+    }
 }
 
 impl From<ClientError> for MillError {
@@ -96,3 +162,53 @@ impl From<ClientError> for MillError {
 
 /// Result type alias for client operations
 pub type ClientResult<T> = Result<T, ClientError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_error_json() {
+        let err = ClientError::connection("Failed to connect: refused");
+
+        assert_eq!(err.exit_code(), 3);
+        assert_eq!(
+            err.to_json(),
+            serde_json::json!({
+                "error_type": "ConnectionError",
+                "message": "Connection error: Failed to connect: refused",
+                "exit_code": 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_exit_codes_are_distinct() {
+        let errors = [
+            ClientError::config(""),
+            ClientError::connection(""),
+            ClientError::authentication(""),
+            ClientError::timeout(""),
+            ClientError::request(""),
+            ClientError::serialization(""),
+            ClientError::io(""),
+            ClientError::transport(""),
+            ClientError::protocol(""),
+            ClientError::Core(MillError::internal("")),
+        ];
+        let codes: std::collections::HashSet<i32> = errors.iter().map(|e| e.exit_code()).collect();
+        assert_eq!(codes.len(), errors.len());
+    }
+
+    #[test]
+    fn test_retryable_classification() {
+        assert!(ClientError::connection("refused").is_retryable());
+        assert!(ClientError::timeout("no response").is_retryable());
+        assert!(ClientError::transport("reset").is_retryable());
+
+        assert!(!ClientError::authentication("bad token").is_retryable());
+        assert!(!ClientError::config("missing url").is_retryable());
+        assert!(!ClientError::request("unknown tool").is_retryable());
+        assert!(!ClientError::Core(MillError::internal("")).is_retryable());
+    }
+}
@@ -3,6 +3,7 @@ use crate::websocket::MCPResponse;
 use console::{style, Emoji};
 use indicatif::{ProgressBar, ProgressStyle};
 use mill_foundation::planning::RefactorPlan;
+use mill_plugin_api::{Symbol, SymbolKind, SymbolNode};
 use serde_json::Value;
 use std::fmt::Write;
 use std::time::Duration;
@@ -338,6 +339,23 @@ impl Formatter {
                 ClientError::SerializationError(format!("Failed to write output: {}", e))
             })?;
 
+            // Parse errors carry the offending source line with a caret
+            if let Some(snippet) = error
+                .data
+                .as_ref()
+                .and_then(|data| data.pointer("/details/snippet"))
+                .and_then(Value::as_str)
+            {
+                let snippet = if self.use_colors {
+                    style(snippet).yellow().to_string()
+                } else {
+                    snippet.to_string()
+                };
+                writeln!(output, "\n{}", snippet).map_err(|e| {
+                    ClientError::SerializationError(format!("Failed to write output: {}", e))
+                })?;
+            }
+
             if let Some(ref data) = error.data {
                 writeln!(output, "\nError details:").map_err(|e| {
                     ClientError::SerializationError(format!("Failed to write output: {}", e))
@@ -351,12 +369,21 @@ impl Formatter {
                 ClientError::SerializationError(format!("Failed to write output: {}", e))
             })?;
 
-            writeln!(output, "\nResult:").map_err(|e| {
-                ClientError::SerializationError(format!("Failed to write output: {}", e))
-            })?;
-            writeln!(output, "{}", self.json(result)?).map_err(|e| {
-                ClientError::SerializationError(format!("Failed to write output: {}", e))
-            })?;
+            if let Some(groups) = symbol_groups(result) {
+                writeln!(output, "\nSymbols:").map_err(|e| {
+                    ClientError::SerializationError(format!("Failed to write output: {}", e))
+                })?;
+                write!(output, "{}", self.symbol_groups(&groups)).map_err(|e| {
+                    ClientError::SerializationError(format!("Failed to write output: {}", e))
+                })?;
+            } else {
+                writeln!(output, "\nResult:").map_err(|e| {
+                    ClientError::SerializationError(format!("Failed to write output: {}", e))
+                })?;
+                writeln!(output, "{}", self.json(result)?).map_err(|e| {
+                    ClientError::SerializationError(format!("Failed to write output: {}", e))
+                })?;
+            }
         } else {
             writeln!(output, "{}", self.success("Success (no result data)")).map_err(|e| {
                 ClientError::SerializationError(format!("Failed to write output: {}", e))
@@ -366,6 +393,64 @@ impl Formatter {
         Ok(output)
     }
 
+    /// Format a symbol list as an indented tree, one symbol per line
+    ///
+    /// Kinds are shown as icons, or as `[kind]` labels when emojis are
+    /// disabled. Symbol lines are 0-based and printed 1-based.
+    pub fn symbol_tree(&self, symbols: &[SymbolNode]) -> String {
+        let mut output = String::new();
+        self.write_symbols(&mut output, symbols, 0);
+        output
+    }
+
+    /// Format symbols grouped by file: each file path followed by its tree,
+    /// indented one level
+    fn symbol_groups(&self, groups: &[SymbolGroup]) -> String {
+        let mut output = String::new();
+        for group in groups {
+            match &group.file {
+                Some(file) => {
+                    output.push_str(&format!("{}\n", self.path(file)));
+                    self.write_symbols(&mut output, &group.symbols, 1);
+                }
+                None => self.write_symbols(&mut output, &group.symbols, 0),
+            }
+        }
+        output
+    }
+
+    fn write_symbols(&self, output: &mut String, symbols: &[SymbolNode], depth: usize) {
+        for node in symbols {
+            let symbol = &node.symbol;
+            let kind = if self.use_emojis {
+                symbol_kind_icon(symbol.kind).to_string()
+            } else {
+                format!("[{}]", format!("{:?}", symbol.kind).to_lowercase())
+            };
+            let name = if self.use_colors {
+                style(&symbol.name).bold().to_string()
+            } else {
+                symbol.name.clone()
+            };
+            let line = format!("line {}", symbol.location.line + 1);
+            let line = if self.use_colors {
+                style(line).dim().to_string()
+            } else {
+                line
+            };
+
+            output.push_str(&format!(
+                "{}{} {} {}\n",
+                "  ".repeat(depth),
+                kind,
+                name,
+                line
+            ));
+
+            self.write_symbols(output, &node.children, depth + 1);
+        }
+    }
+
     /// Format a client error
     pub fn client_error(&self, error: &ClientError) -> String {
         match error {
@@ -545,6 +630,66 @@ impl Formatter {
     }
 }
 
+/// Symbols of one file in a tool result, as a tree
+struct SymbolGroup {
+    /// File the symbols belong to, `None` for a single ungrouped list
+    file: Option<String>,
+    symbols: Vec<SymbolNode>,
+}
+
+/// The symbol lists in a tool result, if the result holds any
+///
+/// Accepts a bare array of symbols, an object with a `symbols` array (such
+/// as a `ParsedSource`), or an object with a `files` map from path to symbol
+/// array (such as a `project_symbols` index). Files without symbols are left
+/// out. Every entry must be a [`Symbol`]; nesting comes from each symbol's
+/// `parent` index.
+fn symbol_groups(result: &Value) -> Option<Vec<SymbolGroup>> {
+    let lists: Vec<(Option<String>, &Vec<Value>)> = match result {
+        Value::Array(items) => vec![(None, items)],
+        Value::Object(map) => match (map.get("symbols"), map.get("files")) {
+            (Some(symbols), _) => vec![(None, symbols.as_array()?)],
+            (None, Some(Value::Object(files))) => files
+                .iter()
+                .map(|(file, symbols)| Some((Some(file.clone()), symbols.as_array()?)))
+                .collect::<Option<_>>()?,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let groups: Vec<SymbolGroup> = lists
+        .into_iter()
+        .filter(|(_, symbols)| !symbols.is_empty())
+        .map(|(file, symbols)| {
+            let symbols: Vec<Symbol> =
+                serde_json::from_value(Value::Array(symbols.clone())).ok()?;
+            Some(SymbolGroup {
+                file,
+                symbols: SymbolNode::build_tree(symbols),
+            })
+        })
+        .collect::<Option<_>>()?;
+    (!groups.is_empty()).then_some(groups)
+}
+
+/// Icon shown for a symbol kind in the symbol tree
+fn symbol_kind_icon(kind: SymbolKind) -> &'static str {
+    match kind {
+        SymbolKind::Function => "🔧",
+        SymbolKind::Method => "🔹",
+        SymbolKind::Class => "🏛️",
+        SymbolKind::Struct => "🧱",
+        SymbolKind::Enum => "🔢",
+        SymbolKind::Interface => "🔌",
+        SymbolKind::Variable => "📌",
+        SymbolKind::Constant => "🔒",
+        SymbolKind::Module => "📦",
+        SymbolKind::Field => "🏷️",
+        SymbolKind::Other => "•",
+    }
+}
+
 /// Format a refactor plan into a human-readable sentence
 ///
 /// Produces sentences like:
@@ -687,6 +832,99 @@ mod tests {
         assert!(result.contains("\"data\": null"));
     }
 
+    #[test]
+    fn test_symbol_tree_nests_by_parent() {
+        let result = json!({
+            "symbols": [
+                {"name": "Config", "kind": "Struct", "location": {"line": 0, "column": 0}},
+                {"name": "main", "kind": "Function", "location": {"line": 11, "column": 0}},
+                {"name": "new", "kind": "Method", "location": {"line": 5, "column": 4}, "parent": 0}
+            ]
+        });
+        let groups = symbol_groups(&result).unwrap();
+        let symbols = &groups[0].symbols;
+
+        let plain = Formatter::with_settings(false, false).symbol_tree(symbols);
+        assert_eq!(
+            plain,
+            "[struct] Config line 1\n  [method] new line 6\n[function] main line 12\n"
+        );
+
+        let icons = Formatter::with_settings(false, true).symbol_tree(symbols);
+        assert!(icons.starts_with("🧱 Config line 1\n  🔹 new line 6\n"));
+
+        assert!(symbol_groups(&json!({"name": "test", "value": 42})).is_none());
+        assert!(symbol_groups(&json!([])).is_none());
+    }
+
+    #[test]
+    fn test_project_symbols_response_grouped_by_file() {
+        let symbol = |name: &str, kind: SymbolKind, line: usize, parent: Option<usize>| Symbol {
+            name: name.to_string(),
+            kind,
+            location: mill_plugin_api::SourceLocation { line, column: 0 },
+            end_location: None,
+            documentation: None,
+            is_test: false,
+            parent,
+        };
+        // Shape of a `project_symbols` result: symbols keyed by relative path
+        let response: MCPResponse = serde_json::from_value(json!({
+            "id": "1",
+            "result": {
+                "files": {
+                    "scripts/tool.py": [symbol("main", SymbolKind::Function, 3, None)],
+                    "src/empty.rs": [],
+                    "src/lib.rs": [
+                        symbol("Config", SymbolKind::Struct, 0, None),
+                        symbol("new", SymbolKind::Method, 2, Some(0)),
+                    ],
+                },
+                "skipped": [],
+            }
+        }))
+        .unwrap();
+
+        let output = Formatter::with_settings(false, false)
+            .mcp_response(&response)
+            .unwrap();
+
+        assert!(
+            output.ends_with(
+                "Symbols:\nscripts/tool.py\n  [function] main line 4\nsrc/lib.rs\n  [struct] Config line 1\n    [method] new line 3\n"
+            ),
+            "unexpected output: {}",
+            output
+        );
+    }
+
+    #[test]
+    fn test_mcp_error_renders_parse_snippet() {
+        let response: MCPResponse = serde_json::from_value(json!({
+            "id": "1",
+            "error": {
+                "code": -1,
+                "message": "Parse error: expected `)`",
+                "data": {
+                    "code": "E1009",
+                    "message": "Parse error: expected `)`",
+                    "category": "parse_error",
+                    "details": {"line": 2, "column": 11, "snippet": "3 | fn broken( {\n  |            ^"}
+                }
+            }
+        }))
+        .unwrap();
+
+        let output = Formatter::with_settings(false, false)
+            .mcp_response(&response)
+            .unwrap();
+        assert!(
+            output.contains("\n3 | fn broken( {\n  |            ^\n"),
+            "{}",
+            output
+        );
+    }
+
     #[test]
     fn test_key_value_formatting() {
         let formatter = Formatter::with_settings(false, false);
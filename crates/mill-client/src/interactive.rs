@@ -86,6 +86,10 @@ impl Interactive {
     /// Prompt for a URL with validation
     pub fn url_input(&self, prompt: &str, default: Option<&str>) -> ClientResult<String> {
         self.input(prompt, default, |input| {
+            // `${VAR}` templates are kept and expanded when the config is read
+            if input.contains("${") {
+                return Ok(());
+            }
             Url::parse(input.as_str())
                 .map(|_| ())
                 .map_err(|e| format!("Invalid URL: {}", e))
@@ -269,7 +273,10 @@ impl Interactive {
             match self.url_input("Server URL", Some(default_url)) {
                 Ok(url) => {
                     // Additional validation for WebSocket URLs
-                    if url.starts_with("ws://") || url.starts_with("wss://") {
+                    if url.starts_with("ws://")
+                        || url.starts_with("wss://")
+                        || url.starts_with("${")
+                    {
                         return Ok(url);
                     } else if url.starts_with("http://") {
                         let ws_url = url.replace("http://", "ws://");
@@ -19,7 +19,7 @@ pub mod formatting;
 pub mod interactive;
 pub mod websocket;
 
-pub use client_config::{ClientConfig, ConfigBuilder};
+pub use client_config::{ClientConfig, ConfigBuilder, InterpolatedField};
 pub use error::{ClientError, ClientResult};
 pub use formatting::format_plan;
 
@@ -115,19 +115,7 @@ impl SessionReport {
 
     /// Add an error to the error summary
     pub fn add_error(&mut self, error: ClientError) {
-        let error_type = match error {
-            ClientError::ConfigError(_) => "ConfigError",
-            ClientError::ConnectionError(_) => "ConnectionError",
-            ClientError::AuthError(_) => "AuthError",
-            ClientError::TimeoutError(_) => "TimeoutError",
-            ClientError::RequestError(_) => "RequestError",
-            ClientError::SerializationError(_) => "SerializationError",
-            ClientError::IoError(_) => "IoError",
-            ClientError::TransportError(_) => "TransportError",
-            ClientError::ProtocolError(_) => "ProtocolError",
-            ClientError::Core(_) => "MillError",
-        }
-        .to_string();
+        let error_type = error.error_type().to_string();
 
         let message = error.to_string();
         let now = chrono::Utc::now();
@@ -243,6 +231,16 @@ pub struct CliArgs {
     /// Disable emoji icons in output.
     #[arg(long, global = true, help_heading = "Display")]
     pub no_emoji: bool,
+
+    /// How errors are reported on stderr. `call --format json` implies `json`.
+    #[arg(
+        long,
+        global = true,
+        default_value = "text",
+        value_enum,
+        help_heading = "Display"
+    )]
+    pub error_format: ErrorFormatArg,
 }
 
 /// Defines the available subcommands for the CLI.
@@ -264,7 +262,9 @@ pub enum Commands {
         url: Option<String>,
 
         /// The authentication token for the server. Overrides config and environment variables.
-        #[arg(short, long, help_heading = "Connection")]
+        ///
+        /// Has no short form: `-t` is the global `--timeout`.
+        #[arg(long, help_heading = "Connection")]
         token: Option<String>,
 
         /// Disable automatic reconnection if the connection is lost.
@@ -293,7 +293,9 @@ pub enum Commands {
         url: Option<String>,
 
         /// The authentication token for the server. Overrides all other settings.
-        #[arg(short, long, help_heading = "Connection")]
+        ///
+        /// Has no short form: `-t` is the global `--timeout`.
+        #[arg(long, help_heading = "Connection")]
         token: Option<String>,
 
         /// The output format for the command's result.
@@ -307,6 +309,19 @@ pub enum Commands {
         /// Read the JSON parameters from standard input (stdin).
         #[arg(long, conflicts_with = "params_file")]
         params_stdin: bool,
+
+        /// Print only the value at this path of the response (e.g., `result.symbols[0].name`).
+        #[arg(long)]
+        select: Option<String>,
+
+        /// Write the output to this file (replaced atomically, without ANSI colors).
+        #[arg(long)]
+        output_file: Option<String>,
+
+        /// Retry the call up to N times on connection, timeout, or transport errors.
+        /// Only use this for tools that are safe to run more than once.
+        #[arg(long, value_name = "N", default_value_t = 0)]
+        retries: u32,
     },
 
     /// Check client status and verify connectivity to the server.
@@ -319,12 +334,18 @@ pub enum Commands {
         url: Option<String>,
 
         /// The authentication token to use for the check.
-        #[arg(short, long, help_heading = "Connection")]
+        ///
+        /// Has no short form: `-t` is the global `--timeout`.
+        #[arg(long, help_heading = "Connection")]
         token: Option<String>,
 
         /// Show detailed information, including configuration sources and values.
         #[arg(short, long)]
         verbose: bool,
+
+        /// Print a machine-readable JSON status report (implies `--error-format json`).
+        #[arg(long)]
+        json: bool,
     },
 
     /// Manage MCP server presets.
@@ -387,6 +408,15 @@ pub enum OutputFormatArg {
     Raw,
 }
 
+/// Format of the error report written to stderr on failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ErrorFormatArg {
+    /// Human-readable message.
+    Text,
+    /// A JSON object with `error_type`, `message`, and `exit_code`.
+    Json,
+}
+
 impl From<OutputFormatArg> for OutputFormat {
     fn from(arg: OutputFormatArg) -> Self {
         match arg {
@@ -401,6 +431,15 @@ impl From<OutputFormatArg> for OutputFormat {
 pub async fn run_cli() -> ClientResult<()> {
     let args = CliArgs::parse();
 
+    let json_errors = args.error_format == ErrorFormatArg::Json
+        || matches!(
+            args.command,
+            Commands::Call {
+                format: OutputFormatArg::Json,
+                ..
+            } | Commands::Status { json: true, .. }
+        );
+
     // Initialize logging if debug is enabled
     if args.debug {
         tracing_subscriber::fmt()
@@ -446,8 +485,13 @@ pub async fn run_cli() -> ClientResult<()> {
             format,
             params_file,
             params_stdin,
+            select,
+            output_file,
+            retries,
         } => {
-            let mut cmd = CallCommand::new(tool, params).with_format(format.into());
+            let mut cmd = CallCommand::new(tool, params)
+                .with_format(format.into())
+                .with_retries(retries);
 
             if let Some(url) = url {
                 cmd = cmd.with_url(url);
@@ -461,6 +505,12 @@ pub async fn run_cli() -> ClientResult<()> {
             if params_stdin {
                 cmd = cmd.with_params_stdin();
             }
+            if let Some(path) = select {
+                cmd = cmd.with_select(path);
+            }
+            if let Some(path) = output_file {
+                cmd = cmd.with_output_file(path);
+            }
 
             cmd.execute(&global_args).await
         }
@@ -468,8 +518,11 @@ pub async fn run_cli() -> ClientResult<()> {
             url,
             token,
             verbose,
+            json,
         } => {
-            let cmd = StatusCommand::new(url, token).with_verbose(verbose);
+            let cmd = StatusCommand::new(url, token)
+                .with_verbose(verbose)
+                .with_json(json);
             cmd.execute(&global_args).await
         }
         #[cfg(feature = "mcp-proxy")]
@@ -508,27 +561,22 @@ pub async fn run_cli() -> ClientResult<()> {
         }
         Err(e) => {
             // Error handling - always display error to stderr
-            let formatter =
-                formatting::Formatter::with_settings(!global_args.no_color, !global_args.no_emoji);
-            eprintln!("{}", formatter.client_error(&e));
+            if json_errors {
+                eprintln!("{}", e.to_json());
+            } else {
+                let formatter = formatting::Formatter::with_settings(
+                    !global_args.no_color,
+                    !global_args.no_emoji,
+                );
+                eprintln!("{}", formatter.client_error(&e));
+            }
 
             if global_args.debug {
                 tracing::error!("Command failed: {:?}", e);
             }
 
-            // Set appropriate exit codes
-            match e {
-                ClientError::ConfigError(_) => std::process::exit(2),
-                ClientError::ConnectionError(_) => std::process::exit(3),
-                ClientError::AuthError(_) => std::process::exit(4),
-                ClientError::TimeoutError(_) => std::process::exit(5),
-                ClientError::RequestError(_) => std::process::exit(6),
-                ClientError::SerializationError(_) => std::process::exit(7),
-                ClientError::IoError(_) => std::process::exit(8),
-                ClientError::TransportError(_) => std::process::exit(9),
-                ClientError::ProtocolError(_) => std::process::exit(10),
-                ClientError::Core(_) => std::process::exit(11),
-            }
+            // Set appropriate exit codes (see `ClientError::exit_code`)
+            std::process::exit(e.exit_code())
         }
     }
 }
@@ -578,6 +626,20 @@ pub(crate) fn is_terminal() -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_cli_short_timeout_flag() {
+        CliArgs::command().debug_assert();
+
+        let args =
+            CliArgs::try_parse_from(["mill", "call", "read_file", "-t", "5000", "--token", "abc"])
+                .unwrap();
+        assert_eq!(args.timeout, Some(5000));
+        match args.command {
+            Commands::Call { token, .. } => assert_eq!(token.as_deref(), Some("abc")),
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
     #[test]
     fn test_global_args_creation() {
         let global_args = GlobalArgs {
@@ -621,7 +683,7 @@ mod tests {
         .unwrap();
 
         assert_eq!(config.get_url().unwrap(), "ws://localhost:3000");
-        assert_eq!(config.get_token(), Some("test-token"));
+        assert_eq!(config.get_token().unwrap().as_deref(), Some("test-token"));
         assert_eq!(config.get_timeout_ms(), 60000);
     }
 }
@@ -1,14 +1,24 @@
 use crate::client_config::ClientConfig;
 use crate::error::{ClientError, ClientResult};
+use crate::SessionReport;
 use futures_util::{SinkExt, StreamExt};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, RootCertStore, SignatureScheme};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::{mpsc, oneshot, Mutex};
-use tokio::time::timeout;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tokio::time::{timeout, timeout_at, MissedTickBehavior};
+use tokio_tungstenite::{
+    connect_async_tls_with_config,
+    tungstenite::{Bytes, Message},
+    Connector,
+};
 use tracing::{debug, error, info, warn};
 use url::Url;
 
@@ -23,6 +33,13 @@ pub struct MCPRequest {
     pub params: Option<serde_json::Value>,
 }
 
+/// MCP notification message (no response expected)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MCPNotification {
+    pub method: String,
+    pub params: Option<serde_json::Value>,
+}
+
 /// MCP response message
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MCPResponse {
@@ -51,6 +68,260 @@ pub enum ConnectionState {
     Failed,
 }
 
+/// Keep-alive ping settings for a connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeepAliveSettings {
+    /// Time between pings
+    pub interval: Duration,
+    /// How long to wait for the matching pong
+    pub pong_timeout: Duration,
+}
+
+impl KeepAliveSettings {
+    /// Read keep-alive settings from the client configuration
+    ///
+    /// Returns `None` when keep-alive pings are disabled.
+    pub fn from_config(config: &ClientConfig) -> Option<Self> {
+        match config.get_keep_alive_interval_ms() {
+            0 => None,
+            interval_ms => Some(Self {
+                interval: Duration::from_millis(interval_ms),
+                pong_timeout: Duration::from_millis(config.get_pong_timeout_ms()),
+            }),
+        }
+    }
+}
+
+/// Delay before the first reconnection attempt, doubled on every attempt
+const RECONNECT_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// How long `cancel_request` waits for the notification to be written
+const CANCEL_WRITE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Exponential reconnect backoff with full jitter
+///
+/// Attempt `n` waits a random delay between zero and
+/// `min(max_backoff, base * 2^n)`, which spreads reconnecting clients out
+/// instead of having them retry in lockstep.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReconnectBackoff {
+    base: Duration,
+    max_backoff: Duration,
+    max_attempts: Option<u32>,
+    attempts: u32,
+}
+
+impl ReconnectBackoff {
+    pub fn new(base: Duration, max_backoff: Duration, max_attempts: Option<u32>) -> Self {
+        Self {
+            base,
+            max_backoff,
+            max_attempts,
+            attempts: 0,
+        }
+    }
+
+    /// Read the backoff limits from the client configuration
+    pub fn from_config(config: &ClientConfig) -> Self {
+        Self::new(
+            RECONNECT_BASE_DELAY,
+            Duration::from_millis(config.get_reconnect_max_backoff_ms()),
+            config.get_reconnect_max_attempts(),
+        )
+    }
+
+    /// Number of attempts handed out so far
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Delay before the next attempt, or `None` once `max_attempts` is used up
+    pub fn next_delay(&mut self, rng: &mut fastrand::Rng) -> Option<Duration> {
+        if self.max_attempts.is_some_and(|max| self.attempts >= max) {
+            return None;
+        }
+        let ceiling = self
+            .base
+            .saturating_mul(2u32.saturating_pow(self.attempts))
+            .min(self.max_backoff);
+        self.attempts += 1;
+        Some(Duration::from_millis(
+            rng.u64(0..=ceiling.as_millis() as u64),
+        ))
+    }
+}
+
+/// Why a keep-alive loop stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeepAliveExit {
+    /// No pong arrived within the pong timeout
+    MissedPong,
+    /// The connection was closed (outgoing or pong channel dropped)
+    Closed,
+}
+
+/// Send pings every `settings.interval` and wait for the matching pongs
+///
+/// Pings go out through `outgoing`; pong payloads received on the socket
+/// must be forwarded to `pongs`. Each ping carries a sequence number, so
+/// late pongs for earlier pings are ignored. The round-trip time of every
+/// answered ping is stored in `latency`.
+pub async fn run_keep_alive(
+    settings: KeepAliveSettings,
+    outgoing: mpsc::UnboundedSender<Message>,
+    mut pongs: mpsc::UnboundedReceiver<Bytes>,
+    latency: Arc<Mutex<Option<Duration>>>,
+) -> KeepAliveExit {
+    let mut ticker = tokio::time::interval(settings.interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    // The first tick completes immediately; the first ping waits one interval
+    ticker.tick().await;
+
+    let mut sequence: u64 = 0;
+    loop {
+        ticker.tick().await;
+        sequence += 1;
+        let payload = Bytes::copy_from_slice(&sequence.to_be_bytes());
+
+        let sent_at = Instant::now();
+        if outgoing.send(Message::Ping(payload.clone())).is_err() {
+            return KeepAliveExit::Closed;
+        }
+
+        let deadline = tokio::time::Instant::from_std(sent_at + settings.pong_timeout);
+        loop {
+            match timeout_at(deadline, pongs.recv()).await {
+                Ok(Some(pong)) if pong == payload => {
+                    let round_trip = sent_at.elapsed();
+                    debug!(latency_ms = round_trip.as_millis() as u64, "Received pong");
+                    *latency.lock().await = Some(round_trip);
+                    break;
+                }
+                // Pong for an earlier ping
+                Ok(Some(_)) => continue,
+                Ok(None) => return KeepAliveExit::Closed,
+                Err(_) => return KeepAliveExit::MissedPong,
+            }
+        }
+    }
+}
+
+/// Build the root certificate store used to verify `wss://` servers
+///
+/// Starts from the bundled Mozilla roots and adds every certificate in the
+/// PEM file at `custom_ca`, if given.
+pub fn tls_root_store(custom_ca: Option<&Path>) -> ClientResult<RootCertStore> {
+    let mut roots = RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+
+    if let Some(path) = custom_ca {
+        let certs = CertificateDer::pem_file_iter(path)
+            .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+            .map_err(|e| {
+                ClientError::ConfigError(format!(
+                    "Failed to read custom CA file {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+        if certs.is_empty() {
+            return Err(ClientError::ConfigError(format!(
+                "No certificates found in custom CA file {}",
+                path.display()
+            )));
+        }
+        for cert in certs {
+            roots.add(cert).map_err(|e| {
+                ClientError::ConfigError(format!(
+                    "Invalid certificate in custom CA file {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+        }
+    }
+
+    Ok(roots)
+}
+
+/// Build the TLS connector for `wss://` connections from the client configuration
+pub fn build_tls_connector(config: &ClientConfig) -> ClientResult<Connector> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let builder = rustls::ClientConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|e| ClientError::ConfigError(format!("Invalid TLS configuration: {}", e)))?;
+
+    let tls_config = if config.accepts_invalid_certs() {
+        warn!("TLS certificate verification is disabled; the server identity is not checked");
+        builder
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(AcceptAnyCertificate { provider }))
+            .with_no_client_auth()
+    } else {
+        builder
+            .with_root_certificates(tls_root_store(config.get_custom_ca_path()?.as_deref())?)
+            .with_no_client_auth()
+    };
+
+    Ok(Connector::Rustls(Arc::new(tls_config)))
+}
+
+/// Certificate verifier used when `accept_invalid_certs` is enabled
+///
+/// Accepts any server certificate but still checks handshake signatures.
+#[derive(Debug)]
+struct AcceptAnyCertificate {
+    provider: Arc<rustls::crypto::CryptoProvider>,
+}
+
+impl ServerCertVerifier for AcceptAnyCertificate {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
+
 /// WebSocket client for MCP communication
 pub struct WebSocketClient {
     config: ClientConfig,
@@ -58,12 +329,26 @@ pub struct WebSocketClient {
     next_id: AtomicU64,
     pending_requests: Arc<Mutex<HashMap<String, oneshot::Sender<ClientResult<MCPResponse>>>>>,
     connection: Arc<Mutex<Option<Connection>>>,
+    /// Round-trip time of the most recent keep-alive ping
+    ping_latency: Arc<Mutex<Option<Duration>>>,
 }
 
 /// Internal connection wrapper
 struct Connection {
     sender: mpsc::UnboundedSender<Message>,
+    /// Messages whose sender waits until the frame has been written
+    acked_sender: mpsc::UnboundedSender<(Message, oneshot::Sender<()>)>,
     _handle: tokio::task::JoinHandle<()>,
+    /// The keep-alive task holds a sender and would outlive the socket
+    keep_alive: tokio::task::AbortHandle,
+}
+
+impl Connection {
+    /// Ask the server to close the socket and stop the keep-alive task
+    fn close(self) {
+        let _ = self.sender.send(Message::Close(None));
+        self.keep_alive.abort();
+    }
 }
 
 impl WebSocketClient {
@@ -75,6 +360,7 @@ impl WebSocketClient {
             next_id: AtomicU64::new(1),
             pending_requests: Arc::new(Mutex::new(HashMap::new())),
             connection: Arc::new(Mutex::new(None)),
+            ping_latency: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -93,10 +379,15 @@ impl WebSocketClient {
         info!(url = %url, "Connecting to server");
 
         // Parse URL and establish connection
-        let url = Url::parse(url)
+        let url = Url::parse(&url)
             .map_err(|e| ClientError::ConnectionError(format!("Invalid URL: {}", e)))?;
 
-        let (ws_stream, _) = connect_async(url.as_str())
+        let connector = match url.scheme() {
+            "wss" => Some(build_tls_connector(&self.config)?),
+            _ => None,
+        };
+
+        let (ws_stream, _) = connect_async_tls_with_config(url.as_str(), None, false, connector)
             .await
             .map_err(|e| ClientError::ConnectionError(format!("Failed to connect: {}", e)))?;
 
@@ -107,6 +398,10 @@ impl WebSocketClient {
 
         // Create a channel for sending messages
         let (tx, mut rx) = mpsc::unbounded_channel::<Message>();
+        let (acked_tx, mut acked_rx) = mpsc::unbounded_channel::<(Message, oneshot::Sender<()>)>();
+
+        // Pong payloads are forwarded from the read task to the keep-alive task
+        let (pong_tx, pong_rx) = mpsc::unbounded_channel::<Bytes>();
 
         // Clone Arcs for the background tasks
         let state_clone = Arc::clone(&self.state);
@@ -116,11 +411,20 @@ impl WebSocketClient {
         let write_handle = {
             let state = Arc::clone(&state_clone);
             tokio::spawn(async move {
-                while let Some(message) = rx.recv().await {
+                loop {
+                    let (message, written) = tokio::select! {
+                        Some(message) = rx.recv() => (message, None),
+                        Some((message, written)) = acked_rx.recv() => (message, Some(written)),
+                        else => break,
+                    };
+                    // `send` flushes the sink, so the frame is on the wire
                     if let Err(e) = write.send(message).await {
                         error!(error = %e, "Failed to send message");
                         break;
                     }
+                    if let Some(written) = written {
+                        let _ = written.send(());
+                    }
                 }
                 debug!("Write task ending");
                 let mut state = state.lock().await;
@@ -143,6 +447,9 @@ impl WebSocketClient {
                                 warn!(error = %e, "Failed to handle message");
                             }
                         }
+                        Ok(Message::Pong(payload)) => {
+                            let _ = pong_tx.send(payload);
+                        }
                         Ok(Message::Close(_)) => {
                             info!("WebSocket connection closed by server");
                             break;
@@ -163,21 +470,62 @@ impl WebSocketClient {
             })
         };
 
-        // Combine both handles
-        let combined_handle = tokio::spawn(async move {
-            tokio::select! {
-                _ = write_handle => {},
-                _ = read_handle => {},
-            }
-        });
+        // Spawn keep-alive task; a missed pong marks the connection as lost
+        // so callers run their reconnection logic
+        let keep_alive_handle = {
+            let settings = KeepAliveSettings::from_config(&self.config);
+            let outgoing = tx.clone();
+            let state = Arc::clone(&state_clone);
+            let pending_requests = Arc::clone(&pending_requests_clone);
+            let latency = Arc::clone(&self.ping_latency);
+            tokio::spawn(async move {
+                let Some(settings) = settings else {
+                    return std::future::pending::<()>().await;
+                };
+                if run_keep_alive(settings, outgoing.clone(), pong_rx, latency).await
+                    == KeepAliveExit::MissedPong
+                {
+                    warn!(
+                        timeout_ms = settings.pong_timeout.as_millis() as u64,
+                        "No pong received, treating connection as lost"
+                    );
+                    let _ = outgoing.send(Message::Close(None));
+                    *state.lock().await = ConnectionState::Disconnected;
+                    for (_, sender) in pending_requests.lock().await.drain() {
+                        let _ = sender.send(Err(ClientError::ConnectionError(
+                            "Keep-alive pong not received".to_string(),
+                        )));
+                    }
+                }
+            })
+        };
+
+        // Combine all handles; once the socket is gone the keep-alive task
+        // has nothing left to ping
+        let keep_alive = keep_alive_handle.abort_handle();
+        let combined_handle = {
+            let keep_alive = keep_alive.clone();
+            tokio::spawn(async move {
+                tokio::select! {
+                    _ = write_handle => {},
+                    _ = read_handle => {},
+                    _ = keep_alive_handle => {},
+                }
+                keep_alive.abort();
+            })
+        };
 
-        // Store the connection
+        // Store the connection, closing any previous one
         {
             let mut connection = self.connection.lock().await;
-            *connection = Some(Connection {
+            if let Some(previous) = connection.replace(Connection {
                 sender: tx,
+                acked_sender: acked_tx,
                 _handle: combined_handle,
-            });
+                keep_alive,
+            }) {
+                previous.close();
+            }
         }
 
         // Update state to connected
@@ -196,7 +544,7 @@ impl WebSocketClient {
 
     /// Authenticate with the server using JWT token
     async fn authenticate(&self) -> ClientResult<()> {
-        let token = self.config.get_token().ok_or_else(|| {
+        let token = self.config.get_token()?.ok_or_else(|| {
             ClientError::AuthError("No authentication token configured".to_string())
         })?;
 
@@ -299,13 +647,60 @@ impl WebSocketClient {
             }
             Err(_) => {
                 // Clean up pending request
-                let mut pending = self.pending_requests.lock().await;
-                pending.remove(&request_id);
+                {
+                    let mut pending = self.pending_requests.lock().await;
+                    pending.remove(&request_id);
+                }
+                // Tell the server to stop working on it
+                if let Err(e) = self.cancel_request(&request_id, "Request timed out").await {
+                    warn!(request_id = %request_id, error = %e, "Failed to send cancellation");
+                }
                 Err(ClientError::TimeoutError("Request timed out".to_string()))
             }
         }
     }
 
+    /// Send an MCP cancellation notification for an in-flight request
+    pub async fn cancel_request(&self, request_id: &str, reason: &str) -> ClientResult<()> {
+        let notification = MCPNotification {
+            method: "notifications/cancelled".to_string(),
+            params: Some(serde_json::json!({
+                "requestId": request_id,
+                "reason": reason,
+            })),
+        };
+        let message = serde_json::to_string(&notification).map_err(|e| {
+            ClientError::SerializationError(format!("Failed to serialize notification: {}", e))
+        })?;
+
+        debug!(request_id = %request_id, "Sending cancellation");
+
+        let (written_tx, written_rx) = oneshot::channel();
+        {
+            let connection = self.connection.lock().await;
+            let conn = connection
+                .as_ref()
+                .ok_or_else(|| ClientError::ConnectionError("No active connection".to_string()))?;
+            conn.acked_sender
+                .send((Message::Text(message.into()), written_tx))
+                .map_err(|e| {
+                    ClientError::ConnectionError(format!("Failed to send message: {}", e))
+                })?;
+        }
+
+        // Callers often exit right after cancelling, so wait until the
+        // notification has actually been written to the socket
+        match timeout(CANCEL_WRITE_TIMEOUT, written_rx).await {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(_)) => Err(ClientError::ConnectionError(
+                "Connection closed before the cancellation was sent".to_string(),
+            )),
+            Err(_) => Err(ClientError::TimeoutError(
+                "Timed out writing the cancellation".to_string(),
+            )),
+        }
+    }
+
     /// Call an MCP tool
     pub async fn call_tool(
         &self,
@@ -342,6 +737,48 @@ impl WebSocketClient {
         )
     }
 
+    /// Reconnect after the connection was lost
+    ///
+    /// Retries with [`ReconnectBackoff`] and records every attempt in
+    /// `report`. Fails with `ConnectionError` once the configured maximum
+    /// number of attempts has been made.
+    pub async fn reconnect(&self, report: &mut SessionReport) -> ClientResult<()> {
+        let mut backoff = ReconnectBackoff::from_config(&self.config);
+        let mut rng = fastrand::Rng::new();
+        let mut last_error = None;
+
+        // Stop the lost connection's tasks before dialing again
+        if let Some(previous) = self.connection.lock().await.take() {
+            previous.close();
+        }
+
+        while let Some(delay) = backoff.next_delay(&mut rng) {
+            *self.state.lock().await = ConnectionState::Reconnecting;
+            debug!(
+                attempt = backoff.attempts(),
+                delay_ms = delay.as_millis() as u64,
+                "Waiting before reconnection attempt"
+            );
+            tokio::time::sleep(delay).await;
+
+            report.record_reconnection();
+            match self.connect().await {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    warn!(attempt = backoff.attempts(), error = %e, "Reconnection attempt failed");
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        *self.state.lock().await = ConnectionState::Failed;
+        Err(ClientError::ConnectionError(format!(
+            "Giving up after {} reconnection attempts{}",
+            backoff.attempts(),
+            last_error.map(|e| format!(": {}", e)).unwrap_or_default()
+        )))
+    }
+
     /// Disconnect from the server
     pub async fn disconnect(&self) -> ClientResult<()> {
         info!("Disconnecting from server");
@@ -355,8 +792,7 @@ impl WebSocketClient {
         {
             let mut connection = self.connection.lock().await;
             if let Some(conn) = connection.take() {
-                let _ = conn.sender.send(Message::Close(None));
-                // The connection will be cleaned up when the task ends
+                conn.close();
             }
         }
 
@@ -412,6 +848,11 @@ impl WebSocketClient {
         Ok(start.elapsed())
     }
 
+    /// Round-trip time of the most recent keep-alive ping, if any was answered
+    pub async fn ping_latency(&self) -> Option<Duration> {
+        *self.ping_latency.lock().await
+    }
+
     /// Get server capabilities
     pub async fn get_capabilities(&self) -> ClientResult<serde_json::Value> {
         let request = MCPRequest {
@@ -444,6 +885,43 @@ impl Drop for WebSocketClient {
 mod tests {
     use super::*;
 
+    fn test_ca_path() -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/test_ca.pem")
+    }
+
+    #[test]
+    fn test_tls_root_store_adds_custom_ca() {
+        let default_roots = tls_root_store(None).unwrap();
+        assert_eq!(default_roots.len(), webpki_roots::TLS_SERVER_ROOTS.len());
+
+        let roots = tls_root_store(Some(&test_ca_path())).unwrap();
+        assert_eq!(roots.len(), default_roots.len() + 1);
+
+        let missing = tls_root_store(Some(Path::new("/nonexistent/ca.pem")));
+        assert!(matches!(missing, Err(ClientError::ConfigError(_))));
+    }
+
+    #[test]
+    fn test_build_tls_connector() {
+        let config = ClientConfig::builder()
+            .with_custom_ca_path(test_ca_path())
+            .build()
+            .unwrap();
+        assert!(matches!(
+            build_tls_connector(&config),
+            Ok(Connector::Rustls(_))
+        ));
+
+        let insecure = ClientConfig::builder()
+            .with_accept_invalid_certs(true)
+            .build()
+            .unwrap();
+        assert!(matches!(
+            build_tls_connector(&insecure),
+            Ok(Connector::Rustls(_))
+        ));
+    }
+
     #[test]
     fn test_mcp_request_serialization() {
         let request = MCPRequest {
@@ -493,4 +971,198 @@ mod tests {
         assert_ne!(id2, id3);
         assert_ne!(id1, id3);
     }
+
+    /// Channels standing in for the socket: pings sent by the keep-alive
+    /// loop and pongs fed back to it
+    struct MockSocket {
+        outgoing: mpsc::UnboundedReceiver<Message>,
+        pongs: mpsc::UnboundedSender<Bytes>,
+        latency: Arc<Mutex<Option<Duration>>>,
+        keep_alive: tokio::task::JoinHandle<KeepAliveExit>,
+    }
+
+    fn spawn_keep_alive(interval_ms: u64, pong_timeout_ms: u64) -> MockSocket {
+        let settings = KeepAliveSettings {
+            interval: Duration::from_millis(interval_ms),
+            pong_timeout: Duration::from_millis(pong_timeout_ms),
+        };
+        let (outgoing_tx, outgoing) = mpsc::unbounded_channel();
+        let (pongs, pong_rx) = mpsc::unbounded_channel();
+        let latency = Arc::new(Mutex::new(None));
+        let keep_alive = tokio::spawn(run_keep_alive(
+            settings,
+            outgoing_tx,
+            pong_rx,
+            Arc::clone(&latency),
+        ));
+        MockSocket {
+            outgoing,
+            pongs,
+            latency,
+            keep_alive,
+        }
+    }
+
+    #[test]
+    fn test_keep_alive_settings_from_config() {
+        let config = ClientConfig::builder()
+            .with_keep_alive(5000, 1000)
+            .build()
+            .unwrap();
+        assert_eq!(
+            KeepAliveSettings::from_config(&config),
+            Some(KeepAliveSettings {
+                interval: Duration::from_millis(5000),
+                pong_timeout: Duration::from_millis(1000),
+            })
+        );
+
+        let disabled = ClientConfig::builder()
+            .with_keep_alive(0, 1000)
+            .build()
+            .unwrap();
+        assert_eq!(KeepAliveSettings::from_config(&disabled), None);
+    }
+
+    #[tokio::test]
+    async fn test_keep_alive_sends_pings_at_interval() {
+        let mut socket = spawn_keep_alive(50, 500);
+        let started = Instant::now();
+
+        for _ in 0..3 {
+            let ping = timeout(Duration::from_secs(2), socket.outgoing.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            let Message::Ping(payload) = ping else {
+                panic!("expected a ping, got {:?}", ping);
+            };
+            socket.pongs.send(payload).unwrap();
+        }
+
+        // Three pings, each one interval after the previous pong
+        assert!(started.elapsed() >= Duration::from_millis(150));
+        assert!(socket.latency.lock().await.is_some());
+        assert!(!socket.keep_alive.is_finished());
+        socket.keep_alive.abort();
+    }
+
+    #[tokio::test]
+    async fn test_keep_alive_detects_missing_pong() {
+        let mut socket = spawn_keep_alive(20, 100);
+
+        let ping = timeout(Duration::from_secs(2), socket.outgoing.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(ping, Message::Ping(_)));
+        let ping_sent = Instant::now();
+
+        // A pong for some other ping does not count
+        socket
+            .pongs
+            .send(Bytes::copy_from_slice(&99u64.to_be_bytes()))
+            .unwrap();
+
+        let exit = timeout(Duration::from_secs(2), &mut socket.keep_alive)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(exit, KeepAliveExit::MissedPong);
+        assert!(ping_sent.elapsed() < Duration::from_secs(1));
+        assert!(socket.latency.lock().await.is_none());
+    }
+
+    #[test]
+    fn test_reconnect_backoff_sequence_is_jittered_exponential() {
+        let mut backoff = ReconnectBackoff::new(
+            Duration::from_millis(100),
+            Duration::from_millis(1000),
+            None,
+        );
+        let mut rng = fastrand::Rng::with_seed(7);
+        let mut expected_rng = fastrand::Rng::with_seed(7);
+
+        for ceiling_ms in [100, 200, 400, 800, 1000, 1000] {
+            let delay = backoff.next_delay(&mut rng).unwrap();
+            assert_eq!(
+                delay,
+                Duration::from_millis(expected_rng.u64(0..=ceiling_ms))
+            );
+            assert!(delay <= Duration::from_millis(ceiling_ms));
+        }
+        assert_eq!(backoff.attempts(), 6);
+    }
+
+    /// Accept WebSocket connections and hold them open until the client closes
+    async fn spawn_idle_server() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    if let Ok(mut ws) = tokio_tungstenite::accept_async(stream).await {
+                        while let Some(Ok(_)) = ws.next().await {}
+                    }
+                });
+            }
+        });
+        format!("ws://{}", addr)
+    }
+
+    async fn keep_alive_of(client: &WebSocketClient) -> tokio::task::AbortHandle {
+        client
+            .connection
+            .lock()
+            .await
+            .as_ref()
+            .map(|conn| conn.keep_alive.clone())
+            .expect("connected")
+    }
+
+    #[tokio::test]
+    async fn test_keep_alive_stopped_on_disconnect_and_reconnect() {
+        let url = spawn_idle_server().await;
+        let config = ClientConfig::builder()
+            .with_url(url.clone())
+            .with_keep_alive(20, 1000)
+            .with_reconnect_max_backoff_ms(1)
+            .build()
+            .unwrap();
+        let client = WebSocketClient::new(config);
+
+        client.connect().await.unwrap();
+        let first = keep_alive_of(&client).await;
+        *client.state.lock().await = ConnectionState::Disconnected;
+        let mut report = SessionReport::new(url, false);
+        client.reconnect(&mut report).await.unwrap();
+        let second = keep_alive_of(&client).await;
+
+        client.disconnect().await.unwrap();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !(first.is_finished() && second.is_finished()) {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("keep-alive tasks are aborted");
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_gives_up_after_max_attempts() {
+        let config = ClientConfig::builder()
+            .with_url("ws://127.0.0.1:1".to_string())
+            .with_reconnect_max_backoff_ms(1)
+            .with_reconnect_max_attempts(3)
+            .build()
+            .unwrap();
+        let client = WebSocketClient::new(config);
+        let mut report = SessionReport::new("ws://127.0.0.1:1".to_string(), false);
+
+        let result = client.reconnect(&mut report).await;
+
+        assert!(matches!(result, Err(ClientError::ConnectionError(_))));
+        assert_eq!(report.connection_info.reconnection_attempts, 3);
+        assert_eq!(client.get_state().await, ConnectionState::Failed);
+    }
 }
@@ -0,0 +1,123 @@
+//! Process-level tests for the CLI's structured error output
+
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Run the client binary with an isolated home so no user config is picked up
+fn run_client(args: &[&str]) -> std::process::Output {
+    let home = TempDir::new().unwrap();
+    Command::new(env!("CARGO_BIN_EXE_mill-client"))
+        .args(args)
+        .env("HOME", home.path())
+        .env_remove("TYPEMILL_URL")
+        .env_remove("TYPEMILL_TOKEN")
+        .output()
+        .unwrap()
+}
+
+/// An address nothing listens on, so connecting fails immediately
+fn unreachable_url() -> String {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    drop(listener);
+    format!("ws://127.0.0.1:{}", port)
+}
+
+#[test]
+fn test_connection_error_reported_as_json() {
+    let url = unreachable_url();
+    let output = run_client(&[
+        "call",
+        "health_check",
+        "{}",
+        "--url",
+        &url,
+        "--format",
+        "json",
+    ]);
+
+    assert_eq!(output.status.code(), Some(3));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let line = stderr
+        .lines()
+        .find(|l| l.starts_with('{'))
+        .unwrap_or_else(|| panic!("no JSON error on stderr: {}", stderr));
+    let error: serde_json::Value = serde_json::from_str(line).unwrap();
+
+    assert_eq!(error["error_type"], "ConnectionError");
+    assert_eq!(error["exit_code"], 3);
+    assert!(error["message"]
+        .as_str()
+        .unwrap()
+        .contains("Failed to connect"));
+}
+
+#[test]
+fn test_error_format_flag_without_json_output() {
+    let url = unreachable_url();
+    let output = run_client(&[
+        "call",
+        "health_check",
+        "{}",
+        "--url",
+        &url,
+        "--error-format",
+        "json",
+    ]);
+
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("\"error_type\":\"ConnectionError\""),
+        "{}",
+        stderr
+    );
+}
+
+#[tokio::test]
+async fn test_call_timeout_sends_cancellation() {
+    use futures_util::StreamExt;
+    use tokio_tungstenite::tungstenite::Message;
+
+    // A server that accepts the tool call but never answers it
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+        let mut call_id = None;
+        while let Some(Ok(message)) = ws.next().await {
+            let Message::Text(text) = message else {
+                continue;
+            };
+            let value: serde_json::Value = serde_json::from_str(&text).unwrap();
+            match value["method"].as_str() {
+                Some("tools/call") => call_id = Some(value["id"].clone()),
+                Some("notifications/cancelled") => return (call_id, value),
+                _ => {}
+            }
+        }
+        panic!("connection closed without a cancellation");
+    });
+
+    let home = TempDir::new().unwrap();
+    let output = tokio::process::Command::new(env!("CARGO_BIN_EXE_mill-client"))
+        .args(["call", "slow_tool", "{}", "--url", &url, "--timeout", "200"])
+        .env("HOME", home.path())
+        .env_remove("TYPEMILL_URL")
+        .env_remove("TYPEMILL_TOKEN")
+        .output()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        output.status.code(),
+        Some(5),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let (call_id, cancellation) = server.await.unwrap();
+    assert_eq!(cancellation["params"]["requestId"], call_id.unwrap());
+}
@@ -41,6 +41,7 @@ impl From<McpError> for MillError {
                 file: None,
                 line: None,
                 column: None,
+                snippet: None,
             },
             -32600 => MillError::InvalidRequest {
                 message: err.message,
@@ -88,6 +89,7 @@ impl From<ApiError> for MillError {
                 file: None,
                 line: None,
                 column: None,
+                snippet: None,
             },
             // Tuple variants
             ApiError::InvalidRequest(msg) => MillError::InvalidRequest {
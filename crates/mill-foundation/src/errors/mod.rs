@@ -61,6 +61,8 @@ pub enum MillError {
         file: Option<String>,
         line: Option<usize>,
         column: Option<usize>,
+        /// The offending source line with a `^` under the error column
+        snippet: Option<String>,
     },
 
     #[error("Invalid data: {message}")]
@@ -264,6 +266,7 @@ impl MillError {
             file: None,
             line: None,
             column: None,
+            snippet: None,
         }
     }
 
@@ -687,6 +690,7 @@ mod tests {
             file: Some("test.rs".to_string()),
             line: Some(42),
             column: Some(15),
+            snippet: None,
         };
 
         let display = err.to_string();
@@ -50,11 +50,28 @@ impl ErrorResponse {
 
 impl From<MillError> for ErrorResponse {
     fn from(err: MillError) -> Self {
+        // Parse errors carry their position and source snippet for display
+        let details = match &err {
+            MillError::Parse {
+                file,
+                line,
+                column,
+                snippet,
+                ..
+            } if file.is_some() || line.is_some() || snippet.is_some() => Some(serde_json::json!({
+                "file": file,
+                "line": line,
+                "column": column,
+                "snippet": snippet,
+            })),
+            _ => None,
+        };
+
         ErrorResponse {
             code: err.error_code().to_string(),
             message: err.to_string(),
             category: err.category().to_string(),
-            details: None,    // Can be enhanced later
+            details,
             suggestion: None, // Can be enhanced later
         }
     }
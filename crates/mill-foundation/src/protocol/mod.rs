@@ -95,12 +95,58 @@ pub enum ImportType {
     TypeOnly,
     /// Python import statement
     PythonImport,
-    /// Python from...import statement
+    /// Python absolute from...import statement
     PythonFromImport,
-    /// C/C++ #include directive
+    /// Python relative from...import statement (`from .module import name`)
+    PythonRelativeImport,
+    /// C/C++ quoted #include directive (`#include "header.h"`)
     CInclude,
+    /// C/C++ angle-bracket #include directive (`#include <header.h>`)
+    CSystemInclude,
     /// C# using directive / Java import / Go import (namespace-based imports)
     Namespace,
+    /// Generic module import (Rust `use`)
+    Module,
+}
+
+impl ImportType {
+    /// Whether the import form alone tells if the target is outside the project
+    ///
+    /// Angle-bracket includes name system or library headers, while quoted
+    /// includes and Python relative imports always point into the project.
+    /// Returns `None` for forms where only the module path can tell.
+    pub fn is_external(&self) -> Option<bool> {
+        match self {
+            Self::CSystemInclude => Some(true),
+            Self::CInclude | Self::PythonRelativeImport => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Render the canonical statement importing `module` with this form
+    ///
+    /// Named imports are not rendered; forms that require them import
+    /// everything (`*`) or nothing (`{}`). For `PythonRelativeImport`,
+    /// `module` keeps its leading dots.
+    pub fn display_syntax(&self, module: &str) -> String {
+        match self {
+            Self::EsModule => format!("import \"{}\";", module),
+            Self::CommonJs => format!("require(\"{}\");", module),
+            Self::Dynamic => format!("import(\"{}\");", module),
+            Self::Amd => format!("require([\"{}\"]);", module),
+            Self::TypeOnly => format!("import type {{}} from \"{}\";", module),
+            Self::PythonImport => format!("import {}", module),
+            Self::PythonFromImport => format!("from {} import *", module),
+            Self::PythonRelativeImport if module.starts_with('.') => {
+                format!("from {} import *", module)
+            }
+            Self::PythonRelativeImport => format!("from .{} import *", module),
+            Self::CInclude => format!("#include \"{}\"", module),
+            Self::CSystemInclude => format!("#include <{}>", module),
+            Self::Namespace => format!("import {};", module),
+            Self::Module => format!("use {};", module),
+        }
+    }
 }
 
 /// Source location information
@@ -243,3 +289,40 @@ pub struct RenameDirectoryParams {
     #[serde(default)]
     pub details: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_syntax_quoted_c_include() {
+        assert_eq!(
+            ImportType::CInclude.display_syntax("util/strings.h"),
+            "#include \"util/strings.h\""
+        );
+        assert_eq!(
+            ImportType::CSystemInclude.display_syntax("stdio.h"),
+            "#include <stdio.h>"
+        );
+        assert_eq!(ImportType::CInclude.is_external(), Some(false));
+        assert_eq!(ImportType::CSystemInclude.is_external(), Some(true));
+        assert_eq!(ImportType::EsModule.is_external(), None);
+    }
+
+    #[test]
+    fn test_display_syntax_python_relative_import() {
+        assert_eq!(
+            ImportType::PythonRelativeImport.display_syntax("..models"),
+            "from ..models import *"
+        );
+        assert_eq!(
+            ImportType::PythonRelativeImport.display_syntax("."),
+            "from . import *"
+        );
+        assert_eq!(
+            ImportType::PythonRelativeImport.display_syntax("utils"),
+            "from .utils import *"
+        );
+        assert_eq!(ImportType::PythonRelativeImport.is_external(), Some(false));
+    }
+}
@@ -2,12 +2,21 @@
 //!
 //! Implements the `inspect_code` tool which aggregates multiple LSP operations
 //! into a single unified response.
+//!
+//! When the LSP is disabled (`lsp.mode = off`) or fails to answer, `typeInfo`
+//! and `definition` fall back to the language plugin's symbols: the hover
+//! describes the innermost symbol enclosing the position, and the definition
+//! points at a same-file symbol named like the identifier under the cursor.
 
+use super::tools::cross_file_references;
+use super::tools::extensions::get_concrete_app_state;
 use super::ToolHandler;
+use crate::handlers::common::lsp_mode;
 use async_trait::async_trait;
 use mill_foundation::core::model::mcp::ToolCall;
 use mill_foundation::errors::{MillError as ServerError, MillResult as ServerResult};
-use mill_plugin_system::PluginRequest;
+use mill_plugin_api::Symbol;
+use mill_plugin_system::{PluginRequest, PluginSystemError};
 use serde::Deserialize;
 use serde_json::{json, Value};
 use std::path::{Path, PathBuf};
@@ -157,9 +166,16 @@ impl InspectHandler {
         );
 
         // Execute LSP operations in parallel where possible
+        let lsp_off = lsp_mode(context) == mill_config::config::LspMode::Off;
         let mut tasks = Vec::new();
+        let mut fallbacks = Vec::new();
 
         for item in include {
+            if lsp_off && PLUGIN_FALLBACK_ITEMS.contains(&item.as_str()) {
+                fallbacks.push(item);
+                continue;
+            }
+
             let method = match item.as_str() {
                 "definition" => "find_definition",
                 "typeInfo" => "get_hover",
@@ -214,6 +230,17 @@ impl InspectHandler {
                         _ => {}
                     }
                 }
+                Err(
+                    e @ (PluginSystemError::PluginNotFound { .. }
+                    | PluginSystemError::MethodNotSupported { .. }),
+                ) if PLUGIN_FALLBACK_ITEMS.contains(&item.as_str()) => {
+                    debug!(
+                        include_item = %item,
+                        error = %e,
+                        "LSP unavailable, falling back to plugin symbols"
+                    );
+                    fallbacks.push(item);
+                }
                 Err(e) => {
                     warn!(
                         include_item = %item,
@@ -225,9 +252,64 @@ impl InspectHandler {
             }
         }
 
+        if !fallbacks.is_empty() {
+            if let Some((content, symbols)) = self.plugin_symbols(context, &file_path).await {
+                for item in fallbacks {
+                    match item.as_str() {
+                        "typeInfo" => {
+                            result.type_info = symbol_at_line(&symbols, line as usize)
+                                .map(symbol_hover)
+                                .or(Some(Value::Null));
+                        }
+                        "definition" => {
+                            let definition =
+                                cross_file_references::extract_symbol_at_position_public(
+                                    &content, line, character,
+                                )
+                                .and_then(|name| {
+                                    symbol_definition(&symbols, &name, &params.file_path)
+                                })
+                                .unwrap_or(Value::Null);
+                            result.definition = Some(self.apply_pagination(definition, params));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
         Ok(result)
     }
 
+    /// Read a file and list its symbols with the matching language plugin
+    async fn plugin_symbols(
+        &self,
+        context: &mill_handler_api::ToolHandlerContext,
+        file_path: &Path,
+    ) -> Option<(String, Vec<Symbol>)> {
+        let abs_path = context
+            .app_state
+            .file_service
+            .to_absolute_path_checked(file_path)
+            .ok()?;
+        let extension = abs_path.extension()?.to_str()?;
+        let concrete_state = get_concrete_app_state(&context.app_state).ok()?;
+        let plugin = concrete_state.language_plugins.get_plugin(extension)?;
+
+        let content = tokio::fs::read_to_string(&abs_path).await.ok()?;
+        match plugin.parse_symbols_only(&content).await {
+            Ok(symbols) => Some((content, symbols)),
+            Err(e) => {
+                warn!(
+                    error = %e,
+                    file = %abs_path.display(),
+                    "Failed to list symbols for plugin fallback"
+                );
+                None
+            }
+        }
+    }
+
     /// Apply pagination to list results
     fn apply_pagination(&self, content: Value, params: &InspectParams) -> Value {
         match content {
@@ -254,6 +336,61 @@ impl InspectHandler {
     }
 }
 
+/// Include items that can be answered from plugin symbols without an LSP
+const PLUGIN_FALLBACK_ITEMS: &[&str] = &["definition", "typeInfo"];
+
+/// The innermost symbol whose span covers `line` (0-based)
+///
+/// Symbols without an end location only cover their first line.
+fn symbol_at_line(symbols: &[Symbol], line: usize) -> Option<&Symbol> {
+    symbols
+        .iter()
+        .filter(|symbol| {
+            let end = symbol
+                .end_location
+                .as_ref()
+                .map_or(symbol.location.line, |end| end.line);
+            (symbol.location.line..=end).contains(&line)
+        })
+        .max_by_key(|symbol| symbol.location.line)
+}
+
+/// An LSP-style hover describing `symbol`: its kind and name, followed by its
+/// doc comment
+fn symbol_hover(symbol: &Symbol) -> Value {
+    let kind = format!("{:?}", symbol.kind).to_lowercase();
+    let mut markdown = format!("```\n{} {}\n```", kind, symbol.name);
+    if let Some(doc) = symbol.documentation.as_deref().filter(|d| !d.is_empty()) {
+        markdown.push_str("\n\n");
+        markdown.push_str(doc);
+    }
+
+    json!({
+        "contents": { "kind": "markdown", "value": markdown },
+        "source": "plugin",
+    })
+}
+
+/// LSP-style locations of the symbols named `name` in the same file
+fn symbol_definition(symbols: &[Symbol], name: &str, file_path: &str) -> Option<Value> {
+    let locations: Vec<Value> = symbols
+        .iter()
+        .filter(|symbol| symbol.name == name)
+        .map(|symbol| {
+            let position = json!({
+                "line": symbol.location.line,
+                "character": symbol.location.column,
+            });
+            json!({
+                "filePath": file_path,
+                "range": { "start": position, "end": position },
+                "source": "plugin",
+            })
+        })
+        .collect();
+    (!locations.is_empty()).then_some(Value::Array(locations))
+}
+
 impl Default for InspectHandler {
     fn default() -> Self {
         Self::new()
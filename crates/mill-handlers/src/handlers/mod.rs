@@ -11,6 +11,7 @@ pub mod refactor_inline;
 #[path = "relocate_ops/mod.rs"]
 pub mod relocate_ops;
 pub mod rename_ops;
+pub mod rename_symbol_handler;
 pub mod system_handler;
 pub mod tool_definitions;
 pub mod tool_registry;
@@ -49,6 +50,7 @@ pub use prune_handler::PruneHandler;
 pub use refactor_handler::RefactorHandler;
 pub use relocate_handler::RelocateHandler;
 pub use rename_all_handler::RenameAllHandler;
+pub use rename_symbol_handler::RenameSymbolHandler;
 pub use search_handler::SearchHandler;
 pub use workspace_handler::WorkspaceHandler;
 // Note: register_all_tools is no longer needed - plugins auto-register
@@ -16,13 +16,14 @@ use mill_foundation::protocol::AstService;
 use mill_plugin_system::{LspAdapterPlugin, PluginManager};
 use mill_services::services::planner::Planner;
 use mill_services::services::workflow_executor::WorkflowExecutor;
+use mill_services::services::{current_request_id, with_request_id};
 use mill_transport::McpDispatcher;
 use mill_workspaces::WorkspaceManager;
 use serde_json::{json, Value};
 use std::sync::Arc;
 use std::time::Instant;
 use tokio::sync::{Mutex, OnceCell};
-use tracing::{debug, error, info, instrument, warn};
+use tracing::{debug, error, info, info_span, instrument, warn, Instrument};
 
 use super::lsp_adapter::DirectLspAdapter;
 
@@ -345,6 +346,8 @@ impl PluginDispatcher {
                     InspectHandler, SearchHandler, RenameAllHandler, RelocateHandler,
                     PruneHandler, RefactorHandler, WorkspaceHandler, SystemHandler, PlanToolsHandler,
                 };
+                use super::tools::{CapabilitiesHandler, ProjectSymbolsHandler};
+                use super::RenameSymbolHandler;
 
                 let mut registry = self.tool_registry.lock().await;
 
@@ -363,7 +366,10 @@ impl PluginDispatcher {
                     RefactorHandler => "RefactorHandler: refactor",
                     WorkspaceHandler => "WorkspaceHandler: workspace",
                     SystemHandler => "SystemHandler: health_check + lifecycle",
-                    PlanToolsHandler => "PlanToolsHandler: apply_plan (internal)"
+                    PlanToolsHandler => "PlanToolsHandler: apply_plan (internal)",
+                    ProjectSymbolsHandler => "ProjectSymbolsHandler: project_symbols (internal)",
+                    CapabilitiesHandler => "CapabilitiesHandler: capabilities (internal)",
+                    RenameSymbolHandler => "RenameSymbolHandler: rename_symbol (internal)"
                 });
 
                 #[cfg(feature = "heavy-tests")]
@@ -383,11 +389,28 @@ impl PluginDispatcher {
     }
 
     /// Dispatches an MCP message using the plugin system.
-    #[instrument(skip(self, message, session_info), fields(request_id = %uuid::Uuid::new_v4()))]
+    ///
+    /// Each message gets a fresh request ID unless the caller already set
+    /// one. It is carried by the `dispatch` span and recorded on operations
+    /// the handlers queue, so worker logs can be traced back to the request.
     pub async fn dispatch(
         &self,
         message: McpMessage,
         session_info: &mill_transport::SessionInfo,
+    ) -> ServerResult<McpMessage> {
+        let request_id = current_request_id().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        with_request_id(request_id, self.dispatch_message(message, session_info)).await
+    }
+
+    #[instrument(
+        name = "dispatch",
+        skip(self, message, session_info),
+        fields(request_id = %current_request_id().unwrap_or_default())
+    )]
+    async fn dispatch_message(
+        &self,
+        message: McpMessage,
+        session_info: &mill_transport::SessionInfo,
     ) -> ServerResult<McpMessage> {
         self.initialize().await?;
 
@@ -441,7 +464,6 @@ impl PluginDispatcher {
     }
 
     /// Handle tools/call request using the unified tool registry
-    #[instrument(skip(self, params, session_info))]
     async fn handle_tool_call(
         &self,
         params: Option<Value>,
@@ -455,6 +477,7 @@ impl PluginDispatcher {
             .map_err(|e| ServerError::invalid_request(format!("Invalid tool call: {}", e)))?;
 
         let tool_name = tool_call.name.clone();
+        let span = info_span!("tool_call", tool_name = %tool_name);
 
         // Create concrete context first
         let concrete_context = super::tools::ToolHandlerContext {
@@ -467,35 +490,39 @@ impl PluginDispatcher {
         // Convert to trait-based context for handler compatibility
         let api_context = concrete_context.to_api_context().await;
 
-        let result = self
-            .tool_registry
-            .lock()
-            .await
-            .handle_tool(tool_call, &api_context)
-            .await;
-
-        let duration = start_time.elapsed();
-        match &result {
-            Ok(_) => {
-                info!(
-                    tool_name = %tool_name,
-                    duration_ms = duration.as_millis() as u64,
-                    status = "success",
-                    "Tool call completed"
-                );
-            }
-            Err(e) => {
-                error!(
-                    tool_name = %tool_name,
-                    duration_ms = duration.as_millis() as u64,
-                    status = "error",
-                    error = %e,
-                    "Tool call failed"
-                );
+        let call = async {
+            let result = self
+                .tool_registry
+                .lock()
+                .await
+                .handle_tool(tool_call, &api_context)
+                .await;
+
+            let duration = start_time.elapsed();
+            match &result {
+                Ok(_) => {
+                    info!(
+                        tool_name = %tool_name,
+                        duration_ms = duration.as_millis() as u64,
+                        status = "success",
+                        "Tool call completed"
+                    );
+                }
+                Err(e) => {
+                    error!(
+                        tool_name = %tool_name,
+                        duration_ms = duration.as_millis() as u64,
+                        status = "error",
+                        error = %e,
+                        "Tool call failed"
+                    );
+                }
             }
-        }
 
-        result
+            result
+        };
+
+        call.instrument(span).await
     }
 
     /// Handle MCP initialize request
@@ -144,7 +144,9 @@ impl RenameAllHandler {
     }
 
     /// Convert RefactorPlan to WriteResponse (Preview Mode)
-    fn convert_plan_to_write_response(plan: &RefactorPlan) -> ServerResult<WriteResponse> {
+    pub(crate) fn convert_plan_to_write_response(
+        plan: &RefactorPlan,
+    ) -> ServerResult<WriteResponse> {
         // Extract summary directly from struct
         let summary = match plan {
             RefactorPlan::RenamePlan(p) => &p.summary,
@@ -191,7 +193,9 @@ impl RenameAllHandler {
     }
 
     /// Convert ExecutionResult to WriteResponse (Execution Mode)
-    fn convert_result_to_write_response(result: &ExecutionResult) -> ServerResult<WriteResponse> {
+    pub(crate) fn convert_result_to_write_response(
+        result: &ExecutionResult,
+    ) -> ServerResult<WriteResponse> {
         let summary_text = if result.success {
             format!(
                 "Successfully renamed {} file(s)",
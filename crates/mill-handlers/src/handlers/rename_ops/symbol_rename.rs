@@ -1,10 +1,12 @@
 use super::{RenameOptions, RenameService, RenameTarget};
-use crate::handlers::common::lsp_mode;
+use crate::handlers::common::{lsp_mode, lsp_uri_from_file_path};
 use crate::handlers::tools::cross_file_references;
-use lsp_types::WorkspaceEdit;
+use crate::handlers::tools::extensions::get_concrete_app_state;
+use lsp_types::{DocumentChanges, Position, Range, TextEdit, WorkspaceEdit};
 use mill_foundation::errors::{MillError as ServerError, MillResult as ServerResult};
 use mill_foundation::planning::{PlanMetadata, RenamePlan};
 use serde_json::json;
+use std::collections::HashMap;
 use std::path::Path;
 use tracing::{debug, error};
 
@@ -47,7 +49,7 @@ impl RenameService {
         let lsp_adapter = context.lsp_adapter.lock().await;
         let adapter = lsp_adapter
             .as_ref()
-            .ok_or_else(|| ServerError::internal("LSP adapter not initialized"))?;
+            .ok_or_else(|| ServerError::not_supported("LSP adapter not initialized"))?;
 
         // Get or create LSP client for this extension
         let client = adapter.get_or_create_client(extension).await.map_err(|e| {
@@ -129,6 +131,105 @@ impl RenameService {
             workspace_edit
         };
 
+        self.build_symbol_rename_plan(workspace_edit, extension, context)
+            .await
+    }
+
+    /// Generate plan for symbol rename, preferring LSP and falling back to
+    /// the language plugin
+    ///
+    /// The plugin fallback runs when LSP is disabled or no server is
+    /// configured or running for the file, and when the server returns no
+    /// edits. Errors from a running server are returned as they are.
+    pub(crate) async fn plan_symbol_rename_with_fallback(
+        &self,
+        target: &RenameTarget,
+        new_name: &str,
+        options: &RenameOptions,
+        context: &mill_handler_api::ToolHandlerContext,
+    ) -> ServerResult<RenamePlan> {
+        match self
+            .plan_symbol_rename(target, new_name, options, context)
+            .await
+        {
+            Ok(plan) if is_empty_edit(&plan.edits) => {
+                debug!("LSP rename returned no edits, falling back to plugin")
+            }
+            Err(e @ ServerError::NotSupported { .. }) => {
+                debug!(error = %e, "LSP rename unavailable, falling back to plugin")
+            }
+            result => return result,
+        }
+
+        self.plan_symbol_rename_via_plugin(target, new_name, context)
+            .await
+    }
+
+    /// Generate plan for symbol rename using the language plugin's
+    /// project-wide rename
+    async fn plan_symbol_rename_via_plugin(
+        &self,
+        target: &RenameTarget,
+        new_name: &str,
+        context: &mill_handler_api::ToolHandlerContext,
+    ) -> ServerResult<RenamePlan> {
+        debug!(path = %target.path, new_name = %new_name, "Planning symbol rename via plugin");
+
+        let position = target
+            .selector
+            .as_ref()
+            .ok_or_else(|| {
+                ServerError::invalid_request("Symbol rename requires selector.position")
+            })?
+            .position;
+
+        // Rejects paths outside the project root
+        let abs_path = context
+            .app_state
+            .file_service
+            .to_absolute_path_checked(Path::new(&target.path))?;
+        let extension = abs_path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .ok_or_else(|| {
+                ServerError::invalid_request(format!("File has no extension: {}", target.path))
+            })?;
+
+        let concrete_state = get_concrete_app_state(&context.app_state)?;
+        let plugins = &concrete_state.language_plugins.inner;
+        let provider = plugins
+            .find_by_extension(extension)
+            .and_then(|plugin| plugin.refactoring_provider())
+            .ok_or_else(|| {
+                ServerError::not_supported(format!(
+                    "No LSP or refactoring plugin available to rename symbols in .{} files",
+                    extension
+                ))
+            })?;
+
+        let plugin_edit = provider
+            .plan_rename_symbol_at(
+                &context.app_state.project_root,
+                &abs_path,
+                position.line,
+                position.character,
+                new_name,
+                plugins,
+            )
+            .await?;
+        let workspace_edit = plugin_edit_to_workspace_edit(plugin_edit)?;
+
+        self.build_symbol_rename_plan(workspace_edit, extension, context)
+            .await
+    }
+
+    /// Wrap a symbol rename edit in a plan with checksums and a summary
+    async fn build_symbol_rename_plan(
+        &self,
+        workspace_edit: WorkspaceEdit,
+        extension: &str,
+        context: &mill_handler_api::ToolHandlerContext,
+    ) -> ServerResult<RenamePlan> {
         // Calculate file checksums and summary
         let (file_checksums, summary, warnings) = self
             .analyze_workspace_edit(&workspace_edit, context)
@@ -161,3 +262,82 @@ impl RenameService {
         })
     }
 }
+
+/// Whether an LSP rename produced no changes at all
+fn is_empty_edit(edit: &WorkspaceEdit) -> bool {
+    let no_changes = edit
+        .changes
+        .as_ref()
+        .is_none_or(|changes| changes.values().all(|edits| edits.is_empty()));
+    let no_document_changes = match &edit.document_changes {
+        None => true,
+        Some(DocumentChanges::Edits(edits)) => edits.iter().all(|e| e.edits.is_empty()),
+        Some(DocumentChanges::Operations(ops)) => ops.is_empty(),
+    };
+    no_changes && no_document_changes
+}
+
+/// Convert a plugin edit (1-based lines) to an LSP edit (0-based lines)
+fn plugin_edit_to_workspace_edit(
+    edit: mill_plugin_api::WorkspaceEdit,
+) -> ServerResult<WorkspaceEdit> {
+    #[allow(clippy::mutable_key_type)]
+    let mut changes = HashMap::new();
+    for (file, edits) in edit.changes {
+        let uri = lsp_uri_from_file_path(Path::new(&file))?;
+        let edits = edits
+            .into_iter()
+            .map(|e| TextEdit {
+                range: Range {
+                    start: Position {
+                        line: e.start_line.saturating_sub(1) as u32,
+                        character: e.start_column as u32,
+                    },
+                    end: Position {
+                        line: e.end_line.saturating_sub(1) as u32,
+                        character: e.end_column as u32,
+                    },
+                },
+                new_text: e.new_text,
+            })
+            .collect();
+        changes.insert(uri, edits);
+    }
+    Ok(WorkspaceEdit {
+        changes: Some(changes),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_empty_edit() {
+        assert!(is_empty_edit(&WorkspaceEdit::default()));
+
+        #[allow(clippy::mutable_key_type)]
+        let mut changes = HashMap::new();
+        let uri = lsp_uri_from_file_path(Path::new("/project/src/lib.rs")).unwrap();
+        changes.insert(uri.clone(), Vec::new());
+        let no_edits = WorkspaceEdit {
+            changes: Some(changes.clone()),
+            ..Default::default()
+        };
+        assert!(is_empty_edit(&no_edits));
+
+        changes.insert(
+            uri,
+            vec![TextEdit {
+                range: Range::default(),
+                new_text: "renamed".to_string(),
+            }],
+        );
+        let with_edit = WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        };
+        assert!(!is_empty_edit(&with_edit));
+    }
+}
@@ -0,0 +1,157 @@
+//! Rename Symbol Handler
+//!
+//! Implements the internal `rename_symbol` tool: rename the symbol at a
+//! position, using the LSP server's `textDocument/rename` when one is
+//! available and the language plugin's project-wide rename otherwise.
+//!
+//! The result uses the same WriteResponse envelope as `rename_all`, and
+//! edits are applied through the plan executor, which coordinates writes
+//! with the operation queue.
+
+use super::rename_all_handler::RenameAllHandler;
+use super::rename_ops::{RenameOptions, RenameService, RenameTarget, SymbolSelector};
+use crate::handlers::tools::ToolHandler;
+use async_trait::async_trait;
+use lsp_types::Position;
+use mill_foundation::core::model::mcp::ToolCall;
+use mill_foundation::errors::{MillError as ServerError, MillResult as ServerResult};
+use mill_foundation::planning::RefactorPlan;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tracing::{debug, info};
+
+/// Handler for the `rename_symbol` tool
+pub struct RenameSymbolHandler {
+    rename_service: RenameService,
+}
+
+impl RenameSymbolHandler {
+    pub fn new() -> Self {
+        Self {
+            rename_service: RenameService::new(),
+        }
+    }
+}
+
+impl Default for RenameSymbolHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parameters for the rename_symbol tool
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RenameSymbolParams {
+    /// File containing the symbol, relative to the project root or absolute
+    file_path: String,
+    /// Line of the symbol (0-based)
+    line: u32,
+    /// Character offset of the symbol (0-based)
+    character: u32,
+    /// New name for the symbol
+    new_name: String,
+    /// Optional configuration
+    #[serde(default)]
+    options: RenameSymbolOptions,
+}
+
+/// Options for rename_symbol
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RenameSymbolOptions {
+    /// Preview changes without applying (default: true for safety)
+    #[serde(default = "crate::default_true")]
+    dry_run: bool,
+}
+
+impl Default for RenameSymbolOptions {
+    fn default() -> Self {
+        Self { dry_run: true }
+    }
+}
+
+#[async_trait]
+impl ToolHandler for RenameSymbolHandler {
+    fn tool_names(&self) -> &[&str] {
+        &["rename_symbol"]
+    }
+
+    async fn handle_tool_call(
+        &self,
+        context: &mill_handler_api::ToolHandlerContext,
+        tool_call: &ToolCall,
+    ) -> ServerResult<Value> {
+        info!(tool_name = %tool_call.name, "Handling rename_symbol");
+
+        let args = tool_call
+            .arguments
+            .as_ref()
+            .ok_or_else(|| ServerError::invalid_request("Missing arguments for rename_symbol"))?;
+        let params: RenameSymbolParams = RenameSymbolParams::deserialize(args).map_err(|e| {
+            ServerError::invalid_request(format!("Invalid rename_symbol parameters: {}", e))
+        })?;
+
+        // Reject files outside the project before asking LSP or a plugin
+        let abs_path = context
+            .app_state
+            .file_service
+            .to_absolute_path_checked(std::path::Path::new(&params.file_path))?;
+
+        debug!(
+            file_path = %abs_path.display(),
+            line = params.line,
+            character = params.character,
+            new_name = %params.new_name,
+            dry_run = params.options.dry_run,
+            "Processing rename_symbol request"
+        );
+
+        let target = RenameTarget {
+            kind: "symbol".to_string(),
+            path: abs_path.to_string_lossy().into_owned(),
+            new_name: Some(params.new_name.clone()),
+            selector: Some(SymbolSelector {
+                position: Position {
+                    line: params.line,
+                    character: params.character,
+                },
+            }),
+        };
+        let options = RenameOptions {
+            dry_run: params.options.dry_run,
+            scope: None,
+            strict: None,
+            validate_scope: None,
+            update_imports: None,
+            custom_scope: None,
+            consolidate: None,
+        };
+
+        let plan = self
+            .rename_service
+            .plan_symbol_rename_with_fallback(&target, &params.new_name, &options, context)
+            .await?;
+        let refactor_plan = RefactorPlan::RenamePlan(plan);
+
+        let write_response = if params.options.dry_run {
+            RenameAllHandler::convert_plan_to_write_response(&refactor_plan)?
+        } else {
+            let result =
+                crate::handlers::common::execute_refactor_plan(context, refactor_plan).await?;
+
+            info!(
+                operation = "rename_symbol",
+                success = result.success,
+                applied_files = result.applied_files.len(),
+                "Rename execution completed"
+            );
+
+            RenameAllHandler::convert_result_to_write_response(&result)?
+        };
+
+        Ok(json!({
+            "content": write_response
+        }))
+    }
+}
@@ -69,7 +69,7 @@ impl SearchHandler {
     }
 
     /// Parse symbol kind from string
-    fn parse_symbol_kind(kind_str: &str) -> Option<SymbolKind> {
+    pub(crate) fn parse_symbol_kind(kind_str: &str) -> Option<SymbolKind> {
         match kind_str.to_lowercase().as_str() {
             "function" | "func" | "fn" => Some(SymbolKind::Function),
             "class" => Some(SymbolKind::Class),
@@ -125,7 +125,8 @@ impl SystemHandler {
                 "total_plugins": stats.total_plugins,
                 "supported_extensions": stats.supported_extensions,
                 "supported_methods": stats.supported_methods,
-                "average_methods_per_plugin": stats.average_methods_per_plugin
+                "average_methods_per_plugin": stats.average_methods_per_plugin,
+                "languages": concrete_state.language_plugins.language_names()
             },
             "metrics": {
                 "total_requests": metrics.total_requests,
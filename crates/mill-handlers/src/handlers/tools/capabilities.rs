@@ -0,0 +1,87 @@
+//! Per-language capability report
+//!
+//! Handles: capabilities
+//!
+//! Lists every registered language plugin with its file extensions and the
+//! refactorings its provider can plan, so clients can decide which actions
+//! to offer before sending a request.
+
+use super::ToolHandler;
+use async_trait::async_trait;
+use mill_foundation::core::model::mcp::ToolCall;
+use mill_foundation::errors::{MillError as ServerError, MillResult as ServerResult};
+use mill_plugin_api::{PluginDiscovery, RefactoringKind};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Capabilities of one language plugin
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguageCapabilities {
+    /// Plugin language name
+    pub language: String,
+    /// File extensions handled by the plugin
+    pub extensions: Vec<String>,
+    /// Refactorings the plugin can plan (empty without a refactoring provider)
+    pub refactorings: Vec<RefactoringKind>,
+}
+
+/// Report the capabilities of every registered plugin, sorted by language
+pub fn language_capabilities(plugins: &PluginDiscovery) -> Vec<LanguageCapabilities> {
+    plugins
+        .sorted_by_name()
+        .into_iter()
+        .map(|plugin| LanguageCapabilities {
+            language: plugin.metadata().name.to_string(),
+            extensions: plugin
+                .metadata()
+                .extensions
+                .iter()
+                .map(|ext| ext.to_string())
+                .collect(),
+            refactorings: plugin
+                .refactoring_provider()
+                .map(|provider| provider.supported_refactorings())
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+pub struct CapabilitiesHandler;
+
+impl CapabilitiesHandler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CapabilitiesHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ToolHandler for CapabilitiesHandler {
+    fn tool_names(&self) -> &[&str] {
+        &["capabilities"]
+    }
+
+    async fn handle_tool_call(
+        &self,
+        context: &mill_handler_api::ToolHandlerContext,
+        tool_call: &ToolCall,
+    ) -> ServerResult<Value> {
+        if tool_call.name != "capabilities" {
+            return Err(ServerError::invalid_request(format!(
+                "Unknown capabilities tool: {}",
+                tool_call.name
+            )));
+        }
+
+        let concrete_state = super::extensions::get_concrete_app_state(&context.app_state)?;
+        let languages = language_capabilities(&concrete_state.language_plugins.inner);
+
+        Ok(serde_json::json!({ "languages": languages }))
+    }
+}
@@ -14,6 +14,7 @@ use tokio::sync::Mutex;
 
 // Tool handler modules
 pub mod advanced;
+pub mod capabilities;
 pub mod cross_file_references;
 pub mod editing;
 pub mod file_ops;
@@ -21,6 +22,7 @@ pub mod internal_intelligence;
 pub mod internal_workspace;
 pub mod lifecycle;
 pub mod plan;
+pub mod project_symbols;
 pub mod workspace;
 pub mod workspace_create;
 pub mod workspace_extract;
@@ -32,12 +34,14 @@ pub mod perf_benchmark_extract;
 
 // Re-export handlers
 pub use advanced::AdvancedToolsHandler;
+pub use capabilities::CapabilitiesHandler;
 pub use editing::EditingToolsHandler;
 pub use file_ops::FileToolsHandler;
 pub use internal_intelligence::InternalIntelligenceHandler;
 pub use internal_workspace::InternalWorkspaceHandler;
 pub use lifecycle::LifecycleHandler;
 pub use plan::PlanToolsHandler;
+pub use project_symbols::ProjectSymbolsHandler;
 pub use workspace_create::WorkspaceCreateService;
 pub use workspace_extract::WorkspaceExtractService;
 
@@ -48,7 +52,9 @@ pub use dispatch::dispatch_to_language_plugin;
 mod dispatch {
 
     use mill_foundation::errors::{MillError, MillResult};
+    use std::future::Future;
     use std::path::Path;
+    use std::pin::Pin;
 
     /// Dispatch a file operation to the appropriate language plugin based on file extension
     ///
@@ -70,19 +76,24 @@ mod dispatch {
     /// let result = dispatch_to_language_plugin(
     ///     &context,
     ///     "src/main.rs",
-    ///     |plugin, content| async move {
-    ///         plugin.parse(content).await
-    ///     }
+    ///     |plugin, content| Box::pin(async move {
+    ///         plugin.parse(&content).await
+    ///     })
     /// ).await?;
     /// ```
-    pub async fn dispatch_to_language_plugin<F, Fut, T>(
+    pub async fn dispatch_to_language_plugin<F, T>(
         context: &mill_handler_api::ToolHandlerContext,
         file_path: &str,
         operation: F,
     ) -> MillResult<T>
     where
-        F: FnOnce(&dyn mill_plugin_api::LanguagePlugin, String) -> Fut,
-        Fut: std::future::Future<Output = mill_plugin_api::PluginResult<T>>,
+        // The future borrows the plugin, so it is boxed with the plugin's lifetime
+        F: for<'a> FnOnce(
+            &'a dyn mill_plugin_api::LanguagePlugin,
+            String,
+        ) -> Pin<
+            Box<dyn Future<Output = mill_plugin_api::PluginResult<T>> + Send + 'a>,
+        >,
     {
         // Get file extension
         let path = Path::new(file_path);
@@ -117,7 +128,8 @@ mod dispatch {
         operation(plugin, content).await.map_err(|e| {
             // Convert PluginApiError to MillError
             match e {
-                mill_plugin_api::PluginApiError::Parse { message, .. } => MillError::parse(message),
+                // Keep the position and source snippet for the client
+                e @ mill_plugin_api::PluginApiError::Parse { .. } => MillError::from(e),
                 mill_plugin_api::PluginApiError::Manifest { message } => MillError::parse(message),
                 mill_plugin_api::PluginApiError::NotSupported { operation } => {
                     MillError::not_supported(operation)
@@ -0,0 +1,292 @@
+//! Project-wide symbol index
+//!
+//! Handles: project_symbols
+//!
+//! Walks a directory (honoring `.gitignore`), parses every file that has a
+//! language plugin, and returns the symbols of each file keyed by its path
+//! relative to the project root. Files are parsed concurrently with a bounded
+//! number of in-flight parses, and parsed symbols are kept in the shared
+//! [`AstCache`] per file and modification time so repeated calls only
+//! re-parse files that changed.
+
+use super::ToolHandler;
+use crate::handlers::search_handler::SearchHandler;
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use ignore::WalkBuilder;
+use mill_ast::AstCache;
+use mill_foundation::core::model::mcp::ToolCall;
+use mill_foundation::errors::{MillError as ServerError, MillResult as ServerResult};
+use mill_plugin_api::{retain_symbols, LanguagePlugin, PluginDiscovery, Symbol, SymbolKind};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+use std::time::SystemTime;
+use tracing::debug;
+
+/// Files larger than this are skipped unless `maxFileBytes` overrides it
+pub const DEFAULT_MAX_FILE_BYTES: u64 = 1024 * 1024;
+
+/// Maximum number of files parsed at the same time
+const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Options for building a project symbol index
+#[derive(Debug, Clone)]
+pub struct ProjectSymbolsOptions {
+    /// Skip files larger than this many bytes
+    pub max_file_bytes: u64,
+    /// Only keep symbols of these kinds (all kinds when `None`)
+    pub kinds: Option<Vec<SymbolKind>>,
+    /// Maximum number of files parsed concurrently
+    pub concurrency: usize,
+}
+
+impl Default for ProjectSymbolsOptions {
+    fn default() -> Self {
+        Self {
+            max_file_bytes: DEFAULT_MAX_FILE_BYTES,
+            kinds: None,
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+}
+
+/// A file left out of the index
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedFile {
+    /// Path relative to the project root
+    pub path: String,
+    /// Why the file was not indexed
+    pub reason: String,
+}
+
+/// Symbols of every indexed file
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSymbols {
+    /// Path relative to the project root -> symbols in that file
+    pub files: BTreeMap<String, Vec<Symbol>>,
+    /// Files that had a plugin but could not be indexed
+    pub skipped: Vec<SkippedFile>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProjectSymbolsParams {
+    directory: Option<String>,
+    kinds: Option<Vec<String>>,
+    max_file_bytes: Option<u64>,
+}
+
+pub struct ProjectSymbolsHandler;
+
+impl ProjectSymbolsHandler {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Build the symbol index for `directory`
+    ///
+    /// Files without a language plugin are ignored. Files over
+    /// `options.max_file_bytes`, unreadable files, and files the plugin fails
+    /// to parse are reported in `skipped`.
+    pub async fn collect(
+        &self,
+        plugins: &PluginDiscovery,
+        cache: &AstCache,
+        project_root: &Path,
+        directory: &Path,
+        options: &ProjectSymbolsOptions,
+    ) -> ServerResult<ProjectSymbols> {
+        let walk_root = directory.to_path_buf();
+        let entries = tokio::task::spawn_blocking(move || {
+            WalkBuilder::new(&walk_root)
+                .git_ignore(true)
+                .require_git(false)
+                .build()
+                .filter_map(|e| e.ok())
+                .filter(|entry| entry.file_type().map(|ft| ft.is_file()).unwrap_or(false))
+                .filter_map(|entry| {
+                    let metadata = entry.metadata().ok()?;
+                    let modified = metadata.modified().ok()?;
+                    Some((entry.into_path(), metadata.len(), modified))
+                })
+                .collect::<Vec<_>>()
+        })
+        .await
+        .map_err(|e| ServerError::internal(format!("Task join error: {}", e)))?;
+
+        let relative = |path: &Path| {
+            path.strip_prefix(project_root)
+                .unwrap_or(path)
+                .to_string_lossy()
+                .replace('\\', "/")
+        };
+
+        let mut result = ProjectSymbols::default();
+        let mut candidates = Vec::new();
+        for (path, len, modified) in entries {
+            let Some(plugin) = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .and_then(|ext| plugins.find_by_extension(ext))
+            else {
+                continue;
+            };
+            if len > options.max_file_bytes {
+                result.skipped.push(SkippedFile {
+                    path: relative(&path),
+                    reason: format!(
+                        "File is {} bytes, over the {} byte limit",
+                        len, options.max_file_bytes
+                    ),
+                });
+                continue;
+            }
+            candidates.push((path, modified, plugin));
+        }
+
+        // Futures are created up front: a stream holding a closure over the
+        // borrowed plugins fails the `Send` check on the handler future
+        let parses: Vec<_> = candidates
+            .into_iter()
+            .map(|(path, modified, plugin)| Self::parse_file(cache, path, modified, plugin))
+            .collect();
+        let parsed: Vec<(PathBuf, Result<Vec<Symbol>, String>)> = stream::iter(parses)
+            .buffer_unordered(options.concurrency.max(1))
+            .collect()
+            .await;
+
+        for (path, symbols) in parsed {
+            match symbols {
+                Ok(mut symbols) => {
+                    if let Some(kinds) = &options.kinds {
+                        retain_symbols(&mut symbols, |symbol| kinds.contains(&symbol.kind));
+                    }
+                    result.files.insert(relative(&path), symbols);
+                }
+                Err(reason) => result.skipped.push(SkippedFile {
+                    path: relative(&path),
+                    reason,
+                }),
+            }
+        }
+        result.skipped.sort_by(|a, b| a.path.cmp(&b.path));
+
+        debug!(
+            files = result.files.len(),
+            skipped = result.skipped.len(),
+            "Built project symbol index"
+        );
+
+        Ok(result)
+    }
+
+    /// Parse one file, reusing cached symbols while its modification time is unchanged
+    async fn parse_file(
+        cache: &AstCache,
+        path: PathBuf,
+        modified: SystemTime,
+        plugin: &dyn LanguagePlugin,
+    ) -> (PathBuf, Result<Vec<Symbol>, String>) {
+        if let Some(symbols) = cache.get_symbols(&path, modified) {
+            return (path, Ok(symbols));
+        }
+
+        let symbols = match tokio::fs::read_to_string(&path).await {
+            Ok(content) => plugin
+                .parse_symbols_only(&content)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(format!("Failed to read file: {}", e)),
+        };
+        if let Ok(symbols) = &symbols {
+            cache.insert_symbols(path.clone(), modified, symbols.clone());
+        }
+        (path, symbols)
+    }
+}
+
+impl Default for ProjectSymbolsHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ToolHandler for ProjectSymbolsHandler {
+    fn tool_names(&self) -> &[&str] {
+        &["project_symbols"]
+    }
+
+    async fn handle_tool_call(
+        &self,
+        context: &mill_handler_api::ToolHandlerContext,
+        tool_call: &ToolCall,
+    ) -> ServerResult<Value> {
+        if tool_call.name != "project_symbols" {
+            return Err(ServerError::invalid_request(format!(
+                "Unknown project symbols tool: {}",
+                tool_call.name
+            )));
+        }
+
+        let params: ProjectSymbolsParams = match &tool_call.arguments {
+            Some(args) => serde_json::from_value(args.clone()).map_err(|e| {
+                ServerError::invalid_request(format!("Invalid project_symbols arguments: {}", e))
+            })?,
+            None => ProjectSymbolsParams::default(),
+        };
+
+        let kinds = params
+            .kinds
+            .map(|kinds| {
+                kinds
+                    .iter()
+                    .map(|kind| {
+                        SearchHandler::parse_symbol_kind(kind).ok_or_else(|| {
+                            ServerError::invalid_request(format!("Invalid symbol kind: '{}'", kind))
+                        })
+                    })
+                    .collect::<ServerResult<Vec<_>>>()
+            })
+            .transpose()?;
+
+        let options = ProjectSymbolsOptions {
+            max_file_bytes: params.max_file_bytes.unwrap_or(DEFAULT_MAX_FILE_BYTES),
+            kinds,
+            ..Default::default()
+        };
+
+        let project_root = &context.app_state.project_root;
+        let directory = match &params.directory {
+            Some(dir) => project_root.join(dir),
+            None => project_root.clone(),
+        };
+        let escapes_root = directory
+            .components()
+            .any(|c| matches!(c, Component::ParentDir));
+        if escapes_root || !directory.starts_with(project_root) || !directory.is_dir() {
+            return Err(ServerError::invalid_request(format!(
+                "Not a directory inside the project: {}",
+                directory.display()
+            )));
+        }
+
+        let concrete_state = super::extensions::get_concrete_app_state(&context.app_state)?;
+        let index = self
+            .collect(
+                &concrete_state.language_plugins.inner,
+                concrete_state.file_service.ast_cache(),
+                project_root,
+                &directory,
+                &options,
+            )
+            .await?;
+
+        serde_json::to_value(index)
+            .map_err(|e| ServerError::internal(format!("Failed to serialize symbols: {}", e)))
+    }
+}
@@ -191,7 +191,8 @@ pub async fn handle_create_package(
         error!(error = ?e, "Failed to create package");
         // Convert PluginApiError to ServerError
         match e {
-            mill_plugin_api::PluginApiError::Parse { message, .. } => ServerError::parse(message),
+            // Keep the position and source snippet for the client
+            e @ mill_plugin_api::PluginApiError::Parse { .. } => ServerError::from(e),
             mill_plugin_api::PluginApiError::Manifest { message } => ServerError::parse(message),
             mill_plugin_api::PluginApiError::NotSupported { operation } => {
                 ServerError::not_supported(operation)
@@ -312,6 +313,10 @@ fn predict_created_files(
                 PackageType::Binary => {
                     files.push(package_path.join("src/main.rs").display().to_string());
                 }
+                PackageType::LibAndBin => {
+                    files.push(package_path.join("src/lib.rs").display().to_string());
+                    files.push(package_path.join("src/main.rs").display().to_string());
+                }
             }
 
             // Full template extras
@@ -343,6 +348,10 @@ fn predict_created_files(
                 PackageType::Binary => {
                     files.push(package_path.join("src/main.ts").display().to_string());
                 }
+                PackageType::LibAndBin => {
+                    files.push(package_path.join("src/index.ts").display().to_string());
+                    files.push(package_path.join("src/main.ts").display().to_string());
+                }
             }
 
             // Baseline files (always included for npm)
@@ -384,6 +393,16 @@ fn predict_created_files(
                             .to_string(),
                     );
                 }
+                PackageType::LibAndBin => {
+                    for entry in ["__init__.py", "main.py"] {
+                        files.push(
+                            package_path
+                                .join(format!("src/{}/{}", package_name, entry))
+                                .display()
+                                .to_string(),
+                        );
+                    }
+                }
             }
 
             // Baseline files (always included for Python)
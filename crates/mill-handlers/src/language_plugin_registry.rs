@@ -73,6 +73,15 @@ impl LanguagePluginRegistry {
         self.inner.all()
     }
 
+    /// Get the names of all registered languages, sorted alphabetically
+    pub fn language_names(&self) -> Vec<&'static str> {
+        self.inner
+            .sorted_by_name()
+            .iter()
+            .map(|plugin| plugin.metadata().name)
+            .collect()
+    }
+
     /// Get a list of all supported file extensions
     pub fn supported_extensions(&self) -> Vec<String> {
         let mut extensions = Vec::new();
@@ -90,19 +99,18 @@ impl LanguagePluginRegistry {
     pub fn get_plugin_for_manifest(&self, filename: &str) -> Option<&dyn LanguagePlugin> {
         debug!(filename = filename, "Looking up plugin for manifest");
 
-        for plugin in self.inner.all() {
-            if plugin.handles_manifest(filename) {
-                debug!(
-                    filename = filename,
-                    plugin = plugin.metadata().name,
-                    "Found plugin for manifest"
-                );
-                return Some(plugin.as_ref());
-            }
+        let result = self.inner.find_by_manifest(filename);
+
+        match result {
+            Some(plugin) => debug!(
+                filename = filename,
+                plugin = plugin.metadata().name,
+                "Found plugin for manifest"
+            ),
+            None => debug!(filename = filename, "No plugin found for manifest"),
         }
 
-        debug!(filename = filename, "No plugin found for manifest");
-        None
+        result
     }
 }
 
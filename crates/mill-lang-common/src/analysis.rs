@@ -0,0 +1,128 @@
+//! Per-language details consumed by code analysis
+//!
+//! Plugins implement [`AnalysisMetadata`] to describe how their language is
+//! analyzed. [`crate::complexity`] scores functions with it, and
+//! [`find_task_comments`] reads the comment syntax to report TODO markers.
+
+use mill_plugin_api::identifier_scanner::{self, LexicalSyntax, TaskComment};
+
+/// Language details needed to analyze source code
+pub trait AnalysisMetadata {
+    /// Keywords that add a branch to the control flow (`if`, `for`, `catch`, ...)
+    fn complexity_keywords(&self) -> &[&str];
+
+    /// Extra score for each level a complexity keyword is nested within its
+    /// function body
+    fn nesting_penalty(&self) -> u32;
+
+    /// Comment and string syntax, so keywords inside them are not counted
+    fn lexical_syntax(&self) -> LexicalSyntax;
+
+    /// Whether blocks are delimited by indentation (Python) rather than braces
+    fn indentation_blocks(&self) -> bool {
+        false
+    }
+
+    /// Prefixes that start a comment running to the end of the line
+    fn line_comment_prefixes(&self) -> Vec<&'static str> {
+        self.lexical_syntax().line_comment_prefixes().to_vec()
+    }
+
+    /// Opening and closing delimiters of block comments, if the language has
+    /// them
+    fn block_comment_delimiters(&self) -> Option<(&'static str, &'static str)> {
+        self.lexical_syntax().block_comment_delimiters()
+    }
+}
+
+/// Find `TODO`, `FIXME`, `HACK` and `XXX` markers in the comments of `source`
+///
+/// Comments are recognized by the metadata's comment syntax, so the same
+/// words in strings or code are ignored. Only `/* */` block comments are
+/// understood; other block delimiters are treated as absent.
+pub fn find_task_comments(source: &str, meta: &dyn AnalysisMetadata) -> Vec<TaskComment> {
+    let block_comments = meta.block_comment_delimiters() == Some(("/*", "*/"));
+    let mut tasks: Vec<TaskComment> = Vec::new();
+    for prefix in meta.line_comment_prefixes() {
+        let syntax = LexicalSyntax {
+            line_comment: prefix,
+            block_comments,
+            ..meta.lexical_syntax()
+        };
+        for task in identifier_scanner::find_task_comments(source, syntax) {
+            // Block comments are seen once per line comment prefix
+            if !tasks.contains(&task) {
+                tasks.push(task);
+            }
+        }
+    }
+    tasks.sort_by_key(|task| task.line);
+    tasks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Rust;
+
+    impl AnalysisMetadata for Rust {
+        fn complexity_keywords(&self) -> &[&str] {
+            &["if", "for", "while", "loop", "match"]
+        }
+
+        fn nesting_penalty(&self) -> u32 {
+            1
+        }
+
+        fn lexical_syntax(&self) -> LexicalSyntax {
+            LexicalSyntax::RUST
+        }
+    }
+
+    /// C-like comments plus `--` line comments
+    struct Dashed;
+
+    impl AnalysisMetadata for Dashed {
+        fn complexity_keywords(&self) -> &[&str] {
+            &["if", "guard", "for", "while", "switch"]
+        }
+
+        fn nesting_penalty(&self) -> u32 {
+            1
+        }
+
+        fn lexical_syntax(&self) -> LexicalSyntax {
+            LexicalSyntax::C_LIKE
+        }
+
+        fn line_comment_prefixes(&self) -> Vec<&'static str> {
+            vec!["//", "--"]
+        }
+    }
+
+    #[test]
+    fn test_task_comments_skip_strings() {
+        let source =
+            "// TODO: fix\nfn main() {\n    let s = \"TODO\";\n    /* FIXME(ana) later */\n}\n";
+
+        let tasks = find_task_comments(source, &Rust);
+
+        let found: Vec<_> = tasks
+            .iter()
+            .map(|task| (task.line, task.tag.as_str(), task.message.as_str()))
+            .collect();
+        assert_eq!(found, vec![(1, "TODO", "fix"), (4, "FIXME", "later")]);
+    }
+
+    #[test]
+    fn test_task_comments_use_every_line_prefix() {
+        let source = "-- HACK: retry\n/* XXX once */\nlet s = \"TODO\"\n";
+
+        let tags: Vec<_> = find_task_comments(source, &Dashed)
+            .into_iter()
+            .map(|task| (task.line, task.tag))
+            .collect();
+        assert_eq!(tags, vec![(1, "HACK".to_string()), (2, "XXX".to_string())]);
+    }
+}
@@ -53,6 +53,8 @@ impl From<AstSymbol> for Symbol {
             },
             end_location: ast.end_line.map(|line| SourceLocation { line, column: 0 }),
             documentation: ast.documentation,
+            is_test: false,
+            parent: None,
         }
     }
 }
@@ -0,0 +1,287 @@
+//! Keyword-based cognitive complexity scoring
+//!
+//! Scores each function by counting the language's complexity keywords,
+//! weighting each by how many other complexity keywords enclose it. Keywords
+//! are found with the plugin API's identifier scanner, which skips string
+//! literals and comments. A keyword's block is delimited by braces, or by
+//! indentation for languages whose [`AnalysisMetadata`] says so.
+
+use crate::analysis::AnalysisMetadata;
+use mill_plugin_api::identifier_scanner::find_nested_identifiers;
+use mill_plugin_api::{Symbol, SymbolKind};
+
+/// Complexity of one function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionComplexity {
+    /// Function name, as reported by the plugin's `parse`
+    pub name: String,
+    /// 0-based line of the function
+    pub line: usize,
+    /// Cognitive complexity score
+    pub score: u32,
+}
+
+/// Complexity of a source file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComplexityReport {
+    /// Sum of all function scores
+    pub total: u32,
+    /// Per-function breakdown, in symbol order
+    pub functions: Vec<FunctionComplexity>,
+}
+
+/// Score the functions and methods among `symbols` in `source`
+///
+/// `symbols` are the ones the plugin's `parse` returned for `source`. Every
+/// complexity keyword scores `1 + nesting_penalty * depth`, where depth is the
+/// number of enclosing complexity keywords of the same function. A keyword
+/// belongs to the innermost function whose lines contain it; functions
+/// without an end location extend to the next function or the end of file.
+pub fn compute_cognitive_complexity(
+    source: &str,
+    meta: &dyn AnalysisMetadata,
+    symbols: &[Symbol],
+) -> ComplexityReport {
+    let keywords = meta.complexity_keywords();
+    let lines: Vec<&str> = source.lines().collect();
+    // Scanner lines are 1-based, symbol lines 0-based
+    let keyword_uses: Vec<(usize, usize)> = find_nested_identifiers(source, meta.lexical_syntax())
+        .into_iter()
+        .filter(|identifier| keywords.contains(&identifier.name.as_str()))
+        .map(|identifier| {
+            let line = identifier.line - 1;
+            let level = if meta.indentation_blocks() {
+                lines.get(line).map_or(0, |text| indentation(text))
+            } else {
+                identifier.depth
+            };
+            (line, level)
+        })
+        .collect();
+
+    let functions: Vec<(&Symbol, usize, usize)> = {
+        let starts: Vec<&Symbol> = symbols
+            .iter()
+            .filter(|symbol| matches!(symbol.kind, SymbolKind::Function | SymbolKind::Method))
+            .collect();
+        starts
+            .iter()
+            .enumerate()
+            .map(|(index, symbol)| {
+                let start = symbol.location.line;
+                let end = match &symbol.end_location {
+                    Some(end) => end.line,
+                    None => starts
+                        .iter()
+                        .skip(index + 1)
+                        .map(|next| next.location.line)
+                        .find(|&line| line > start)
+                        .map_or(usize::MAX, |line| line - 1),
+                };
+                (*symbol, start, end)
+            })
+            .collect()
+    };
+
+    // Levels of the keywords whose blocks are still open, per function
+    let mut open: Vec<Vec<usize>> = vec![Vec::new(); functions.len()];
+    let mut scores = vec![0; functions.len()];
+    for (line, level) in keyword_uses {
+        let owner = functions
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, start, end))| (*start..=*end).contains(&line))
+            .max_by_key(|(_, (_, start, _))| *start)
+            .map(|(index, _)| index);
+        let Some(index) = owner else {
+            continue;
+        };
+        // A keyword at the same or an outer level closes the earlier blocks
+        let enclosing = &mut open[index];
+        while enclosing.last().is_some_and(|&outer| outer >= level) {
+            enclosing.pop();
+        }
+        scores[index] += 1 + meta.nesting_penalty() * enclosing.len() as u32;
+        enclosing.push(level);
+    }
+
+    let functions: Vec<FunctionComplexity> = functions
+        .iter()
+        .zip(scores)
+        .map(|((symbol, start, _), score)| FunctionComplexity {
+            name: symbol.name.clone(),
+            line: *start,
+            score,
+        })
+        .collect();
+
+    ComplexityReport {
+        total: functions.iter().map(|function| function.score).sum(),
+        functions,
+    }
+}
+
+/// Width of the leading whitespace of a line, counting a tab as four spaces
+fn indentation(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mill_plugin_api::identifier_scanner::LexicalSyntax;
+    use mill_plugin_api::SourceLocation;
+
+    struct Swift;
+
+    impl AnalysisMetadata for Swift {
+        fn complexity_keywords(&self) -> &[&str] {
+            &[
+                "if", "guard", "for", "while", "repeat", "switch", "case", "catch",
+            ]
+        }
+
+        fn nesting_penalty(&self) -> u32 {
+            1
+        }
+
+        fn lexical_syntax(&self) -> LexicalSyntax {
+            LexicalSyntax::C_LIKE
+        }
+    }
+
+    struct Python;
+
+    impl AnalysisMetadata for Python {
+        fn complexity_keywords(&self) -> &[&str] {
+            &["if", "elif", "for", "while", "except"]
+        }
+
+        fn nesting_penalty(&self) -> u32 {
+            2
+        }
+
+        fn lexical_syntax(&self) -> LexicalSyntax {
+            LexicalSyntax::PYTHON
+        }
+
+        fn indentation_blocks(&self) -> bool {
+            true
+        }
+    }
+
+    fn function(name: &str, line: usize) -> Symbol {
+        Symbol {
+            name: name.to_string(),
+            kind: SymbolKind::Function,
+            location: SourceLocation { line, column: 0 },
+            end_location: None,
+            documentation: None,
+            is_test: false,
+            parent: None,
+        }
+    }
+
+    fn scores(report: &ComplexityReport) -> Vec<(&str, u32)> {
+        report
+            .functions
+            .iter()
+            .map(|function| (function.name.as_str(), function.score))
+            .collect()
+    }
+
+    #[test]
+    fn test_swift_nested_scores_higher_than_flat() {
+        let source = r#"func nested(items: [Int]) {
+    for item in items {
+        if item > 0 {
+            print("if positive")
+        }
+    }
+}
+
+func flat(items: [Int]) {
+    // if this were nested it would score more
+    for item in items {
+        print("while \(item)")
+    }
+    if items.isEmpty {
+        print("empty")
+    }
+}
+"#;
+        let symbols = vec![function("nested", 0), function("flat", 8)];
+
+        let report = compute_cognitive_complexity(source, &Swift, &symbols);
+
+        assert_eq!(
+            report.functions,
+            vec![
+                FunctionComplexity {
+                    name: "nested".to_string(),
+                    line: 0,
+                    score: 3,
+                },
+                FunctionComplexity {
+                    name: "flat".to_string(),
+                    line: 8,
+                    score: 2,
+                },
+            ]
+        );
+        assert_eq!(report.total, 5);
+    }
+
+    #[test]
+    fn test_closure_braces_do_not_count_as_nesting() {
+        let source = "func run(items: [Int]) {\n    items.forEach { item in\n        if item > 0 {\n            print(item)\n        }\n    }\n}\n";
+
+        let report = compute_cognitive_complexity(source, &Swift, &[function("run", 0)]);
+
+        assert_eq!(scores(&report), vec![("run", 1)]);
+    }
+
+    #[test]
+    fn test_python_nesting_follows_indentation() {
+        let source = r#"def nested(items):
+    for item in items:
+        if item > 0:
+            print("for each if")
+        elif item < 0:
+            print("negative")
+
+def flat(items):
+    """if this were nested it would score more"""
+    for item in items:
+        print(item)
+    if not items:
+        print("empty")
+"#;
+        let symbols = vec![function("nested", 0), function("flat", 7)];
+
+        let report = compute_cognitive_complexity(source, &Python, &symbols);
+
+        // for: 1; if and elif: 1 + 2 * 1 each
+        assert_eq!(scores(&report), vec![("nested", 7), ("flat", 2)]);
+    }
+
+    #[test]
+    fn test_methods_keep_their_own_keywords() {
+        let source = "class Store {\n    func load() {\n        if cached {\n            return\n        }\n    }\n\n    func save() {\n        while dirty {\n            flush()\n        }\n    }\n}\n";
+        let mut load = function("load", 1);
+        load.kind = SymbolKind::Method;
+        let mut save = function("save", 7);
+        save.kind = SymbolKind::Method;
+        let class = Symbol {
+            kind: SymbolKind::Class,
+            ..function("Store", 0)
+        };
+
+        let report = compute_cognitive_complexity(source, &Swift, &[class, load, save]);
+
+        assert_eq!(scores(&report), vec![("load", 1), ("save", 1)]);
+    }
+}
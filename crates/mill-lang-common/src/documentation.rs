@@ -0,0 +1,219 @@
+//! Documentation comment extraction
+//!
+//! Collects the doc comment attached to a symbol so plugins can populate
+//! `Symbol::documentation` without re-implementing comment scanning.
+
+/// How a language attaches documentation to a symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocCommentStyle {
+    /// `///` line comments or a `/** */` block above the symbol
+    TripleSlash,
+    /// A run of `#` comments above the symbol
+    Hash,
+    /// A string literal on the first line of the symbol's body (Python)
+    Docstring,
+}
+
+/// Extract the doc comment of the symbol declared on `symbol_line` (0-based)
+///
+/// For `TripleSlash` and `Hash` the contiguous comment lines immediately
+/// above the declaration are used, skipping attributes and decorators in
+/// between. For `Docstring` the string literal opening the body is used.
+/// Comment markers are stripped and the lines joined with newlines; `None`
+/// is returned when the symbol has no documentation.
+pub fn extract_doc_comment(
+    source: &str,
+    symbol_line: usize,
+    style: DocCommentStyle,
+) -> Option<String> {
+    let lines: Vec<&str> = source.lines().collect();
+    extract_doc_comment_from_lines(&lines, symbol_line, style)
+}
+
+/// Same as [`extract_doc_comment`] over already split lines
+///
+/// Use this when extracting documentation for many symbols of one file so
+/// the source is only split once.
+pub fn extract_doc_comment_from_lines(
+    lines: &[&str],
+    symbol_line: usize,
+    style: DocCommentStyle,
+) -> Option<String> {
+    if symbol_line >= lines.len() {
+        return None;
+    }
+
+    let doc_lines = match style {
+        DocCommentStyle::TripleSlash => triple_slash_doc(&lines[..symbol_line]),
+        DocCommentStyle::Hash => hash_doc(&lines[..symbol_line]),
+        DocCommentStyle::Docstring => docstring(&lines[symbol_line + 1..]),
+    }?;
+
+    let doc = doc_lines.join("\n").trim().to_string();
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc)
+    }
+}
+
+/// Index one past the last line that belongs to the doc comment, skipping
+/// attribute and decorator lines directly above the declaration
+fn doc_end(preceding: &[&str]) -> usize {
+    let mut end = preceding.len();
+    while end > 0 {
+        let trimmed = preceding[end - 1].trim();
+        if trimmed.starts_with("#[") || trimmed.starts_with('@') {
+            end -= 1;
+        } else {
+            break;
+        }
+    }
+    end
+}
+
+fn triple_slash_doc(preceding: &[&str]) -> Option<Vec<String>> {
+    let end = doc_end(preceding);
+    let last = preceding.get(end.checked_sub(1)?)?.trim();
+
+    if last.starts_with("///") {
+        let start = preceding[..end]
+            .iter()
+            .rposition(|line| !line.trim().starts_with("///"))
+            .map_or(0, |i| i + 1);
+        return Some(
+            preceding[start..end]
+                .iter()
+                .map(|line| strip_marker(line.trim(), "///"))
+                .collect(),
+        );
+    }
+
+    if last.ends_with("*/") {
+        let start = preceding[..end]
+            .iter()
+            .rposition(|line| line.contains("/*"))?;
+        if !preceding[start].trim().starts_with("/**") {
+            return None;
+        }
+        return Some(
+            preceding[start..end]
+                .iter()
+                .map(|line| {
+                    let line = line.trim();
+                    let line = line.strip_prefix("/**").unwrap_or(line);
+                    let line = line.strip_suffix("*/").unwrap_or(line).trim_end();
+                    strip_marker(line, "*")
+                })
+                .collect(),
+        );
+    }
+
+    None
+}
+
+fn hash_doc(preceding: &[&str]) -> Option<Vec<String>> {
+    let end = doc_end(preceding);
+    let is_comment = |line: &str| {
+        let trimmed = line.trim();
+        trimmed.starts_with('#') && !trimmed.starts_with("#!") && !trimmed.starts_with("#[")
+    };
+    let start = preceding[..end]
+        .iter()
+        .rposition(|line| !is_comment(line))
+        .map_or(0, |i| i + 1);
+    if start == end {
+        return None;
+    }
+    Some(
+        preceding[start..end]
+            .iter()
+            .map(|line| strip_marker(line.trim(), "#"))
+            .collect(),
+    )
+}
+
+fn docstring(body: &[&str]) -> Option<Vec<String>> {
+    let first = body.iter().position(|line| !line.trim().is_empty())?;
+    let opening = body[first].trim();
+    let opening = opening
+        .strip_prefix(['r', 'R', 'u', 'U'])
+        .unwrap_or(opening);
+    let quote = ["\"\"\"", "'''"]
+        .into_iter()
+        .find(|quote| opening.starts_with(quote))?;
+
+    let rest = &opening[quote.len()..];
+    if let Some(closing) = rest.find(quote) {
+        return Some(vec![rest[..closing].to_string()]);
+    }
+
+    let mut doc = vec![rest.to_string()];
+    for line in &body[first + 1..] {
+        let line = line.trim();
+        if let Some(closing) = line.find(quote) {
+            doc.push(line[..closing].to_string());
+            return Some(doc);
+        }
+        doc.push(line.to_string());
+    }
+    // Unterminated docstring
+    None
+}
+
+/// Remove a comment marker and the single space following it
+fn strip_marker(line: &str, marker: &str) -> String {
+    let line = line.strip_prefix(marker).unwrap_or(line);
+    line.strip_prefix(' ').unwrap_or(line).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triple_slash_doc() {
+        let source = "use std::fmt;\n\n/// Adds two numbers.\n///\n/// Overflow wraps.\n#[inline]\npub fn add(a: u8, b: u8) -> u8 {\n    a.wrapping_add(b)\n}\n";
+
+        assert_eq!(
+            extract_doc_comment(source, 6, DocCommentStyle::TripleSlash),
+            Some("Adds two numbers.\n\nOverflow wraps.".to_string())
+        );
+        assert_eq!(
+            extract_doc_comment(source, 0, DocCommentStyle::TripleSlash),
+            None
+        );
+    }
+
+    #[test]
+    fn test_block_doc() {
+        let source = "/**\n * Greets the user.\n * @param name who to greet\n */\nfunction greet(name) {}\n\n/* not documentation */\nfunction other() {}\n";
+
+        assert_eq!(
+            extract_doc_comment(source, 4, DocCommentStyle::TripleSlash),
+            Some("Greets the user.\n@param name who to greet".to_string())
+        );
+        assert_eq!(
+            extract_doc_comment(source, 7, DocCommentStyle::TripleSlash),
+            None
+        );
+    }
+
+    #[test]
+    fn test_hash_and_docstring_doc() {
+        let source = "#!/usr/bin/env python\n# Loads the config.\n# Returns a dict.\n@cached\ndef load():\n    \"\"\"Read settings\n    from disk.\n    \"\"\"\n    return {}\n";
+
+        assert_eq!(
+            extract_doc_comment(source, 4, DocCommentStyle::Hash),
+            Some("Loads the config.\nReturns a dict.".to_string())
+        );
+        assert_eq!(
+            extract_doc_comment(source, 4, DocCommentStyle::Docstring),
+            Some("Read settings\nfrom disk.".to_string())
+        );
+        assert_eq!(
+            extract_doc_comment("def f():\n    return 1\n", 0, DocCommentStyle::Docstring),
+            None
+        );
+    }
+}
@@ -4,7 +4,7 @@
 //! reducing boilerplate across language plugins.
 
 use mill_foundation::protocol::{ImportGraph, ImportGraphMetadata, ImportInfo};
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::path::Path;
 
 /// Builder for constructing ImportGraph instances
@@ -164,6 +164,74 @@ impl ImportGraphBuilder {
     }
 }
 
+/// Where an import resolves to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportOrigin {
+    /// Resolves to a file inside the project
+    Internal,
+    /// Matches a dependency declared in the project manifest
+    External,
+    /// Neither (standard library, undeclared package, unresolvable path)
+    Unresolved,
+}
+
+/// Classify every import of `graph` as internal or external using the
+/// project layout, and populate `metadata.external_dependencies`
+///
+/// An import is internal when `resolves_to_project_file(project_root, module_path)`
+/// returns `true`. Otherwise it is external when the first segment of its
+/// module path (split on `module_separator`) matches one of
+/// `dependency_names`; names are compared ignoring case and treating `-` and
+/// `_` as equal, since manifests and import statements spell them differently.
+///
+/// `external_dependencies` receives the sorted, deduplicated module paths of
+/// the external imports. The returned classifications are in import order.
+pub fn classify_imports<F>(
+    graph: &mut ImportGraph,
+    project_root: &Path,
+    dependency_names: &[String],
+    module_separator: &str,
+    resolves_to_project_file: F,
+) -> Vec<ImportOrigin>
+where
+    F: Fn(&Path, &str) -> bool,
+{
+    let normalize = |name: &str| name.trim().to_ascii_lowercase().replace('-', "_");
+    let declared: HashSet<String> = dependency_names.iter().map(|n| normalize(n)).collect();
+
+    let origins: Vec<ImportOrigin> = graph
+        .imports
+        .iter()
+        .map(|import| {
+            let module_path = import.module_path.as_str();
+            if resolves_to_project_file(project_root, module_path) {
+                return ImportOrigin::Internal;
+            }
+            let package = module_path
+                .split(module_separator)
+                .next()
+                .unwrap_or(module_path);
+            if declared.contains(&normalize(package)) {
+                ImportOrigin::External
+            } else {
+                ImportOrigin::Unresolved
+            }
+        })
+        .collect();
+
+    graph.metadata.external_dependencies = graph
+        .imports
+        .iter()
+        .zip(&origins)
+        .filter(|(_, origin)| **origin == ImportOrigin::External)
+        .map(|(import, _)| import.module_path.clone())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    origins
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -231,4 +299,52 @@ mod tests {
             "in-memory.java"
         );
     }
+
+    #[test]
+    fn test_classify_imports_internal_and_external() {
+        use mill_foundation::protocol::{ImportType, SourceLocation};
+
+        let import = |module_path: &str| ImportInfo {
+            module_path: module_path.to_string(),
+            import_type: ImportType::EsModule,
+            named_imports: vec![],
+            default_import: None,
+            namespace_import: None,
+            type_only: false,
+            location: SourceLocation {
+                start_line: 0,
+                start_column: 0,
+                end_line: 0,
+                end_column: 0,
+            },
+        };
+        let mut graph = ImportGraphBuilder::new("python")
+            .with_imports(vec![
+                import("utils.helpers"),
+                import("requests.adapters"),
+                import("os.path"),
+            ])
+            .build();
+
+        let origins = classify_imports(
+            &mut graph,
+            Path::new("/project"),
+            &["Requests".to_string()],
+            ".",
+            |_, module| module.starts_with("utils"),
+        );
+
+        assert_eq!(
+            origins,
+            vec![
+                ImportOrigin::Internal,
+                ImportOrigin::External,
+                ImportOrigin::Unresolved
+            ]
+        );
+        assert_eq!(
+            graph.metadata.external_dependencies,
+            vec!["requests.adapters".to_string()]
+        );
+    }
 }
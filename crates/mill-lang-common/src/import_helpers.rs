@@ -14,6 +14,7 @@
 //! - [`find_last_matching_line`] - Find the last line matching a predicate
 //! - [`insert_line_at`] - Insert a line at a specific position
 //! - [`remove_lines_matching`] - Remove all lines matching a predicate
+//! - [`remove_duplicate_lines`] - Remove repeated lines, keeping the first
 //! - [`replace_in_lines`] - Replace all occurrences of a pattern
 //!
 //! # Examples
@@ -184,6 +185,59 @@ where
     (result, removed_count)
 }
 
+/// Remove repeated lines, keeping the first occurrence.
+///
+/// `key` returns the comparison key for lines that take part in
+/// deduplication and `None` for lines that are always kept. A later line is
+/// removed when its key matches an earlier line's key. Returns
+/// (new_content, count_removed) and preserves line endings, including a
+/// trailing newline.
+///
+/// # Complexity
+/// O(n) - single pass with a hash set of seen keys
+///
+/// # Examples
+/// ```
+/// use mill_lang_common::import_helpers::remove_duplicate_lines;
+///
+/// let content = "import A\nimport B\nimport A  # again\ncode\n";
+/// let (result, count) = remove_duplicate_lines(content, |line| {
+///     line.starts_with("import")
+///         .then(|| line.split('#').next().unwrap_or("").trim().to_string())
+/// });
+/// assert_eq!(result, "import A\nimport B\ncode\n");
+/// assert_eq!(count, 1);
+/// ```
+pub fn remove_duplicate_lines<F>(content: &str, key: F) -> (String, usize)
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let line_ending = if content.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+
+    let mut seen = std::collections::HashSet::new();
+    let mut removed_count = 0;
+    let remaining: Vec<&str> = content
+        .lines()
+        .filter(|line| {
+            let duplicate = key(line).is_some_and(|key| !seen.insert(key));
+            if duplicate {
+                removed_count += 1;
+            }
+            !duplicate
+        })
+        .collect();
+
+    let mut result = remaining.join(line_ending);
+    if content.ends_with('\n') && !result.is_empty() {
+        result.push_str(line_ending);
+    }
+    (result, removed_count)
+}
+
 /// Replace all occurrences of a pattern in content.
 ///
 /// Returns (new_content, count_replaced).
@@ -35,8 +35,12 @@
 //! - [`plugin_scaffold`] - Plugin code generation
 //!
 //! ## Additional Utilities
+//! - [`analysis`] - Per-language analysis details and TODO comment scanning
+//! - [`complexity`] - Keyword-based cognitive complexity per function
 //! - [`import_graph`] - ImportGraph builder for consistent construction
 //! - [`parsing`] - Common parsing patterns (fallback strategies)
+//! - [`documentation`] - Doc comment extraction by comment style
+//! - [`naming`] - Identifier casing and reserved-keyword validation
 //!
 //! # Examples
 //!
@@ -109,16 +113,24 @@ pub mod plugin_scaffold;
 pub mod testing;
 
 // Additional utility modules
+pub mod analysis;
+pub mod complexity;
+pub mod documentation;
 pub mod import_graph;
+pub mod naming;
 pub mod parsing;
+pub mod paths;
 
 // LSP installation utilities (for plugin-based LSP installation)
 pub mod lsp;
 
 // Re-export commonly used types for convenience
+pub use analysis::{find_task_comments, AnalysisMetadata};
 pub use ast_deserialization::{parse_ast_output, AstSymbol, AstToolOutput};
+pub use complexity::{compute_cognitive_complexity, ComplexityReport, FunctionComplexity};
+pub use documentation::{extract_doc_comment, extract_doc_comment_from_lines, DocCommentStyle};
 pub use error_helpers::ErrorBuilder;
-pub use import_graph::ImportGraphBuilder;
+pub use import_graph::{classify_imports, ImportGraphBuilder, ImportOrigin};
 pub use import_helpers::{
     find_last_matching_line, insert_line_at, remove_lines_matching, replace_in_lines,
 };
@@ -128,6 +140,7 @@ pub use location::{
 };
 pub use manifest_common::{JsonWorkspace, TomlWorkspace};
 pub use parsing::{parse_with_fallback, parse_with_optional_fallback, try_parsers};
+pub use paths::normalize_module_path;
 pub use refactoring::{
     edit_plan_builder::EditPlanBuilder, extract_constant_builder::ExtractConstantEditPlanBuilder,
     find_literal_occurrences, CodeRange, ExtractConstantAnalysis, ExtractVariableAnalysis,
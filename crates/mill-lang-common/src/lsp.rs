@@ -253,6 +253,59 @@ pub fn check_binary_in_path(name: &str) -> Option<PathBuf> {
     which::which(name).ok()
 }
 
+/// Runs external commands for LSP installers
+///
+/// Installers go through this trait instead of spawning processes directly
+/// so version probing and pinned installs can be tested without the real
+/// toolchains.
+pub trait CommandRunner: Send + Sync {
+    /// Run `program` with `args` and return its stdout
+    ///
+    /// Fails if the program cannot be started or exits unsuccessfully.
+    fn run(&self, program: &Path, args: &[&str]) -> LspResult<String>;
+}
+
+/// [`CommandRunner`] that spawns real processes
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemCommandRunner;
+
+impl CommandRunner for SystemCommandRunner {
+    fn run(&self, program: &Path, args: &[&str]) -> LspResult<String> {
+        let output = std::process::Command::new(program).args(args).output()?;
+        if !output.status.success() {
+            return Err(LspError::InstallationFailed(format!(
+                "{} {} failed with exit code {:?}: {}",
+                program.display(),
+                args.join(" "),
+                output.status.code(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+/// Extract the version number from typical `--version` output
+///
+/// Returns the first whitespace-separated token that starts with a digit
+/// (after an optional `v` prefix) and contains a dot, e.g. `1.84.0` from
+/// `rust-analyzer 1.84.0 (9fc6b43 2025-01-07)` or `1.12.0` from `pylsp v1.12.0`.
+pub fn parse_version_output(output: &str) -> Option<String> {
+    output.split_whitespace().find_map(|token| {
+        let token = token
+            .trim_start_matches('v')
+            .trim_end_matches([',', ';', ')']);
+        let starts_with_digit = token.chars().next().is_some_and(|c| c.is_ascii_digit());
+        (starts_with_digit && token.contains('.')).then(|| token.to_string())
+    })
+}
+
+/// Run `binary --version` and parse the reported version
+pub fn binary_version(runner: &dyn CommandRunner, binary: &Path) -> LspResult<Option<String>> {
+    let output = runner.run(binary, &["--version"])?;
+    Ok(parse_version_output(&output))
+}
+
 /// Install an npm package globally
 pub async fn install_npm_package(package_name: &str, binary_name: &str) -> LspResult<PathBuf> {
     info!("Installing npm package: {}", package_name);
@@ -399,6 +452,20 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_parse_version_output() {
+        assert_eq!(
+            parse_version_output("rust-analyzer 1.84.0 (9fc6b43 2025-01-07)\n").as_deref(),
+            Some("1.84.0")
+        );
+        assert_eq!(
+            parse_version_output("pylsp v1.12.0\n").as_deref(),
+            Some("1.12.0")
+        );
+        assert_eq!(parse_version_output("4.3.3\n").as_deref(), Some("4.3.3"));
+        assert_eq!(parse_version_output("rust-analyzer\n"), None);
+    }
+
     #[test]
     fn test_placeholder_checksum() {
         // Test that placeholder checksums pass with warning
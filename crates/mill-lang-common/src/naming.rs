@@ -0,0 +1,251 @@
+//! Identifier naming validation for refactoring operations
+//!
+//! Extract operations insert user-supplied names into source code. This
+//! module checks that such a name is a valid identifier and not a reserved
+//! keyword of the target language. Casing is only a convention, so a name
+//! that doesn't follow it is logged rather than rejected.
+
+use crate::validation::is_screaming_snake_case;
+use std::fmt;
+use tracing::warn;
+
+/// Casing convention an identifier is expected to follow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NamingConvention {
+    /// `totalPrice`
+    CamelCase,
+    /// `TotalPrice`
+    PascalCase,
+    /// `total_price`, optionally with leading underscores
+    SnakeCase,
+    /// `TOTAL_PRICE`
+    ScreamingSnakeCase,
+}
+
+impl fmt::Display for NamingConvention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::CamelCase => "camelCase",
+            Self::PascalCase => "PascalCase",
+            Self::SnakeCase => "snake_case",
+            Self::ScreamingSnakeCase => "SCREAMING_SNAKE_CASE",
+        })
+    }
+}
+
+/// Language whose reserved keywords an identifier must avoid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Rust,
+    Python,
+    /// TypeScript and JavaScript
+    TypeScript,
+    Swift,
+}
+
+impl Language {
+    /// Display name used in error messages
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Rust => "Rust",
+            Self::Python => "Python",
+            Self::TypeScript => "TypeScript",
+            Self::Swift => "Swift",
+        }
+    }
+
+    /// Whether `c` may start an identifier
+    ///
+    /// Letters are accepted in any script, matching Rust, Python 3, Swift
+    /// and ECMAScript identifiers.
+    fn is_identifier_start(&self, c: char) -> bool {
+        c.is_alphabetic() || c == '_' || (*self == Self::TypeScript && c == '$')
+    }
+
+    /// Whether `c` may continue an identifier
+    fn is_identifier_continue(&self, c: char) -> bool {
+        self.is_identifier_start(c) || c.is_alphanumeric()
+    }
+
+    /// Whether `name` is a keyword that can't be used as an identifier
+    pub fn is_reserved_keyword(&self, name: &str) -> bool {
+        self.reserved_keywords()
+            .split_whitespace()
+            .any(|keyword| keyword == name)
+    }
+
+    fn reserved_keywords(&self) -> &'static str {
+        match self {
+            Self::Rust => concat!(
+                "as async await break const continue crate dyn else enum extern false fn for if ",
+                "impl in let loop match mod move mut pub ref return self Self static struct super ",
+                "trait true type unsafe use where while abstract become box do final gen macro ",
+                "override priv try typeof unsized virtual yield _",
+            ),
+            Self::Python => concat!(
+                "False None True and as assert async await break class continue def del elif ",
+                "else except finally for from global if import in is lambda nonlocal not or pass ",
+                "raise return try while with yield",
+            ),
+            Self::TypeScript => concat!(
+                "break case catch class const continue debugger default delete do else enum ",
+                "export extends false finally for function if import in instanceof new null ",
+                "return super switch this throw true try typeof var void while with implements ",
+                "interface let package private protected public static yield await",
+            ),
+            Self::Swift => concat!(
+                "associatedtype class deinit enum extension fileprivate func import init inout ",
+                "internal let open operator private precedencegroup protocol public rethrows ",
+                "static struct subscript typealias var break case catch continue default defer ",
+                "do else fallthrough for guard if in repeat return throw switch where while Any ",
+                "as false is nil self Self super throws true try",
+            ),
+        }
+    }
+}
+
+/// Check that `name` is a valid `language` identifier
+///
+/// Only the syntax is checked: the characters allowed by the language and
+/// its reserved keywords. Returns a message describing the first problem
+/// found, suitable for an `invalid_input` error. Use [`check_convention`]
+/// for the casing.
+///
+/// # Example
+/// ```
+/// use mill_lang_common::naming::{validate_identifier, Language};
+///
+/// assert!(validate_identifier("total_price", Language::Rust).is_ok());
+/// assert!(validate_identifier("$store", Language::TypeScript).is_ok());
+/// assert!(validate_identifier("fn", Language::Rust).is_err());
+/// assert!(validate_identifier("total price", Language::Python).is_err());
+/// ```
+pub fn validate_identifier(name: &str, language: Language) -> Result<(), String> {
+    let Some(first) = name.chars().next() else {
+        return Err("Identifier name cannot be empty".to_string());
+    };
+    if !language.is_identifier_start(first) {
+        return Err(format!(
+            "'{}' is not a valid {} identifier: it starts with {:?}",
+            name,
+            language.name(),
+            first
+        ));
+    }
+    if let Some(invalid) = name.chars().find(|c| !language.is_identifier_continue(*c)) {
+        return Err(format!(
+            "'{}' is not a valid {} identifier: it contains {:?}",
+            name,
+            language.name(),
+            invalid
+        ));
+    }
+    if language.is_reserved_keyword(name) {
+        return Err(format!(
+            "'{}' is a reserved keyword in {}",
+            name,
+            language.name()
+        ));
+    }
+    Ok(())
+}
+
+/// Whether `name` follows `convention`, logging a warning when it doesn't
+///
+/// A name in another casing still compiles, so callers go ahead with it.
+pub fn check_convention(name: &str, convention: NamingConvention) -> bool {
+    let follows = follows_convention(name, convention);
+    if !follows {
+        warn!(name, %convention, "Identifier does not follow the naming convention");
+    }
+    follows
+}
+
+/// Whether an identifier follows `convention`
+///
+/// Leading `_` and `$` are ignored, so `_private` is snake_case and
+/// `$store` camelCase.
+pub fn follows_convention(name: &str, convention: NamingConvention) -> bool {
+    let body = name.trim_start_matches(['_', '$']);
+    let first = body.chars().next();
+    match convention {
+        NamingConvention::CamelCase => {
+            first.is_some_and(|c| !c.is_uppercase()) && !body.contains('_')
+        }
+        NamingConvention::PascalCase => {
+            first.is_some_and(char::is_uppercase) && !body.contains('_')
+        }
+        NamingConvention::SnakeCase => {
+            first.is_some_and(|c| !c.is_uppercase()) && !body.chars().any(char::is_uppercase)
+        }
+        NamingConvention::ScreamingSnakeCase => is_screaming_snake_case(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conventions() {
+        let cases = [
+            ("totalPrice", NamingConvention::CamelCase, true),
+            ("$store", NamingConvention::CamelCase, true),
+            ("TotalPrice", NamingConvention::CamelCase, false),
+            ("TotalPrice", NamingConvention::PascalCase, true),
+            ("total_price", NamingConvention::PascalCase, false),
+            ("total_price", NamingConvention::SnakeCase, true),
+            ("_private", NamingConvention::SnakeCase, true),
+            ("größe", NamingConvention::SnakeCase, true),
+            ("totalPrice", NamingConvention::SnakeCase, false),
+            ("TAX_RATE", NamingConvention::ScreamingSnakeCase, true),
+            ("tax_rate", NamingConvention::ScreamingSnakeCase, false),
+        ];
+        for (name, convention, expected) in cases {
+            assert_eq!(
+                check_convention(name, convention),
+                expected,
+                "{} as {}",
+                name,
+                convention
+            );
+        }
+    }
+
+    #[test]
+    fn test_casing_does_not_invalidate_identifier() {
+        assert!(validate_identifier("totalPrice", Language::Rust).is_ok());
+        assert!(validate_identifier("MAX", Language::Python).is_ok());
+    }
+
+    #[test]
+    fn test_accepts_language_specific_identifiers() {
+        assert!(validate_identifier("$store", Language::TypeScript).is_ok());
+        assert!(validate_identifier("_private", Language::TypeScript).is_ok());
+        assert!(validate_identifier("größe", Language::Python).is_ok());
+        assert!(validate_identifier("変数", Language::Python).is_ok());
+
+        let err = validate_identifier("$store", Language::Python).unwrap_err();
+        assert!(err.contains("not a valid Python identifier"), "{}", err);
+    }
+
+    #[test]
+    fn test_rejects_swift_keyword_as_variable() {
+        let err = validate_identifier("class", Language::Swift).unwrap_err();
+        assert!(err.contains("reserved keyword in Swift"), "{}", err);
+
+        // Not reserved in Rust
+        assert!(validate_identifier("class", Language::Rust).is_ok());
+        assert!(validate_identifier("_", Language::Rust).is_err());
+    }
+
+    #[test]
+    fn test_rejects_invalid_characters() {
+        let err = validate_identifier("total price", Language::Python).unwrap_err();
+        assert!(err.contains("not a valid Python identifier"), "{}", err);
+
+        assert!(validate_identifier("", Language::TypeScript).is_err());
+        assert!(validate_identifier("2fast", Language::Rust).is_err());
+        assert!(validate_identifier("total-price", Language::TypeScript).is_err());
+    }
+}
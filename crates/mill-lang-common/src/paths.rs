@@ -0,0 +1,58 @@
+//! Module path helpers shared by language plugins
+//!
+//! Users and tools write module paths with whatever separator they are used
+//! to (`a.b.c`, `a::b::c`, `a/b/c`). Plugins normalize them to their own
+//! `LanguageMetadata::module_separator` before resolving anything.
+
+/// Separators recognized in incoming module paths
+const MODULE_SEPARATORS: &[&str] = &["::", ".", "/"];
+
+/// Rewrite `path` to use `separator` between its segments
+///
+/// Splits on any of `::`, `.`, and `/`, drops empty segments (so leading,
+/// trailing, and doubled separators disappear) and rejoins with `separator`.
+///
+/// # Example
+///
+/// ```rust
+/// use mill_lang_common::paths::normalize_module_path;
+///
+/// assert_eq!(normalize_module_path("services.planner", "::"), "services::planner");
+/// ```
+pub fn normalize_module_path(path: &str, separator: &str) -> String {
+    let mut segments = vec![path];
+    for sep in MODULE_SEPARATORS {
+        segments = segments.iter().flat_map(|s| s.split(sep)).collect();
+    }
+    segments
+        .into_iter()
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_to_rust_separator() {
+        for path in ["a.b.c", "a::b::c", "a/b/c"] {
+            assert_eq!(normalize_module_path(path, "::"), "a::b::c", "{}", path);
+        }
+    }
+
+    #[test]
+    fn test_normalize_to_swift_separator() {
+        for path in ["a.b.c", "a::b::c", "a/b/c"] {
+            assert_eq!(normalize_module_path(path, "."), "a.b.c", "{}", path);
+        }
+    }
+
+    #[test]
+    fn test_normalize_trims_empty_segments() {
+        assert_eq!(normalize_module_path("::a..b/", "::"), "a::b");
+        assert_eq!(normalize_module_path("a::b.c/d", "/"), "a/b/c/d");
+        assert_eq!(normalize_module_path("::", "::"), "");
+    }
+}
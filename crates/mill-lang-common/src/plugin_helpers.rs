@@ -42,7 +42,9 @@
 ///     struct: PythonPlugin,
 ///     name: "python",
 ///     extensions: ["py"],
+///     filenames: ["SConstruct"], // optional
 ///     manifest: "pyproject.toml",
+///     manifest_patterns: ["requirements*.txt"], // optional
 ///     lsp_command: "pylsp",
 ///     lsp_args: ["pylsp"],
 ///     source_dir: ".",
@@ -62,7 +64,9 @@ macro_rules! define_language_plugin {
         struct: $struct_name:ident,
         name: $name:expr,
         extensions: [$($ext:expr),+ $(,)?],
+        $(filenames: [$($filename:expr),* $(,)?],)?
         manifest: $manifest:expr,
+        $(manifest_patterns: [$($pattern:expr),* $(,)?],)?
         lsp_command: $lsp_cmd:expr,
         lsp_args: [$($lsp_arg:expr),+ $(,)?],
         source_dir: $source_dir:expr,
@@ -78,10 +82,12 @@ macro_rules! define_language_plugin {
         mill_plugin_api::mill_plugin! {
             name: $name,
             extensions: [$($ext),+],
+            filenames: [$($($filename),*)?],
             manifest: $manifest,
+            manifest_patterns: [$($($pattern),*)?],
             capabilities: $struct_name::CAPABILITIES,
             factory: $struct_name::new,
-            lsp: Some(mill_plugin_api::LspConfig::new($lsp_cmd, &[$($lsp_arg),+]))
+            lsp: $struct_name::METADATA.lsp
         }
 
         $(#[doc = $doc])?
@@ -95,10 +101,13 @@ macro_rules! define_language_plugin {
             pub const METADATA: mill_plugin_api::LanguageMetadata = mill_plugin_api::LanguageMetadata {
                 name: $name,
                 extensions: &[$($ext),+],
+                filenames: &[$($($filename),*)?],
                 manifest_filename: $manifest,
+                manifest_patterns: &[$($($pattern),*)?],
                 source_dir: $source_dir,
                 entry_point: $entry_point,
                 module_separator: $module_sep,
+                lsp: Some(mill_plugin_api::LspConfig::new($lsp_cmd, &[$($lsp_arg),+])),
             };
 
             /// The capabilities of this plugin.
@@ -204,6 +204,7 @@ pub fn parse_source(source: &str) -> PluginResult<ParsedSource> {{
             "source_length": source.len(),
         }}),
         symbols: vec![],
+        diagnostics: Vec::new(),
     }})
 }}
 
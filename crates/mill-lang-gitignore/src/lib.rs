@@ -21,6 +21,7 @@ use import_support_impl::GitignoreImportSupport;
 mill_plugin! {
     name: "gitignore",
     extensions: [],  // .gitignore has no extension
+    filenames: [".gitignore"],
     manifest: ".gitignore",
     capabilities: GitignoreLanguagePlugin::CAPABILITIES,
     factory: GitignoreLanguagePlugin::boxed,
@@ -52,11 +53,14 @@ impl GitignoreLanguagePlugin {
         Self {
             metadata: LanguageMetadata {
                 name: "gitignore",
-                extensions: &[], // Special case - matched by filename
+                extensions: &[],
+                filenames: &[".gitignore"],
                 manifest_filename: ".gitignore",
+                manifest_patterns: &[],
                 source_dir: ".",
                 entry_point: ".gitignore",
                 module_separator: "/",
+                lsp: None,
             },
             import_support: GitignoreImportSupport::new(),
         }
@@ -84,6 +88,7 @@ impl LanguagePlugin for GitignoreLanguagePlugin {
         Ok(ParsedSource {
             data: json!({}),
             symbols: vec![],
+            diagnostics: Vec::new(),
         })
     }
 
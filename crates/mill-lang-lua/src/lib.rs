@@ -63,10 +63,13 @@ impl LuaPlugin {
             metadata: LanguageMetadata {
                 name: "lua",
                 extensions: &["lua"],
+                filenames: &[],
                 manifest_filename: "",
+                manifest_patterns: &[],
                 source_dir: ".",
                 entry_point: "init.lua",
                 module_separator: ".",
+                lsp: None,
             },
             import_support: LuaImportSupport::new(),
         }
@@ -108,11 +111,13 @@ impl LanguagePlugin for LuaPlugin {
                         name: name.as_str().to_string(),
                         kind: SymbolKind::Function,
                         location: SourceLocation {
-                            line: line_idx + 1,
+                            line: line_idx,
                             column: name.start(),
                         },
                         end_location: None,
                         documentation: None,
+                        is_test: false,
+                        parent: None,
                     });
                     // Don't continue — line may also have require
                 }
@@ -124,11 +129,13 @@ impl LanguagePlugin for LuaPlugin {
                         name: name.as_str().to_string(),
                         kind: SymbolKind::Function,
                         location: SourceLocation {
-                            line: line_idx + 1,
+                            line: line_idx,
                             column: name.start(),
                         },
                         end_location: None,
                         documentation: Some("table function".to_string()),
+                        is_test: false,
+                        parent: None,
                     });
                 }
             }
@@ -139,11 +146,13 @@ impl LanguagePlugin for LuaPlugin {
                         name: name.as_str().to_string(),
                         kind: SymbolKind::Variable,
                         location: SourceLocation {
-                            line: line_idx + 1,
+                            line: line_idx,
                             column: name.start(),
                         },
                         end_location: None,
                         documentation: None,
+                        is_test: false,
+                        parent: None,
                     });
                 }
             }
@@ -162,6 +171,7 @@ impl LanguagePlugin for LuaPlugin {
                 "requires": requires,
             }),
             symbols,
+            diagnostics: Vec::new(),
         })
     }
 
@@ -251,6 +261,8 @@ end
         assert!(names.contains(&"greet"));
         assert!(names.contains(&"helper"));
         assert!(names.contains(&"M:method"));
+        // Lines are 0-based
+        assert_eq!(parsed.symbols[0].location.line, 1);
     }
 
     #[tokio::test]
@@ -83,17 +83,31 @@ impl MarkdownImportSupport {
 
     /// Check if a path looks like a file reference (not a URL)
     fn is_file_reference(path: &str) -> bool {
-        // Not a URL (http://, https://, mailto:, etc.)
-        !path.starts_with("http://")
-            && !path.starts_with("https://")
-            && !path.starts_with("mailto:")
-            && !path.starts_with("ftp://")
+        // Any `scheme:` prefix (https:, mailto:, data:, ...) marks an external
+        // link; single letters are left alone so `C:\docs` still counts
+        let has_scheme = path.split_once(':').is_some_and(|(scheme, _)| {
+            scheme.len() > 1
+                && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        });
+        !has_scheme
+            && !path.starts_with("//") // Protocol-relative URL
             && !path.starts_with('#') // Not just an anchor
     }
 
-    /// Extract the path without anchor
+    /// Split a link target into its path and its `?query`/`#fragment` suffix
+    fn split_link_target(path: &str) -> (&str, &str) {
+        match path.find(['?', '#']) {
+            Some(index) => path.split_at(index),
+            None => (path, ""),
+        }
+    }
+
+    /// Extract the path without query or anchor
     fn path_without_anchor(path: &str) -> &str {
-        path.split('#').next().unwrap_or(path)
+        Self::split_link_target(path).0
     }
 
     /// Normalize path for comparison (resolve relative paths)
@@ -112,16 +126,16 @@ impl MarkdownImportSupport {
                 .is_some_and(|prefix| prefix.ends_with('/'))
     }
 
-    /// Extract anchor from path if present
-    fn extract_anchor(path: &str) -> String {
-        if path.contains('#') {
-            path.split('#')
-                .nth(1)
-                .map(|a| format!("#{}", a))
-                .unwrap_or_default()
+    /// Replace the path of a link target, keeping its `./` prefix and its
+    /// `?query`/`#fragment` suffix
+    fn rewrite_link_target(original_path: &str, new_path: &str) -> String {
+        let (path, suffix) = Self::split_link_target(original_path);
+        let prefix = if path.starts_with("./") && !new_path.starts_with("./") {
+            "./"
         } else {
-            String::new()
-        }
+            ""
+        };
+        format!("{}{}{}", prefix, new_path, suffix)
     }
 
     /// Build markdown inline link with preserved anchor and image syntax
@@ -131,9 +145,13 @@ impl MarkdownImportSupport {
         new_path: &str,
         original_path: &str,
     ) -> String {
-        let anchor = Self::extract_anchor(original_path);
         let prefix = if full_match.starts_with('!') { "!" } else { "" };
-        format!("{}[{}]({}{})", prefix, link_text, new_path, anchor)
+        format!(
+            "{}[{}]({})",
+            prefix,
+            link_text,
+            Self::rewrite_link_target(original_path, new_path)
+        )
     }
 
     /// Build markdown reference-style link definition with preserved anchor and whitespace
@@ -143,18 +161,21 @@ impl MarkdownImportSupport {
         new_path: &str,
         original_path: &str,
     ) -> String {
-        let anchor = Self::extract_anchor(original_path);
         let leading_ws = full_match
             .chars()
             .take_while(|c| c.is_whitespace())
             .collect::<String>();
-        format!("{}[{}]: {}{}", leading_ws, ref_label, new_path, anchor)
+        format!(
+            "{}[{}]: {}",
+            leading_ws,
+            ref_label,
+            Self::rewrite_link_target(original_path, new_path)
+        )
     }
 
     /// Build markdown autolink with preserved anchor
     fn build_autolink(new_path: &str, original_path: &str) -> String {
-        let anchor = Self::extract_anchor(original_path);
-        format!("<{}{}>", new_path, anchor)
+        format!("<{}>", Self::rewrite_link_target(original_path, new_path))
     }
 
     /// Update prose identifiers with context-aware matching
@@ -714,6 +735,37 @@ Also [here](docs/architecture/ARCHITECTURE.md#overview).
         assert!(!updated.contains("ARCHITECTURE.md"));
     }
 
+    #[test]
+    fn test_rewrite_keeps_query_and_anchor_and_skips_urls() {
+        let support = MarkdownImportSupport::new();
+        let content = "\
+See [setup](./docs/setup.md#install) and [raw](docs/setup.md?plain=1#L10).
+Upstream copy: [setup](https://example.com/docs/setup.md#install).
+
+[guide]: docs/setup.md#usage
+[site]: https://example.com/docs/setup.md
+";
+
+        let (updated, count) = ImportRenameSupport::rewrite_imports_for_rename(
+            &support,
+            content,
+            "docs/setup.md",
+            "docs/getting-started.md",
+        );
+
+        assert_eq!(count, 3);
+        assert_eq!(
+            updated,
+            "\
+See [setup](./docs/getting-started.md#install) and [raw](docs/getting-started.md?plain=1#L10).
+Upstream copy: [setup](https://example.com/docs/setup.md#install).
+
+[guide]: docs/getting-started.md#usage
+[site]: https://example.com/docs/setup.md
+"
+        );
+    }
+
     #[test]
     fn test_rewrite_imports_preserves_images() {
         let support = MarkdownImportSupport::new();
@@ -810,4 +862,25 @@ Directory tree:
             "https://example.com/path"
         ));
     }
+
+    #[test]
+    fn test_is_file_reference_skips_any_url_scheme() {
+        assert!(MarkdownImportSupport::is_file_reference("docs/guide.md"));
+        assert!(MarkdownImportSupport::is_file_reference("../guide.md?x=1"));
+        assert!(MarkdownImportSupport::is_file_reference(
+            "C:\\docs\\guide.md"
+        ));
+
+        assert!(!MarkdownImportSupport::is_file_reference(
+            "https://example.com"
+        ));
+        assert!(!MarkdownImportSupport::is_file_reference(
+            "vscode://file/a.md"
+        ));
+        assert!(!MarkdownImportSupport::is_file_reference("tel:+15551234"));
+        assert!(!MarkdownImportSupport::is_file_reference(
+            "//cdn.example.com/a.png"
+        ));
+        assert!(!MarkdownImportSupport::is_file_reference("#section"));
+    }
 }
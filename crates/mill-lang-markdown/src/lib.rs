@@ -56,10 +56,13 @@ impl MarkdownPlugin {
             metadata: LanguageMetadata {
                 name: "Markdown",
                 extensions: &["md", "markdown"],
+                filenames: &[],
                 manifest_filename: "package.json", // No specific manifest for markdown
+                manifest_patterns: &[],
                 source_dir: "docs",
                 entry_point: "README.md",
                 module_separator: "/",
+                lsp: None,
             },
             import_support: MarkdownImportSupport::new(),
         }
@@ -93,6 +96,7 @@ impl LanguagePlugin for MarkdownPlugin {
                 "headers": symbols.len(),
             }),
             symbols,
+            diagnostics: Vec::new(),
         })
     }
 
@@ -247,14 +251,16 @@ fn extract_headers(content: &str) -> Vec<Symbol> {
                 name: title,
                 kind,
                 location: SourceLocation {
-                    line: line_num + 1,
+                    line: line_num,
                     column: 0,
                 },
                 end_location: Some(SourceLocation {
-                    line: line_num + 1,
+                    line: line_num,
                     column: 0,
                 }),
                 documentation: None,
+                is_test: false,
+                parent: None,
             });
         }
     }
@@ -288,6 +294,9 @@ Details.
         assert_eq!(parsed.symbols[1].name, "Section 1");
         assert_eq!(parsed.symbols[2].name, "Subsection 1.1");
         assert_eq!(parsed.symbols[3].name, "Section 2");
+        // Lines are 0-based
+        assert_eq!(parsed.symbols[0].location.line, 0);
+        assert_eq!(parsed.symbols[1].location.line, 3);
     }
 
     #[test]
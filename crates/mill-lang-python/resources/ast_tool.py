@@ -24,6 +24,21 @@ def list_functions(source_code):
             },
         }
 
+def check_syntax(source_code):
+    """
+    Parses Python source code and returns None when it is valid, or the
+    first syntax error otherwise.
+    """
+    try:
+        ast.parse(source_code)
+        return None
+    except SyntaxError as e:
+        return {
+            "message": e.msg,
+            "lineno": e.lineno,
+            "offset": e.offset,
+        }
+
 if __name__ == "__main__":
     if len(sys.argv) < 2:
         print(json.dumps({"status": "error", "error": {"type": "UsageError", "message": "No command provided."}}), file=sys.stderr)
@@ -39,6 +54,8 @@ if __name__ == "__main__":
         else:
             print(json.dumps(result["error"]), file=sys.stderr)
             sys.exit(1)
+    elif command == "check-syntax":
+        print(json.dumps(check_syntax(source)))
     else:
         print(json.dumps({"status": "error", "error": {"type": "UsageError", "message": f"Unknown command: {command}"}}), file=sys.stderr)
         sys.exit(1)
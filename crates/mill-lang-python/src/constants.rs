@@ -93,6 +93,11 @@ pub static CLASS_DEF_PATTERN: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"^class\s+(\w+)").expect("Python class definition pattern should be valid")
 });
 
+/// Pattern for identifiers (names, keywords, builtins)
+pub static IDENTIFIER_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[A-Za-z_][A-Za-z0-9_]*").expect("Python identifier pattern should be valid")
+});
+
 // === Helper Functions ===
 
 /// Generates a regex pattern for matching qualified path references to a module.
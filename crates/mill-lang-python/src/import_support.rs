@@ -95,6 +95,11 @@ impl ImportRenameSupport for PythonImportSupport {
         // Use primitive to replace all occurrences in import lines
         let (result, changes) = replace_in_lines(content, old_name, new_name);
 
+        // Renaming onto an already imported module leaves a duplicate import;
+        // other repeated imports in the file are not the rename's business
+        let (result, removed) = dedupe_import_statements(&result, Some(new_name));
+        let changes = changes + removed;
+
         debug!(
             changes_count = changes,
             "Completed import rewrite for rename"
@@ -258,6 +263,113 @@ impl ImportMutationSupport for PythonImportSupport {
 
         result
     }
+
+    fn dedupe_imports(&self, source: &str) -> (String, usize) {
+        dedupe_import_statements(source, None)
+    }
+}
+
+/// A module-level import statement, possibly spanning several lines
+struct ImportStatement {
+    /// First and last line index, inclusive
+    lines: (usize, usize),
+    /// Imported module, or the first module of an `import a, b`
+    module: String,
+    /// Statement without comments, parentheses and layout, for comparison
+    key: String,
+}
+
+/// Module-level `import`/`from` statements, following parenthesised name
+/// lists and backslash continuations
+///
+/// Indented imports are skipped: they may sit in try/except or functions
+/// where repeating an import is intentional.
+fn module_import_statements(lines: &[&str]) -> Vec<ImportStatement> {
+    let mut statements = Vec::new();
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        if !(line.starts_with("import ") || line.starts_with("from ")) {
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        let mut text = String::new();
+        let mut depth = 0i32;
+        loop {
+            let code = lines[index].split('#').next().unwrap_or("");
+            depth += code.matches('(').count() as i32 - code.matches(')').count() as i32;
+            let continued = code.trim_end().ends_with('\\');
+            text.push(' ');
+            text.push_str(code.trim_end().trim_end_matches('\\'));
+            if (depth <= 0 && !continued) || index + 1 == lines.len() {
+                break;
+            }
+            index += 1;
+        }
+
+        let flat = text.replace(['(', ')'], " ");
+        let words: Vec<&str> = flat.split_whitespace().collect();
+        let module = words.get(1).map_or("", |m| m.trim_end_matches(','));
+        let key = words
+            .join(" ")
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(", ");
+        statements.push(ImportStatement {
+            lines: (start, index),
+            module: module.to_string(),
+            key,
+        });
+        index += 1;
+    }
+    statements
+}
+
+/// Remove repeated module-level import statements, keeping the first
+///
+/// With `module`, only statements importing that module (or a submodule)
+/// are considered. Returns the content and the number of statements removed.
+fn dedupe_import_statements(source: &str, module: Option<&str>) -> (String, usize) {
+    let line_ending = if source.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let lines: Vec<&str> = source.lines().collect();
+
+    let mut seen = std::collections::HashSet::new();
+    let mut dropped = vec![false; lines.len()];
+    let mut removed = 0;
+    for statement in module_import_statements(&lines) {
+        let touched = module.is_none_or(|module| {
+            statement.module == module || statement.module.starts_with(&format!("{}.", module))
+        });
+        if touched && !seen.insert(statement.key) {
+            let (first, last) = statement.lines;
+            dropped[first..=last].iter_mut().for_each(|d| *d = true);
+            removed += 1;
+        }
+    }
+    if removed == 0 {
+        return (source.to_string(), 0);
+    }
+
+    let kept: Vec<&str> = lines
+        .iter()
+        .zip(&dropped)
+        .filter(|(_, dropped)| !**dropped)
+        .map(|(line, _)| *line)
+        .collect();
+    let mut result = kept.join(line_ending);
+    if source.ends_with('\n') && !result.is_empty() {
+        result.push_str(line_ending);
+    }
+    debug!(removed_count = removed, "Removed duplicate Python imports");
+    (result, removed)
 }
 
 /// Convert a file path to a Python module path
@@ -360,4 +472,45 @@ def main():
         let docstring_line = result.find("\"\"\"Module docstring.\"\"\"").unwrap();
         assert!(import_pos > docstring_line);
     }
+
+    #[test]
+    fn test_rename_onto_existing_import_dedupes() {
+        let support = PythonImportSupport;
+        let source = "import os  # filesystem\nimport helpers\nfrom utils import run\n\ndef main():\n    import os\n";
+
+        let (result, changes) = support.rewrite_imports_for_rename(source, "helpers", "os");
+
+        assert_eq!(
+            result,
+            "import os  # filesystem\nfrom utils import run\n\ndef main():\n    import os\n"
+        );
+        assert_eq!(changes, 2);
+        assert_eq!(support.dedupe_imports(&result), (result.clone(), 0));
+    }
+
+    #[test]
+    fn test_dedupe_parenthesised_imports() {
+        let support = PythonImportSupport;
+        let source = "from utils import (\n    run,\n    stop,  # halts\n)\nfrom utils import run, stop\nfrom other import (\n    run,\n)\n";
+
+        let (result, removed) = support.dedupe_imports(source);
+
+        assert_eq!(
+            result,
+            "from utils import (\n    run,\n    stop,  # halts\n)\nfrom other import (\n    run,\n)\n"
+        );
+        assert_eq!(removed, 1);
+    }
+
+    #[test]
+    fn test_rename_dedupes_only_renamed_module() {
+        let support = PythonImportSupport;
+        let source = "import json\nimport json\nimport helpers\nimport os\n";
+
+        let (result, changes) = support.rewrite_imports_for_rename(source, "helpers", "os");
+
+        // The pre-existing duplicate of `json` is left alone
+        assert_eq!(result, "import json\nimport json\nimport os\n");
+        assert_eq!(changes, 2);
+    }
 }
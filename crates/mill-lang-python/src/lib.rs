@@ -33,11 +33,18 @@ use mill_lang_common::{
     define_language_plugin, impl_capability_delegations, impl_language_plugin_basics,
 };
 use mill_plugin_api::{
-    FileDiscovery, LanguagePlugin, ManifestData, ParsedSource, PluginResult, StandardFileDiscovery,
+    FileDiscovery, LanguagePlugin, ManifestData, ParseDiagnostic, ParsedSource, PluginResult,
+    SourceLocation, StandardFileDiscovery,
 };
 use std::path::Path;
+use std::time::Instant;
 use tracing::{debug, warn};
 
+/// Version of the JSON shape this plugin stores in `ParsedSource::data`
+///
+/// Bump it whenever a `data` field is renamed, removed, or changes meaning.
+pub const AST_SCHEMA_VERSION: u32 = 1;
+
 #[derive(Default)]
 pub struct PythonFileDiscovery;
 
@@ -47,6 +54,7 @@ define_language_plugin! {
     name: "python",
     extensions: ["py"],
     manifest: "pyproject.toml",
+    manifest_patterns: ["requirements*.txt"],
     lsp_command: "pylsp",
     lsp_args: ["pylsp"],
     source_dir: ".",
@@ -72,6 +80,48 @@ impl FileDiscovery for PythonFileDiscovery {
     }
 }
 
+/// Mark `parsed` as degraded when Python's native parser rejects `source`
+///
+/// The single-pass parser is regex based and tolerates broken code, so the
+/// native parser decides whether its symbols can be fully trusted. Without
+/// python3 that is not decided and `degraded` stays null.
+async fn check_native_syntax(source: &str, parsed: &mut ParsedSource) {
+    let available = tokio::task::spawn_blocking(parser::native_parser_available)
+        .await
+        .unwrap_or(false);
+    if !available {
+        parsed.data["degraded"] = serde_json::Value::Null;
+        return;
+    }
+    let diagnostic = match parser::check_syntax(source).await {
+        Ok(None) => None,
+        Ok(Some(error)) => Some(ParseDiagnostic {
+            message: format!("SyntaxError: {}", error.message),
+            location: error.lineno.map(|lineno| SourceLocation {
+                line: (lineno as usize).saturating_sub(1),
+                column: 0,
+            }),
+        }),
+        Err(e) => {
+            warn!(error = %e, "Native Python parser failed, syntax is unchecked");
+            parsed.data["degraded"] = serde_json::Value::Null;
+            return;
+        }
+    };
+    parsed.data["degraded"] = serde_json::json!(diagnostic.is_some());
+    if let Some(diagnostic) = diagnostic {
+        warn!(
+            diagnostic = %diagnostic.message,
+            "Python source did not parse natively, returning regex-extracted symbols"
+        );
+        parsed.data["diagnostics"] = serde_json::json!([{
+            "message": diagnostic.message,
+            "line": diagnostic.location.map(|location| location.line + 1),
+        }]);
+        parsed.diagnostics.push(diagnostic);
+    }
+}
+
 #[async_trait]
 impl LanguagePlugin for PythonPlugin {
     impl_language_plugin_basics!();
@@ -79,11 +129,15 @@ impl LanguagePlugin for PythonPlugin {
     async fn parse(&self, source: &str) -> PluginResult<ParsedSource> {
         debug!("Parsing Python source code");
 
-        let source = source.to_string();
+        let owned_source = source.to_string();
 
-        tokio::task::spawn_blocking(move || {
-            // Use unified single-pass parser
+        let mut parsed = tokio::task::spawn_blocking(move || {
+            let source = owned_source;
+            // Use unified single-pass parser; it extracts symbols while
+            // parsing, so `parse_ms` covers both
+            let started = Instant::now();
             let result = parser::parse_source_code(&source)?;
+            let parse_ms = started.elapsed().as_secs_f64() * 1000.0;
 
             let symbols = result.symbols;
             let imports = result.imports;
@@ -91,11 +145,13 @@ impl LanguagePlugin for PythonPlugin {
             let variables = result.variables;
 
             let ast_json = serde_json::json!({
+                "schema_version": AST_SCHEMA_VERSION,
                 "type": "Module",
                 "functions_count": functions.len(),
                 "variables_count": variables.len(),
                 "imports_count": imports.len(),
                 "imports": imports,
+                "timing": { "parse_ms": parse_ms },
             });
 
             debug!(
@@ -105,12 +161,29 @@ impl LanguagePlugin for PythonPlugin {
                 "Parsed Python source"
             );
 
-            Ok(ParsedSource {
+            Ok::<_, mill_plugin_api::PluginApiError>(ParsedSource {
                 data: ast_json,
                 symbols,
+                diagnostics: Vec::new(),
             })
         })
         .await
+        .map_err(|e| {
+            mill_plugin_api::PluginApiError::internal(format!("Task join error: {}", e))
+        })??;
+
+        check_native_syntax(source, &mut parsed).await;
+        Ok(parsed)
+    }
+
+    async fn parse_symbols_only(&self, source: &str) -> PluginResult<Vec<mill_plugin_api::Symbol>> {
+        // Symbols come from the single-pass parser alone, so listing them
+        // never runs the python3 syntax check
+        let owned_source = source.to_string();
+        tokio::task::spawn_blocking(move || {
+            parser::parse_source_code(&owned_source).map(|result| result.symbols)
+        })
+        .await
         .map_err(|e| mill_plugin_api::PluginApiError::internal(format!("Task join error: {}", e)))?
     }
 
@@ -126,6 +199,9 @@ impl LanguagePlugin for PythonPlugin {
             "pyproject.toml" => manifest::parse_pyproject_toml(path).await,
             "setup.py" => manifest::parse_setup_py(path).await,
             "Pipfile" => manifest::parse_pipfile(path).await,
+            _ if Self::METADATA.matches_manifest_pattern(filename) => {
+                manifest::parse_requirements_txt(path).await
+            }
             _ => Err(mill_plugin_api::PluginApiError::not_supported(format!(
                 "Unsupported Python manifest file: {}",
                 filename
@@ -434,8 +510,24 @@ impl mill_plugin_api::ModuleReferenceScanner for PythonPlugin {
         module_name: &str,
         scope: mill_plugin_api::ScanScope,
     ) -> mill_plugin_api::PluginResult<Vec<mill_plugin_api::ModuleReference>> {
+        use mill_plugin_api::identifier_scanner::{find_comment_occurrences, LexicalSyntax};
         use mill_plugin_api::{ModuleReference, ReferenceKind, ScanScope};
 
+        if scope == ScanScope::Comments {
+            return Ok(
+                find_comment_occurrences(content, module_name, LexicalSyntax::PYTHON)
+                    .into_iter()
+                    .map(|occurrence| ModuleReference {
+                        line: occurrence.line,
+                        column: occurrence.column,
+                        length: module_name.chars().count(),
+                        text: module_name.to_string(),
+                        kind: ReferenceKind::Comment,
+                    })
+                    .collect(),
+            );
+        }
+
         let mut references = Vec::new();
 
         for (line_idx, line) in content.lines().enumerate() {
@@ -512,6 +604,28 @@ impl mill_plugin_api::ModuleReferenceScanner for PythonPlugin {
     }
 }
 
+// ============================================================================
+// Code Analysis
+// ============================================================================
+
+impl mill_lang_common::AnalysisMetadata for PythonPlugin {
+    fn complexity_keywords(&self) -> &[&str] {
+        &["if", "elif", "for", "while", "except", "case"]
+    }
+
+    fn nesting_penalty(&self) -> u32 {
+        1
+    }
+
+    fn lexical_syntax(&self) -> mill_plugin_api::identifier_scanner::LexicalSyntax {
+        mill_plugin_api::identifier_scanner::LexicalSyntax::PYTHON
+    }
+
+    fn indentation_blocks(&self) -> bool {
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -531,9 +645,45 @@ mod tests {
         let plugin = PythonPlugin::new();
 
         assert!(plugin.handles_manifest("pyproject.toml"));
+        assert!(plugin.handles_manifest("requirements.txt"));
+        assert!(plugin.handles_manifest("requirements-dev.txt"));
         assert!(!plugin.handles_manifest("Cargo.toml"));
     }
 
+    #[test]
+    fn test_task_comments_skip_strings_and_docstrings() {
+        let plugin = PythonPlugin::default();
+        let source =
+            "def run():\n    \"\"\"TODO: not a comment\"\"\"\n    s = \"TODO\"  # FIXME: quoting\n";
+
+        let tasks = mill_lang_common::find_task_comments(source, &plugin);
+
+        let found: Vec<_> = tasks
+            .iter()
+            .map(|task| (task.line, task.tag.as_str(), task.message.as_str()))
+            .collect();
+        assert_eq!(found, vec![(3, "FIXME", "quoting")]);
+    }
+
+    #[test]
+    fn test_supported_refactorings() {
+        use mill_plugin_api::RefactoringKind;
+
+        let plugin = PythonPlugin::new();
+        let provider = plugin.refactoring_provider().unwrap();
+
+        assert_eq!(
+            provider.supported_refactorings(),
+            vec![
+                RefactoringKind::ExtractFunction,
+                RefactoringKind::ExtractVariable,
+                RefactoringKind::ExtractConstant,
+                RefactoringKind::InlineVariable,
+                RefactoringKind::Rename,
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_python_plugin_parse() {
         let plugin = PythonPlugin::new();
@@ -556,6 +706,7 @@ class MyClass:
 
         let parsed = result.unwrap();
         assert!(!parsed.symbols.is_empty());
+        assert_eq!(parsed.schema_version(), Some(AST_SCHEMA_VERSION));
 
         // Should have function, class, and constant symbols
         let has_function = parsed
@@ -576,6 +727,59 @@ class MyClass:
         assert!(has_constant, "Should parse constant");
     }
 
+    #[tokio::test]
+    async fn test_python_plugin_parse_reports_timing() {
+        let plugin = PythonPlugin::new();
+
+        let parsed = plugin.parse("def hello():\n    pass\n").await.unwrap();
+
+        assert!(parsed.data["timing"]["parse_ms"].as_f64().unwrap() >= 0.0);
+        // Symbols come from the same pass, so they are not timed separately
+        assert!(parsed.data["timing"]["symbol_extraction_ms"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_python_plugin_parse_symbols_only_matches_parse() {
+        let plugin = PythonPlugin::new();
+        let source = "class Runner:\n    def run(self):\n        pass\n\ndef broken(:\n";
+
+        let listed = plugin.parse_symbols_only(source).await.unwrap();
+        let parsed = plugin.parse(source).await.unwrap();
+
+        assert_eq!(listed, parsed.symbols);
+        assert!(listed.iter().any(|s| s.name == "Runner"));
+    }
+
+    #[tokio::test]
+    async fn test_python_plugin_parse_degrades_on_syntax_error() {
+        let plugin = PythonPlugin::new();
+
+        let valid = plugin.parse("def ok():\n    pass\n").await.unwrap();
+        let broken = plugin
+            .parse("def ok():\n    return 1\n\ndef broken(:\n    return (\n")
+            .await
+            .unwrap();
+
+        assert!(broken.symbols.iter().any(|s| s.name == "ok"));
+        assert!(valid.diagnostics.is_empty());
+        if !parser::native_parser_available() {
+            // Without python3 the syntax is unchecked, not degraded
+            assert!(valid.data["degraded"].is_null());
+            assert!(broken.data["degraded"].is_null());
+            assert!(broken.diagnostics.is_empty());
+            return;
+        }
+        assert_eq!(valid.data["degraded"], false);
+        assert_eq!(broken.data["degraded"], true);
+        assert_eq!(broken.diagnostics.len(), 1);
+        assert!(broken.data["diagnostics"][0]["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("SyntaxError"));
+        assert_eq!(broken.data["diagnostics"][0]["line"], 4);
+        assert_eq!(broken.diagnostics[0].location.unwrap().line, 3);
+    }
+
     #[tokio::test]
     async fn test_python_plugin_list_functions() {
         let plugin = PythonPlugin::new();
@@ -694,6 +898,27 @@ class MyClass:
         );
     }
 
+    #[test]
+    fn test_scan_references_comments_scope() {
+        use mill_plugin_api::ReferenceKind;
+
+        let plugin = PythonPlugin::new();
+        let scanner = plugin
+            .module_reference_scanner()
+            .expect("Should have scanner");
+        let content = "# Thin wrapper over helpers
+import helpers
+from helpers import run
+";
+
+        let comments = scanner
+            .scan_references(content, "helpers", ScanScope::Comments)
+            .expect("Should scan");
+        let spans: Vec<_> = comments.iter().map(|r| (r.line, r.column)).collect();
+        assert_eq!(spans, vec![(1, 20)], "import statements are not comments");
+        assert_eq!(comments[0].kind, ReferenceKind::Comment);
+    }
+
     // ========================================================================
     // INTEGRATION TESTS (3 tests)
     // ========================================================================
@@ -1,7 +1,9 @@
 //! Python LSP installer implementation
 
 use async_trait::async_trait;
-use mill_lang_common::lsp::{check_binary_in_path, install_pip_package};
+use mill_lang_common::lsp::{
+    binary_version, check_binary_in_path, install_pip_package, SystemCommandRunner,
+};
 use mill_plugin_api::{LspInstaller, PluginApiError, PluginResult};
 use std::path::{Path, PathBuf};
 use tracing::debug;
@@ -27,6 +29,28 @@ impl LspInstaller for PythonLspInstaller {
         Ok(check_binary_in_path("pylsp"))
     }
 
+    fn installed_version(&self) -> PluginResult<Option<String>> {
+        match self.check_installed()? {
+            Some(binary) => binary_version(&SystemCommandRunner, &binary).map_err(|e| {
+                PluginApiError::internal(format!("Failed to query pylsp version: {}", e))
+            }),
+            None => Ok(None),
+        }
+    }
+
+    fn install_hint(&self) -> Option<String> {
+        Some("Run `pip install python-lsp-server` or `mill install-lsp python`".to_string())
+    }
+
+    async fn install_version(&self, version: &str) -> PluginResult<()> {
+        debug!("Installing pylsp {} via pip", version);
+
+        install_pip_package(&format!("python-lsp-server=={}", version), "pylsp")
+            .await
+            .map(|_| ())
+            .map_err(|e| PluginApiError::internal(format!("pip install failed: {}", e)))
+    }
+
     async fn install_lsp(&self, _cache_dir: &Path) -> PluginResult<PathBuf> {
         debug!("Installing pylsp via pip/pipx");
 
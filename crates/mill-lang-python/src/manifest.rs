@@ -4,14 +4,17 @@
 //! - requirements.txt (pip)
 //! - pyproject.toml (Poetry, PDM, setuptools)
 //! - setup.py (legacy setuptools)
-//! - Pipfile (Pipenv)
+//! - Pipfile (Pipenv), with exact versions from Pipfile.lock
 use mill_lang_common::read_manifest;
-use mill_plugin_api::{Dependency, DependencySource, ManifestData, PluginApiError, PluginResult};
+use mill_plugin_api::{
+    normalize_dependency_name, Dependency, DependencySource, ManifestData, PluginApiError,
+    PluginResult,
+};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 use tracing::{debug, warn};
 /// Parse requirements.txt file
@@ -27,10 +30,7 @@ pub async fn parse_requirements_txt(path: &Path) -> PluginResult<ManifestData> {
             continue;
         }
         if let Some((name, version)) = parse_requirement_line(line) {
-            dependencies.push(Dependency {
-                name,
-                source: DependencySource::Version(version),
-            });
+            dependencies.push(Dependency::new(name, DependencySource::Version(version)));
         }
     }
     debug!(
@@ -78,73 +78,99 @@ fn parse_requirement_line(line: &str) -> Option<(String, String)> {
 }
 /// Parse pyproject.toml file
 ///
-/// Supports both Poetry and PDM formats
+/// Understands both the standard PEP 621 `[project]` table and Poetry's
+/// `[tool.poetry]` layout, and merges them when both are present. Runtime
+/// dependencies come from `project.dependencies` and
+/// `tool.poetry.dependencies`; `project.optional-dependencies`, Poetry groups
+/// and Poetry's legacy `dev-dependencies` become dev dependencies. Version
+/// constraints are kept with their operators (`^1.2`, `~=1.2`, `>=1,<2`) so
+/// both layouts yield the same `ManifestData`. The layout that was found is
+/// reported as `raw_data.style` (`pep621`, `poetry`, `mixed`, or `unknown`).
+///
+/// Dependency groups declared outside those tables — PEP 735
+/// `[dependency-groups]`, `[tool.pdm.dev-dependencies]` and
+/// `[tool.uv] dev-dependencies` — are also collected as dev dependencies, and
+/// the sources found are listed in `raw_data.dependency_groups` (`pep735`,
+/// `pdm`, `uv`).
 pub async fn parse_pyproject_toml(path: &Path) -> PluginResult<ManifestData> {
     let content = read_manifest(path).await?;
     let toml: PyProjectToml = toml::from_str(&content)
         .map_err(|e| PluginApiError::parse(format!("Failed to parse pyproject.toml: {}", e)))?;
+    let poetry = toml.tool.as_ref().and_then(|t| t.poetry.as_ref());
+    let pdm = toml.tool.as_ref().and_then(|t| t.pdm.as_ref());
+    let uv = toml.tool.as_ref().and_then(|t| t.uv.as_ref());
     let name = toml
         .project
         .as_ref()
         .and_then(|p| p.name.clone())
-        .or_else(|| {
-            toml.tool
-                .as_ref()
-                .and_then(|t| t.poetry.as_ref().and_then(|p| p.name.clone()))
-        })
+        .or_else(|| poetry.and_then(|p| p.name.clone()))
         .unwrap_or_else(|| "python-project".to_string());
     let version = toml
         .project
         .as_ref()
         .and_then(|p| p.version.clone())
-        .or_else(|| {
-            toml.tool
-                .as_ref()
-                .and_then(|t| t.poetry.as_ref().and_then(|p| p.version.clone()))
-        })
+        .or_else(|| poetry.and_then(|p| p.version.clone()))
         .unwrap_or_else(|| "0.1.0".to_string());
-    let mut dependencies = Vec::new();
-    let mut dev_dependencies = Vec::new();
-    if let Some(tool) = &toml.tool {
-        if let Some(poetry) = &tool.poetry {
-            if let Some(deps) = &poetry.dependencies {
-                for (name, spec) in deps {
-                    if name != "python" {
-                        let version = dependency_spec_to_version(spec);
-                        dependencies.push(Dependency {
-                            name: name.clone(),
-                            source: DependencySource::Version(version),
-                        });
-                    }
-                }
+
+    let mut dependencies = DependencySet::default();
+    let mut dev_dependencies = DependencySet::default();
+    if let Some(project) = &toml.project {
+        for requirement in project.dependencies.iter().flatten() {
+            dependencies.extend(parse_pep508_requirement(requirement));
+        }
+        for requirement in project.optional_dependencies.values().flatten() {
+            dev_dependencies.extend(parse_pep508_requirement(requirement));
+        }
+    }
+    if let Some(poetry) = poetry {
+        for (name, spec) in poetry.dependencies.iter().flatten() {
+            if name != "python" {
+                dependencies.insert(poetry_dependency(name, spec));
             }
-            if let Some(dev_deps) = &poetry.dev_dependencies {
-                for (name, spec) in dev_deps {
-                    let version = dependency_spec_to_version(spec);
-                    dev_dependencies.push(Dependency {
-                        name: name.clone(),
-                        source: DependencySource::Version(version),
-                    });
-                }
+        }
+        let groups = poetry
+            .group
+            .values()
+            .filter_map(|group| group.dependencies.as_ref());
+        for deps in poetry.dev_dependencies.iter().chain(groups) {
+            for (name, spec) in deps {
+                dev_dependencies.insert(poetry_dependency(name, spec));
             }
         }
     }
-    if dependencies.is_empty() {
-        if let Some(project) = &toml.project {
-            if let Some(deps) = &project.dependencies {
-                for dep in deps {
-                    if let Some((name, version)) = parse_requirement_line(dep) {
-                        dependencies.push(Dependency {
-                            name,
-                            source: DependencySource::Version(version),
-                        });
-                    }
-                }
-            }
+    let mut dependency_groups = Vec::new();
+    if !toml.dependency_groups.is_empty() {
+        dependency_groups.push("pep735");
+        // `{ include-group = "..." }` entries point at groups collected anyway
+        let requirements = toml.dependency_groups.values().flatten();
+        for requirement in requirements.filter_map(toml::Value::as_str) {
+            dev_dependencies.extend(parse_pep508_requirement(requirement));
+        }
+    }
+    if let Some(pdm) = pdm.filter(|pdm| !pdm.dev_dependencies.is_empty()) {
+        dependency_groups.push("pdm");
+        for requirement in pdm.dev_dependencies.values().flatten() {
+            dev_dependencies.extend(parse_pep508_requirement(requirement));
         }
     }
+    if let Some(uv) = uv.filter(|uv| !uv.dev_dependencies.is_empty()) {
+        dependency_groups.push("uv");
+        for requirement in &uv.dev_dependencies {
+            dev_dependencies.extend(parse_pep508_requirement(requirement));
+        }
+    }
+
+    let style = match (toml.project.is_some(), poetry.is_some()) {
+        (true, true) => "mixed",
+        (true, false) => "pep621",
+        (false, true) => "poetry",
+        (false, false) => "unknown",
+    };
+    let dependencies = dependencies.into_vec();
+    let dev_dependencies = dev_dependencies.into_vec();
     debug!(
-        name = % name, version = % version, dependencies_count = dependencies.len(),
+        name = % name, version = % version, style = style,
+        dependencies_count = dependencies.len(),
         dev_dependencies_count = dev_dependencies.len(), "Parsed pyproject.toml"
     );
     Ok(ManifestData {
@@ -152,108 +178,344 @@ pub async fn parse_pyproject_toml(path: &Path) -> PluginResult<ManifestData> {
         version,
         dependencies,
         dev_dependencies,
-        raw_data: json!({ "format" : "pyproject.toml" }),
+        raw_data: json!({
+            "format" : "pyproject.toml",
+            "style" : style,
+            "dependency_groups" : dependency_groups,
+        }),
     })
 }
-/// Convert Poetry/PDM dependency spec to version string
-fn dependency_spec_to_version(spec: &DependencySpec) -> String {
+/// Dependencies keyed by normalized name; the first declaration wins
+#[derive(Default)]
+struct DependencySet(BTreeMap<String, Dependency>);
+impl DependencySet {
+    fn insert(&mut self, dependency: Dependency) {
+        self.0
+            .entry(normalize_dependency_name(&dependency.name))
+            .or_insert(dependency);
+    }
+    fn extend(&mut self, dependency: Option<Dependency>) {
+        if let Some(dependency) = dependency {
+            self.insert(dependency);
+        }
+    }
+    fn into_vec(self) -> Vec<Dependency> {
+        self.0.into_values().collect()
+    }
+}
+/// Parse a PEP 508 requirement such as `requests[socks]>=2,<3; python_version > "3.8"`
+///
+/// Direct references (`name @ git+https://...@rev`, `name @ file:///path`)
+/// become git and path sources; anything else keeps its version constraint
+/// verbatim (minus whitespace), with `*` for an unconstrained requirement.
+fn parse_pep508_requirement(requirement: &str) -> Option<Dependency> {
+    let requirement = requirement.split(';').next()?.trim();
+    if requirement.is_empty() {
+        return None;
+    }
+    let (head, reference) = match requirement.split_once('@') {
+        Some((head, reference)) => (head.trim(), Some(reference.trim())),
+        None => (requirement, None),
+    };
+    let name_end = head
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(head.len());
+    let name = &head[..name_end];
+    if name.is_empty() {
+        return None;
+    }
+    let mut rest = head[name_end..].trim();
+    let mut features = Vec::new();
+    if let Some(extras) = rest.strip_prefix('[') {
+        let (extras, after) = extras.split_once(']')?;
+        features = extras
+            .split(',')
+            .map(|e| e.trim().to_string())
+            .filter(|e| !e.is_empty())
+            .collect();
+        rest = after.trim();
+    }
+
+    let source = match reference {
+        Some(reference) => direct_reference_source(reference),
+        None => {
+            let constraint: String = rest
+                .trim_start_matches('(')
+                .trim_end_matches(')')
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect();
+            DependencySource::Version(if constraint.is_empty() {
+                "*".to_string()
+            } else {
+                constraint
+            })
+        }
+    };
+    let mut dependency = Dependency::new(name, source);
+    dependency.features = features;
+    Some(dependency)
+}
+/// Classify a PEP 508 direct reference URL
+fn direct_reference_source(reference: &str) -> DependencySource {
+    if let Some(url) = reference.strip_prefix("git+") {
+        let url = url.split('#').next().unwrap_or(url);
+        let path_start = url.rfind('/').unwrap_or(0);
+        return match url.rfind('@').filter(|&at| at > path_start) {
+            Some(at) => DependencySource::Git {
+                url: url[..at].to_string(),
+                rev: Some(url[at + 1..].to_string()),
+            },
+            None => DependencySource::Git {
+                url: url.to_string(),
+                rev: None,
+            },
+        };
+    }
+    if let Some(path) = reference.strip_prefix("file://") {
+        return DependencySource::Path(path.to_string());
+    }
+    DependencySource::Version(reference.to_string())
+}
+/// Convert a Poetry dependency entry into a `Dependency`
+fn poetry_dependency(name: &str, spec: &DependencySpec) -> Dependency {
     match spec {
-        DependencySpec::Simple(version) => version.clone(),
+        DependencySpec::Simple(version) => Dependency::new(
+            name,
+            DependencySource::Version(version.split_whitespace().collect()),
+        ),
         DependencySpec::Detailed(details) => {
-            details.version.clone().unwrap_or_else(|| "*".to_string())
+            let source = if let Some(path) = &details.path {
+                DependencySource::Path(path.clone())
+            } else if let Some(url) = &details.git {
+                DependencySource::Git {
+                    url: url.clone(),
+                    rev: details
+                        .rev
+                        .clone()
+                        .or_else(|| details.tag.clone())
+                        .or_else(|| details.branch.clone()),
+                }
+            } else {
+                DependencySource::Version(
+                    details
+                        .version
+                        .as_deref()
+                        .map(|v| v.split_whitespace().collect())
+                        .unwrap_or_else(|| "*".to_string()),
+                )
+            };
+            let mut dependency = Dependency::new(name, source);
+            dependency.features = details.extras.clone();
+            dependency.optional = details.optional;
+            dependency
         }
     }
 }
-// Static regexes for setup.py parsing
-static SETUP_NAME_PATTERN: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r#"name\s*=\s*["']([^"']+)["']"#).expect("Setup name regex should be valid")
-});
-
-static SETUP_VERSION_PATTERN: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r#"version\s*=\s*["']([^"']+)["']"#).expect("Setup version regex should be valid")
-});
-
-static SETUP_INSTALL_REQUIRES_PATTERN: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r#"install_requires\s*=\s*\[(.*?)\]"#)
-        .expect("Setup install_requires regex should be valid")
-});
-
-static SETUP_EXTRAS_REQUIRE_PATTERN: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r#"extras_require\s*=\s*\[(.*?)\]"#)
-        .expect("Setup extras_require regex should be valid")
+// Static regex for locating the setup() call in setup.py
+static SETUP_CALL_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|[^\w.])(?:setuptools\.)?setup\s*\(")
+        .expect("Setup call regex should be valid")
 });
 
 /// Parse setup.py file
 ///
-/// Extracts basic metadata and dependencies from setup.py
-/// Note: This is a best-effort parser for common setup.py patterns
+/// Best-effort extraction without evaluating Python: the keyword arguments of
+/// the `setup(...)` call are read when they are literals. `name` and
+/// `version` must be string literals, `install_requires` a list of string
+/// literals, and `extras_require` a dict of such lists (its entries become dev
+/// dependencies). Anything computed at runtime is skipped and reported by
+/// setting `raw_data.partial` to true.
 pub async fn parse_setup_py(path: &Path) -> PluginResult<ManifestData> {
     let content = read_manifest(path).await?;
     let mut name = "python-project".to_string();
     let mut version = "0.1.0".to_string();
     let mut dependencies = Vec::new();
     let mut dev_dependencies = Vec::new();
-    if let Some(name_match) = SETUP_NAME_PATTERN.captures(&content) {
-        if let Some(n) = name_match.get(1) {
-            name = n.as_str().to_string();
-        }
-    }
-    if let Some(version_match) = SETUP_VERSION_PATTERN.captures(&content) {
-        if let Some(v) = version_match.get(1) {
-            version = v.as_str().to_string();
-        }
-    }
-    if let Some(install_requires) =
-        extract_list_from_setup(&content, &SETUP_INSTALL_REQUIRES_PATTERN)
-    {
-        for dep in install_requires {
-            if let Some((dep_name, dep_version)) = parse_requirement_line(&dep) {
-                dependencies.push(Dependency {
-                    name: dep_name,
-                    source: DependencySource::Version(dep_version),
-                });
-            }
-        }
-    }
-    if let Some(extras_require) = extract_list_from_setup(&content, &SETUP_EXTRAS_REQUIRE_PATTERN) {
-        for dep in extras_require {
-            if let Some((dep_name, dep_version)) = parse_requirement_line(&dep) {
-                dev_dependencies.push(Dependency {
-                    name: dep_name,
-                    source: DependencySource::Version(dep_version),
-                });
+    let mut partial = false;
+
+    match setup_call_arguments(&content) {
+        Some(arguments) => {
+            for argument in split_top_level(arguments, ',') {
+                let Some((key, value)) = argument.split_once('=') else {
+                    continue;
+                };
+                let value = value.trim();
+                match key.trim() {
+                    "name" => match string_literal(value) {
+                        Some(literal) => name = literal,
+                        None => partial = true,
+                    },
+                    "version" => match string_literal(value) {
+                        Some(literal) => version = literal,
+                        None => partial = true,
+                    },
+                    "install_requires" => match string_list_literal(value) {
+                        Some(requirements) => {
+                            dependencies.extend(requirements_to_dependencies(&requirements))
+                        }
+                        None => partial = true,
+                    },
+                    "extras_require" => match extras_literal(value) {
+                        Some(requirements) => {
+                            dev_dependencies.extend(requirements_to_dependencies(&requirements))
+                        }
+                        None => partial = true,
+                    },
+                    _ => {}
+                }
             }
         }
+        None => partial = true,
     }
+
     debug!(
         name = % name, version = % version, dependencies_count = dependencies.len(),
-        dev_dependencies_count = dev_dependencies.len(), "Parsed setup.py"
+        dev_dependencies_count = dev_dependencies.len(), partial = partial, "Parsed setup.py"
     );
     Ok(ManifestData {
         name,
         version,
         dependencies,
         dev_dependencies,
-        raw_data: json!({ "format" : "setup.py" }),
+        raw_data: json!({ "format" : "setup.py", "partial" : partial }),
     })
 }
-/// Extract list values from setup() call
-fn extract_list_from_setup(content: &str, re: &Regex) -> Option<Vec<String>> {
-    let captures = re.captures(content)?;
-    let list_content = captures.get(1)?.as_str();
-    let mut items = Vec::new();
-    for item in list_content.split(',') {
-        let item = item.trim();
-        let item = item.trim_matches(|c| c == '"' || c == '\'');
-        if !item.is_empty() {
-            items.push(item.to_string());
+/// Convert PEP 508 requirement strings into dependencies, keeping the full
+/// version specifier
+fn requirements_to_dependencies(requirements: &[String]) -> Vec<Dependency> {
+    requirements
+        .iter()
+        .filter_map(|requirement| parse_pep508_requirement(requirement))
+        .collect()
+}
+/// Text between the parentheses of the first `setup(...)` call
+fn setup_call_arguments(content: &str) -> Option<&str> {
+    let open = SETUP_CALL_PATTERN.find(content)?.end();
+    let mut depth = 1;
+    let mut quote: Option<char> = None;
+    let mut chars = content[open..].char_indices();
+    while let Some((i, c)) = chars.next() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) if c == '\\' => {
+                chars.next();
+            }
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(&content[open..open + i]);
+                    }
+                }
+                '#' => {
+                    // Skip a comment up to the end of the line
+                    for (_, c) in chars.by_ref() {
+                        if c == '\n' {
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+    None
+}
+/// Split `text` on `separator` where it is outside brackets, strings and comments
+fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+    let mut chars = text.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) if c == '\\' => {
+                chars.next();
+            }
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' => quote = Some(c),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth = depth.saturating_sub(1),
+                '#' => {
+                    for (_, c) in chars.by_ref() {
+                        if c == '\n' {
+                            break;
+                        }
+                    }
+                }
+                c if c == separator && depth == 0 => {
+                    parts.push(strip_comments(&text[start..i]));
+                    start = i + c.len_utf8();
+                }
+                _ => {}
+            },
         }
     }
-    Some(items)
+    parts.push(strip_comments(&text[start..]));
+    parts.into_iter().filter(|part| !part.is_empty()).collect()
+}
+/// Trim whitespace and whole-line comments around an expression
+fn strip_comments(text: &str) -> &str {
+    let mut text = text.trim();
+    while text.starts_with('#') {
+        text = text
+            .split_once('\n')
+            .map(|(_, rest)| rest.trim())
+            .unwrap_or("");
+    }
+    match text.rfind('\n') {
+        Some(pos) if text[pos..].trim_start().starts_with('#') => text[..pos].trim(),
+        _ => text,
+    }
+}
+/// Value of a single plain string literal (`"x"` or `'x'`)
+fn string_literal(expr: &str) -> Option<String> {
+    let expr = expr.trim();
+    let quote = expr.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let inner = expr.strip_prefix(quote)?.strip_suffix(quote)?;
+    if inner.contains(quote) || inner.contains('\\') {
+        return None;
+    }
+    Some(inner.to_string())
+}
+/// Values of a list (or tuple) containing only string literals
+fn string_list_literal(expr: &str) -> Option<Vec<String>> {
+    let expr = expr.trim();
+    let inner = expr
+        .strip_prefix('[')
+        .and_then(|e| e.strip_suffix(']'))
+        .or_else(|| expr.strip_prefix('(').and_then(|e| e.strip_suffix(')')))?;
+    split_top_level(inner, ',')
+        .into_iter()
+        .map(string_literal)
+        .collect()
+}
+/// All requirements of an `extras_require` dict of string-literal lists
+fn extras_literal(expr: &str) -> Option<Vec<String>> {
+    let inner = expr.trim().strip_prefix('{')?.strip_suffix('}')?;
+    let mut requirements = Vec::new();
+    for entry in split_top_level(inner, ',') {
+        let [key, value] = split_top_level(entry, ':')[..] else {
+            return None;
+        };
+        string_literal(key)?;
+        requirements.extend(string_list_literal(value)?);
+    }
+    Some(requirements)
 }
 /// Parse Pipfile
 ///
-/// Pipfile uses TOML-like format for dependency management
+/// Pipfile uses TOML-like format for dependency management. When a sibling
+/// `Pipfile.lock` exists, the exact versions it pins win over the Pipfile
+/// constraints: its `default` section applies to packages and `develop` to
+/// dev packages. Packages missing from the lock keep their constraint.
 pub async fn parse_pipfile(path: &Path) -> PluginResult<ManifestData> {
     let content = read_manifest(path).await?;
     let pipfile: PipfileFormat = toml::from_str(&content)
@@ -265,38 +527,69 @@ pub async fn parse_pipfile(path: &Path) -> PluginResult<ManifestData> {
         .unwrap_or("python-project")
         .to_string();
     let version = "0.1.0".to_string();
+    let lock = read_pipfile_lock(path).await;
     let mut dependencies = Vec::new();
     let mut dev_dependencies = Vec::new();
     if let Some(packages) = pipfile.packages {
         for (name, spec) in packages {
-            let version = pipfile_spec_to_version(&spec);
-            dependencies.push(Dependency {
-                name,
-                source: DependencySource::Version(version),
-            });
+            let version = lock
+                .as_ref()
+                .and_then(|lock| locked_version(&lock.default, &name))
+                .unwrap_or_else(|| pipfile_spec_to_version(&spec));
+            dependencies.push(Dependency::new(name, DependencySource::Version(version)));
         }
     }
     if let Some(dev_packages) = pipfile.dev_packages {
         for (name, spec) in dev_packages {
-            let version = pipfile_spec_to_version(&spec);
-            dev_dependencies.push(Dependency {
-                name,
-                source: DependencySource::Version(version),
-            });
+            let version = lock
+                .as_ref()
+                .and_then(|lock| locked_version(&lock.develop, &name))
+                .unwrap_or_else(|| pipfile_spec_to_version(&spec));
+            dev_dependencies.push(Dependency::new(name, DependencySource::Version(version)));
         }
     }
     debug!(
         name = % name, version = % version, dependencies_count = dependencies.len(),
-        dev_dependencies_count = dev_dependencies.len(), "Parsed Pipfile"
+        dev_dependencies_count = dev_dependencies.len(), locked = lock.is_some(),
+        "Parsed Pipfile"
     );
     Ok(ManifestData {
         name,
         version,
         dependencies,
         dev_dependencies,
-        raw_data: json!({ "format" : "Pipfile" }),
+        raw_data: json!({ "format" : "Pipfile", "locked" : lock.is_some() }),
     })
 }
+/// Read the `Pipfile.lock` next to a Pipfile, if there is a readable one
+async fn read_pipfile_lock(pipfile_path: &Path) -> Option<PipfileLock> {
+    let lock_path = pipfile_path.with_file_name("Pipfile.lock");
+    let content = tokio::fs::read_to_string(&lock_path).await.ok()?;
+    match serde_json::from_str(&content) {
+        Ok(lock) => Some(lock),
+        Err(e) => {
+            warn!(
+                path = ? lock_path, error = % e,
+                "Ignoring unparseable Pipfile.lock, using Pipfile constraints"
+            );
+            None
+        }
+    }
+}
+/// Exact version pinned for `name` in a `Pipfile.lock` section
+///
+/// Lock keys are normalized package names and versions look like `==2.28.0`;
+/// the `==` is dropped to match how exact requirements are reported.
+fn locked_version(section: &HashMap<String, PipfileLockEntry>, name: &str) -> Option<String> {
+    let normalized = normalize_dependency_name(name);
+    let version = section
+        .iter()
+        .find(|(locked, _)| normalize_dependency_name(locked) == normalized)?
+        .1
+        .version
+        .as_deref()?;
+    Some(version.strip_prefix("==").unwrap_or(version).to_string())
+}
 /// Convert Pipfile dependency spec to version string
 fn pipfile_spec_to_version(spec: &PipfileSpec) -> String {
     match spec {
@@ -498,6 +791,8 @@ struct PyProjectToml {
     project: Option<ProjectMetadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tool: Option<ToolConfig>,
+    #[serde(default, rename = "dependency-groups")]
+    dependency_groups: BTreeMap<String, Vec<toml::Value>>,
 }
 #[derive(Debug, Deserialize, Serialize)]
 struct ProjectMetadata {
@@ -507,11 +802,27 @@ struct ProjectMetadata {
     version: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     dependencies: Option<Vec<String>>,
+    #[serde(default, rename = "optional-dependencies")]
+    optional_dependencies: BTreeMap<String, Vec<String>>,
 }
 #[derive(Debug, Deserialize, Serialize)]
 struct ToolConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     poetry: Option<PoetryConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pdm: Option<PdmConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uv: Option<UvConfig>,
+}
+#[derive(Debug, Deserialize, Serialize)]
+struct PdmConfig {
+    #[serde(default, rename = "dev-dependencies")]
+    dev_dependencies: BTreeMap<String, Vec<String>>,
+}
+#[derive(Debug, Deserialize, Serialize)]
+struct UvConfig {
+    #[serde(default, rename = "dev-dependencies")]
+    dev_dependencies: Vec<String>,
 }
 #[derive(Debug, Deserialize, Serialize)]
 struct PoetryConfig {
@@ -523,6 +834,13 @@ struct PoetryConfig {
     dependencies: Option<HashMap<String, DependencySpec>>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "dev-dependencies")]
     dev_dependencies: Option<HashMap<String, DependencySpec>>,
+    #[serde(default)]
+    group: BTreeMap<String, PoetryGroup>,
+}
+#[derive(Debug, Deserialize, Serialize)]
+struct PoetryGroup {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dependencies: Option<HashMap<String, DependencySpec>>,
 }
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(untagged)]
@@ -538,6 +856,16 @@ struct DetailedDependency {
     path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     git: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rev: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    #[serde(default)]
+    extras: Vec<String>,
+    #[serde(default)]
+    optional: bool,
 }
 #[derive(Debug, Deserialize, Serialize)]
 struct PipfileFormat {
@@ -561,6 +889,19 @@ struct PipfileDetailedDependency {
     #[serde(skip_serializing_if = "Option::is_none")]
     git: Option<String>,
 }
+/// The parts of `Pipfile.lock` used to resolve exact versions
+#[derive(Debug, Deserialize)]
+struct PipfileLock {
+    #[serde(default)]
+    default: HashMap<String, PipfileLockEntry>,
+    #[serde(default)]
+    develop: HashMap<String, PipfileLockEntry>,
+}
+#[derive(Debug, Deserialize)]
+struct PipfileLockEntry {
+    /// Pinned version such as `==2.28.0`; absent for path and VCS packages
+    version: Option<String>,
+}
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -635,6 +976,72 @@ pytest = "^7.0.0"
         assert!(manifest.dev_dependencies.iter().any(|d| d.name == "pytest"));
     }
     #[tokio::test]
+    async fn test_parse_pyproject_toml_pep735_dependency_groups() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+[project]
+name = "grouped"
+version = "0.3.0"
+dependencies = ["httpx>=0.27"]
+
+[dependency-groups]
+test = ["pytest>=8", "coverage[toml]"]
+lint = ["ruff"]
+dev = [{{ include-group = "test" }}, {{ include-group = "lint" }}, "ipython"]
+
+[tool.uv]
+dev-dependencies = ["mypy>=1.10"]
+"#
+        )
+        .unwrap();
+        let manifest = parse_pyproject_toml(file.path()).await.unwrap();
+        assert_eq!(manifest.raw_data["style"], "pep621");
+        assert_eq!(
+            manifest.raw_data["dependency_groups"],
+            json!(["pep735", "uv"])
+        );
+        assert_eq!(manifest.dependencies.len(), 1);
+        let dev: Vec<&str> = manifest
+            .dev_dependencies
+            .iter()
+            .map(|d| d.name.as_str())
+            .collect();
+        assert_eq!(dev, ["coverage", "ipython", "mypy", "pytest", "ruff"]);
+    }
+    #[tokio::test]
+    async fn test_parse_pyproject_toml_pdm_dev_dependencies() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+[project]
+name = "pdm-app"
+version = "1.1.0"
+dependencies = ["click>=8"]
+
+[tool.pdm.dev-dependencies]
+test = ["pytest>=7", "pytest-cov"]
+docs = ["mkdocs~=1.5"]
+"#
+        )
+        .unwrap();
+        let manifest = parse_pyproject_toml(file.path()).await.unwrap();
+        assert_eq!(manifest.raw_data["dependency_groups"], json!(["pdm"]));
+        assert!(manifest.dependencies.iter().any(|d| d.name == "click"));
+        let mkdocs = manifest
+            .dev_dependencies
+            .iter()
+            .find(|d| d.name == "mkdocs")
+            .unwrap();
+        assert_eq!(
+            mkdocs.source,
+            DependencySource::Version("~=1.5".to_string())
+        );
+        assert_eq!(manifest.dev_dependencies.len(), 3);
+    }
+    #[tokio::test]
     async fn test_update_requirements_txt() {
         let mut file = NamedTempFile::new().unwrap();
         writeln!(file, "django==3.2.0\nrequests>=2.25.1").unwrap();
@@ -662,4 +1069,244 @@ requests = "^2.25.1"
         assert!(updated.contains("django"));
         assert!(updated.contains("4.0"));
     }
+    #[tokio::test]
+    async fn test_pep621_and_poetry_produce_equivalent_manifests() {
+        let mut pep621 = NamedTempFile::new().unwrap();
+        writeln!(
+            pep621,
+            r#"
+[project]
+name = "service"
+version = "2.0.0"
+dependencies = [
+    "requests >=2.25, <3",
+    "django~=4.2",
+    "uvicorn[standard]>=0.20",
+    "utils @ file:///srv/libs/utils",
+    "toolkit @ git+https://github.com/acme/toolkit.git@v1.4",
+    "colorama; sys_platform == 'win32'",
+]
+
+[project.optional-dependencies]
+dev = ["pytest>=7"]
+"#
+        )
+        .unwrap();
+        let mut poetry = NamedTempFile::new().unwrap();
+        writeln!(
+            poetry,
+            r#"
+[tool.poetry]
+name = "service"
+version = "2.0.0"
+
+[tool.poetry.dependencies]
+python = "^3.10"
+requests = ">=2.25,<3"
+django = "~=4.2"
+uvicorn = {{ version = ">=0.20", extras = ["standard"] }}
+utils = {{ path = "/srv/libs/utils" }}
+toolkit = {{ git = "https://github.com/acme/toolkit.git", tag = "v1.4" }}
+colorama = "*"
+
+[tool.poetry.group.dev.dependencies]
+pytest = ">=7"
+"#
+        )
+        .unwrap();
+
+        let from_pep621 = parse_pyproject_toml(pep621.path()).await.unwrap();
+        let from_poetry = parse_pyproject_toml(poetry.path()).await.unwrap();
+
+        assert_eq!(from_pep621.raw_data["style"], "pep621");
+        assert_eq!(from_poetry.raw_data["style"], "poetry");
+        assert_eq!(from_pep621.name, from_poetry.name);
+        assert_eq!(from_pep621.version, from_poetry.version);
+        assert_eq!(from_pep621.dependencies, from_poetry.dependencies);
+        assert_eq!(from_pep621.dev_dependencies, from_poetry.dev_dependencies);
+
+        let find = |name: &str| {
+            from_pep621
+                .dependencies
+                .iter()
+                .find(|d| d.name == name)
+                .unwrap()
+                .clone()
+        };
+        assert_eq!(
+            find("requests").source,
+            DependencySource::Version(">=2.25,<3".to_string())
+        );
+        assert_eq!(
+            find("django").source,
+            DependencySource::Version("~=4.2".to_string())
+        );
+        assert_eq!(find("uvicorn").features, vec!["standard"]);
+        assert_eq!(
+            find("utils").source,
+            DependencySource::Path("/srv/libs/utils".to_string())
+        );
+        assert_eq!(
+            find("toolkit").source,
+            DependencySource::Git {
+                url: "https://github.com/acme/toolkit.git".to_string(),
+                rev: Some("v1.4".to_string()),
+            }
+        );
+        assert_eq!(
+            find("colorama").source,
+            DependencySource::Version("*".to_string())
+        );
+        assert_eq!(from_poetry.dev_dependencies.len(), 1);
+        assert_eq!(from_poetry.dev_dependencies[0].name, "pytest");
+    }
+    fn dependency_version<'a>(dependencies: &'a [Dependency], name: &str) -> &'a str {
+        match &dependencies.iter().find(|d| d.name == name).unwrap().source {
+            DependencySource::Version(v) => v,
+            other => panic!("Expected version source, got {:?}", other),
+        }
+    }
+    #[tokio::test]
+    async fn test_parse_pipfile_prefers_locked_versions() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let pipfile = dir.path().join("Pipfile");
+        std::fs::write(
+            &pipfile,
+            r#"
+[packages]
+requests = "*"
+Django = ">=3.0"
+
+[dev-packages]
+pytest = "*"
+black = ">=22.0"
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("Pipfile.lock"),
+            r#"{
+    "_meta": {"hash": {"sha256": "abc"}},
+    "default": {
+        "requests": {"hashes": [], "version": "==2.28.0"},
+        "django": {"hashes": [], "version": "==3.2.0"},
+        "urllib3": {"hashes": [], "version": "==1.26.0"}
+    },
+    "develop": {
+        "pytest": {"hashes": [], "version": "==7.0.0"}
+    }
+}"#,
+        )
+        .unwrap();
+        let manifest = parse_pipfile(&pipfile).await.unwrap();
+        assert_eq!(manifest.raw_data["locked"], true);
+        assert_eq!(
+            dependency_version(&manifest.dependencies, "requests"),
+            "2.28.0"
+        );
+        assert_eq!(
+            dependency_version(&manifest.dependencies, "Django"),
+            "3.2.0"
+        );
+        // Transitive packages from the lock are not added
+        assert_eq!(manifest.dependencies.len(), 2);
+        assert_eq!(
+            dependency_version(&manifest.dev_dependencies, "pytest"),
+            "7.0.0"
+        );
+        // Not in the lock, so the Pipfile constraint is kept
+        assert_eq!(
+            dependency_version(&manifest.dev_dependencies, "black"),
+            ">=22.0"
+        );
+    }
+    #[tokio::test]
+    async fn test_parse_pipfile_without_lock_uses_constraints() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let pipfile = dir.path().join("Pipfile");
+        std::fs::write(&pipfile, "[packages]\nrequests = \">=2.0\"\n").unwrap();
+        let manifest = parse_pipfile(&pipfile).await.unwrap();
+        assert_eq!(manifest.raw_data["locked"], false);
+        assert_eq!(
+            dependency_version(&manifest.dependencies, "requests"),
+            ">=2.0"
+        );
+    }
+    #[tokio::test]
+    async fn test_parse_setup_py_literal_lists() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+from setuptools import setup, find_packages
+
+setup(
+    name="widgets",
+    version='1.4.0',
+    packages=find_packages(),
+    install_requires=[
+        "requests>=2.25,<3",  # HTTP client
+        "click",
+    ],
+    extras_require={{
+        "test": ["pytest==7.4.0"],
+        "docs": ["sphinx"],
+    }},
+)
+"#
+        )
+        .unwrap();
+
+        let manifest = parse_setup_py(file.path()).await.unwrap();
+        assert_eq!(manifest.name, "widgets");
+        assert_eq!(manifest.version, "1.4.0");
+        assert_eq!(
+            manifest.dependencies,
+            vec![
+                Dependency::new(
+                    "requests",
+                    DependencySource::Version(">=2.25,<3".to_string())
+                ),
+                Dependency::new("click", DependencySource::Version("*".to_string())),
+            ]
+        );
+        let dev: Vec<&str> = manifest
+            .dev_dependencies
+            .iter()
+            .map(|d| d.name.as_str())
+            .collect();
+        assert_eq!(dev, vec!["pytest", "sphinx"]);
+        assert_eq!(
+            dependency_version(&manifest.dev_dependencies, "pytest"),
+            "==7.4.0"
+        );
+        assert_eq!(manifest.raw_data["partial"], false);
+    }
+    #[tokio::test]
+    async fn test_parse_setup_py_dynamic_list_is_skipped() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"
+import setuptools
+
+with open("requirements.txt") as f:
+    requirements = [line.strip() for line in f]
+
+setuptools.setup(
+    name="dynamic",
+    version=get_version(),
+    install_requires=requirements + ["extra"],
+)
+"#
+        )
+        .unwrap();
+
+        let manifest = parse_setup_py(file.path()).await.unwrap();
+        assert_eq!(manifest.name, "dynamic");
+        assert_eq!(manifest.version, "0.1.0");
+        assert!(manifest.dependencies.is_empty());
+        assert!(manifest.dev_dependencies.is_empty());
+        assert_eq!(manifest.raw_data["partial"], true);
+    }
 }
@@ -15,11 +15,59 @@ use crate::constants::{
 };
 use mill_foundation::protocol::{ImportGraph, ImportInfo, ImportType, NamedImport, SourceLocation};
 use mill_lang_common::{
-    parse_import_alias, parse_with_fallback, run_ast_tool_async, ImportGraphBuilder,
-    SubprocessAstTool,
+    extract_doc_comment_from_lines, parse_import_alias, parse_with_fallback, run_ast_tool_async,
+    DocCommentStyle, ImportGraphBuilder, SubprocessAstTool,
 };
 use mill_plugin_api::{PluginApiError, PluginResult, Symbol, SymbolKind};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
 use std::path::Path;
+use std::sync::Mutex;
+
+/// Whether `python3` can be started, probed once per process
+static NATIVE_PARSER_AVAILABLE: Lazy<bool> = Lazy::new(|| {
+    std::process::Command::new("python3")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+});
+
+/// Whether Python's native AST parser can be used
+///
+/// The first call spawns `python3 --version` and blocks, so call it from a
+/// blocking context.
+pub(crate) fn native_parser_available() -> bool {
+    *NATIVE_PARSER_AVAILABLE
+}
+
+/// Maximum number of syntax check results kept; the oldest is evicted first
+const SYNTAX_CHECK_CACHE_CAPACITY: usize = 1024;
+
+/// Syntax check results keyed by a hash of the checked source
+#[derive(Default)]
+struct SyntaxCheckCache {
+    results: HashMap<u64, Option<PythonSyntaxError>>,
+    order: VecDeque<u64>,
+}
+
+impl SyntaxCheckCache {
+    fn insert(&mut self, key: u64, result: Option<PythonSyntaxError>) {
+        if self.results.insert(key, result).is_none() {
+            self.order.push_back(key);
+        }
+        while self.order.len() > SYNTAX_CHECK_CACHE_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.results.remove(&oldest);
+            }
+        }
+    }
+}
+
+static SYNTAX_CHECKS: Lazy<Mutex<SyntaxCheckCache>> =
+    Lazy::new(|| Mutex::new(SyntaxCheckCache::default()));
 
 /// List all function names in Python source code using Python's native AST parser.
 /// This function spawns a Python subprocess to perform the parsing.
@@ -28,10 +76,53 @@ pub(crate) async fn list_functions(source: &str) -> PluginResult<Vec<String>> {
     let tool = SubprocessAstTool::new("python3")
         .with_embedded_str(AST_TOOL_PY)
         .with_temp_filename("ast_tool.py")
-        .with_args(vec!["{script}".to_string(), "list-functions".to_string()]);
+        .with_arg("list-functions");
     run_ast_tool_async(tool, source).await.map_err(Into::into)
 }
 
+/// A syntax error reported by Python's native AST parser
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct PythonSyntaxError {
+    pub message: String,
+    /// 1-based line of the error, when Python reports one
+    pub lineno: Option<u32>,
+}
+
+/// Check Python source code for syntax errors using Python's native AST parser.
+/// Returns `None` for valid code; fails when the subprocess cannot run.
+///
+/// Results are cached by content, so unchanged sources do not spawn
+/// `python3` again.
+pub(crate) async fn check_syntax(source: &str) -> PluginResult<Option<PythonSyntaxError>> {
+    const AST_TOOL_PY: &str = include_str!("../resources/ast_tool.py");
+
+    let key = content_hash(source);
+    if let Some(cached) = SYNTAX_CHECKS
+        .lock()
+        .ok()
+        .and_then(|checks| checks.results.get(&key).cloned())
+    {
+        return Ok(cached);
+    }
+
+    let tool = SubprocessAstTool::new("python3")
+        .with_embedded_str(AST_TOOL_PY)
+        .with_temp_filename("ast_tool.py")
+        .with_arg("check-syntax");
+    let result: Option<PythonSyntaxError> = run_ast_tool_async(tool, source).await?;
+
+    if let Ok(mut checks) = SYNTAX_CHECKS.lock() {
+        checks.insert(key, result.clone());
+    }
+    Ok(result)
+}
+
+fn content_hash(source: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    source.hash(&mut hasher);
+    hasher.finish()
+}
+
 /// Analyze Python imports and produce an import graph.
 /// Uses dual-mode parsing: Python AST parser with regex fallback.
 pub(crate) fn analyze_imports(source: &str, file_path: Option<&Path>) -> PluginResult<ImportGraph> {
@@ -78,8 +169,9 @@ pub(crate) fn parse_source_code(source: &str) -> PluginResult<PythonParseResult>
     // Stack: (start_line, indentation, ScopeData, pending_symbol)
     let mut active_scopes: Vec<(u32, usize, ScopeData, Symbol)> = Vec::new();
     let mut last_line_idx = 0;
+    let source_lines: Vec<&str> = source.lines().collect();
 
-    for (line_num, line) in source.lines().enumerate() {
+    for (line_num, line) in source_lines.iter().enumerate() {
         let line_num = line_num as u32;
         last_line_idx = line_num;
         let trimmed = line.trim();
@@ -117,7 +209,11 @@ pub(crate) fn parse_source_code(source: &str) -> PluginResult<PythonParseResult>
             let named_imports = parse_import_names(imports_str);
             imports.push(ImportInfo {
                 module_path: module_name.to_string(),
-                import_type: ImportType::PythonFromImport,
+                import_type: if module_name.starts_with('.') {
+                    ImportType::PythonRelativeImport
+                } else {
+                    ImportType::PythonFromImport
+                },
                 named_imports,
                 default_import: None,
                 namespace_import: None,
@@ -202,7 +298,14 @@ pub(crate) fn parse_source_code(source: &str) -> PluginResult<PythonParseResult>
                     column: 0,
                 },
                 end_location: None, // Will update when scope closes
-                documentation: None,
+                documentation: extract_doc_comment_from_lines(
+                    &source_lines,
+                    line_num as usize,
+                    DocCommentStyle::Docstring,
+                ),
+                // pytest/unittest collect functions named `test*`
+                is_test: name.starts_with("test"),
+                parent: None,
             };
 
             let func_indent = _indent_str.len();
@@ -224,7 +327,13 @@ pub(crate) fn parse_source_code(source: &str) -> PluginResult<PythonParseResult>
                         column: 0,
                     },
                     end_location: None, // Will update when scope closes
-                    documentation: None,
+                    documentation: extract_doc_comment_from_lines(
+                        &source_lines,
+                        line_num as usize,
+                        DocCommentStyle::Docstring,
+                    ),
+                    is_test: false,
+                    parent: None,
                 };
 
                 // Indent is already calculated
@@ -272,6 +381,8 @@ pub(crate) fn parse_source_code(source: &str) -> PluginResult<PythonParseResult>
                     column: 0,
                 }),
                 documentation: None,
+                is_test: false,
+                parent: None,
             });
         }
     }
@@ -588,9 +699,10 @@ import os
 import sys as system
 from pathlib import Path
 from typing import Dict, List as ArrayList
+from .models import User
 "#;
         let imports = parse_python_imports(source).unwrap();
-        assert_eq!(imports.len(), 4);
+        assert_eq!(imports.len(), 5);
         assert_eq!(imports[0].module_path, "os");
         assert_eq!(imports[0].namespace_import, Some("os".to_string()));
         assert_eq!(imports[0].import_type, ImportType::PythonImport);
@@ -605,6 +717,40 @@ from typing import Dict, List as ArrayList
             imports[3].named_imports[1].alias,
             Some("ArrayList".to_string())
         );
+        assert_eq!(imports[4].module_path, ".models");
+        assert_eq!(imports[4].import_type, ImportType::PythonRelativeImport);
+    }
+    #[tokio::test]
+    async fn test_check_syntax_caches_by_content() {
+        if !native_parser_available() {
+            return;
+        }
+        let source = "def cached(:\n    pass\n";
+
+        let first = check_syntax(source).await.unwrap();
+        let cached = SYNTAX_CHECKS
+            .lock()
+            .unwrap()
+            .results
+            .contains_key(&content_hash(source));
+        let second = check_syntax(source).await.unwrap();
+
+        assert!(cached);
+        assert_eq!(first.map(|e| e.lineno), second.map(|e| e.lineno));
+    }
+    #[test]
+    fn test_syntax_check_cache_evicts_oldest_first() {
+        let mut cache = SyntaxCheckCache::default();
+        for key in 0..=SYNTAX_CHECK_CACHE_CAPACITY as u64 {
+            cache.insert(key, None);
+        }
+
+        assert_eq!(cache.results.len(), SYNTAX_CHECK_CACHE_CAPACITY);
+        assert!(!cache.results.contains_key(&0));
+        assert!(cache.results.contains_key(&1));
+        assert!(cache
+            .results
+            .contains_key(&(SYNTAX_CHECK_CACHE_CAPACITY as u64)));
     }
     #[tokio::test]
     async fn test_extract_python_functions_basic() {
@@ -731,4 +877,46 @@ variable = "value"
         assert!(has_class, "Should extract class");
         assert!(has_variable, "Should extract variable");
     }
+    #[test]
+    fn test_extract_symbols_marks_test_functions() {
+        let source = r#"
+def test_addition():
+    assert 1 + 1 == 2
+
+def helper():
+    pass
+"#;
+        let symbols = extract_symbols(source).unwrap();
+        let is_test = |name: &str| symbols.iter().find(|s| s.name == name).unwrap().is_test;
+        assert!(is_test("test_addition"));
+        assert!(!is_test("helper"));
+    }
+    #[test]
+    fn test_extract_symbols_docstrings() {
+        let source = r#"
+class Loader:
+    """Loads settings."""
+
+    def load(self):
+        """Read settings
+        from disk.
+        """
+        return {}
+
+def undocumented():
+    pass
+"#;
+        let symbols = extract_symbols(source).unwrap();
+        let doc = |name: &str| {
+            symbols
+                .iter()
+                .find(|s| s.name == name)
+                .unwrap()
+                .documentation
+                .clone()
+        };
+        assert_eq!(doc("Loader"), Some("Loads settings.".to_string()));
+        assert_eq!(doc("load"), Some("Read settings\nfrom disk.".to_string()));
+        assert_eq!(doc("undocumented"), None);
+    }
 }
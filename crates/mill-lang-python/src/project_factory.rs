@@ -55,6 +55,13 @@ impl ProjectFactory for PythonProjectFactory {
         write_project_file(&entry_file_path, &entry_content)?;
         created_files.push(entry_file_path.display().to_string());
 
+        // The binary of a combined package imports its library package
+        if config.package_type == PackageType::LibAndBin {
+            let main_path = package_path.join(entry_file(&package_name, PackageType::Binary));
+            write_project_file(&main_path, &generate_lib_and_bin_main(&package_name))?;
+            created_files.push(main_path.display().to_string());
+        }
+
         // Create baseline files (README, .gitignore, tests) for minimal template
         let baseline = create_baseline_files(&package_path, &package_name)?;
         created_files.extend(baseline);
@@ -123,7 +130,9 @@ fn create_directory_structure(package_path: &Path, package_name: &str) -> Plugin
 
 fn entry_file(package_name: &str, package_type: PackageType) -> PathBuf {
     match package_type {
-        PackageType::Library => PathBuf::from("src").join(package_name).join("__init__.py"),
+        PackageType::Library | PackageType::LibAndBin => {
+            PathBuf::from("src").join(package_name).join("__init__.py")
+        }
         PackageType::Binary => PathBuf::from("src").join(package_name).join("main.py"),
     }
 }
@@ -168,6 +177,29 @@ build-backend = "setuptools.build_meta"
 [tool.setuptools.packages.find]
 where = ["src"]
 
+[tool.setuptools.package-dir]
+"" = "src"
+"#,
+            package_name, package_name, package_name
+        ),
+        PackageType::LibAndBin => format!(
+            r#"[project]
+name = "{}"
+version = "0.1.0"
+description = ""
+requires-python = ">=3.8"
+dependencies = []
+
+[project.scripts]
+{} = "{}.main:main"
+
+[build-system]
+requires = ["setuptools>=61.0"]
+build-backend = "setuptools.build_meta"
+
+[tool.setuptools.packages.find]
+where = ["src"]
+
 [tool.setuptools.package-dir]
 "" = "src"
 "#,
@@ -200,9 +232,44 @@ if __name__ == "__main__":
     main()
 "#
         .to_string(),
+        PackageType::LibAndBin => format!(
+            r#""""{} package
+
+TODO: Add package description
+"""
+
+__version__ = "0.1.0"
+
+
+def hello():
+    """Greeting printed by the command-line entry point"""
+    return "Hello, world!"
+"#,
+            package_name
+        ),
     }
 }
 
+/// `main.py` of a combined package, using the library package
+fn generate_lib_and_bin_main(package_name: &str) -> String {
+    format!(
+        r#""""Command-line entry point"""
+
+from {} import hello
+
+
+def main():
+    """Main function"""
+    print(hello())
+
+
+if __name__ == "__main__":
+    main()
+"#,
+        package_name
+    )
+}
+
 fn create_baseline_files(package_path: &Path, package_name: &str) -> PluginResult<Vec<String>> {
     let mut created = Vec::new();
 
@@ -328,5 +395,9 @@ mod tests {
             entry_file("myapp", PackageType::Binary),
             PathBuf::from("src/myapp/main.py")
         );
+        assert_eq!(
+            entry_file("mytool", PackageType::LibAndBin),
+            PathBuf::from("src/mytool/__init__.py")
+        );
     }
 }
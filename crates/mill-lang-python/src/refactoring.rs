@@ -7,70 +7,128 @@
 //!
 //! These refactoring operations analyze Python code structure and generate edit plans
 //! that can be applied to transform the code while preserving semantics.
+use crate::constants::IDENTIFIER_PATTERN;
 use crate::parser::{
-    analyze_python_expression_range, extract_python_functions, extract_python_variables,
-    find_variable_at_position, get_variable_usages_in_scope,
+    analyze_python_expression_range, find_variable_at_position, get_variable_usages_in_scope,
 };
 use mill_foundation::protocol::{EditPlan, EditType, TextEdit};
+use mill_lang_common::naming::{check_convention, validate_identifier, Language, NamingConvention};
 use mill_lang_common::{
     count_unescaped_quotes, find_literal_occurrences,
     refactoring::edit_plan_builder::EditPlanBuilder, ExtractConstantAnalysis,
     ExtractVariableAnalysis, ExtractableFunction, InlineVariableAnalysis, LineExtractor,
 };
 use mill_plugin_api::{PluginApiError, PluginResult};
+use std::collections::HashSet;
 
 // Re-export for use within the plugin
 pub use mill_lang_common::CodeRange;
 
 /// Analyze code selection for function extraction (Python)
+///
+/// Python statements are line-oriented, so the selection is widened to whole
+/// lines. Parameters are names the selection reads before assigning that are
+/// bound earlier in the enclosing scope; return values are names the selection
+/// assigns that the rest of the scope reads.
 pub(crate) fn analyze_extract_function(
     source: &str,
     range: &CodeRange,
     _file_path: &str,
 ) -> PluginResult<ExtractableFunction> {
     let lines: Vec<&str> = source.lines().collect();
+    let (start, end) = (range.start_line as usize, range.end_line as usize);
+    if start > end || end >= lines.len() {
+        return Err(PluginApiError::invalid_input(format!(
+            "Selection lines {}-{} are outside the file",
+            range.start_line, range.end_line
+        )));
+    }
+    let block = &lines[start..=end];
+    let base_indent = block
+        .iter()
+        .filter(|line| is_code_line(line))
+        .map(|line| indentation(line))
+        .min()
+        .ok_or_else(|| PluginApiError::invalid_input("Selection contains no statements"))?;
+    if let Some(first) = block.iter().find(|line| is_code_line(line)) {
+        if indentation(first) != base_indent {
+            return Err(PluginApiError::invalid_input(
+                "Selection must start at a statement, not inside an indented block",
+            ));
+        }
+    }
+
+    let scope = enclosing_scope(&lines, start, end, base_indent);
+
+    // Names bound before the selection: enclosing function parameters and
+    // assignments in its body (outside nested functions and classes)
+    let mut bound_before = HashSet::new();
+    for (_, line) in scope_lines(&lines, scope.start, start) {
+        for (name, access) in line_accesses(line) {
+            if access == Access::Store {
+                bound_before.insert(name);
+            }
+        }
+    }
+
+    let mut stored = Vec::new();
     let mut required_parameters = Vec::new();
-    let mut required_imports = Vec::new();
-    let functions = extract_python_functions(source)?;
-    let variables = extract_python_variables(source)?;
-    for line_num in range.start_line..=range.end_line {
-        if let Some(line) = lines.get(line_num as usize) {
-            let line_text = if line_num == range.start_line && line_num == range.end_line {
-                &line[range.start_col as usize..range.end_col as usize]
-            } else if line_num == range.start_line {
-                &line[range.start_col as usize..]
-            } else if line_num == range.end_line {
-                &line[..range.end_col as usize]
-            } else {
-                line
-            };
-            for var in &variables {
-                if var.line < range.start_line
-                    && line_text.contains(&var.name)
-                    && !required_parameters.contains(&var.name)
-                {
-                    required_parameters.push(var.name.clone());
+    for line in block {
+        for (name, access) in line_accesses(line) {
+            match access {
+                Access::Load => {
+                    if !stored.contains(&name)
+                        && bound_before.contains(&name)
+                        && !required_parameters.contains(&name)
+                    {
+                        required_parameters.push(name);
+                    }
                 }
-            }
-            for func in &functions {
-                if func.start_line < range.start_line
-                    && line_text.contains(&format!("{}(", func.name))
-                    && !required_imports.contains(&func.name)
-                {
-                    required_imports.push(func.name.clone());
+                Access::Store => {
+                    if !stored.contains(&name) {
+                        stored.push(name);
+                    }
                 }
             }
         }
     }
-    let selected_text = extract_range_text(source, range)?;
-    let contains_return = selected_text.contains("return ");
-    let insertion_point = find_insertion_point(source, range.start_line)?;
+
+    let loaded_after: HashSet<String> = scope_lines(&lines, end + 1, scope.end + 1)
+        .flat_map(|(_, line)| line_accesses(line))
+        .filter(|(_, access)| *access == Access::Load)
+        .map(|(name, _)| name)
+        .collect();
+    let return_variables: Vec<String> = stored
+        .into_iter()
+        .filter(|name| loaded_after.contains(name))
+        .collect();
+
+    let contains_return = block.iter().any(|line| {
+        let code = mask_strings_and_comments(line);
+        let trimmed = code.trim_start();
+        trimmed == "return"
+            || trimmed.starts_with("return ")
+            || trimmed == "yield"
+            || trimmed.starts_with("yield ")
+    });
+
+    let insertion_line = find_insertion_point(&lines, start);
     Ok(ExtractableFunction {
-        selected_range: *range,
+        selected_range: CodeRange {
+            start_line: range.start_line,
+            start_col: 0,
+            end_line: range.end_line,
+            end_col: lines[end].len() as u32,
+        },
         required_parameters,
-        return_variables: Vec::new(),
+        return_variables,
         suggested_name: "extracted_function".to_string(),
-        insertion_point,
+        insertion_point: CodeRange {
+            start_line: insertion_line as u32,
+            start_col: 0,
+            end_line: insertion_line as u32,
+            end_col: 0,
+        },
         contains_return_statements: contains_return,
         complexity_score: 2,
     })
@@ -192,28 +250,57 @@ pub(crate) fn plan_extract_function(
     new_function_name: &str,
     file_path: &str,
 ) -> PluginResult<EditPlan> {
+    validate_identifier(new_function_name, Language::Python)
+        .map_err(PluginApiError::invalid_input)?;
+    check_convention(new_function_name, NamingConvention::SnakeCase);
     let analysis = analyze_extract_function(source, range, file_path)?;
+    if analysis.contains_return_statements {
+        return Err(PluginApiError::invalid_input(
+            "Cannot extract a selection containing return or yield statements",
+        ));
+    }
+    let lines: Vec<&str> = source.lines().collect();
+    let function_code = generate_extracted_function(&lines, &analysis, new_function_name);
+    let call_code = generate_function_call(&lines, &analysis, new_function_name);
+    let original_text = lines
+        [analysis.selected_range.start_line as usize..=analysis.selected_range.end_line as usize]
+        .join("\n");
+
     let mut edits = Vec::new();
-    let function_code = generate_extracted_function(source, &analysis, new_function_name)?;
-    edits.push(TextEdit {
-        file_path: None,
-        edit_type: EditType::Insert,
-        location: analysis.insertion_point.into(),
-        original_text: String::new(),
-        new_text: format!("{}\n\n", function_code),
-        priority: 100,
-        description: format!("Create extracted function '{}'", new_function_name),
-    });
-    let call_code = generate_function_call(&analysis, new_function_name)?;
-    edits.push(TextEdit {
-        file_path: None,
-        edit_type: EditType::Replace,
-        location: analysis.selected_range.into(),
-        original_text: extract_range_text(source, &analysis.selected_range)?,
-        new_text: call_code,
-        priority: 90,
-        description: format!("Replace selected code with call to '{}'", new_function_name),
-    });
+    if analysis.insertion_point.start_line == analysis.selected_range.start_line {
+        // Top-level selection: the function goes right where the selection was
+        edits.push(TextEdit {
+            file_path: None,
+            edit_type: EditType::Replace,
+            location: analysis.selected_range.into(),
+            original_text,
+            new_text: format!("{}\n\n\n{}", function_code, call_code),
+            priority: 100,
+            description: format!(
+                "Extract selected code into function '{}'",
+                new_function_name
+            ),
+        });
+    } else {
+        edits.push(TextEdit {
+            file_path: None,
+            edit_type: EditType::Insert,
+            location: analysis.insertion_point.into(),
+            original_text: String::new(),
+            new_text: format!("{}\n\n\n", function_code),
+            priority: 100,
+            description: format!("Create extracted function '{}'", new_function_name),
+        });
+        edits.push(TextEdit {
+            file_path: None,
+            edit_type: EditType::Replace,
+            location: analysis.selected_range.into(),
+            original_text,
+            new_text: call_code,
+            priority: 90,
+            description: format!("Replace selected code with call to '{}'", new_function_name),
+        });
+    }
     Ok(EditPlanBuilder::new(file_path, "extract_function")
         .with_edits(edits)
         .with_syntax_validation("Verify Python syntax is valid after extraction")
@@ -305,6 +392,8 @@ pub(crate) fn plan_extract_variable(
         )));
     }
     let var_name = variable_name.unwrap_or_else(|| analysis.suggested_name.clone());
+    validate_identifier(&var_name, Language::Python).map_err(PluginApiError::invalid_input)?;
+    check_convention(&var_name, NamingConvention::SnakeCase);
     let indent = LineExtractor::get_indentation_str(source, start_line);
     let mut edits = Vec::new();
     let declaration = format!("{}{} = {}\n", indent, var_name, analysis.expression);
@@ -354,78 +443,394 @@ fn extract_range_text(source: &str, range: &CodeRange) -> PluginResult<String> {
         range.end_col,
     )
 }
-/// Find proper insertion point for a new Python function
-fn find_insertion_point(source: &str, start_line: u32) -> PluginResult<CodeRange> {
-    let lines: Vec<&str> = source.lines().collect();
-    let mut insertion_line = 0;
-    for (idx, line) in lines.iter().enumerate() {
-        let line_idx = idx as u32;
-        if line_idx >= start_line {
+/// Python keywords, never treated as variable names
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+/// How a statement uses a name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Access {
+    Load,
+    Store,
+}
+/// Line range of the scope enclosing a selection (inclusive)
+struct Scope {
+    start: usize,
+    end: usize,
+}
+fn is_code_line(line: &str) -> bool {
+    let trimmed = line.trim();
+    !trimmed.is_empty() && !trimmed.starts_with('#')
+}
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+fn is_def_line(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("def ") || trimmed.starts_with("async def ")
+}
+/// Find the function enclosing lines `start..=end`, or the whole module
+fn enclosing_scope(lines: &[&str], start: usize, end: usize, base_indent: usize) -> Scope {
+    let mut indent = base_indent;
+    for idx in (0..start).rev() {
+        let line = lines[idx];
+        if !is_code_line(line) || indentation(line) >= indent {
+            continue;
+        }
+        indent = indentation(line);
+        if is_def_line(line) {
+            let scope_end = lines
+                .iter()
+                .enumerate()
+                .skip(end + 1)
+                .find(|(_, l)| is_code_line(l) && indentation(l) <= indent)
+                .map_or(lines.len() - 1, |(i, _)| i - 1);
+            return Scope {
+                start: idx,
+                end: scope_end,
+            };
+        }
+        if indent == 0 {
             break;
         }
-        let trimmed = line.trim();
-        if trimmed.starts_with("def ") || trimmed.starts_with("class ") {
-            insertion_line = line_idx;
+    }
+    Scope {
+        start: 0,
+        end: lines.len() - 1,
+    }
+}
+/// Code lines in `from..to`, skipping bodies of nested functions and classes
+fn scope_lines<'a>(
+    lines: &'a [&'a str],
+    from: usize,
+    to: usize,
+) -> impl Iterator<Item = (usize, &'a str)> + 'a {
+    let mut nested_indent: Option<usize> = None;
+    lines
+        .iter()
+        .enumerate()
+        .take(to.min(lines.len()))
+        .skip(from)
+        .filter(|(_, line)| is_code_line(line))
+        .filter_map(move |(idx, line)| {
+            let indent = indentation(line);
+            if let Some(nested) = nested_indent {
+                if indent > nested {
+                    return None;
+                }
+                nested_indent = None;
+            }
+            let trimmed = line.trim_start();
+            if idx != from && (is_def_line(line) || trimmed.starts_with("class ")) {
+                nested_indent = Some(indent);
+            }
+            Some((idx, *line))
+        })
+}
+/// Line where an extracted module-level function can be inserted: before the
+/// top-level statement (and its decorators) containing `start`
+fn find_insertion_point(lines: &[&str], start: usize) -> usize {
+    let mut line = (0..=start)
+        .rev()
+        .find(|&idx| is_code_line(lines[idx]) && indentation(lines[idx]) == 0)
+        .unwrap_or(start);
+    while line > 0 && lines[line - 1].trim_start().starts_with('@') {
+        line -= 1;
+    }
+    line
+}
+/// Replace string literal contents and comments with spaces so scanning for
+/// names and operators only sees code
+fn mask_strings_and_comments(line: &str) -> String {
+    let mut masked = String::with_capacity(line.len());
+    let mut quote: Option<char> = None;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match quote {
+            Some(q) => {
+                if c == '\\' {
+                    masked.push(' ');
+                    if chars.next().is_some() {
+                        masked.push(' ');
+                    }
+                    continue;
+                }
+                if c == q {
+                    quote = None;
+                    masked.push(c);
+                } else {
+                    masked.push(' ');
+                }
+            }
+            None => match c {
+                '#' => break,
+                '\'' | '"' => {
+                    quote = Some(c);
+                    masked.push(c);
+                }
+                _ => masked.push(c),
+            },
+        }
+    }
+    masked
+}
+/// Byte offsets of top-level `=` assignment operators in masked code, with
+/// whether the assignment is augmented (`+=`, `//=`, ...)
+fn assignment_operators(code: &str) -> Vec<(usize, bool)> {
+    let bytes = code.as_bytes();
+    let mut depth = 0i32;
+    let mut operators = Vec::new();
+    for (idx, &b) in bytes.iter().enumerate() {
+        match b {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth -= 1,
+            b'=' if depth == 0 => {
+                let prev = idx.checked_sub(1).map(|i| bytes[i]);
+                let next = bytes.get(idx + 1).copied();
+                if next == Some(b'=') || matches!(prev, Some(b'=' | b'!' | b'<' | b'>')) {
+                    // Comparison, unless it is `<<=` or `>>=`
+                    let shift = idx >= 2 && matches!(&bytes[idx - 2..idx], b"<<" | b">>");
+                    if !shift {
+                        continue;
+                    }
+                }
+                let augmented = matches!(
+                    prev,
+                    Some(
+                        b'+' | b'-' | b'*' | b'/' | b'%' | b'&' | b'|' | b'^' | b'@' | b'<' | b'>'
+                    )
+                );
+                operators.push((idx, augmented));
+            }
+            _ => {}
+        }
+    }
+    operators
+}
+/// Names read by an expression, skipping attributes and keyword arguments
+fn expression_loads(code: &str) -> Vec<String> {
+    IDENTIFIER_PATTERN
+        .find_iter(code)
+        .filter(|m| {
+            let before = code[..m.start()].trim_end();
+            let after = code[m.end()..].trim_start();
+            let is_attribute = before.ends_with('.');
+            let is_keyword_arg = after.starts_with('=') && !after.starts_with("==");
+            let is_number_suffix = code[..m.start()]
+                .chars()
+                .last()
+                .is_some_and(|c| c.is_ascii_digit());
+            !is_attribute
+                && !is_keyword_arg
+                && !is_number_suffix
+                && !PYTHON_KEYWORDS.contains(&m.as_str())
+        })
+        .map(|m| m.as_str().to_string())
+        .collect()
+}
+/// Record an assignment target: plain names are stored, anything else
+/// (attributes, subscripts) only reads names
+fn target_accesses(target: &str, augmented: bool, accesses: &mut Vec<(String, Access)>) {
+    for part in target.split(',') {
+        let part = part
+            .trim()
+            .trim_start_matches(['(', '['])
+            .trim_end_matches([')', ']'])
+            .trim_start_matches('*')
+            .trim();
+        // Drop a type annotation (`count: int = 0`)
+        let part = part.split(':').next().unwrap_or(part).trim();
+        let is_name = !part.is_empty()
+            && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && !part.starts_with(|c: char| c.is_ascii_digit());
+        if is_name {
+            if augmented {
+                accesses.push((part.to_string(), Access::Load));
+            }
+            accesses.push((part.to_string(), Access::Store));
+        } else {
+            accesses.extend(
+                expression_loads(part)
+                    .into_iter()
+                    .map(|name| (name, Access::Load)),
+            );
         }
     }
-    Ok(CodeRange {
-        start_line: insertion_line,
-        start_col: 0,
-        end_line: insertion_line,
-        end_col: 0,
-    })
+}
+/// Names a single line reads and binds, in evaluation order
+fn line_accesses(line: &str) -> Vec<(String, Access)> {
+    let code = mask_strings_and_comments(line);
+    let trimmed = code.trim();
+    let mut accesses = Vec::new();
+    let loads = |text: &str| {
+        expression_loads(text)
+            .into_iter()
+            .map(|name| (name, Access::Load))
+            .collect::<Vec<_>>()
+    };
+
+    let def = trimmed
+        .strip_prefix("async def ")
+        .or_else(|| trimmed.strip_prefix("def "));
+    if let Some(def) = def {
+        let (name, rest) = def.split_once('(').unwrap_or((def, ""));
+        accesses.push((name.trim().to_string(), Access::Store));
+        let params = rest.rsplit_once(')').map_or(rest, |(params, _)| params);
+        for param in params.split(',') {
+            let param = param.split(['=', ':']).next().unwrap_or("");
+            let param = param.trim().trim_start_matches('*').trim();
+            if !param.is_empty() {
+                accesses.push((param.to_string(), Access::Store));
+            }
+        }
+        return accesses;
+    }
+    if let Some(class) = trimmed.strip_prefix("class ") {
+        let name = class.split(['(', ':']).next().unwrap_or("").trim();
+        accesses.push((name.to_string(), Access::Store));
+        return accesses;
+    }
+    if trimmed.starts_with("import ") || trimmed.starts_with("from ") {
+        let names = trimmed
+            .split_once(" import ")
+            .map_or(trimmed.trim_start_matches("import "), |(_, names)| names);
+        for name in names.trim_matches(['(', ')']).split(',') {
+            let bound = name
+                .split_once(" as ")
+                .map_or(name.split('.').next().unwrap_or(""), |(_, alias)| alias)
+                .trim();
+            if !bound.is_empty() && bound != "*" {
+                accesses.push((bound.to_string(), Access::Store));
+            }
+        }
+        return accesses;
+    }
+    let for_loop = trimmed
+        .strip_prefix("async for ")
+        .or_else(|| trimmed.strip_prefix("for "));
+    if let Some(for_loop) = for_loop {
+        if let Some((target, iterable)) = for_loop.split_once(" in ") {
+            accesses.extend(loads(iterable));
+            target_accesses(target, false, &mut accesses);
+            return accesses;
+        }
+    }
+    if trimmed.starts_with("with ") || trimmed.starts_with("except") {
+        for item in trimmed.trim_end_matches(':').split(',') {
+            match item.split_once(" as ") {
+                Some((expr, target)) => {
+                    accesses.extend(loads(expr));
+                    target_accesses(target, false, &mut accesses);
+                }
+                None => accesses.extend(loads(item)),
+            }
+        }
+        return accesses;
+    }
+
+    let operators = assignment_operators(trimmed);
+    match operators.last() {
+        Some(&(rhs_start, augmented)) => {
+            accesses.extend(loads(&trimmed[rhs_start + 1..]));
+            let mut target_start = 0;
+            for &(op, _) in &operators {
+                let op_len = if augmented {
+                    operator_len(trimmed, op)
+                } else {
+                    1
+                };
+                target_accesses(
+                    &trimmed[target_start..op + 1 - op_len],
+                    augmented,
+                    &mut accesses,
+                );
+                target_start = op + 1;
+            }
+        }
+        None => accesses.extend(loads(trimmed)),
+    }
+    accesses
+}
+/// Length of the augmented assignment operator ending at `eq` (e.g. 3 for `//=`)
+fn operator_len(code: &str, eq: usize) -> usize {
+    let before = &code[..eq];
+    if ["//", "**", ">>", "<<"]
+        .iter()
+        .any(|op| before.ends_with(op))
+    {
+        3
+    } else {
+        2
+    }
 }
 /// Generate Python function code for extraction
 fn generate_extracted_function(
-    source: &str,
+    lines: &[&str],
     analysis: &ExtractableFunction,
     function_name: &str,
-) -> PluginResult<String> {
-    let params = analysis.required_parameters.join(", ");
-    let extracted_code = extract_range_text(source, &analysis.selected_range)?;
-    let indented_code = extracted_code
-        .lines()
+) -> String {
+    let range = &analysis.selected_range;
+    let block = &lines[range.start_line as usize..=range.end_line as usize];
+    let base_indent = block
+        .iter()
+        .filter(|line| is_code_line(line))
+        .map(|line| indentation(line))
+        .min()
+        .unwrap_or(0);
+    let mut body: Vec<String> = block
+        .iter()
         .map(|line| {
             if line.trim().is_empty() {
-                line.to_string()
+                String::new()
             } else {
-                format!("    {}", line)
+                format!("    {}", &line[base_indent.min(indentation(line))..])
             }
         })
-        .collect::<Vec<_>>()
-        .join("\n");
-    let return_statement = if analysis.return_variables.is_empty() {
-        String::new()
-    } else if analysis.return_variables.len() == 1 {
-        format!("    return {}", analysis.return_variables[0])
-    } else {
-        format!("    return {}", analysis.return_variables.join(", "))
-    };
-    Ok(format!(
-        "def {}({}):\n{}\n{}",
-        function_name, params, indented_code, return_statement
-    ))
+        .collect();
+    while body.last().is_some_and(|line| line.is_empty()) {
+        body.pop();
+    }
+    if !analysis.return_variables.is_empty() {
+        body.push(format!(
+            "    return {}",
+            analysis.return_variables.join(", ")
+        ));
+    }
+    format!(
+        "def {}({}):\n{}",
+        function_name,
+        analysis.required_parameters.join(", "),
+        body.join("\n")
+    )
 }
-/// Generate Python function call
+/// Generate the call that replaces the selection, indented like the selection
 fn generate_function_call(
+    lines: &[&str],
     analysis: &ExtractableFunction,
     function_name: &str,
-) -> PluginResult<String> {
-    let args = analysis.required_parameters.join(", ");
+) -> String {
+    let range = &analysis.selected_range;
+    let indent = lines[range.start_line as usize..=range.end_line as usize]
+        .iter()
+        .find(|line| is_code_line(line))
+        .map_or("", |line| &line[..indentation(line)]);
+    let call = format!(
+        "{}({})",
+        function_name,
+        analysis.required_parameters.join(", ")
+    );
     if analysis.return_variables.is_empty() {
-        Ok(format!("{}({})", function_name, args))
-    } else if analysis.return_variables.len() == 1 {
-        Ok(format!(
-            "{} = {}({})",
-            analysis.return_variables[0], function_name, args
-        ))
+        format!("{}{}", indent, call)
     } else {
-        Ok(format!(
-            "{} = {}({})",
+        format!(
+            "{}{} = {}",
+            indent,
             analysis.return_variables.join(", "),
-            function_name,
-            args
-        ))
+            call
+        )
     }
 }
 /// Suggest a Python variable name based on the expression
@@ -643,6 +1048,8 @@ pub(crate) fn plan_extract_constant(
     name: &str,
     file_path: &str,
 ) -> PluginResult<EditPlan> {
+    validate_identifier(name, Language::Python).map_err(PluginApiError::invalid_input)?;
+    check_convention(name, NamingConvention::ScreamingSnakeCase);
     let analysis = analyze_extract_constant(source, line, character, file_path)?;
 
     if !analysis.is_valid_literal {
@@ -1182,6 +1589,60 @@ def calculate():
         assert_eq!(analysis.suggested_name, "result");
     }
     #[test]
+    fn test_plan_extract_function_parameter_and_return() {
+        let source = "import math\n\n\ndef main():\n    price = 10\n    total = price * 2  # doubled\n    print(total)\n";
+        let range = CodeRange {
+            start_line: 5,
+            start_col: 4,
+            end_line: 5,
+            end_col: 22,
+        };
+        let analysis = analyze_extract_function(source, &range, "test.py").unwrap();
+        assert_eq!(analysis.required_parameters, vec!["price"]);
+        assert_eq!(analysis.return_variables, vec!["total"]);
+
+        let plan = plan_extract_function(source, &range, "double", "test.py").unwrap();
+        assert_eq!(plan.edits.len(), 2);
+        let insert = &plan.edits[0];
+        assert_eq!(insert.location.start_line, 3);
+        assert_eq!(
+            insert.new_text,
+            "def double(price):\n    total = price * 2  # doubled\n    return total\n\n\n"
+        );
+        let replace = &plan.edits[1];
+        assert_eq!(
+            (replace.location.start_column, replace.location.end_column),
+            (0, 32)
+        );
+        assert_eq!(replace.new_text, "    total = double(price)");
+    }
+    #[test]
+    fn test_plan_extract_function_dedents_nested_block() {
+        let source = "class Report:\n    @property\n    def lines(self):\n        rows = []\n        for item in self.items:\n            if item:\n                rows.append(str(item))\n        return rows\n";
+        let range = CodeRange {
+            start_line: 5,
+            start_col: 12,
+            end_line: 6,
+            end_col: 38,
+        };
+        let plan = plan_extract_function(source, &range, "add_row", "test.py").unwrap();
+        let insert = &plan.edits[0];
+        assert_eq!(insert.location.start_line, 0);
+        assert_eq!(
+            insert.new_text,
+            "def add_row(item, rows):\n    if item:\n        rows.append(str(item))\n\n\n"
+        );
+        assert_eq!(plan.edits[1].new_text, "            add_row(item, rows)");
+
+        let with_return = CodeRange {
+            start_line: 7,
+            start_col: 0,
+            end_line: 7,
+            end_col: 19,
+        };
+        assert!(plan_extract_function(source, &with_return, "f", "test.py").is_err());
+    }
+    #[test]
     fn test_inline_variable_analysis() {
         let source = r#"x = 42
 y = x + 1
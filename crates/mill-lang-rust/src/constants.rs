@@ -13,17 +13,21 @@ pub const DEFAULT_EDITION: &str = "2021";
 #[allow(dead_code)] // Future enhancement: Parser versioning
 pub const PARSER_VERSION: &str = "0.1.0";
 
+/// Get cached test annotation patterns
+static TEST_PATTERNS: OnceLock<Vec<Regex>> = OnceLock::new();
+
 /// Regex pattern for extracting Rust test annotations
 ///
 /// Matches: `#[test]`, `#[tokio::test]`, `#[async_std::test]`, `#[actix_rt::test]`
-#[allow(dead_code)]
-pub fn test_patterns() -> Vec<Regex> {
-    vec![
-        Regex::new(r"#\[test\]").expect("Valid test pattern regex"),
-        Regex::new(r"#\[tokio::test\]").expect("Valid tokio::test pattern regex"),
-        Regex::new(r"#\[async_std::test\]").expect("Valid async_std::test pattern regex"),
-        Regex::new(r"#\[actix_rt::test\]").expect("Valid actix_rt::test pattern regex"),
-    ]
+pub fn test_patterns() -> &'static [Regex] {
+    TEST_PATTERNS.get_or_init(|| {
+        vec![
+            Regex::new(r"#\[test\]").expect("Valid test pattern regex"),
+            Regex::new(r"#\[tokio::test\]").expect("Valid tokio::test pattern regex"),
+            Regex::new(r"#\[async_std::test\]").expect("Valid async_std::test pattern regex"),
+            Regex::new(r"#\[actix_rt::test\]").expect("Valid actix_rt::test pattern regex"),
+        ]
+    })
 }
 
 /// Regex patterns for extracting Rust assertion macros
@@ -104,6 +108,22 @@ pub fn variable_decl_pattern() -> &'static Regex {
     })
 }
 
+/// Get cached item declaration pattern
+static ITEM_DECL_PATTERN: OnceLock<Regex> = OnceLock::new();
+
+/// Regex pattern for item declarations at the start of a line
+///
+/// Matches: `fn main(`, `pub(crate) async fn load(`, `struct Config`, `pub mod net`
+/// Captures the item keyword (group 1) and its name (group 2)
+pub fn item_decl_pattern() -> &'static Regex {
+    ITEM_DECL_PATTERN.get_or_init(|| {
+        Regex::new(
+            r#"^\s*(?:pub(?:\([^)]*\))?\s+)?(?:(?:const|async|unsafe|extern\s+"[^"]*")\s+)*(fn|struct|enum|const|static|mod)\s+(?:mut\s+)?([A-Za-z_][A-Za-z0-9_]*)"#,
+        )
+        .expect("Valid item declaration regex")
+    })
+}
+
 /// Generate a regex pattern for matching qualified paths
 ///
 /// # Arguments
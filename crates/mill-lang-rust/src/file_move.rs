@@ -0,0 +1,541 @@
+//! Planning of single-file moves within a crate
+//!
+//! Moving a `.rs` file changes the module path other files import it by.
+//! The planner computes the module path of the file before and after the
+//! move, scans every file of the crate with the plugin's module reference
+//! scanner for `use` declarations and qualified paths (`utils::helper()`)
+//! naming the old module, and rewrites them to name the new one. The `mod`
+//! declaration is renamed in place, or moved to the new parent module when
+//! the file changes directory, in which case the `super::` imports of the
+//! moved file are made absolute.
+//!
+//! Paths nested in a `use` group (`use crate::{utils::helper}`) are rewritten
+//! after the group's opening brace, so they are skipped when the new path no
+//! longer starts with the group's prefix.
+
+use crate::RustPlugin;
+use ignore::WalkBuilder;
+use mill_plugin_api::{
+    ModuleReference, ModuleReferenceScanner, PluginApiError, PluginResult, ReferenceKind,
+    ScanScope, TextEdit, WorkspaceEdit,
+};
+use proc_macro2::LineColumn;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use syn::visit::Visit;
+use syn::{Item, ItemMod, ItemUse, UseTree};
+use tracing::debug;
+
+/// How a use path names a module relative to the importing file
+#[derive(Debug, Clone, Copy)]
+enum PathStyle {
+    /// `crate::a::utils`
+    Absolute,
+    /// `utils`, for a child of the importing module
+    Relative,
+    /// `self::utils`
+    SelfRelative,
+    /// `super::utils`, with the number of `super` segments
+    Super(usize),
+}
+
+/// A parsed file of the crate with its module path
+struct CrateFile {
+    path: PathBuf,
+    module: Vec<String>,
+    content: String,
+    ast: syn::File,
+}
+
+/// Plan the edits for moving `old_path` to `new_path`
+///
+/// Both paths may be relative to `root`. The file must stay within its
+/// crate, and crate root files (`lib.rs`, `main.rs`) can't be moved.
+pub(crate) fn plan_move_file(
+    root: &Path,
+    old_path: &Path,
+    new_path: &Path,
+) -> PluginResult<WorkspaceEdit> {
+    let old_path = root.join(old_path);
+    let new_path = root.join(new_path);
+    for path in [&old_path, &new_path] {
+        if path.extension().and_then(|ext| ext.to_str()) != Some("rs") {
+            return Err(PluginApiError::invalid_input(format!(
+                "Not a Rust source file: {}",
+                path.display()
+            )));
+        }
+    }
+    if !old_path.is_file() {
+        return Err(PluginApiError::invalid_input(format!(
+            "File not found: {}",
+            old_path.display()
+        )));
+    }
+    if new_path.exists() {
+        return Err(PluginApiError::invalid_input(format!(
+            "Destination already exists: {}",
+            new_path.display()
+        )));
+    }
+
+    let crate_dir = crate_dir(root, &old_path).ok_or_else(|| {
+        PluginApiError::invalid_input(format!(
+            "No lib.rs or main.rs found above {}",
+            old_path.display()
+        ))
+    })?;
+    let (Some(old_module), Some(new_module)) = (
+        module_path(&crate_dir, &old_path),
+        module_path(&crate_dir, &new_path),
+    ) else {
+        return Err(PluginApiError::invalid_input(
+            "Moving a file out of its crate is not supported",
+        ));
+    };
+    if old_module.is_empty() || new_module.is_empty() {
+        return Err(PluginApiError::invalid_input(
+            "Crate root files cannot be moved",
+        ));
+    }
+
+    let old_parent = &old_module[..old_module.len() - 1];
+    let new_parent = &new_module[..new_module.len() - 1];
+    let new_name = &new_module[new_module.len() - 1];
+    let changes_parent = old_parent != new_parent;
+
+    let mut changes: HashMap<String, Vec<TextEdit>> = HashMap::new();
+    let mut declaration_prefix = None;
+    let files = crate_files(&crate_dir);
+    let scanner = RustPlugin::default();
+
+    for file in &files {
+        let is_moved = file.path == old_path;
+        let module_after = if is_moved { &new_module } else { &file.module };
+
+        let mut edits = path_edits(&scanner, file, module_after, &old_module, &new_module);
+        if is_moved && changes_parent {
+            for edit in super_edits(file, module_after) {
+                if !edits.iter().any(|e| same_start(e, &edit)) {
+                    edits.push(edit);
+                }
+            }
+        }
+
+        if file.module == old_parent {
+            if let Some(item) = module_declaration(&file.ast, &old_module[old_module.len() - 1]) {
+                if changes_parent {
+                    declaration_prefix = Some(declaration_prefix_of(&file.content, item));
+                    edits.push(delete_declaration(item));
+                } else {
+                    let span = item.ident.span();
+                    edits.push(edit_at(span.start(), span.end(), new_name.clone()));
+                }
+            }
+        }
+
+        if !edits.is_empty() {
+            edits.sort_by_key(|e| (e.start_line, e.start_column));
+            changes.insert(file.path.to_string_lossy().into_owned(), edits);
+        }
+    }
+
+    if changes_parent {
+        let parent_file = files
+            .iter()
+            .find(|file| file.module == new_parent && file.path != old_path)
+            .ok_or_else(|| {
+                PluginApiError::invalid_input(format!(
+                    "No file defines the destination module `crate::{}`",
+                    new_parent.join("::")
+                ))
+            })?;
+        // After the last `mod` declaration, or at the top of the file
+        let line = parent_file
+            .ast
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Mod(item) => item.semi.map(|semi| semi.span.end().line + 1),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(1);
+        let prefix = declaration_prefix.unwrap_or_else(|| "mod ".to_string());
+        let insert = format!("{}{};\n", prefix, new_name);
+        let edits = changes
+            .entry(parent_file.path.to_string_lossy().into_owned())
+            .or_default();
+        edits.push(edit_at(
+            LineColumn { line, column: 0 },
+            LineColumn { line, column: 0 },
+            insert,
+        ));
+        edits.sort_by_key(|e| (e.start_line, e.start_column));
+    }
+
+    debug!(
+        old_path = %old_path.display(),
+        new_path = %new_path.display(),
+        files_changed = changes.len(),
+        "Planned file move"
+    );
+    Ok(WorkspaceEdit { changes })
+}
+
+/// Directory holding the crate root file (`lib.rs` or `main.rs`) of `file`
+fn crate_dir(root: &Path, file: &Path) -> Option<PathBuf> {
+    file.ancestors()
+        .skip(1)
+        .take_while(|dir| dir.starts_with(root))
+        .find(|dir| dir.join("lib.rs").is_file() || dir.join("main.rs").is_file())
+        .map(Path::to_path_buf)
+}
+
+/// Module path of `file` within the crate rooted at `crate_dir`
+fn module_path(crate_dir: &Path, file: &Path) -> Option<Vec<String>> {
+    let relative = file.strip_prefix(crate_dir).ok()?;
+    let mut segments: Vec<String> = relative
+        .with_extension("")
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    match segments.last().map(String::as_str) {
+        Some("mod") => {
+            segments.pop();
+        }
+        Some("lib") | Some("main") if segments.len() == 1 => {
+            segments.pop();
+        }
+        _ => {}
+    }
+    Some(segments)
+}
+
+/// Every parsable `.rs` file under `crate_dir`, sorted by path
+fn crate_files(crate_dir: &Path) -> Vec<CrateFile> {
+    let mut paths: Vec<PathBuf> = WalkBuilder::new(crate_dir)
+        .git_ignore(true)
+        .require_git(false)
+        .build()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_some_and(|ft| ft.is_file()))
+        .map(|entry| entry.into_path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("rs"))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let module = module_path(crate_dir, &path)?;
+            let content = std::fs::read_to_string(&path)
+                .map_err(|e| debug!(file = %path.display(), error = %e, "Skipping unreadable file"))
+                .ok()?;
+            let ast = syn::parse_file(&content)
+                .map_err(|e| debug!(file = %path.display(), error = %e, "Skipping unparsable file"))
+                .ok()?;
+            Some(CrateFile {
+                path,
+                module,
+                content,
+                ast,
+            })
+        })
+        .collect()
+}
+
+/// Spell `target` as seen from the module `from`, if the style can reach it
+fn spell(style: PathStyle, from: &[String], target: &[String]) -> Option<String> {
+    match style {
+        PathStyle::Absolute => Some(format!("crate::{}", target.join("::"))),
+        PathStyle::Relative => target
+            .strip_prefix(from)
+            .filter(|rest| !rest.is_empty())
+            .map(|rest| rest.join("::")),
+        PathStyle::SelfRelative => {
+            spell(PathStyle::Relative, from, target).map(|relative| format!("self::{}", relative))
+        }
+        PathStyle::Super(levels) => {
+            let base = &from[..from.len().checked_sub(levels)?];
+            let rest = target.strip_prefix(base).filter(|rest| !rest.is_empty())?;
+            Some(format!("{}{}", "super::".repeat(levels), rest.join("::")))
+        }
+    }
+}
+
+/// Edits rewriting the paths that name `old_module` in `file`
+///
+/// The scanner finds every `use` and qualified path mentioning the module's
+/// name; the path written before the name, through any enclosing `use`
+/// groups, tells whether it is the old module and in which style.
+fn path_edits(
+    scanner: &dyn ModuleReferenceScanner,
+    file: &CrateFile,
+    module_after: &[String],
+    old_module: &[String],
+    new_module: &[String],
+) -> Vec<TextEdit> {
+    let name = &old_module[old_module.len() - 1];
+    let references = match scanner.scan_references(&file.content, name, ScanScope::QualifiedPaths) {
+        Ok(references) => references,
+        Err(e) => {
+            debug!(file = %file.path.display(), error = %e, "Skipping unscannable file");
+            return Vec::new();
+        }
+    };
+    let source = SourceText::new(&file.content);
+    let styles: Vec<PathStyle> = [
+        PathStyle::Absolute,
+        PathStyle::Relative,
+        PathStyle::SelfRelative,
+    ]
+    .into_iter()
+    .chain((1..=file.module.len()).map(PathStyle::Super))
+    .collect();
+
+    let mut edits: Vec<TextEdit> = Vec::new();
+    for reference in &references {
+        let Some(path) = source.written_path(reference) else {
+            continue;
+        };
+        let written: Vec<&str> = path.segments.iter().map(|(_, s)| s.as_str()).collect();
+        let Some(style) = styles.iter().copied().find(|&style| {
+            spell(style, &file.module, old_module)
+                .is_some_and(|spelling| spelling.split("::").eq(written.iter().copied()))
+        }) else {
+            continue;
+        };
+        let replacement = spell(style, module_after, new_module)
+            .or_else(|| spell(PathStyle::Absolute, module_after, new_module))
+            .unwrap_or_default();
+        let replacement: Vec<&str> = replacement.split("::").collect();
+
+        // Segments before the innermost group brace stay as written
+        let kept = path.grouped;
+        if replacement.len() <= kept || replacement[..kept] != written[..kept] {
+            debug!(
+                file = %file.path.display(),
+                line = reference.line,
+                "Skipping grouped path whose prefix no longer leads to the module"
+            );
+            continue;
+        }
+        let start = source.position(path.segments[kept].0);
+        let end = LineColumn {
+            line: reference.line,
+            column: reference.column + reference.length,
+        };
+        let edit = edit_at(start, end, replacement[kept..].join("::"));
+        if !edits.iter().any(|e| same_start(e, &edit)) {
+            edits.push(edit);
+        }
+    }
+    edits
+}
+
+/// A path as written in the source, ending at a scanned reference
+struct WrittenPath {
+    /// Character offset and text of every segment, first to last
+    segments: Vec<(usize, String)>,
+    /// Number of segments written before the innermost `use` group brace
+    grouped: usize,
+}
+
+/// File content indexed by character, for walking back from a reference
+struct SourceText {
+    chars: Vec<char>,
+    line_starts: Vec<usize>,
+}
+
+impl SourceText {
+    fn new(content: &str) -> Self {
+        let chars: Vec<char> = content.chars().collect();
+        let line_starts = std::iter::once(0)
+            .chain(
+                chars
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, c)| **c == '\n')
+                    .map(|(i, _)| i + 1),
+            )
+            .collect();
+        Self { chars, line_starts }
+    }
+
+    /// 1-based line and 0-based column of a character offset
+    fn position(&self, offset: usize) -> LineColumn {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        LineColumn {
+            line,
+            column: offset - self.line_starts[line - 1],
+        }
+    }
+
+    /// The whole path ending with the module name `reference` points at
+    ///
+    /// Walks back over `::` separators and, for `use` declarations, out of
+    /// enclosing groups (`crate::{other, utils}`). Paths starting with a bare
+    /// `::` name an external crate and yield `None`.
+    fn written_path(&self, reference: &ModuleReference) -> Option<WrittenPath> {
+        let start = self.line_starts.get(reference.line - 1)? + reference.column;
+        let mut segments = vec![(start, reference.text.clone())];
+        let mut after_group = None;
+        let mut at = start;
+        loop {
+            let before = self.skip_whitespace_back(at);
+            if before >= 2 && self.chars[before - 2..before] == [':', ':'] {
+                at = self.skip_whitespace_back(before - 2);
+            } else if reference.kind == ReferenceKind::Declaration
+                && before >= 1
+                && matches!(self.chars[before - 1], '{' | ',')
+            {
+                let brace = self.open_brace_before(before)?;
+                let separator = self.skip_whitespace_back(brace);
+                if separator < 2 || self.chars[separator - 2..separator] != [':', ':'] {
+                    return None;
+                }
+                after_group.get_or_insert(segments.len());
+                at = self.skip_whitespace_back(separator - 2);
+            } else {
+                break;
+            }
+            let ident_start = (0..at)
+                .rev()
+                .take_while(|&i| self.chars[i].is_alphanumeric() || self.chars[i] == '_')
+                .last()?;
+            segments.push((ident_start, self.chars[ident_start..at].iter().collect()));
+            at = ident_start;
+        }
+        segments.reverse();
+        let grouped = after_group.map_or(0, |after| segments.len() - after);
+        Some(WrittenPath { segments, grouped })
+    }
+
+    /// Offset just past the last non-whitespace character before `offset`
+    fn skip_whitespace_back(&self, offset: usize) -> usize {
+        (0..offset)
+            .rev()
+            .find(|&i| !self.chars[i].is_whitespace())
+            .map_or(0, |i| i + 1)
+    }
+
+    /// Offset of the unclosed `{` opening the group that contains `offset`
+    fn open_brace_before(&self, offset: usize) -> Option<usize> {
+        let mut depth = 0usize;
+        for i in (0..offset).rev() {
+            match self.chars[i] {
+                '}' => depth += 1,
+                '{' if depth == 0 => return Some(i),
+                '{' => depth -= 1,
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// Edits making the leading `super::` chains of the moved file absolute
+fn super_edits(file: &CrateFile, module_after: &[String]) -> Vec<TextEdit> {
+    struct SuperChains(Vec<(LineColumn, LineColumn, usize)>);
+    impl<'ast> Visit<'ast> for SuperChains {
+        fn visit_item_use(&mut self, node: &'ast ItemUse) {
+            let mut tree = &node.tree;
+            let mut chain: Option<(LineColumn, LineColumn)> = None;
+            let mut levels = 0;
+            while let UseTree::Path(path) = tree {
+                if path.ident != "super" {
+                    break;
+                }
+                let span = path.ident.span();
+                chain = Some((chain.map_or(span.start(), |(start, _)| start), span.end()));
+                levels += 1;
+                tree = &path.tree;
+            }
+            if let Some((start, end)) = chain {
+                self.0.push((start, end, levels));
+            }
+        }
+    }
+
+    let mut chains = SuperChains(Vec::new());
+    chains.visit_file(&file.ast);
+
+    chains
+        .0
+        .into_iter()
+        .filter(|(start, end, _)| start.line == end.line)
+        .filter_map(|(start, end, levels)| {
+            let base = &file.module[..file.module.len().checked_sub(levels)?];
+            let same_base = module_after
+                .len()
+                .checked_sub(levels)
+                .is_some_and(|len| &module_after[..len] == base);
+            if same_base {
+                return None;
+            }
+            let absolute: String = std::iter::once("crate")
+                .chain(base.iter().map(String::as_str))
+                .collect::<Vec<_>>()
+                .join("::");
+            Some(edit_at(start, end, absolute))
+        })
+        .collect()
+}
+
+/// The `mod name;` declaration at the top level of `ast`
+fn module_declaration<'a>(ast: &'a syn::File, name: &str) -> Option<&'a ItemMod> {
+    ast.items.iter().find_map(|item| match item {
+        Item::Mod(item) if item.ident == name && item.content.is_none() => Some(item),
+        _ => None,
+    })
+}
+
+/// Text preceding the module name in a declaration (`pub(crate) mod `)
+fn declaration_prefix_of(content: &str, item: &ItemMod) -> String {
+    let ident = item.ident.span().start();
+    content
+        .lines()
+        .nth(ident.line - 1)
+        .map(|line| line.chars().take(ident.column).collect::<String>())
+        .map(|prefix| prefix.trim_start().to_string())
+        .unwrap_or_else(|| "mod ".to_string())
+}
+
+/// Edit removing the whole lines of a declaration, attributes included
+fn delete_declaration(item: &ItemMod) -> TextEdit {
+    let first = item
+        .attrs
+        .first()
+        .map_or(item.mod_token.span.start().line, |attr| {
+            attr.pound_token.span.start().line
+        });
+    let last = item
+        .semi
+        .map_or(item.ident.span().end().line, |semi| semi.span.end().line);
+    edit_at(
+        LineColumn {
+            line: first,
+            column: 0,
+        },
+        LineColumn {
+            line: last + 1,
+            column: 0,
+        },
+        String::new(),
+    )
+}
+
+fn edit_at(start: LineColumn, end: LineColumn, new_text: String) -> TextEdit {
+    TextEdit {
+        start_line: start.line,
+        start_column: start.column,
+        end_line: end.line,
+        end_column: end.column,
+        new_text,
+    }
+}
+
+fn same_start(a: &TextEdit, b: &TextEdit) -> bool {
+    (a.start_line, a.start_column) == (b.start_line, b.start_column)
+}
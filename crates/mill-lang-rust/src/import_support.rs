@@ -234,6 +234,17 @@ impl ImportRenameSupport for RustImportSupport {
             }
         };
 
+        // A `use path::Item as old_ident;` alias shadows the old name: body
+        // references like `old_ident::f()` then point at the alias, not the
+        // renamed crate/module, and must be left alone.
+        if Self::use_aliases(content).contains(&old_rust_ident) {
+            tracing::debug!(
+                alias = %old_rust_ident,
+                "Old name is a use alias; skipping qualified path rewrite"
+            );
+            return (result, changes_count);
+        }
+
         for line in lines {
             let trimmed = line.trim();
 
@@ -368,6 +379,29 @@ enum ImportPrefix {
 
 // Helper methods for RustImportSupport
 impl RustImportSupport {
+    /// Collect the aliases introduced by `use ... as alias;` anywhere in the file
+    ///
+    /// Returns an empty set if the content does not parse.
+    fn use_aliases(content: &str) -> std::collections::HashSet<String> {
+        use syn::visit::Visit;
+
+        #[derive(Default)]
+        struct AliasVisitor {
+            aliases: std::collections::HashSet<String>,
+        }
+        impl<'ast> Visit<'ast> for AliasVisitor {
+            fn visit_use_rename(&mut self, node: &'ast syn::UseRename) {
+                self.aliases.insert(node.rename.to_string());
+            }
+        }
+
+        let mut visitor = AliasVisitor::default();
+        if let Ok(file) = syn::parse_file(content) {
+            visitor.visit_file(&file);
+        }
+        visitor.aliases
+    }
+
     /// Count the number of nested super/self prefixes in a UseTree.
     ///
     /// For `use super::super::utils::Thing;`, this returns (Super, 2).
@@ -771,6 +805,46 @@ use other::stuff;
         assert!(result.contains("other::stuff"));
     }
 
+    #[test]
+    fn test_rewrite_imports_for_rename_skips_alias_in_nested_group() {
+        let support = RustImportSupport;
+        let content =
+            "use other::{inner::{Thing as old}, Else};\n\nfn run() {\n    old::method();\n}\n";
+
+        let (result, changes) =
+            ImportRenameSupport::rewrite_imports_for_rename(&support, content, "old", "new");
+
+        assert_eq!(changes, 0);
+        assert!(result.contains("use other::{inner::{Thing as old}, Else};"));
+        assert!(result.contains("    old::method();"));
+    }
+
+    #[test]
+    fn test_rewrite_imports_for_rename_skips_alias_beside_glob() {
+        let support = RustImportSupport;
+        let content = "use other::{*, Thing as old};\n\nfn run() {\n    old::method();\n}\n";
+
+        let (result, changes) =
+            ImportRenameSupport::rewrite_imports_for_rename(&support, content, "old", "new");
+
+        assert_eq!(changes, 0);
+        assert!(result.contains("use other::{*, Thing as old};"));
+        assert!(result.contains("    old::method();"));
+    }
+
+    #[test]
+    fn test_rewrite_imports_for_rename_skips_alias_named_like_old() {
+        let support = RustImportSupport;
+        let content = "use other::Thing as old;\n\nfn run() {\n    old::method();\n}\n";
+
+        let (result, changes) =
+            ImportRenameSupport::rewrite_imports_for_rename(&support, content, "old", "new");
+
+        assert_eq!(changes, 0);
+        assert!(result.contains("use other::Thing as old;"));
+        assert!(result.contains("    old::method();"));
+    }
+
     #[test]
     fn test_contains_import_no_false_positives() {
         let support = RustImportSupport;
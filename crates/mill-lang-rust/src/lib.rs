@@ -31,20 +31,27 @@ pub mod dependency_analysis;
 // Reference detection for rename/move operations
 pub mod reference_detector;
 
+// Single-file move planning
+mod file_move;
+
 use async_trait::async_trait;
 use mill_lang_common::{
-    define_language_plugin, impl_capability_delegations, impl_language_plugin_basics,
-    manifest_templates::{ManifestTemplate, TomlManifestTemplate},
-    read_manifest,
+    define_language_plugin, impl_capability_delegations, impl_language_plugin_basics, read_manifest,
 };
 use mill_plugin_api::{
     FileDiscovery, LanguagePlugin, ManifestData, ParsedSource, PluginResult, StandardFileDiscovery,
 };
 use std::path::Path;
+use std::time::Instant;
 
 // Import helpers from the imports module
 use imports::{compute_module_path_from_file, find_crate_name_from_cargo_toml};
 
+/// Version of the JSON shape this plugin stores in `ParsedSource::data`
+///
+/// Bump it whenever a `data` field is renamed, removed, or changes meaning.
+pub const AST_SCHEMA_VERSION: u32 = 1;
+
 #[derive(Default)]
 pub struct RustFileDiscovery;
 
@@ -79,30 +86,81 @@ impl FileDiscovery for RustFileDiscovery {
     }
 }
 
+#[cfg(test)]
+thread_local! {
+    /// Number of times [`ast_json`] ran on this thread
+    static AST_JSON_BUILDS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Build the `ParsedSource::data` JSON for `ast`, or for a degraded parse
+/// when the source didn't parse
+fn ast_json(ast: Option<&syn::File>, timing: serde_json::Value) -> serde_json::Value {
+    #[cfg(test)]
+    AST_JSON_BUILDS.with(|builds| builds.set(builds.get() + 1));
+
+    // For now, we'll store a simplified representation
+    match ast {
+        Some(ast) => serde_json::json!({
+            "schema_version": AST_SCHEMA_VERSION,
+            "type": "File",
+            "items_count": ast.items.len(),
+            "shebang": ast.shebang,
+            "degraded": false,
+            "timing": timing,
+        }),
+        None => serde_json::json!({
+            "schema_version": AST_SCHEMA_VERSION,
+            "type": "File",
+            "degraded": true,
+            "timing": timing,
+        }),
+    }
+}
+
 #[async_trait]
 impl LanguagePlugin for RustPlugin {
     impl_language_plugin_basics!();
 
     async fn parse(&self, source: &str) -> PluginResult<ParsedSource> {
-        // Extract all symbols from the source code
-        let symbols = parser::extract_symbols(source)?;
-
         // Parse the source into a syn AST and serialize it as JSON
-        let ast: syn::File = syn::parse_file(source).map_err(|e| {
-            mill_plugin_api::PluginApiError::parse(format!("Failed to parse Rust code: {}", e))
-        })?;
-
-        // Serialize the AST to JSON using quote
-        // For now, we'll store a simplified representation
-        let ast_json = serde_json::json!({
-            "type": "File",
-            "items_count": ast.items.len(),
-            "shebang": ast.shebang,
-        });
+        let started = Instant::now();
+        let ast: syn::File = match syn::parse_file(source) {
+            Ok(ast) => ast,
+            Err(e) => {
+                // Keep whatever symbols can be recognized line by line and
+                // report the syntax error instead of failing outright
+                let parse_ms = started.elapsed().as_secs_f64() * 1000.0;
+                let diagnostic = parser::syntax_diagnostic(&e);
+                tracing::warn!(
+                    error = %diagnostic.message,
+                    "Rust source did not parse, returning line-based symbols"
+                );
+                return Ok(ParsedSource {
+                    data: ast_json(None, serde_json::json!({ "parse_ms": parse_ms })),
+                    symbols: parser::extract_symbols_fallback(source),
+                    diagnostics: vec![diagnostic],
+                });
+            }
+        };
+        let parse_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+        // Extract all symbols from the parsed file
+        let started = Instant::now();
+        let symbols = parser::extract_file_symbols(&ast);
+        let symbol_extraction_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+        let data = ast_json(
+            Some(&ast),
+            serde_json::json!({
+                "parse_ms": parse_ms,
+                "symbol_extraction_ms": symbol_extraction_ms,
+            }),
+        );
 
         Ok(ParsedSource {
-            data: ast_json,
+            data,
             symbols,
+            diagnostics: Vec::new(),
         })
     }
 
@@ -122,6 +180,14 @@ impl LanguagePlugin for RustPlugin {
         parser::list_functions(source)
     }
 
+    async fn parse_symbols_only(&self, source: &str) -> PluginResult<Vec<mill_plugin_api::Symbol>> {
+        // Same symbols as `parse`, without building the AST JSON
+        match syn::parse_file(source) {
+            Ok(ast) => Ok(parser::extract_file_symbols(&ast)),
+            Err(_) => Ok(parser::extract_symbols_fallback(source)),
+        }
+    }
+
     fn analyze_detailed_imports(
         &self,
         source: &str,
@@ -410,6 +476,32 @@ impl mill_plugin_api::RefactoringProvider for RustPlugin {
             mill_plugin_api::PluginApiError::internal(format!("Rust refactoring error: {}", e))
         })
     }
+
+    fn supports_reflow_signature(&self) -> bool {
+        true
+    }
+
+    async fn plan_reflow_signature(
+        &self,
+        source: &str,
+        function_name: &str,
+        max_width: usize,
+        file_path: &str,
+    ) -> mill_plugin_api::PluginResult<mill_foundation::protocol::EditPlan> {
+        refactoring::plan_reflow_signature(source, function_name, max_width, file_path)
+    }
+
+    fn supports_organize_imports(&self) -> bool {
+        true
+    }
+
+    async fn plan_organize_imports(
+        &self,
+        source: &str,
+        file_path: &str,
+    ) -> mill_plugin_api::PluginResult<mill_foundation::protocol::EditPlan> {
+        refactoring::plan_organize_imports(source, file_path)
+    }
 }
 
 impl mill_plugin_api::ImportAnalyzer for RustPlugin {
@@ -466,6 +558,54 @@ impl mill_plugin_api::ManifestUpdater for RustPlugin {
         )
         .await
     }
+
+    async fn remove_dependency(
+        &self,
+        manifest_content: &str,
+        dep_name: &str,
+    ) -> mill_plugin_api::PluginResult<String> {
+        manifest::remove_dependency(manifest_content, dep_name)
+    }
+
+    async fn prune_unused_dependencies(
+        &self,
+        manifest_path: &Path,
+        used_modules: &[String],
+    ) -> mill_plugin_api::PluginResult<String> {
+        let content = read_manifest(manifest_path).await?;
+        // Dev-dependencies are used by test, bench and example targets, which
+        // the caller's import scan of the package sources does not cover
+        let mut used = used_modules.to_vec();
+        if let Some(package_dir) = manifest_path.parent() {
+            used.extend(dev_target_path_roots(package_dir).await);
+        }
+        manifest::prune_unused_dependencies(&content, &used, manifest::PruneOptions::default())
+    }
+}
+
+/// Path roots referenced by the `.rs` files under `tests/`, `benches/` and `examples/`
+async fn dev_target_path_roots(package_dir: &Path) -> Vec<String> {
+    let mut roots = Vec::new();
+    for target_dir in ["tests", "benches", "examples"] {
+        let dir = package_dir.join(target_dir);
+        if !dir.is_dir() {
+            continue;
+        }
+        for entry in ignore::WalkBuilder::new(&dir)
+            .hidden(false)
+            .build()
+            .flatten()
+        {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("rs") {
+                continue;
+            }
+            if let Ok(source) = tokio::fs::read_to_string(path).await {
+                roots.extend(parser::path_roots(&source));
+            }
+        }
+    }
+    roots
 }
 
 // ============================================================================
@@ -500,6 +640,24 @@ impl mill_plugin_api::ModuleDeclarationSupport for RustPlugin {
     }
 }
 
+// ============================================================================
+// Complexity Analysis
+// ============================================================================
+
+impl mill_lang_common::AnalysisMetadata for RustPlugin {
+    fn complexity_keywords(&self) -> &[&str] {
+        &["if", "for", "while", "loop", "match"]
+    }
+
+    fn nesting_penalty(&self) -> u32 {
+        1
+    }
+
+    fn lexical_syntax(&self) -> mill_plugin_api::identifier_scanner::LexicalSyntax {
+        mill_plugin_api::identifier_scanner::LexicalSyntax::RUST
+    }
+}
+
 // ============================================================================
 // Plugin-specific helper methods for consumers
 // These are NOT part of capability traits - they're Rust-specific utilities
@@ -532,6 +690,23 @@ impl RustPlugin {
         }
     }
 
+    /// Analyze a workspace member's Cargo.toml, resolving `workspace = true`
+    /// dependencies and package version against the manifest in `workspace_root`
+    pub async fn analyze_manifest_in_workspace(
+        &self,
+        path: &Path,
+        workspace_root: &Path,
+    ) -> PluginResult<ManifestData> {
+        if path.file_name().and_then(|s| s.to_str()) != Some("Cargo.toml") {
+            return Err(mill_plugin_api::PluginApiError::invalid_input(format!(
+                "Expected Cargo.toml, got: {:?}",
+                path.file_name()
+            )));
+        }
+
+        manifest::load_cargo_toml_in_workspace(path, workspace_root).await
+    }
+
     /// Locate module files for a given module path
     ///
     /// Navigates the Rust module system to find .rs files for a module path like "services::planner"
@@ -540,16 +715,18 @@ impl RustPlugin {
         package_path: &Path,
         module_path: &str,
     ) -> PluginResult<Vec<std::path::PathBuf>> {
+        // Normalize module path (accepts ::, . and / separators)
+        let normalized =
+            mill_lang_common::normalize_module_path(module_path, Self::METADATA.module_separator);
+
         // Handle empty module path
-        if module_path.is_empty() {
+        if normalized.is_empty() {
             return Err(mill_plugin_api::PluginApiError::invalid_input(
                 "Module path cannot be empty",
             ));
         }
 
-        // Normalize module path (handle both:: and . separators)
-        let normalized = module_path.replace('.', "::");
-        let parts: Vec<&str> = normalized.split("::").collect();
+        let parts: Vec<&str> = normalized.split(Self::METADATA.module_separator).collect();
 
         // Start from src/ directory
         let src_dir = package_path.join("src");
@@ -608,22 +785,20 @@ impl RustPlugin {
         Ok(import_infos.iter().map(|i| i.module_path.clone()).collect())
     }
 
-    /// Generate a Cargo.toml manifest
+    /// Generate a Cargo.toml manifest (edition 2021)
     pub fn generate_manifest(&self, package_name: &str, dependencies: &[String]) -> String {
-        let template = TomlManifestTemplate::new("package");
-        let mut manifest = template.generate(package_name, "0.1.0", dependencies);
-
-        // Add Rust-specific edition field
-        if let Some(version_pos) = manifest.find("version = \"0.1.0\"") {
-            let insert_pos = manifest[version_pos..]
-                .find('\n')
-                .map(|p| version_pos + p + 1);
-            if let Some(pos) = insert_pos {
-                manifest.insert_str(pos, "edition = \"2021\"\n");
-            }
-        }
+        self.generate_manifest_with_options(package_name, dependencies, &ManifestOptions::default())
+    }
 
-        manifest
+    /// Generate a Cargo.toml manifest with a chosen edition, features, and
+    /// optional workspace inheritance
+    pub fn generate_manifest_with_options(
+        &self,
+        package_name: &str,
+        dependencies: &[String],
+        options: &ManifestOptions,
+    ) -> String {
+        manifest::generate_cargo_toml(package_name, dependencies, options)
     }
 
     /// Remove module declaration from source
@@ -635,9 +810,7 @@ impl RustPlugin {
         use syn::{File, Item};
 
         // Parse the source file
-        let ast: File = syn::parse_file(source).map_err(|e| {
-            mill_plugin_api::PluginApiError::parse(format!("Failed to parse Rust code: {}", e))
-        })?;
+        let ast: File = syn::parse_file(source).map_err(|e| parser::syntax_error(&e, source))?;
 
         // Filter out module declarations matching the name
         let filtered_items: Vec<Item> = ast
@@ -790,50 +963,80 @@ impl RustPlugin {
     }
 
     /// Find module references with full signature
+    ///
+    /// Each reference points at the module segment(s) inside a `use`
+    /// declaration, using the real source span of the path segments. Nested
+    /// `use` items (inside functions or inline modules) are only included when
+    /// `scope` is wider than `TopLevelOnly`. Under `QualifiedPaths` and `All`,
+    /// paths qualified by the module outside `use` (`utils::helper()`) are
+    /// reported too, as `ReferenceKind::QualifiedPath`. Mentions inside
+    /// comments are only reported, as `ReferenceKind::Comment`, under
+    /// `Comments`.
     pub fn find_module_references(
         &self,
         content: &str,
         module_to_find: &str,
-        _scope: mill_plugin_api::ScanScope,
+        scope: mill_plugin_api::ScanScope,
     ) -> PluginResult<Vec<mill_plugin_api::ModuleReference>> {
-        use mill_plugin_api::{ModuleReference, ReferenceKind};
-        use syn::{File, Item};
+        use mill_plugin_api::identifier_scanner::{find_comment_occurrences, LexicalSyntax};
+        use mill_plugin_api::{ModuleReference, ReferenceKind, ScanScope};
+
+        if scope == ScanScope::Comments {
+            return Ok(
+                find_comment_occurrences(content, module_to_find, LexicalSyntax::RUST)
+                    .into_iter()
+                    .map(|occurrence| ModuleReference {
+                        line: occurrence.line,
+                        column: occurrence.column,
+                        length: module_to_find.chars().count(),
+                        text: module_to_find.to_string(),
+                        kind: ReferenceKind::Comment,
+                    })
+                    .collect(),
+            );
+        }
 
-        let ast: File = syn::parse_file(content).map_err(|e| {
-            mill_plugin_api::PluginApiError::parse(format!("Failed to parse Rust code: {}", e))
-        })?;
+        let ast: syn::File =
+            syn::parse_file(content).map_err(|e| parser::syntax_error(&e, content))?;
 
-        let mut references = Vec::new();
+        let include_nested = scope != ScanScope::TopLevelOnly;
         let lines: Vec<&str> = content.lines().collect();
 
-        for (line_num, item) in ast.items.iter().enumerate() {
-            if let Item::Use(item_use) = item {
-                let use_str = quote::quote!(#item_use).to_string();
-                if use_str.contains(module_to_find) {
-                    // Get the actual line length from source content, not from quote-generated string
-                    // The quote-generated string may have different formatting/whitespace
-                    let actual_line = if line_num < lines.len() {
-                        lines[line_num]
-                    } else {
-                        ""
-                    };
-
-                    // We need to replace the entire use statement line, not just the module name
-                    // The text field contains the full formatted import from quote::quote!
-                    // So column should be 0 and length should be the entire line length
-                    let column = 0;
-                    let length = actual_line.len();
-
-                    references.push(ModuleReference {
-                        line: line_num + 1, // 1-based
-                        column,
-                        length,
-                        text: use_str,
-                        kind: ReferenceKind::Declaration,
-                    });
-                }
-            }
-        }
+        let qualified = if matches!(scope, ScanScope::QualifiedPaths | ScanScope::All) {
+            parser::find_qualified_path_spans(&ast, module_to_find)
+        } else {
+            Vec::new()
+        };
+        let spans = parser::find_use_path_spans(&ast, module_to_find, include_nested)
+            .into_iter()
+            .map(|span| (span, ReferenceKind::Declaration))
+            .chain(
+                qualified
+                    .into_iter()
+                    .map(|span| (span, ReferenceKind::QualifiedPath)),
+            );
+
+        let mut references: Vec<ModuleReference> = spans
+            .filter_map(|((start, end), kind)| {
+                // Spans are 1-based lines and 0-based character columns
+                let line = lines.get(start.line.checked_sub(1)?)?;
+                let length = if end.line == start.line {
+                    end.column.saturating_sub(start.column)
+                } else {
+                    line.chars().count().saturating_sub(start.column)
+                };
+                let text: String = line.chars().skip(start.column).take(length).collect();
+
+                Some(ModuleReference {
+                    line: start.line,
+                    column: start.column,
+                    length,
+                    text,
+                    kind,
+                })
+            })
+            .collect();
+        references.sort_by_key(|r| (r.line, r.column));
 
         Ok(references)
     }
@@ -1146,11 +1349,15 @@ impl RustPlugin {
 }
 
 // Re-export public API items
-pub use manifest::{load_cargo_toml, parse_cargo_toml, rename_dependency};
-pub use parser::{extract_symbols, list_functions, parse_imports, rewrite_use_tree};
+pub use manifest::{load_cargo_toml, parse_cargo_toml, rename_dependency, ManifestOptions};
+pub use parser::{
+    expand_glob_import, expand_glob_import_from, extract_symbols, list_function_signatures,
+    list_functions, parse_imports, rewrite_use_tree, FunctionSignature,
+};
 pub use test_fixtures::rust_test_fixtures;
 pub use workspace::{
-    add_path_dependency, add_workspace_member, generate_workspace_manifest, is_workspace_manifest,
+    add_path_dependency, add_workspace_member, generate_workspace_manifest,
+    generate_workspace_manifest_with_options, is_workspace_manifest, WorkspaceManifestOptions,
 };
 
 #[cfg(test)]
@@ -1158,6 +1365,37 @@ mod tests {
     use super::*;
     use mill_plugin_api::ScanScope;
 
+    #[tokio::test]
+    async fn test_cognitive_complexity_of_parsed_functions() {
+        let plugin = RustPlugin::default();
+        let source = r#"fn nested(items: &[i32]) {
+    for item in items {
+        if *item > 0 {
+            println!("if positive");
+        }
+    }
+}
+
+fn flat(items: &[i32]) {
+    // if this were nested it would score more
+    for item in items {
+        println!("while {item}");
+    }
+}
+"#;
+        let parsed = plugin.parse(source).await.unwrap();
+
+        let report =
+            mill_lang_common::compute_cognitive_complexity(source, &plugin, &parsed.symbols);
+
+        let scores: Vec<_> = report
+            .functions
+            .iter()
+            .map(|function| (function.name.as_str(), function.score))
+            .collect();
+        assert_eq!(scores, vec![("nested", 3), ("flat", 1)]);
+    }
+
     #[tokio::test]
     async fn test_rust_plugin_basic() {
         let plugin = RustPlugin::new();
@@ -1167,6 +1405,10 @@ mod tests {
         assert_eq!(plugin_trait.metadata().extensions, &["rs"]);
         assert!(plugin_trait.handles_extension("rs"));
         assert!(!plugin_trait.handles_extension("py"));
+
+        let lsp = plugin_trait.lsp_config().unwrap();
+        assert_eq!(lsp.command, "rust-analyzer");
+        assert_eq!(lsp.arguments, &["rust-analyzer"]);
     }
 
     #[tokio::test]
@@ -1186,6 +1428,8 @@ struct TestStruct {
 
         let parsed = plugin_trait.parse(source).await.unwrap();
 
+        assert_eq!(parsed.schema_version(), Some(AST_SCHEMA_VERSION));
+
         // Should extract both function and struct
         assert_eq!(parsed.symbols.len(), 2);
 
@@ -1207,6 +1451,18 @@ struct TestStruct {
         assert_eq!(struc.kind, mill_plugin_api::SymbolKind::Struct);
     }
 
+    #[tokio::test]
+    async fn test_rust_plugin_parse_reports_timing() {
+        let plugin = RustPlugin::new();
+        let plugin_trait: &dyn LanguagePlugin = plugin.as_ref();
+
+        let parsed = plugin_trait.parse("fn main() {}\n").await.unwrap();
+
+        let timing = &parsed.data["timing"];
+        assert!(timing["parse_ms"].as_f64().unwrap() >= 0.0);
+        assert!(timing["symbol_extraction_ms"].as_f64().unwrap() >= 0.0);
+    }
+
     #[tokio::test]
     async fn test_rust_plugin_list_functions() {
         let plugin = RustPlugin::new();
@@ -1233,8 +1489,53 @@ impl MyStruct {
         let plugin_trait: &dyn LanguagePlugin = plugin.as_ref();
         let invalid_source = "fn incomplete_function {";
 
-        let result = plugin_trait.parse(invalid_source).await;
-        assert!(result.is_err());
+        let parsed = plugin_trait.parse(invalid_source).await.unwrap();
+        assert_eq!(parsed.data["degraded"], true);
+        assert_eq!(parsed.diagnostics.len(), 1);
+        assert!(parsed.diagnostics[0]
+            .message
+            .starts_with("Failed to parse Rust code"));
+    }
+
+    #[tokio::test]
+    async fn test_rust_plugin_parse_keeps_symbols_around_syntax_error() {
+        let plugin = RustPlugin::new();
+        let plugin_trait: &dyn LanguagePlugin = plugin.as_ref();
+        let source = r#"pub struct Config {
+    name: String,
+}
+
+fn first() -> u32 {
+    1
+}
+
+fn broken(x: u32 -> u32 {
+    x
+}
+
+#[test]
+fn last() {}
+"#;
+
+        let parsed = plugin_trait.parse(source).await.unwrap();
+        assert_eq!(parsed.data["degraded"], true);
+        assert_eq!(parsed.schema_version(), Some(AST_SCHEMA_VERSION));
+
+        let names: Vec<&str> = parsed.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert!(names.contains(&"Config"));
+        assert!(names.contains(&"first"));
+        assert!(names.contains(&"last"));
+        let last = parsed.symbols.iter().find(|s| s.name == "last").unwrap();
+        assert_eq!(last.kind, mill_plugin_api::SymbolKind::Function);
+        assert_eq!(last.location.line, 13);
+        assert!(last.is_test);
+
+        assert_eq!(parsed.diagnostics.len(), 1);
+        let location = parsed.diagnostics[0].location.unwrap();
+        assert_eq!(
+            location.line, 8,
+            "error is reported on the broken signature"
+        );
     }
 
     #[tokio::test]
@@ -1643,6 +1944,62 @@ fn тестфункция() {
         let _ = scanner.scan_references(content, "std::collections", ScanScope::All);
     }
 
+    #[tokio::test]
+    async fn test_parse_symbols_only_includes_types() {
+        let plugin = RustPlugin::new();
+        let source = "struct Config;\nenum Mode { A, B }\nfn run() {}\n";
+
+        let symbols = plugin.parse_symbols_only(source).await.unwrap();
+        let kinds: Vec<_> = symbols.iter().map(|s| (s.name.as_str(), s.kind)).collect();
+
+        assert!(kinds.contains(&("Config", mill_plugin_api::SymbolKind::Struct)));
+        assert!(kinds.contains(&("Mode", mill_plugin_api::SymbolKind::Enum)));
+        assert!(kinds.contains(&("run", mill_plugin_api::SymbolKind::Function)));
+    }
+
+    #[tokio::test]
+    async fn test_parse_symbols_only_matches_parse() {
+        let plugin = RustPlugin::new();
+        let valid = "pub struct Config {\n    port: u16,\n}\n\nimpl Config {\n    pub fn new() -> Self {\n        Self { port: 80 }\n    }\n}\n\n#[test]\nfn config_defaults() {}\n";
+        let broken = "pub fn ok() {}\n\nfn broken( {\n";
+
+        for source in [valid, broken] {
+            let parsed = plugin.parse(source).await.unwrap();
+            let listed = plugin.parse_symbols_only(source).await.unwrap();
+            assert!(!listed.is_empty(), "{}", source);
+            assert_eq!(listed, parsed.symbols, "{}", source);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parse_symbols_only_skips_ast_json() {
+        let plugin = RustPlugin::new();
+        let valid = "pub struct Config;
+
+impl Config {
+    pub fn new() -> Self {
+        Self
+    }
+}
+";
+        let broken = "pub fn ok() {}
+
+fn broken( {
+";
+        let builds = || AST_JSON_BUILDS.with(|builds| builds.get());
+
+        for source in [valid, broken] {
+            let before = builds();
+            for _ in 0..100 {
+                plugin.parse_symbols_only(source).await.unwrap();
+            }
+            assert_eq!(builds(), before, "{}", source);
+
+            plugin.parse(source).await.unwrap();
+            assert_eq!(builds(), before + 1, "{}", source);
+        }
+    }
+
     #[tokio::test]
     async fn test_edge_parse_empty_file() {
         let plugin = RustPlugin::new();
@@ -1683,6 +2040,127 @@ fn тестфункция() {
         let _ = scanner.scan_references(content, "std::collections", ScanScope::All);
     }
 
+    #[test]
+    fn test_scan_references_reports_real_spans_in_multiline_file() {
+        let plugin = RustPlugin::new();
+        let scanner = plugin
+            .module_reference_scanner()
+            .expect("Should have scanner");
+        let content = "//! Crate docs\n\nuse std::fmt;\n\n/// Docs\nfn helper() {}\n\nuse old_crate::{\n    config::Settings,\n    util,\n};\n";
+
+        let refs = scanner
+            .scan_references(content, "old_crate", ScanScope::AllUseStatements)
+            .unwrap();
+
+        assert_eq!(refs.len(), 1);
+        assert_eq!((refs[0].line, refs[0].column, refs[0].length), (8, 4, 9));
+        assert_eq!(refs[0].text, "old_crate");
+    }
+
+    #[test]
+    fn test_scan_references_finds_module_in_grouped_use_tree() {
+        let plugin = RustPlugin::new();
+        let scanner = plugin
+            .module_reference_scanner()
+            .expect("Should have scanner");
+        let content = "use a::{b, module_to_find};\nuse c::module_to_find::Item as Alias;\nuse d::module_to_find_more;\n";
+
+        let refs = scanner
+            .scan_references(content, "module_to_find", ScanScope::TopLevelOnly)
+            .unwrap();
+        let spans: Vec<_> = refs.iter().map(|r| (r.line, r.column, r.length)).collect();
+
+        assert_eq!(spans, vec![(1, 11, 14), (2, 7, 14)]);
+        assert!(refs.iter().all(|r| r.text == "module_to_find"));
+    }
+
+    #[test]
+    fn test_scan_references_multi_segment_and_nested_scope() {
+        let plugin = RustPlugin::new();
+        let scanner = plugin
+            .module_reference_scanner()
+            .expect("Should have scanner");
+        let content =
+            "use crate::common::utils::parse;\n\nfn run() {\n    use crate::common::utils;\n}\n";
+
+        let top_level = scanner
+            .scan_references(content, "common::utils", ScanScope::TopLevelOnly)
+            .unwrap();
+        assert_eq!(top_level.len(), 1);
+        assert_eq!((top_level[0].line, top_level[0].column), (1, 11));
+        assert_eq!(top_level[0].text, "common::utils");
+
+        let all = scanner
+            .scan_references(content, "common::utils", ScanScope::AllUseStatements)
+            .unwrap();
+        let lines: Vec<_> = all.iter().map(|r| (r.line, r.column)).collect();
+        assert_eq!(lines, vec![(1, 11), (4, 15)]);
+    }
+
+    #[test]
+    fn test_scan_references_qualified_paths_scope() {
+        use mill_plugin_api::ReferenceKind;
+
+        let plugin = RustPlugin::new();
+        let scanner = plugin
+            .module_reference_scanner()
+            .expect("Should have scanner");
+        let content =
+            "use utils::helper;\n\nfn run(utils: u32) -> u32 {\n    utils::helper() + utils\n}\n";
+
+        let uses = scanner
+            .scan_references(content, "utils", ScanScope::AllUseStatements)
+            .unwrap();
+        assert_eq!(uses.len(), 1);
+
+        let qualified = scanner
+            .scan_references(content, "utils", ScanScope::QualifiedPaths)
+            .unwrap();
+        // The parameter named like the module is not a qualifier
+        let spans: Vec<_> = qualified
+            .iter()
+            .map(|r| (r.line, r.column, r.kind.clone()))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                (1, 4, ReferenceKind::Declaration),
+                (4, 4, ReferenceKind::QualifiedPath)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scan_references_comments_scope() {
+        use mill_plugin_api::ReferenceKind;
+
+        let plugin = RustPlugin::new();
+        let scanner = plugin
+            .module_reference_scanner()
+            .expect("Should have scanner");
+        let content = "/// Thin wrapper over common::utils\nuse crate::common::utils;\n";
+
+        let uses = scanner
+            .scan_references(content, "common::utils", ScanScope::AllUseStatements)
+            .unwrap();
+        assert!(uses.iter().all(|r| r.kind == ReferenceKind::Declaration));
+        assert_eq!(uses.len(), 1);
+
+        let comments = scanner
+            .scan_references(content, "common::utils", ScanScope::Comments)
+            .unwrap();
+        let spans: Vec<_> = comments.iter().map(|r| (r.line, r.column)).collect();
+        assert_eq!(spans, vec![(1, 22)]);
+        assert_eq!(comments[0].kind, ReferenceKind::Comment);
+
+        let all = scanner
+            .scan_references(content, "common::utils", ScanScope::All)
+            .unwrap();
+        // Comment mentions are only reported under `Comments`
+        let kinds: Vec<_> = all.iter().map(|r| r.kind.clone()).collect();
+        assert_eq!(kinds, vec![ReferenceKind::Declaration]);
+    }
+
     // ========================================================================
     // PERFORMANCE TESTS (2 tests)
     // ========================================================================
@@ -1742,4 +2220,48 @@ fn тестфункция() {
         // Result may vary based on parser implementation
         let _ = result;
     }
+
+    #[tokio::test]
+    async fn test_prune_keeps_dev_dependencies_used_by_test_targets() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifest_path = dir.path().join("Cargo.toml");
+        std::fs::write(
+            &manifest_path,
+            r#"[package]
+name = "demo"
+version = "0.1.0"
+
+[dev-dependencies]
+tempfile = "3"
+criterion = "0.5"
+pretty_assertions = "1"
+unused-dev = "1"
+"#,
+        )
+        .unwrap();
+        std::fs::create_dir_all(dir.path().join("tests")).unwrap();
+        std::fs::write(
+            dir.path().join("tests/it.rs"),
+            "use pretty_assertions::assert_eq;\n#[test]\nfn t() { let _ = tempfile::tempdir(); }\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(dir.path().join("benches")).unwrap();
+        std::fs::write(
+            dir.path().join("benches/bench.rs"),
+            "criterion::criterion_main!(benches);\n",
+        )
+        .unwrap();
+
+        let plugin = RustPlugin::new();
+        let updater = plugin.manifest_updater().unwrap();
+        let result = updater
+            .prune_unused_dependencies(&manifest_path, &[])
+            .await
+            .unwrap();
+
+        assert!(result.contains("tempfile"));
+        assert!(result.contains("criterion"));
+        assert!(result.contains("pretty_assertions"));
+        assert!(!result.contains("unused-dev"));
+    }
 }
@@ -2,20 +2,40 @@
 
 use async_trait::async_trait;
 use mill_lang_common::lsp::{
-    check_binary_in_path, decompress_gzip, download_file, get_cache_dir, make_executable,
-    verify_checksum, Platform,
+    binary_version, check_binary_in_path, decompress_gzip, download_file, get_cache_dir,
+    make_executable, verify_checksum, CommandRunner, Platform, SystemCommandRunner,
 };
 use mill_plugin_api::{LspInstaller, PluginApiError, PluginResult};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tracing::{debug, info};
 
 /// Rust LSP installer (rust-analyzer)
-#[derive(Default)]
-pub struct RustLspInstaller;
+pub struct RustLspInstaller {
+    runner: Arc<dyn CommandRunner>,
+}
+
+impl Default for RustLspInstaller {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl RustLspInstaller {
-    pub const fn new() -> Self {
-        Self
+    pub fn new() -> Self {
+        Self::with_runner(Arc::new(SystemCommandRunner))
+    }
+
+    /// Create an installer that runs commands through `runner`
+    pub fn with_runner(runner: Arc<dyn CommandRunner>) -> Self {
+        Self { runner }
+    }
+
+    /// Version reported by the rust-analyzer binary at `binary`
+    fn version_of(&self, binary: &Path) -> PluginResult<Option<String>> {
+        binary_version(self.runner.as_ref(), binary).map_err(|e| {
+            PluginApiError::internal(format!("Failed to query rust-analyzer version: {}", e))
+        })
     }
 
     /// Get download URL for current platform
@@ -90,6 +110,44 @@ impl LspInstaller for RustLspInstaller {
         Ok(None)
     }
 
+    fn installed_version(&self) -> PluginResult<Option<String>> {
+        match self.check_installed()? {
+            Some(binary) => self.version_of(&binary),
+            None => Ok(None),
+        }
+    }
+
+    fn install_hint(&self) -> Option<String> {
+        Some("Run `rustup component add rust-analyzer` or `mill install-lsp rust`".to_string())
+    }
+
+    /// Install rust-analyzer as a rustup component of the `version` toolchain
+    /// (e.g. `stable`, `1.84.0` or `nightly-2025-01-01`)
+    async fn install_version(&self, version: &str) -> PluginResult<()> {
+        info!("Installing rust-analyzer for toolchain {}", version);
+
+        let runner = self.runner.clone();
+        let toolchain = version.to_string();
+        tokio::task::spawn_blocking(move || {
+            runner.run(
+                Path::new("rustup"),
+                &[
+                    "component",
+                    "add",
+                    "rust-analyzer",
+                    "--toolchain",
+                    &toolchain,
+                ],
+            )
+        })
+        .await
+        .map_err(|e| PluginApiError::internal(format!("Task join error: {}", e)))?
+        .map_err(|e| PluginApiError::internal(format!("rustup install failed: {}", e)))?;
+
+        info!("✅ Installed rust-analyzer for toolchain {}", version);
+        Ok(())
+    }
+
     async fn install_lsp(&self, cache_dir: &Path) -> PluginResult<PathBuf> {
         info!("Installing rust-analyzer from GitHub releases");
 
@@ -128,6 +186,59 @@ impl LspInstaller for RustLspInstaller {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use mill_lang_common::lsp::LspResult;
+    use std::sync::Mutex;
+
+    /// Records every command and answers with canned stdout
+    struct MockRunner {
+        stdout: &'static str,
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl MockRunner {
+        fn new(stdout: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                stdout,
+                calls: Mutex::new(Vec::new()),
+            })
+        }
+    }
+
+    impl CommandRunner for MockRunner {
+        fn run(&self, program: &Path, args: &[&str]) -> LspResult<String> {
+            let mut call = vec![program.display().to_string()];
+            call.extend(args.iter().map(|arg| arg.to_string()));
+            self.calls.lock().unwrap().push(call.join(" "));
+            Ok(self.stdout.to_string())
+        }
+    }
+
+    #[test]
+    fn test_version_parsed_from_version_output() {
+        let runner = MockRunner::new("rust-analyzer 1.84.0 (9fc6b43 2025-01-07)\n");
+        let installer = RustLspInstaller::with_runner(runner.clone());
+
+        let version = installer.version_of(Path::new("rust-analyzer")).unwrap();
+
+        assert_eq!(version.as_deref(), Some("1.84.0"));
+        assert_eq!(
+            runner.calls.lock().unwrap().as_slice(),
+            ["rust-analyzer --version"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_install_version_adds_rustup_component() {
+        let runner = MockRunner::new("");
+        let installer = RustLspInstaller::with_runner(runner.clone());
+
+        installer.install_version("1.84.0").await.unwrap();
+
+        assert_eq!(
+            runner.calls.lock().unwrap().as_slice(),
+            ["rustup component add rust-analyzer --toolchain 1.84.0"]
+        );
+    }
 
     #[test]
     fn test_get_download_url() {
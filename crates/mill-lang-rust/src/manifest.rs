@@ -3,12 +3,74 @@
 //! This module provides functionality for parsing and manipulating Cargo.toml
 //! manifest files, extracting dependency information, and updating dependencies.
 
+use mill_lang_common::manifest_templates::{ManifestTemplate, TomlManifestTemplate};
 use mill_lang_common::read_manifest;
 use mill_plugin_api::{Dependency, DependencySource, ManifestData, PluginApiError, PluginResult};
 use std::path::Path;
-use toml_edit::{value, DocumentMut, Item};
+use toml_edit::{value, DocumentMut, Item, TableLike};
+use tracing::warn;
+
+/// Options for generating a new Cargo.toml
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestOptions {
+    /// Rust edition written to `[package] edition` (e.g., "2021", "2024")
+    pub edition: String,
+    /// Inherit `version` and `edition` from the workspace root instead of
+    /// writing literal values (`version.workspace = true`)
+    pub is_workspace_member: bool,
+    /// Entries for the `[features]` table as (feature, enabled features/deps)
+    pub features: Vec<(String, Vec<String>)>,
+}
+
+impl Default for ManifestOptions {
+    fn default() -> Self {
+        Self {
+            edition: "2021".to_string(),
+            is_workspace_member: false,
+            features: Vec::new(),
+        }
+    }
+}
+
+/// Generate Cargo.toml content for a new package
+pub fn generate_cargo_toml(
+    package_name: &str,
+    dependencies: &[String],
+    options: &ManifestOptions,
+) -> String {
+    let template = TomlManifestTemplate::new("package");
+    let mut manifest = template.generate(package_name, "0.1.0", dependencies);
+
+    let version_line = "version = \"0.1.0\"\n";
+    let package_fields = if options.is_workspace_member {
+        "version.workspace = true\nedition.workspace = true\n".to_string()
+    } else {
+        format!("{}edition = \"{}\"\n", version_line, options.edition)
+    };
+    manifest = manifest.replacen(version_line, &package_fields, 1);
+
+    if !options.features.is_empty() {
+        if !manifest.ends_with("\n\n") {
+            manifest.push('\n');
+        }
+        manifest.push_str("[features]\n");
+        for (feature, enables) in &options.features {
+            let enables = enables
+                .iter()
+                .map(|e| format!("\"{}\"", e))
+                .collect::<Vec<_>>()
+                .join(", ");
+            manifest.push_str(&format!("{} = [{}]\n", feature, enables));
+        }
+    }
+
+    manifest
+}
 
 /// Parse a Cargo.toml file and extract manifest information
+///
+/// `raw_data` is `{"manifest": <Cargo.toml>}`, the same object
+/// [`parse_cargo_toml_in_workspace`] returns.
 pub fn parse_cargo_toml(content: &str) -> PluginResult<ManifestData> {
     let doc = content
         .parse::<DocumentMut>()
@@ -41,46 +103,220 @@ pub fn parse_cargo_toml(content: &str) -> PluginResult<ManifestData> {
         version,
         dependencies,
         dev_dependencies,
-        raw_data: serde_json::to_value(doc.to_string()).map_err(|e| {
-            PluginApiError::internal(format!("Failed to serialize manifest: {}", e))
-        })?,
+        raw_data: serde_json::json!({ "manifest": doc.to_string() }),
+    })
+}
+
+/// Parse a workspace member's Cargo.toml, resolving inherited fields against
+/// the workspace root manifest
+///
+/// Dependencies declared with `workspace = true` take their source, features
+/// and `default-features` from the root's `[workspace.dependencies]`; the
+/// member's own `features` and `optional` are kept on top. An inherited
+/// `version.workspace = true` comes from `[workspace.package]`. Path sources
+/// in the root are rewritten relative to the member via `member_to_root`.
+///
+/// `raw_data` is `{"manifest": <member Cargo.toml>,
+/// "unresolved_workspace_dependencies": [names]}`, listing inherited
+/// dependencies the root does not declare; those keep a `*` version.
+pub fn parse_cargo_toml_in_workspace(
+    content: &str,
+    root_content: &str,
+    member_to_root: &Path,
+) -> PluginResult<ManifestData> {
+    let doc = content
+        .parse::<DocumentMut>()
+        .map_err(|e| PluginApiError::manifest(format!("Failed to parse Cargo.toml: {}", e)))?;
+    let root = root_content.parse::<DocumentMut>().map_err(|e| {
+        PluginApiError::manifest(format!("Failed to parse workspace Cargo.toml: {}", e))
+    })?;
+    let workspace = root
+        .get("workspace")
+        .and_then(Item::as_table_like)
+        .ok_or_else(|| {
+            PluginApiError::manifest("Missing [workspace] section in root Cargo.toml")
+        })?;
+
+    let package_table = doc
+        .get("package")
+        .and_then(Item::as_table_like)
+        .ok_or_else(|| PluginApiError::manifest("Missing [package] section in Cargo.toml"))?;
+    let name = package_table
+        .get("name")
+        .and_then(Item::as_str)
+        .ok_or_else(|| PluginApiError::manifest("Missing 'name' field in [package]"))?
+        .to_string();
+    let version = match package_table.get("version") {
+        Some(entry) if is_workspace_inherited(entry) => workspace
+            .get("package")
+            .and_then(Item::as_table_like)
+            .and_then(|package| package.get("version"))
+            .and_then(Item::as_str),
+        Some(entry) => entry.as_str(),
+        None => None,
+    }
+    .ok_or_else(|| PluginApiError::manifest("Missing 'version' field in [package]"))?
+    .to_string();
+
+    let workspace_deps = workspace.get("dependencies").and_then(Item::as_table_like);
+    let mut unresolved = Vec::new();
+    let mut resolve = |table_name: &str| {
+        let mut deps = extract_dependencies(&doc, table_name);
+        let member_tables = dependency_tables(&doc, table_name);
+        for dep in deps.iter_mut() {
+            let inherited = member_tables
+                .iter()
+                .find(|(target, _)| *target == dep.target.as_deref())
+                .and_then(|(_, table)| table.get(&dep.name))
+                .is_some_and(is_workspace_inherited);
+            if !inherited {
+                continue;
+            }
+            match workspace_deps.and_then(|table| table.get(&dep.name)) {
+                Some(entry) => resolve_inherited_dependency(dep, entry, member_to_root),
+                None => unresolved.push(dep.name.clone()),
+            }
+        }
+        deps
+    };
+    let dependencies = resolve("dependencies");
+    let dev_dependencies = resolve("dev-dependencies");
+    unresolved.sort();
+    unresolved.dedup();
+
+    Ok(ManifestData {
+        name,
+        version,
+        dependencies,
+        dev_dependencies,
+        raw_data: serde_json::json!({
+            "manifest": doc.to_string(),
+            "unresolved_workspace_dependencies": unresolved,
+        }),
     })
 }
 
+/// Fill in an inherited dependency from its `[workspace.dependencies]` entry
+fn resolve_inherited_dependency(dep: &mut Dependency, entry: &Item, member_to_root: &Path) {
+    if let Some(version) = entry.as_str() {
+        dep.source = DependencySource::Version(version.to_string());
+        return;
+    }
+    let Some(table) = entry.as_table_like() else {
+        return;
+    };
+
+    dep.source = match parse_dependency_source(table) {
+        DependencySource::Path(path) => DependencySource::Path(
+            member_to_root
+                .join(path)
+                .to_string_lossy()
+                .replace('\\', "/"),
+        ),
+        source => source,
+    };
+    let mut features = parse_features(table);
+    for feature in std::mem::take(&mut dep.features) {
+        if !features.contains(&feature) {
+            features.push(feature);
+        }
+    }
+    dep.features = features;
+    dep.default_features = parse_default_features(table);
+}
+
 /// Extract dependencies from a specific table in the TOML document
+///
+/// Platform-specific `[target.<predicate>.<table_name>]` dependencies are
+/// included after the unconditional ones, tagged with their predicate.
 fn extract_dependencies(doc: &DocumentMut, table_name: &str) -> Vec<Dependency> {
     let mut deps = Vec::new();
 
-    if let Some(deps_table) = doc.get(table_name).and_then(|i| i.as_table()) {
+    for (target, deps_table) in dependency_tables(doc, table_name) {
         for (name, value) in deps_table.iter() {
-            let source = match value {
-                Item::Value(val) if val.is_str() => {
-                    // Simple version string: dep = "1.0"
-                    DependencySource::Version(val.as_str().unwrap_or("").to_string())
-                }
-                Item::Value(val) if val.is_inline_table() => {
-                    // Inline table: dep = { version = "1.0", features = [...] }
-                    if let Some(table) = val.as_inline_table() {
-                        parse_dependency_source(table)
-                    } else {
-                        continue;
-                    }
-                }
-                _ => continue,
+            let mut dependency = if let Some(version) = value.as_str() {
+                // Simple version string: dep = "1.0"
+                Dependency::new(name, DependencySource::Version(version.to_string()))
+            } else if let Some(table) = value.as_table_like() {
+                // Inline table (dep = { version = "1.0", features = [...] }),
+                // dotted keys (dep.workspace = true) or a [dependencies.dep] table
+                parse_table_dependency(name, table)
+            } else {
+                continue;
             };
+            dependency.target = target.map(str::to_string);
 
-            deps.push(Dependency {
-                name: name.to_string(),
-                source,
-            });
+            deps.push(dependency);
         }
     }
 
     deps
 }
 
-/// Parse dependency source from an inline table
-fn parse_dependency_source(table: &toml_edit::InlineTable) -> DependencySource {
+/// The `table_name` table followed by each `[target.<predicate>.<table_name>]`
+/// table, paired with its predicate (`cfg(unix)`, a target triple, ...)
+fn dependency_tables<'a>(
+    doc: &'a DocumentMut,
+    table_name: &str,
+) -> Vec<(Option<&'a str>, &'a dyn TableLike)> {
+    let mut tables = Vec::new();
+    if let Some(table) = doc.get(table_name).and_then(Item::as_table_like) {
+        tables.push((None, table));
+    }
+    if let Some(targets) = doc.get("target").and_then(Item::as_table_like) {
+        for (predicate, target) in targets.iter() {
+            if let Some(table) = target
+                .as_table_like()
+                .and_then(|target| target.get(table_name))
+                .and_then(Item::as_table_like)
+            {
+                tables.push((Some(predicate), table));
+            }
+        }
+    }
+    tables
+}
+
+/// Parse a dependency written as a table, including its feature flags
+fn parse_table_dependency(name: &str, table: &dyn TableLike) -> Dependency {
+    let mut dependency = Dependency::new(name, parse_dependency_source(table));
+
+    dependency.features = parse_features(table);
+    dependency.optional = table
+        .get("optional")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    dependency.default_features = parse_default_features(table);
+
+    dependency
+}
+
+/// Read the `features` array of a dependency table
+fn parse_features(table: &dyn TableLike) -> Vec<String> {
+    table
+        .get("features")
+        .and_then(|v| v.as_array())
+        .map(|features| {
+            features
+                .iter()
+                .filter_map(|f| f.as_str())
+                .map(|f| f.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Read `default-features` (or the legacy `default_features`) of a dependency table
+fn parse_default_features(table: &dyn TableLike) -> bool {
+    table
+        .get("default-features")
+        .or_else(|| table.get("default_features"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true)
+}
+
+/// Parse dependency source from a dependency table
+fn parse_dependency_source(table: &dyn TableLike) -> DependencySource {
     // Check for path dependency
     if let Some(path) = table.get("path").and_then(|v| v.as_str()) {
         return DependencySource::Path(path.to_string());
@@ -219,15 +455,234 @@ pub fn rename_dependency(
         }
     }
 
+    // Update in [patch.<registry>] and [replace] so overrides keep applying
+    rename_in_overrides(&mut doc, old_name, new_name, new_path);
+
+    Ok(doc.to_string())
+}
+
+/// Remove a dependency from every dependency table of a Cargo.toml
+///
+/// `[patch]` and `[replace]` entries are left alone; a warning is logged
+/// when the removed dependency is still overridden there.
+pub fn remove_dependency(content: &str, name: &str) -> PluginResult<String> {
+    let mut doc = content
+        .parse::<DocumentMut>()
+        .map_err(|e| PluginApiError::manifest(format!("Failed to parse Cargo.toml: {}", e)))?;
+
+    for table_name in ["dependencies", "dev-dependencies", "build-dependencies"] {
+        if let Some(deps) = doc.get_mut(table_name).and_then(Item::as_table_like_mut) {
+            deps.remove(name);
+        }
+    }
+
+    if is_overridden(&doc, name) {
+        warn!(
+            dependency = %name,
+            "Removed dependency is still listed in [patch] or [replace]"
+        );
+    }
+
+    Ok(doc.to_string())
+}
+
+/// Which entries `prune_unused_dependencies` may remove
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruneOptions {
+    /// Also prune entries inherited from the workspace (`dep.workspace = true`)
+    pub include_workspace_inherited: bool,
+    /// Also prune `[build-dependencies]`, which are imported by build scripts
+    pub include_build_dependencies: bool,
+}
+
+/// Remove dependencies whose crate name never appears in `used_modules`
+///
+/// `used_modules` holds module paths as imported from source (`serde::Deserialize`,
+/// `tokio`); only the first segment is compared, with `-` read as `_`. The key
+/// of a dependency table is the name code imports, so renamed packages
+/// (`alias = { package = "real" }`) are matched by their alias. Dependencies
+/// named by a `[features]` entry (`dep:foo`, `foo/feat`, `foo?/feat`) are
+/// kept, since removing them would leave the feature dangling.
+pub fn prune_unused_dependencies(
+    content: &str,
+    used_modules: &[String],
+    options: PruneOptions,
+) -> PluginResult<String> {
+    let mut doc = content
+        .parse::<DocumentMut>()
+        .map_err(|e| PluginApiError::manifest(format!("Failed to parse Cargo.toml: {}", e)))?;
+
+    let crate_name = |name: &str| name.replace('-', "_");
+    let mut used: std::collections::HashSet<String> = used_modules
+        .iter()
+        .filter_map(|module| module.split("::").next())
+        .map(crate_name)
+        .collect();
+    used.extend(
+        feature_dependencies(&doc)
+            .iter()
+            .map(|name| crate_name(name)),
+    );
+
+    let mut tables = vec!["dependencies", "dev-dependencies"];
+    if options.include_build_dependencies {
+        tables.push("build-dependencies");
+    }
+
+    for table_name in tables {
+        let Some(deps) = doc.get_mut(table_name).and_then(Item::as_table_like_mut) else {
+            continue;
+        };
+        let unused: Vec<String> = deps
+            .iter()
+            .filter(|(name, _)| !used.contains(&crate_name(name)))
+            .filter(|(_, entry)| {
+                options.include_workspace_inherited || !is_workspace_inherited(entry)
+            })
+            .map(|(name, _)| name.to_string())
+            .collect();
+        for name in unused {
+            deps.remove(&name);
+        }
+    }
+
     Ok(doc.to_string())
 }
 
+/// Dependency keys named by `[features]` entries
+///
+/// `dep:foo` enables an optional dependency, and `foo/feat` or `foo?/feat`
+/// enables a feature of one.
+fn feature_dependencies(doc: &DocumentMut) -> Vec<String> {
+    let Some(features) = doc.get("features").and_then(Item::as_table_like) else {
+        return Vec::new();
+    };
+    features
+        .iter()
+        .filter_map(|(_, enables)| enables.as_array())
+        .flat_map(|enables| enables.iter().filter_map(|value| value.as_str()))
+        .filter_map(|entry| {
+            if let Some(dep) = entry.strip_prefix("dep:") {
+                Some(dep.to_string())
+            } else {
+                entry
+                    .split_once('/')
+                    .map(|(dep, _)| dep.trim_end_matches('?').to_string())
+            }
+        })
+        .collect()
+}
+
+/// Whether a dependency entry is `{ workspace = true }` or `dep.workspace = true`
+fn is_workspace_inherited(entry: &Item) -> bool {
+    entry
+        .as_table_like()
+        .and_then(|fields| fields.get("workspace"))
+        .and_then(Item::as_bool)
+        .unwrap_or(false)
+}
+
+/// Rename `old_name` to `new_name` in `[patch.*]` and `[replace]`
+///
+/// A new path is only written to entries that already override with a path,
+/// so git and registry overrides keep their source.
+fn rename_in_overrides(
+    doc: &mut DocumentMut,
+    old_name: &str,
+    new_name: &str,
+    new_path: Option<&str>,
+) {
+    fn rename_entry(
+        table: &mut dyn toml_edit::TableLike,
+        old_key: &str,
+        new_key: &str,
+        new_path: Option<&str>,
+    ) {
+        if let Some(mut entry) = table.remove(old_key) {
+            if let (Some(path), Some(fields)) = (new_path, entry.as_table_like_mut()) {
+                if fields.contains_key("path") {
+                    fields.insert("path", value(path));
+                }
+            }
+            table.insert(new_key, entry);
+        }
+    }
+
+    if let Some(patch) = doc.get_mut("patch").and_then(Item::as_table_like_mut) {
+        for (_registry, patches) in patch.iter_mut() {
+            if let Some(patches) = patches.as_table_like_mut() {
+                rename_entry(patches, old_name, new_name, new_path);
+            }
+        }
+    }
+
+    // [replace] keys are package specs such as "foo:1.0.0"
+    if let Some(replace) = doc.get_mut("replace").and_then(Item::as_table_like_mut) {
+        let renames: Vec<(String, String)> = replace
+            .iter()
+            .filter_map(|(spec, _)| {
+                let (name, version) = spec.split_once(':').unwrap_or((spec, ""));
+                (name == old_name).then(|| {
+                    let new_spec = if version.is_empty() {
+                        new_name.to_string()
+                    } else {
+                        format!("{}:{}", new_name, version)
+                    };
+                    (spec.to_string(), new_spec)
+                })
+            })
+            .collect();
+        for (old_spec, new_spec) in renames {
+            rename_entry(replace, &old_spec, &new_spec, new_path);
+        }
+    }
+}
+
+/// Whether `name` appears in any `[patch.*]` table or `[replace]` spec
+fn is_overridden(doc: &DocumentMut, name: &str) -> bool {
+    let patched = doc
+        .get("patch")
+        .and_then(Item::as_table_like)
+        .is_some_and(|patch| {
+            patch.iter().any(|(_, patches)| {
+                patches
+                    .as_table_like()
+                    .is_some_and(|patches| patches.contains_key(name))
+            })
+        });
+    let replaced = doc
+        .get("replace")
+        .and_then(Item::as_table_like)
+        .is_some_and(|replace| {
+            replace
+                .iter()
+                .any(|(spec, _)| spec.split_once(':').map_or(spec, |(n, _)| n) == name)
+        });
+    patched || replaced
+}
+
 /// Load and parse a Cargo.toml file from a path
 pub async fn load_cargo_toml(path: &Path) -> PluginResult<ManifestData> {
     let content = read_manifest(path).await?;
     parse_cargo_toml(&content)
 }
 
+/// Load a workspace member's Cargo.toml and resolve its inherited fields
+/// against the Cargo.toml in `workspace_root`
+///
+/// See [`parse_cargo_toml_in_workspace`] for how inheritance is resolved.
+pub async fn load_cargo_toml_in_workspace(
+    path: &Path,
+    workspace_root: &Path,
+) -> PluginResult<ManifestData> {
+    let content = read_manifest(path).await?;
+    let root_content = read_manifest(&workspace_root.join("Cargo.toml")).await?;
+    let member_dir = path.parent().unwrap_or(Path::new("."));
+    let member_to_root = pathdiff::diff_paths(workspace_root, member_dir)
+        .unwrap_or_else(|| workspace_root.to_path_buf());
+    parse_cargo_toml_in_workspace(&content, &root_content, &member_to_root)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -260,6 +715,99 @@ my-local-dep = { path = "../my-dep" }
             d.name == "my-local-dep"
                 && matches!(&d.source, DependencySource::Path(p) if p == "../my-dep")
         }));
+
+        assert_eq!(manifest.raw_data["manifest"], content);
+    }
+
+    #[test]
+    fn test_parse_target_specific_dependencies() {
+        let content = r#"
+[package]
+name = "test-crate"
+version = "0.1.0"
+
+[dependencies]
+serde = "1.0"
+
+[target.'cfg(unix)'.dependencies]
+libc = "0.2"
+
+[target.x86_64-pc-windows-msvc.dev-dependencies]
+winapi = { version = "0.3", features = ["winuser"] }
+"#;
+
+        let manifest = parse_cargo_toml(content).unwrap();
+        assert_eq!(manifest.dependencies.len(), 2);
+        assert_eq!(manifest.dependencies[0].name, "serde");
+        assert_eq!(manifest.dependencies[0].target, None);
+        assert_eq!(manifest.dependencies[1].name, "libc");
+        assert_eq!(
+            manifest.dependencies[1].target.as_deref(),
+            Some("cfg(unix)")
+        );
+
+        assert_eq!(manifest.dev_dependencies.len(), 1);
+        assert_eq!(manifest.dev_dependencies[0].features, vec!["winuser"]);
+        assert_eq!(
+            manifest.dev_dependencies[0].target.as_deref(),
+            Some("x86_64-pc-windows-msvc")
+        );
+    }
+
+    #[test]
+    fn test_parse_cargo_toml_in_workspace_resolves_inherited() {
+        let root = r#"
+[workspace]
+members = ["crates/app"]
+
+[workspace.package]
+version = "0.3.0"
+
+[workspace.dependencies]
+serde = { version = "1", features = ["derive"] }
+tokio = "1.40"
+common = { path = "crates/common" }
+"#;
+        let member = r#"
+[package]
+name = "app"
+version.workspace = true
+
+[dependencies]
+serde = { workspace = true, features = ["rc"] }
+tokio.workspace = true
+common = { workspace = true }
+missing = { workspace = true }
+"#;
+
+        let manifest = parse_cargo_toml_in_workspace(member, root, Path::new("../..")).unwrap();
+        let dep = |name: &str| {
+            manifest
+                .dependencies
+                .iter()
+                .find(|d| d.name == name)
+                .unwrap()
+        };
+
+        assert_eq!(manifest.version, "0.3.0");
+        assert_eq!(
+            dep("serde").source,
+            DependencySource::Version("1".to_string())
+        );
+        assert_eq!(dep("serde").features, vec!["derive", "rc"]);
+        assert_eq!(
+            dep("tokio").source,
+            DependencySource::Version("1.40".to_string())
+        );
+        assert_eq!(
+            dep("common").source,
+            DependencySource::Path("../../crates/common".to_string())
+        );
+        assert_eq!(manifest.raw_data["manifest"], member);
+        assert_eq!(
+            manifest.raw_data["unresolved_workspace_dependencies"],
+            serde_json::json!(["missing"])
+        );
     }
 
     #[test]
@@ -313,6 +861,137 @@ my-dep = { path = "../my-dep", version = "0.1", optional = true, features = ["fe
         assert!(result.contains("version = \"0.1\""));
     }
 
+    #[test]
+    fn test_rename_dependency_updates_patch_and_replace() {
+        let cargo_toml = r#"
+[package]
+name = "test-crate"
+version = "0.1.0"
+
+[dependencies]
+my-dep = "0.1"
+
+[patch.crates-io]
+my-dep = { path = "../my-dep" }
+other = { git = "https://github.com/example/other" }
+
+[replace]
+"my-dep:0.1.0" = { path = "../my-dep" }
+"#;
+
+        let result =
+            rename_dependency(cargo_toml, "my-dep", "renamed-dep", Some("../renamed-dep")).unwrap();
+        let doc = result.parse::<DocumentMut>().unwrap();
+
+        assert_eq!(
+            doc["dependencies"]["renamed-dep"]["path"].as_str(),
+            Some("../renamed-dep")
+        );
+        assert_eq!(
+            doc["patch"]["crates-io"]["renamed-dep"]["path"].as_str(),
+            Some("../renamed-dep")
+        );
+        assert!(doc["patch"]["crates-io"].get("my-dep").is_none());
+        assert!(doc["patch"]["crates-io"]["other"].get("path").is_none());
+        assert_eq!(
+            doc["replace"]["renamed-dep:0.1.0"]["path"].as_str(),
+            Some("../renamed-dep")
+        );
+        assert!(doc["replace"].get("my-dep:0.1.0").is_none());
+    }
+
+    #[test]
+    fn test_remove_dependency_keeps_patch() {
+        let cargo_toml = r#"
+[package]
+name = "test-crate"
+version = "0.1.0"
+
+[dependencies]
+my-dep = "0.1"
+other = "1.0"
+
+[dev-dependencies]
+my-dep = "0.1"
+
+[patch.crates-io]
+my-dep = { path = "../my-dep" }
+"#;
+
+        let result = remove_dependency(cargo_toml, "my-dep").unwrap();
+        let doc = result.parse::<DocumentMut>().unwrap();
+
+        assert!(doc["dependencies"].get("my-dep").is_none());
+        assert!(doc["dev-dependencies"].get("my-dep").is_none());
+        assert!(doc["dependencies"].get("other").is_some());
+        assert!(is_overridden(&doc, "my-dep"));
+        assert!(!is_overridden(&doc, "other"));
+    }
+
+    #[test]
+    fn test_prune_unused_dependencies() {
+        let cargo_toml = r#"
+[package]
+name = "test-crate"
+version = "0.1.0"
+
+[dependencies]
+serde_json = "1.0"
+unused-dep = "0.3"
+async-trait = "0.1"
+tokio.workspace = true
+
+[build-dependencies]
+cc = "1.0"
+"#;
+        let used = vec!["serde_json::Value".to_string(), "async_trait".to_string()];
+
+        let result = prune_unused_dependencies(cargo_toml, &used, PruneOptions::default()).unwrap();
+        let doc = result.parse::<DocumentMut>().unwrap();
+        assert!(doc["dependencies"].get("unused-dep").is_none());
+        assert!(doc["dependencies"].get("serde_json").is_some());
+        assert!(doc["dependencies"].get("async-trait").is_some());
+        assert!(doc["dependencies"].get("tokio").is_some());
+        assert!(doc["build-dependencies"].get("cc").is_some());
+
+        let options = PruneOptions {
+            include_workspace_inherited: true,
+            include_build_dependencies: true,
+        };
+        let result = prune_unused_dependencies(cargo_toml, &used, options).unwrap();
+        let doc = result.parse::<DocumentMut>().unwrap();
+        assert!(doc["dependencies"].get("tokio").is_none());
+        assert!(doc["build-dependencies"].get("cc").is_none());
+        assert!(doc["dependencies"].get("serde_json").is_some());
+    }
+
+    #[test]
+    fn test_prune_keeps_dependencies_named_by_features() {
+        let cargo_toml = r#"
+[package]
+name = "test-crate"
+version = "0.1.0"
+
+[features]
+default = ["json"]
+json = ["dep:serde_json", "tokio?/rt"]
+tls = ["reqwest/rustls-tls"]
+
+[dependencies]
+serde_json = { version = "1.0", optional = true }
+tokio = { version = "1", optional = true }
+reqwest = "0.12"
+unused-dep = "0.3"
+"#;
+
+        let result = prune_unused_dependencies(cargo_toml, &[], PruneOptions::default()).unwrap();
+        let doc = result.parse::<DocumentMut>().unwrap();
+        assert!(doc["dependencies"].get("serde_json").is_some());
+        assert!(doc["dependencies"].get("tokio").is_some());
+        assert!(doc["dependencies"].get("reqwest").is_some());
+        assert!(doc["dependencies"].get("unused-dep").is_none());
+    }
+
     #[test]
     fn test_rename_dependency_updates_features() {
         let cargo_toml = r#"
@@ -365,6 +1044,51 @@ advanced = ["old-ast/extra-feature"]
         );
     }
 
+    #[test]
+    fn test_generate_cargo_toml_default_edition() {
+        let manifest = generate_cargo_toml("my-crate", &[], &ManifestOptions::default());
+
+        assert!(manifest.contains("name = \"my-crate\""));
+        assert!(manifest.contains("version = \"0.1.0\"\nedition = \"2021\""));
+        assert!(!manifest.contains("[features]"));
+        assert!(manifest.parse::<DocumentMut>().is_ok());
+    }
+
+    #[test]
+    fn test_generate_cargo_toml_with_edition_and_features() {
+        let options = ManifestOptions {
+            edition: "2024".to_string(),
+            features: vec![
+                ("default".to_string(), vec!["std".to_string()]),
+                ("std".to_string(), vec!["serde/std".to_string()]),
+            ],
+            ..Default::default()
+        };
+        let manifest = generate_cargo_toml("my-crate", &["serde".to_string()], &options);
+
+        assert!(manifest.contains("edition = \"2024\""));
+        assert!(!manifest.contains("edition = \"2021\""));
+        assert!(manifest.contains("[features]\ndefault = [\"std\"]\nstd = [\"serde/std\"]\n"));
+
+        let doc = manifest.parse::<DocumentMut>().unwrap();
+        assert!(doc["dependencies"]["serde"].is_value());
+        assert_eq!(doc["features"]["std"][0].as_str(), Some("serde/std"));
+    }
+
+    #[test]
+    fn test_generate_cargo_toml_workspace_member() {
+        let options = ManifestOptions {
+            is_workspace_member: true,
+            ..Default::default()
+        };
+        let manifest = generate_cargo_toml("member", &[], &options);
+
+        assert!(manifest.contains("version.workspace = true"));
+        assert!(manifest.contains("edition.workspace = true"));
+        assert!(!manifest.contains("version = \"0.1.0\""));
+        assert!(manifest.parse::<DocumentMut>().is_ok());
+    }
+
     #[test]
     fn test_parse_git_dependency() {
         let content = r#"
@@ -387,4 +1111,44 @@ my-git-dep = { git = "https://github.com/user/repo", rev = "abc123" }
             if url == "https://github.com/user/repo" && rev.as_deref() == Some("abc123")
         ));
     }
+
+    #[test]
+    fn test_parse_dependency_features() {
+        let content = r#"
+[package]
+name = "test-crate"
+version = "0.1.0"
+
+[dependencies]
+serde = { version = "1", features = ["derive"], default-features = false }
+tokio = { version = "1", optional = true }
+anyhow = "1.0"
+"#;
+
+        let manifest = parse_cargo_toml(content).unwrap();
+        let find = |name: &str| {
+            manifest
+                .dependencies
+                .iter()
+                .find(|d| d.name == name)
+                .unwrap()
+        };
+
+        let serde = find("serde");
+        assert_eq!(serde.features, vec!["derive"]);
+        assert!(!serde.default_features);
+        assert!(!serde.optional);
+        assert!(matches!(&serde.source, DependencySource::Version(v) if v == "1"));
+
+        let tokio = find("tokio");
+        assert!(tokio.optional);
+        assert!(tokio.default_features);
+        assert!(tokio.features.is_empty());
+
+        let anyhow = find("anyhow");
+        assert_eq!(
+            anyhow,
+            &Dependency::new("anyhow", DependencySource::Version("1.0".to_string()))
+        );
+    }
 }
@@ -3,8 +3,10 @@
 //! This module provides functionality for parsing Rust source code into ASTs,
 //! extracting symbols, and analyzing imports.
 use mill_foundation::protocol::{ImportGraph, ImportInfo, ImportType, NamedImport};
-use mill_lang_common::ImportGraphBuilder;
-use mill_plugin_api::{PluginApiError, PluginResult, SourceLocation, Symbol, SymbolKind};
+use mill_lang_common::{classify_imports, ImportGraphBuilder};
+use mill_plugin_api::{
+    ParseDiagnostic, PluginApiError, PluginResult, SourceLocation, Symbol, SymbolKind,
+};
 use syn::{spanned::Spanned, visit::Visit, File, Item, ItemUse, UseTree};
 /// A visitor that walks the AST and collects function names
 struct FunctionVisitor {
@@ -23,8 +25,39 @@ impl<'ast> Visit<'ast> for FunctionVisitor {
 /// A visitor that collects all symbols (functions, structs, enums, etc.)
 struct SymbolVisitor {
     symbols: Vec<Symbol>,
+    /// Self type of the `impl` block being visited
+    current_impl: Option<String>,
+    /// Methods and the self type of the `impl` block they were found in
+    impl_methods: Vec<(usize, String)>,
+}
+impl SymbolVisitor {
+    fn new() -> Self {
+        Self {
+            symbols: Vec::new(),
+            current_impl: None,
+            impl_methods: Vec::new(),
+        }
+    }
+    /// Attach methods to the struct or enum their `impl` block is for
+    fn into_symbols(mut self) -> Vec<Symbol> {
+        for (method, type_name) in self.impl_methods {
+            self.symbols[method].parent = self.symbols.iter().position(|s| {
+                s.name == type_name && matches!(s.kind, SymbolKind::Struct | SymbolKind::Enum)
+            });
+        }
+        self.symbols
+    }
 }
 impl<'ast> Visit<'ast> for SymbolVisitor {
+    fn visit_item_impl(&mut self, i: &'ast syn::ItemImpl) {
+        let self_type = match &*i.self_ty {
+            syn::Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string()),
+            _ => None,
+        };
+        let outer = std::mem::replace(&mut self.current_impl, self_type);
+        syn::visit::visit_item_impl(self, i);
+        self.current_impl = outer;
+    }
     fn visit_item_fn(&mut self, i: &'ast syn::ItemFn) {
         let span = i.span();
         let start = span.start();
@@ -41,6 +74,8 @@ impl<'ast> Visit<'ast> for SymbolVisitor {
                 column: end.column,
             }),
             documentation: extract_doc_comments(&i.attrs),
+            is_test: is_test_function(&i.attrs),
+            parent: None,
         });
         syn::visit::visit_item_fn(self, i);
     }
@@ -60,6 +95,8 @@ impl<'ast> Visit<'ast> for SymbolVisitor {
                 column: end.column,
             }),
             documentation: extract_doc_comments(&i.attrs),
+            is_test: false,
+            parent: None,
         });
         syn::visit::visit_item_struct(self, i);
     }
@@ -79,6 +116,8 @@ impl<'ast> Visit<'ast> for SymbolVisitor {
                 column: end.column,
             }),
             documentation: extract_doc_comments(&i.attrs),
+            is_test: false,
+            parent: None,
         });
         syn::visit::visit_item_enum(self, i);
     }
@@ -98,6 +137,8 @@ impl<'ast> Visit<'ast> for SymbolVisitor {
                 column: end.column,
             }),
             documentation: extract_doc_comments(&i.attrs),
+            is_test: false,
+            parent: None,
         });
         syn::visit::visit_item_const(self, i);
     }
@@ -117,6 +158,8 @@ impl<'ast> Visit<'ast> for SymbolVisitor {
                 column: end.column,
             }),
             documentation: extract_doc_comments(&i.attrs),
+            is_test: false,
+            parent: None,
         });
         syn::visit::visit_item_static(self, i);
     }
@@ -136,6 +179,8 @@ impl<'ast> Visit<'ast> for SymbolVisitor {
                 column: end.column,
             }),
             documentation: extract_doc_comments(&i.attrs),
+            is_test: false,
+            parent: None,
         });
         syn::visit::visit_item_mod(self, i);
     }
@@ -155,10 +200,34 @@ impl<'ast> Visit<'ast> for SymbolVisitor {
                 column: end.column,
             }),
             documentation: extract_doc_comments(&i.attrs),
+            is_test: is_test_function(&i.attrs),
+            parent: None,
         });
+        if let Some(type_name) = &self.current_impl {
+            self.impl_methods
+                .push((self.symbols.len() - 1, type_name.clone()));
+        }
         syn::visit::visit_impl_item_fn(self, i);
     }
 }
+/// Check whether a function's attributes mark it as a test
+///
+/// Each attribute path is rendered as `#[path::to::attr]` and matched against
+/// the plugin's test patterns (`#[test]`, `#[tokio::test]`, ...).
+fn is_test_function(attrs: &[syn::Attribute]) -> bool {
+    let patterns = crate::constants::test_patterns();
+    attrs.iter().any(|attr| {
+        let path = attr
+            .path()
+            .segments
+            .iter()
+            .map(|s| s.ident.to_string())
+            .collect::<Vec<_>>()
+            .join("::");
+        let rendered = format!("#[{}]", path);
+        patterns.iter().any(|p| p.is_match(&rendered))
+    })
+}
 /// Extract documentation from attributes
 fn extract_doc_comments(attrs: &[syn::Attribute]) -> Option<String> {
     let docs: Vec<String> = attrs
@@ -186,28 +255,160 @@ fn extract_doc_comments(attrs: &[syn::Attribute]) -> Option<String> {
 }
 /// Parses Rust source code and returns a list of all function and method names
 pub fn list_functions(source: &str) -> PluginResult<Vec<String>> {
-    let ast: File = syn::parse_file(source)
-        .map_err(|e| PluginApiError::parse(format!("Failed to parse Rust code: {}", e)))?;
+    let ast: File = syn::parse_file(source).map_err(|e| syntax_error(&e, source))?;
     let mut visitor = FunctionVisitor {
         functions: Vec::new(),
     };
     visitor.visit_file(&ast);
     Ok(visitor.functions)
 }
-/// Parses Rust source code and extracts all symbols
-pub fn extract_symbols(source: &str) -> PluginResult<Vec<Symbol>> {
-    let ast: File = syn::parse_file(source)
-        .map_err(|e| PluginApiError::parse(format!("Failed to parse Rust code: {}", e)))?;
-    let mut visitor = SymbolVisitor {
-        symbols: Vec::new(),
+/// Signature details of a function or method
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct FunctionSignature {
+    pub name: String,
+    pub is_async: bool,
+    pub is_unsafe: bool,
+    pub is_const: bool,
+    /// Generic parameters as written, e.g. `T: Clone` or `'a`
+    pub generics: Vec<String>,
+    /// Parameters as written, including any receiver, e.g. `&self` or `x: T`
+    pub params: Vec<String>,
+    /// Return type, `None` for the unit return
+    pub return_type: Option<String>,
+}
+/// A visitor that walks the AST and collects function signatures
+struct SignatureVisitor {
+    signatures: Vec<FunctionSignature>,
+}
+impl<'ast> Visit<'ast> for SignatureVisitor {
+    fn visit_item_fn(&mut self, i: &'ast syn::ItemFn) {
+        self.signatures.push(function_signature(&i.sig));
+        syn::visit::visit_item_fn(self, i);
+    }
+    fn visit_impl_item_fn(&mut self, i: &'ast syn::ImplItemFn) {
+        self.signatures.push(function_signature(&i.sig));
+        syn::visit::visit_impl_item_fn(self, i);
+    }
+}
+fn function_signature(sig: &syn::Signature) -> FunctionSignature {
+    FunctionSignature {
+        name: sig.ident.to_string(),
+        is_async: sig.asyncness.is_some(),
+        is_unsafe: sig.unsafety.is_some(),
+        is_const: sig.constness.is_some(),
+        generics: sig.generics.params.iter().map(render_tokens).collect(),
+        params: sig.inputs.iter().map(render_tokens).collect(),
+        return_type: match &sig.output {
+            syn::ReturnType::Default => None,
+            syn::ReturnType::Type(_, ty) => Some(render_tokens(ty)),
+        },
+    }
+}
+/// Render a syntax node as source, tightening the spacing `quote!` inserts
+fn render_tokens(node: &impl quote::ToTokens) -> String {
+    node.to_token_stream()
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(" : ", ": ")
+        .replace(" ,", ",")
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace("& ", "&")
+}
+/// Parses Rust source code and returns the signature of every function and method
+pub fn list_function_signatures(source: &str) -> PluginResult<Vec<FunctionSignature>> {
+    let ast: File = syn::parse_file(source).map_err(|e| syntax_error(&e, source))?;
+    let mut visitor = SignatureVisitor {
+        signatures: Vec::new(),
     };
     visitor.visit_file(&ast);
-    Ok(visitor.symbols)
+    Ok(visitor.signatures)
+}
+/// Parses Rust source code and extracts all symbols
+pub fn extract_symbols(source: &str) -> PluginResult<Vec<Symbol>> {
+    let ast: File = syn::parse_file(source).map_err(|e| syntax_error(&e, source))?;
+    Ok(extract_file_symbols(&ast))
+}
+/// Extract symbols from an already parsed file
+pub fn extract_file_symbols(ast: &File) -> Vec<Symbol> {
+    let mut visitor = SymbolVisitor::new();
+    visitor.visit_file(ast);
+    visitor.into_symbols()
+}
+/// Best-effort symbols for source that `syn` cannot parse
+///
+/// Item declarations are recognized line by line, so one malformed item
+/// doesn't hide the others. Only the item kinds `extract_symbols` reports
+/// are returned; functions are reported as `Function` even inside `impl`
+/// blocks, and no end locations or documentation are recovered.
+pub fn extract_symbols_fallback(source: &str) -> Vec<Symbol> {
+    let pattern = crate::constants::item_decl_pattern();
+    let mut symbols = Vec::new();
+    let mut pending_test = false;
+    for (line, text) in source.lines().enumerate() {
+        let trimmed = text.trim_start();
+        if trimmed.starts_with("#[") {
+            pending_test |= crate::constants::test_patterns()
+                .iter()
+                .any(|p| p.is_match(trimmed));
+            continue;
+        }
+        if trimmed.starts_with("//") {
+            continue;
+        }
+        let Some(caps) = pattern.captures(text) else {
+            pending_test = false;
+            continue;
+        };
+        let kind = match &caps[1] {
+            "fn" => SymbolKind::Function,
+            "struct" => SymbolKind::Struct,
+            "enum" => SymbolKind::Enum,
+            "const" => SymbolKind::Constant,
+            "static" => SymbolKind::Variable,
+            _ => SymbolKind::Module,
+        };
+        symbols.push(Symbol {
+            name: caps[2].to_string(),
+            is_test: kind == SymbolKind::Function && pending_test,
+            parent: None,
+            kind,
+            location: SourceLocation {
+                line,
+                column: text.len() - trimmed.len(),
+            },
+            end_location: None,
+            documentation: None,
+        });
+        pending_test = false;
+    }
+    symbols
+}
+/// Turn a `syn` parse error into a parse error with a snippet of `source`
+pub fn syntax_error(error: &syn::Error, source: &str) -> PluginApiError {
+    let start = error.span().start();
+    PluginApiError::parse_with_snippet(
+        format!("Failed to parse Rust code: {}", error),
+        start.line.saturating_sub(1),
+        start.column,
+        source,
+    )
+}
+/// Describe a `syn` parse error as a diagnostic at the error's position
+pub fn syntax_diagnostic(error: &syn::Error) -> ParseDiagnostic {
+    let start = error.span().start();
+    ParseDiagnostic {
+        message: format!("Failed to parse Rust code: {}", error),
+        location: Some(SourceLocation {
+            line: start.line.saturating_sub(1),
+            column: start.column,
+        }),
+    }
 }
 /// Parse Rust imports using AST analysis with syn
 pub fn parse_imports(source: &str) -> PluginResult<Vec<ImportInfo>> {
-    let syntax_tree: File = syn::parse_str(source)
-        .map_err(|e| PluginApiError::parse(format!("Failed to parse Rust source: {}", e)))?;
+    let syntax_tree: File = syn::parse_str(source).map_err(|e| syntax_error(&e, source))?;
     struct ImportVisitor {
         imports: Vec<ImportInfo>,
         current_line: u32,
@@ -240,7 +441,7 @@ pub fn parse_imports(source: &str) -> PluginResult<Vec<ImportInfo>> {
                     };
                     self.imports.push(ImportInfo {
                         module_path: module_path.clone(),
-                        import_type: ImportType::EsModule,
+                        import_type: ImportType::Module,
                         named_imports: vec![NamedImport {
                             name: name.ident.to_string(),
                             alias: None,
@@ -261,7 +462,7 @@ pub fn parse_imports(source: &str) -> PluginResult<Vec<ImportInfo>> {
                     let module_path = prefix.clone();
                     self.imports.push(ImportInfo {
                         module_path: module_path.clone(),
-                        import_type: ImportType::EsModule,
+                        import_type: ImportType::Module,
                         named_imports: vec![NamedImport {
                             name: rename.ident.to_string(),
                             alias: Some(rename.rename.to_string()),
@@ -281,7 +482,7 @@ pub fn parse_imports(source: &str) -> PluginResult<Vec<ImportInfo>> {
                 UseTree::Glob(_) => {
                     self.imports.push(ImportInfo {
                         module_path: prefix.clone(),
-                        import_type: ImportType::EsModule,
+                        import_type: ImportType::Module,
                         named_imports: Vec::new(),
                         default_import: None,
                         namespace_import: Some(prefix),
@@ -494,6 +695,465 @@ fn build_new_use_tree(segments: &[&str], remainder: &UseTree) -> Option<UseTree>
 
     Some(current)
 }
+/// Replace `use <module>::*;` with the names of the module the file uses
+///
+/// Names are found by scanning the file for identifiers in path, type,
+/// pattern, derive and macro positions that are neither defined in the file,
+/// bound locally, imported by another `use`, nor part of the prelude; all of
+/// them are attributed to the glob. The glob is rewritten to a sorted
+/// explicit list, or removed when no name is left. Traits only used through
+/// method calls are invisible to the scan, and `pub use` globs are
+/// re-exports, so those are left alone.
+///
+/// Without the module's items an unresolved name could come from any glob,
+/// so a file with a glob import of another module is refused; use
+/// [`expand_glob_import_from`] when the module's public items are known.
+///
+/// Returns the updated source and the number of globs rewritten.
+pub fn expand_glob_import(source: &str, module: &str) -> PluginResult<(String, usize)> {
+    expand_glob(source, module, None)
+}
+/// Like [`expand_glob_import`], but only names among `public_items` (the
+/// public items of `module`) are imported, so other globs in the file are
+/// allowed
+pub fn expand_glob_import_from(
+    source: &str,
+    module: &str,
+    public_items: &[&str],
+) -> PluginResult<(String, usize)> {
+    expand_glob(source, module, Some(public_items))
+}
+fn expand_glob(
+    source: &str,
+    module: &str,
+    public_items: Option<&[&str]>,
+) -> PluginResult<(String, usize)> {
+    let ast: File = syn::parse_file(source).map_err(|e| syntax_error(&e, source))?;
+    let segments: Vec<&str> = module.split("::").collect();
+
+    let globs: Vec<&ItemUse> = ast
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Use(item_use)
+                if matches!(item_use.vis, syn::Visibility::Inherited)
+                    && is_glob_of(&item_use.tree, &segments) =>
+            {
+                Some(item_use)
+            }
+            _ => None,
+        })
+        .collect();
+    if globs.is_empty() {
+        return Ok((source.to_string(), 0));
+    }
+
+    let mut names = glob_candidate_names(&ast);
+    match public_items {
+        Some(items) => names.retain(|name| items.contains(&name.as_str())),
+        None => {
+            let other_globs = ast.items.iter().any(|item| {
+                matches!(item, Item::Use(item_use)
+                    if has_glob(&item_use.tree) && !is_glob_of(&item_use.tree, &segments))
+            });
+            if other_globs {
+                return Err(PluginApiError::invalid_input(format!(
+                    "Cannot expand the glob import of {}: the file has other glob imports, \
+                     so the names it uses cannot be attributed without the module's items",
+                    module
+                )));
+            }
+        }
+    }
+    let replacement = match names.as_slice() {
+        [] => None,
+        [name] => Some(format!("use {}::{};", module, name)),
+        _ => Some(format!("use {}::{{{}}};", module, names.join(", "))),
+    };
+
+    let mut result = source.to_string();
+    for item_use in globs.iter().rev() {
+        let semi_end = item_use.semi_token.span.end();
+        let (start, end) = match &replacement {
+            Some(_) => (
+                byte_offset(source, item_use.use_token.span.start()),
+                byte_offset(source, semi_end),
+            ),
+            // Drop the whole lines, attributes included
+            None => {
+                let first_line = item_use
+                    .attrs
+                    .first()
+                    .map_or(item_use.use_token.span.start().line, |attr| {
+                        attr.pound_token.span.start().line
+                    });
+                let start = byte_offset(
+                    source,
+                    proc_macro2::LineColumn {
+                        line: first_line,
+                        column: 0,
+                    },
+                );
+                let end = byte_offset(source, semi_end);
+                let end = source[end..]
+                    .find('\n')
+                    .map_or(source.len(), |i| end + i + 1);
+                (start, end)
+            }
+        };
+        result.replace_range(start..end, replacement.as_deref().unwrap_or(""));
+    }
+
+    tracing::debug!(module = %module, names = ?names, "Expanded glob import");
+    Ok((result, globs.len()))
+}
+/// Whether a use tree is exactly `<segments>::*`
+fn is_glob_of(tree: &UseTree, segments: &[&str]) -> bool {
+    match (tree, segments.split_first()) {
+        (UseTree::Path(path), Some((first, rest))) => {
+            path.ident == first && is_glob_of(&path.tree, rest)
+        }
+        (UseTree::Glob(_), None) => true,
+        _ => false,
+    }
+}
+/// Whether a use tree contains a `*` import anywhere
+fn has_glob(tree: &UseTree) -> bool {
+    match tree {
+        UseTree::Path(path) => has_glob(&path.tree),
+        UseTree::Group(group) => group.items.iter().any(has_glob),
+        UseTree::Glob(_) => true,
+        UseTree::Name(_) | UseTree::Rename(_) => false,
+    }
+}
+/// Byte offset of a 1-based line and 0-based character column
+fn byte_offset(source: &str, position: proc_macro2::LineColumn) -> usize {
+    let line_start: usize = source
+        .split_inclusive('\n')
+        .take(position.line - 1)
+        .map(str::len)
+        .sum();
+    let column: usize = source[line_start..]
+        .chars()
+        .take(position.column)
+        .map(char::len_utf8)
+        .sum();
+    line_start + column
+}
+/// Names used by the file that nothing in the file defines or imports, sorted
+///
+/// Only unqualified names count. The head of a multi-segment path is a crate
+/// or module (`std::fmt`, `serde_json::Value`), never an item of the glob,
+/// unless it is a type qualifying an associated item or variant
+/// (`Role::Admin`).
+fn glob_candidate_names(ast: &File) -> Vec<String> {
+    #[derive(Default)]
+    struct NameCollector {
+        used: std::collections::BTreeSet<String>,
+        known: std::collections::HashSet<String>,
+    }
+    impl NameCollector {
+        fn collect_tokens(&mut self, tokens: proc_macro2::TokenStream) {
+            use proc_macro2::{Spacing, TokenTree};
+            // Method and field names follow a `.`, path continuations a `::`;
+            // field names and named arguments precede a lone `:` or `=`
+            let mut previous: [Option<char>; 2] = [None, None];
+            let mut tokens = tokens.into_iter().peekable();
+            while let Some(token) = tokens.next() {
+                let names_field = matches!(
+                    tokens.peek(),
+                    Some(TokenTree::Punct(punct))
+                        if matches!(punct.as_char(), ':' | '=') && punct.spacing() == Spacing::Alone
+                );
+                let starts_path = matches!(
+                    tokens.peek(),
+                    Some(TokenTree::Punct(punct))
+                        if punct.as_char() == ':' && punct.spacing() == Spacing::Joint
+                );
+                match &token {
+                    TokenTree::Ident(ident)
+                        if previous[1] != Some('.')
+                            && previous != [Some(':'), Some(':')]
+                            && !names_field
+                            && (!starts_path || is_type_name(&ident.to_string())) =>
+                    {
+                        self.used.insert(ident.to_string());
+                    }
+                    TokenTree::Group(group) => self.collect_tokens(group.stream()),
+                    _ => {}
+                }
+                let punct = match &token {
+                    TokenTree::Punct(punct) => Some(punct.as_char()),
+                    _ => None,
+                };
+                previous = [previous[1], punct];
+            }
+        }
+    }
+    impl<'ast> Visit<'ast> for NameCollector {
+        fn visit_path(&mut self, path: &'ast syn::Path) {
+            if path.leading_colon.is_none() {
+                if let Some(first) = path.segments.first() {
+                    let name = first.ident.to_string();
+                    if path.segments.len() == 1 || is_type_name(&name) {
+                        self.used.insert(name);
+                    }
+                }
+            }
+            syn::visit::visit_path(self, path);
+        }
+        fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+            self.collect_tokens(mac.tokens.clone());
+            syn::visit::visit_macro(self, mac);
+        }
+        fn visit_attribute(&mut self, attr: &'ast syn::Attribute) {
+            if attr.path().is_ident("derive") {
+                let _ = attr.parse_nested_meta(|meta| {
+                    if let Some(ident) = meta.path.get_ident() {
+                        self.used.insert(ident.to_string());
+                    }
+                    Ok(())
+                });
+            }
+            // Other attribute paths (`allow`, `cfg`, ...) name no items
+        }
+        fn visit_item(&mut self, item: &'ast Item) {
+            let ident = match item {
+                Item::Const(i) => Some(&i.ident),
+                Item::Enum(i) => Some(&i.ident),
+                Item::Fn(i) => Some(&i.sig.ident),
+                Item::Macro(i) => i.ident.as_ref(),
+                Item::Mod(i) => Some(&i.ident),
+                Item::Static(i) => Some(&i.ident),
+                Item::Struct(i) => Some(&i.ident),
+                Item::Trait(i) => Some(&i.ident),
+                Item::TraitAlias(i) => Some(&i.ident),
+                Item::Type(i) => Some(&i.ident),
+                Item::Union(i) => Some(&i.ident),
+                _ => None,
+            };
+            if let Some(ident) = ident {
+                self.known.insert(ident.to_string());
+            }
+            syn::visit::visit_item(self, item);
+        }
+        fn visit_pat_ident(&mut self, pat: &'ast syn::PatIdent) {
+            self.known.insert(pat.ident.to_string());
+            syn::visit::visit_pat_ident(self, pat);
+        }
+        fn visit_type_param(&mut self, param: &'ast syn::TypeParam) {
+            self.known.insert(param.ident.to_string());
+            syn::visit::visit_type_param(self, param);
+        }
+        fn visit_use_name(&mut self, name: &'ast syn::UseName) {
+            self.known.insert(name.ident.to_string());
+        }
+        fn visit_use_rename(&mut self, rename: &'ast syn::UseRename) {
+            self.known.insert(rename.rename.to_string());
+        }
+    }
+
+    let mut collector = NameCollector::default();
+    collector.visit_file(ast);
+    collector
+        .used
+        .into_iter()
+        .filter(|name| !collector.known.contains(name) && !is_builtin_name(name))
+        .collect()
+}
+/// Whether `name` is cased like a type (`Role`) rather than a module or crate
+fn is_type_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// Keywords, primitive types, standard crates, and prelude items and macros
+fn is_builtin_name(name: &str) -> bool {
+    const BUILTIN: &str = concat!(
+        // Keywords
+        "as async await break const continue crate dyn else enum extern false fn for if impl in ",
+        "let loop match mod move mut pub ref return self Self static struct super trait true ",
+        "type unsafe use where while ",
+        // Primitive types
+        "bool char str u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64 ",
+        // Standard crates
+        "std core alloc ",
+        // Prelude
+        "Option Some None Result Ok Err Vec String Box ToString ToOwned Clone Copy Default ",
+        "Drop Fn FnMut FnOnce Iterator IntoIterator DoubleEndedIterator ExactSizeIterator ",
+        "Extend Send Sync Sized Unpin Eq PartialEq Ord PartialOrd AsRef AsMut Into From ",
+        "TryFrom TryInto FromIterator Debug Hash ",
+        // Standard macros
+        "assert assert_eq assert_ne cfg concat dbg debug_assert debug_assert_eq ",
+        "debug_assert_ne env eprint eprintln format include include_bytes include_str matches ",
+        "panic print println stringify todo unimplemented unreachable vec write writeln",
+    );
+    BUILTIN.split_whitespace().any(|builtin| builtin == name)
+}
+/// Find the source spans of a module path inside `use` declarations
+///
+/// `module` may have several segments (`common::utils`); a match is any run of
+/// consecutive path segments equal to it, at any depth of the use tree. Aliases
+/// introduced with `as` are never matched. When `include_nested` is false only
+/// top-level `use` items are searched.
+pub(crate) fn find_use_path_spans(
+    ast: &File,
+    module: &str,
+    include_nested: bool,
+) -> Vec<(proc_macro2::LineColumn, proc_macro2::LineColumn)> {
+    struct UseSpanVisitor<'a> {
+        target: Vec<&'a str>,
+        spans: Vec<(proc_macro2::LineColumn, proc_macro2::LineColumn)>,
+    }
+    impl<'ast> Visit<'ast> for UseSpanVisitor<'_> {
+        fn visit_item_use(&mut self, node: &'ast ItemUse) {
+            self.walk(&node.tree, &mut Vec::new());
+        }
+    }
+    impl UseSpanVisitor<'_> {
+        fn walk<'t>(&mut self, tree: &'t UseTree, path: &mut Vec<&'t syn::Ident>) {
+            match tree {
+                UseTree::Path(p) => {
+                    path.push(&p.ident);
+                    self.check(path);
+                    self.walk(&p.tree, path);
+                    path.pop();
+                }
+                UseTree::Name(n) => {
+                    path.push(&n.ident);
+                    self.check(path);
+                    path.pop();
+                }
+                UseTree::Rename(r) => {
+                    path.push(&r.ident);
+                    self.check(path);
+                    path.pop();
+                }
+                UseTree::Group(g) => {
+                    for item in &g.items {
+                        self.walk(item, path);
+                    }
+                }
+                UseTree::Glob(_) => {}
+            }
+        }
+        fn check(&mut self, path: &[&syn::Ident]) {
+            let Some(start) = path.len().checked_sub(self.target.len()) else {
+                return;
+            };
+            let tail = &path[start..];
+            if tail
+                .iter()
+                .zip(&self.target)
+                .all(|(ident, seg)| *ident == seg)
+            {
+                let first = tail[0].span().start();
+                let last = tail[tail.len() - 1].span().end();
+                self.spans.push((first, last));
+            }
+        }
+    }
+
+    let mut visitor = UseSpanVisitor {
+        target: module.split("::").collect(),
+        spans: Vec::new(),
+    };
+    if include_nested {
+        visitor.visit_file(ast);
+    } else {
+        for item in &ast.items {
+            if let Item::Use(item_use) = item {
+                visitor.visit_item_use(item_use);
+            }
+        }
+    }
+    visitor.spans
+}
+/// Find the source spans of a module path used as a qualifier outside `use`
+///
+/// Matches runs of consecutive segments equal to `module` in expression, type
+/// and attribute paths (`utils::helper()`, `crate::utils::Config`) that are
+/// followed by at least one more segment, so a local binding that happens to
+/// share the module's name is never matched. Paths inside macro arguments are
+/// not parsed and so not searched.
+pub(crate) fn find_qualified_path_spans(
+    ast: &File,
+    module: &str,
+) -> Vec<(proc_macro2::LineColumn, proc_macro2::LineColumn)> {
+    struct QualifiedSpanVisitor<'a> {
+        target: Vec<&'a str>,
+        spans: Vec<(proc_macro2::LineColumn, proc_macro2::LineColumn)>,
+    }
+    impl<'ast> Visit<'ast> for QualifiedSpanVisitor<'_> {
+        fn visit_path(&mut self, node: &'ast syn::Path) {
+            let idents: Vec<&syn::Ident> = node.segments.iter().map(|s| &s.ident).collect();
+            // The last segment is the item itself, never the qualifier
+            let qualifiers = idents.len().saturating_sub(1);
+            for start in 0..qualifiers {
+                let end = start + self.target.len();
+                if end > qualifiers {
+                    break;
+                }
+                if idents[start..end]
+                    .iter()
+                    .zip(&self.target)
+                    .all(|(ident, seg)| *ident == seg)
+                {
+                    self.spans
+                        .push((idents[start].span().start(), idents[end - 1].span().end()));
+                }
+            }
+            syn::visit::visit_path(self, node);
+        }
+    }
+
+    let mut visitor = QualifiedSpanVisitor {
+        target: module.split("::").collect(),
+        spans: Vec::new(),
+    };
+    visitor.visit_file(ast);
+    visitor.spans
+}
+/// First segments of every path in `source`: `use` roots, qualified paths
+/// (`serde_json::json!`, `#[tokio::test]`) and `extern crate` names
+///
+/// Source that fails to parse yields no roots.
+pub(crate) fn path_roots(source: &str) -> Vec<String> {
+    struct RootVisitor {
+        roots: Vec<String>,
+    }
+    impl<'ast> Visit<'ast> for RootVisitor {
+        fn visit_item_use(&mut self, node: &'ast ItemUse) {
+            self.use_roots(&node.tree);
+        }
+        fn visit_item_extern_crate(&mut self, node: &'ast syn::ItemExternCrate) {
+            self.roots.push(node.ident.to_string());
+        }
+        fn visit_path(&mut self, node: &'ast syn::Path) {
+            if node.segments.len() > 1 {
+                self.roots.push(node.segments[0].ident.to_string());
+            }
+            syn::visit::visit_path(self, node);
+        }
+    }
+    impl RootVisitor {
+        fn use_roots(&mut self, tree: &UseTree) {
+            match tree {
+                UseTree::Path(p) => self.roots.push(p.ident.to_string()),
+                UseTree::Name(n) => self.roots.push(n.ident.to_string()),
+                UseTree::Rename(r) => self.roots.push(r.ident.to_string()),
+                UseTree::Group(g) => g.items.iter().for_each(|item| self.use_roots(item)),
+                UseTree::Glob(_) => {}
+            }
+        }
+    }
+
+    let Ok(ast) = syn::parse_file(source) else {
+        return Vec::new();
+    };
+    let mut visitor = RootVisitor { roots: Vec::new() };
+    visitor.visit_file(&ast);
+    visitor.roots
+}
 /// Analyzes Rust source code to produce an import graph.
 /// Uses native syn AST parsing (no subprocess required).
 pub(crate) fn analyze_imports(
@@ -501,12 +1161,66 @@ pub(crate) fn analyze_imports(
     file_path: Option<&std::path::Path>,
 ) -> PluginResult<ImportGraph> {
     let imports = parse_imports(source)?;
-    Ok(ImportGraphBuilder::new("rust")
+    let mut graph = ImportGraphBuilder::new("rust")
         .with_source_file(file_path)
         .with_imports(imports)
         .extract_external_dependencies(is_external_dependency)
         .with_parser_version("0.1.0-plugin")
-        .build())
+        .build();
+
+    // With a crate on disk, classify against Cargo.toml instead of the
+    // path-prefix heuristic so `std` and local modules aren't reported
+    if let Some((crate_root, dependency_names)) = file_path.and_then(find_crate_dependencies) {
+        let current_dir = file_path.and_then(|p| p.parent()).map(|p| p.to_path_buf());
+        classify_imports(
+            &mut graph,
+            &crate_root,
+            &dependency_names,
+            "::",
+            |root, module_path| resolves_to_crate_module(root, current_dir.as_deref(), module_path),
+        );
+    }
+
+    Ok(graph)
+}
+
+/// Find the crate containing `file_path` and the names of its declared
+/// dependencies (regular and dev)
+fn find_crate_dependencies(
+    file_path: &std::path::Path,
+) -> Option<(std::path::PathBuf, Vec<String>)> {
+    let crate_root = file_path
+        .ancestors()
+        .skip(1)
+        .find(|dir| dir.join("Cargo.toml").is_file())?;
+    let content = std::fs::read_to_string(crate_root.join("Cargo.toml")).ok()?;
+    let manifest = crate::manifest::parse_cargo_toml(&content).ok()?;
+    let names = manifest
+        .dependencies
+        .iter()
+        .chain(&manifest.dev_dependencies)
+        .map(|dep| dep.name.clone())
+        .collect();
+    Some((crate_root.to_path_buf(), names))
+}
+
+/// Whether a `use` path resolves to a module file of the crate
+fn resolves_to_crate_module(
+    crate_root: &std::path::Path,
+    current_dir: Option<&std::path::Path>,
+    module_path: &str,
+) -> bool {
+    let first = module_path.split("::").next().unwrap_or(module_path);
+    if matches!(first, "crate" | "self" | "super") {
+        return true;
+    }
+
+    let src_dir = crate_root.join("src");
+    std::iter::once(src_dir.as_path())
+        .chain(current_dir)
+        .any(|dir| {
+            dir.join(format!("{}.rs", first)).is_file() || dir.join(first).join("mod.rs").is_file()
+        })
 }
 /// Check if a module path represents an external dependency
 fn is_external_dependency(module_path: &str) -> bool {
@@ -553,6 +1267,46 @@ fn outer() {
         assert!(functions.contains(&"inner".to_string()));
     }
     #[test]
+    fn test_list_function_signatures() {
+        let source = r#"
+async fn f<T>(x: T) -> T {
+    x
+}
+struct S;
+impl S {
+    const unsafe fn raw<'a>(&self, data: &'a [u8], len: usize) -> Option<&'a str> {
+        None
+    }
+}
+"#;
+        let signatures = list_function_signatures(source).unwrap();
+        assert_eq!(
+            signatures[0],
+            FunctionSignature {
+                name: "f".to_string(),
+                is_async: true,
+                is_unsafe: false,
+                is_const: false,
+                generics: vec!["T".to_string()],
+                params: vec!["x: T".to_string()],
+                return_type: Some("T".to_string()),
+            }
+        );
+        let raw = &signatures[1];
+        assert_eq!(raw.name, "raw");
+        assert!(raw.is_const && raw.is_unsafe && !raw.is_async);
+        assert_eq!(raw.generics, vec!["'a"]);
+        assert_eq!(raw.params, vec!["&self", "data: &'a [u8]", "len: usize"]);
+        assert_eq!(raw.return_type.as_deref(), Some("Option<&'a str>"));
+    }
+    #[test]
+    fn test_list_function_signatures_unit_return() {
+        let signatures = list_function_signatures("fn main() {}").unwrap();
+        assert_eq!(signatures.len(), 1);
+        assert!(signatures[0].params.is_empty());
+        assert_eq!(signatures[0].return_type, None);
+    }
+    #[test]
     fn test_syntax_error() {
         let source = "fn my_func {";
         let result = list_functions(source);
@@ -592,6 +1346,67 @@ const MY_CONST: i32 = 42;
             .any(|s| s.name == "MY_CONST" && s.kind == SymbolKind::Constant));
     }
     #[test]
+    fn test_extract_symbols_marks_test_functions() {
+        let source = r#"
+#[test]
+fn foo() {}
+
+#[tokio::test]
+async fn bar() {}
+
+#[inline]
+fn normal() {}
+
+impl Suite {
+    #[test]
+    fn method_case() {}
+}
+"#;
+        let symbols = extract_symbols(source).unwrap();
+        let is_test = |name: &str| symbols.iter().find(|s| s.name == name).unwrap().is_test;
+        assert!(is_test("foo"));
+        assert!(is_test("bar"));
+        assert!(is_test("method_case"));
+        assert!(!is_test("normal"));
+    }
+    #[test]
+    fn test_extract_symbols_nests_impl_methods_under_type() {
+        let source = r#"
+impl Config {
+    pub fn new() -> Self {
+        Config
+    }
+}
+
+pub struct Config;
+
+impl std::fmt::Display for Config {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Ok(())
+    }
+}
+
+fn helper() {}
+"#;
+        let symbols = extract_symbols(source).unwrap();
+        let config = symbols.iter().position(|s| s.name == "Config").unwrap();
+        let parent = |name: &str| symbols.iter().find(|s| s.name == name).unwrap().parent;
+        assert_eq!(parent("new"), Some(config));
+        assert_eq!(parent("fmt"), Some(config));
+        assert_eq!(parent("helper"), None);
+        assert_eq!(parent("Config"), None);
+
+        let tree = mill_plugin_api::SymbolNode::build_tree(symbols);
+        let names: Vec<&str> = tree.iter().map(|n| n.symbol.name.as_str()).collect();
+        assert_eq!(names, vec!["Config", "helper"]);
+        let methods: Vec<&str> = tree[0]
+            .children
+            .iter()
+            .map(|n| n.symbol.name.as_str())
+            .collect();
+        assert_eq!(methods, vec!["new", "fmt"]);
+    }
+    #[test]
     fn test_parse_imports() {
         let source = r#"
 use std::collections::HashMap;
@@ -601,4 +1416,115 @@ use crate::my_module::*;
         let imports = parse_imports(source).unwrap();
         assert_eq!(imports.len(), 4);
     }
+    #[test]
+    fn test_analyze_imports_classifies_against_manifest() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("Cargo.toml"),
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[dependencies]\nserde = \"1.0\"\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/utils.rs"), "pub fn helper() {}\n").unwrap();
+        let lib = dir.path().join("src/lib.rs");
+        let source = r#"
+use crate::utils::helper;
+use utils::helper as h;
+use serde::Serialize;
+use std::fmt;
+"#;
+        std::fs::write(&lib, source).unwrap();
+
+        let graph = analyze_imports(source, Some(&lib)).unwrap();
+
+        assert_eq!(graph.metadata.external_dependencies.len(), 1);
+        assert!(graph.metadata.external_dependencies[0].starts_with("serde"));
+    }
+    #[test]
+    fn test_expand_glob_import() {
+        let source = r#"use std::fmt;
+use crate::models::*;
+
+#[derive(Debug, Serialize)]
+struct Report {
+    user: User,
+}
+
+fn describe(id: u32) -> String {
+    let user = find_user(id);
+    format!("{}", Role::Admin.label(&user))
+}
+"#;
+
+        let (result, changes) =
+            expand_glob_import_from(source, "crate::models", &["Role", "User", "find_user"])
+                .unwrap();
+
+        assert_eq!(changes, 1);
+        // `Serialize` is not one of the module's items
+        assert!(result.contains("use crate::models::{Role, User, find_user};\n"));
+        assert!(!result.contains("::*"));
+        assert!(result.contains("use std::fmt;\n"));
+        // Other globs and locals are untouched
+        let (unchanged, changes) = expand_glob_import(&result, "crate::other").unwrap();
+        assert_eq!(changes, 0);
+        assert_eq!(unchanged, result);
+    }
+    #[test]
+    fn test_expand_glob_import_skips_macro_field_names() {
+        let source = r#"use crate::models::*;
+
+fn build() -> Value {
+    json!({ name: default_name(), id = 1 })
+}
+"#;
+
+        let (result, _) = expand_glob_import(source, "crate::models").unwrap();
+
+        assert!(result.contains("use crate::models::{Value, default_name, json};\n"));
+    }
+    #[test]
+    fn test_expand_glob_import_skips_crate_paths() {
+        let source = r#"use crate::models::*;
+
+impl std::fmt::Display for User {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", serde_json::to_string(&self.id).unwrap_or_default())
+    }
+}
+
+fn parse(raw: &str) -> serde_json::Value {
+    core::hint::black_box(serde_json::from_str(raw).unwrap_or_default())
+}
+"#;
+
+        let (result, _) = expand_glob_import(source, "crate::models").unwrap();
+
+        assert!(result.contains("use crate::models::User;\n"), "{}", result);
+    }
+    #[test]
+    fn test_expand_glob_import_refuses_with_other_globs() {
+        let source =
+            "use crate::models::*;\nuse crate::helpers::*;\n\nfn run() -> User {\n    load()\n}\n";
+
+        assert!(expand_glob_import(source, "crate::models").is_err());
+
+        let (result, changes) =
+            expand_glob_import_from(source, "crate::models", &["User"]).unwrap();
+        assert_eq!(changes, 1);
+        assert!(result.contains("use crate::models::User;\n"));
+        assert!(result.contains("use crate::helpers::*;\n"));
+    }
+    #[test]
+    fn test_expand_unused_glob_import_removes_it() {
+        let source = "use std::collections::HashMap;\n#[allow(unused_imports)]\nuse crate::helpers::*;\n\nfn count(map: &HashMap<String, u32>) -> usize {\n    map.len()\n}\n";
+
+        let (result, changes) = expand_glob_import(source, "crate::helpers").unwrap();
+
+        assert_eq!(changes, 1);
+        assert_eq!(
+            result,
+            "use std::collections::HashMap;\n\nfn count(map: &HashMap<String, u32>) -> usize {\n    map.len()\n}\n"
+        );
+    }
 }
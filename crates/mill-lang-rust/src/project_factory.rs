@@ -56,6 +56,13 @@ impl ProjectFactory for RustProjectFactory {
         write_project_file(&entry_file_path, &entry_content)?;
         created_files.push(entry_file_path.display().to_string());
 
+        // The binary of a combined package calls into its library
+        if config.package_type == PackageType::LibAndBin {
+            let main_path = package_path.join("src/main.rs");
+            write_project_file(&main_path, &generate_lib_and_bin_main(&package_name))?;
+            created_files.push(main_path.display().to_string());
+        }
+
         // Create additional files for full template
         if matches!(config.template, Template::Full) {
             let additional = create_full_template(&package_path, &package_name)?;
@@ -118,7 +125,7 @@ fn create_directory_structure(package_path: &Path, _package_type: PackageType) -
 
 fn entry_file(package_type: PackageType) -> &'static str {
     match package_type {
-        PackageType::Library => "src/lib.rs",
+        PackageType::Library | PackageType::LibAndBin => "src/lib.rs",
         PackageType::Binary => "src/main.rs",
     }
 }
@@ -146,6 +153,25 @@ edition = "{}"
 name = "{}"
 path = "src/main.rs"
 
+[dependencies]
+"#,
+            package_name,
+            constants::DEFAULT_EDITION,
+            package_name
+        ),
+        PackageType::LibAndBin => format!(
+            r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "{}"
+
+[lib]
+path = "src/lib.rs"
+
+[[bin]]
+name = "{}"
+path = "src/main.rs"
+
 [dependencies]
 "#,
             package_name,
@@ -179,9 +205,42 @@ mod tests {{
 }
 "#
         .to_string(),
+        PackageType::LibAndBin => format!(
+            r#"//! {} crate
+//!
+//! TODO: Add crate description
+
+/// Greeting printed by the binary
+pub fn greeting() -> String {{
+    "Hello, world!".to_string()
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    fn test_greeting() {{
+        assert_eq!(greeting(), "Hello, world!");
+    }}
+}}
+"#,
+            package_name
+        ),
     }
 }
 
+/// `src/main.rs` of a combined package, using the library crate
+fn generate_lib_and_bin_main(package_name: &str) -> String {
+    format!(
+        r#"fn main() {{
+    println!("{{}}", {}::greeting());
+}}
+"#,
+        package_name.replace('-', "_")
+    )
+}
+
 fn create_full_template(package_path: &Path, package_name: &str) -> PluginResult<Vec<String>> {
     let mut created = Vec::new();
 
@@ -265,5 +324,33 @@ mod tests {
     fn test_entry_file() {
         assert_eq!(entry_file(PackageType::Library), "src/lib.rs");
         assert_eq!(entry_file(PackageType::Binary), "src/main.rs");
+        assert_eq!(entry_file(PackageType::LibAndBin), "src/lib.rs");
+    }
+
+    #[test]
+    fn test_create_lib_and_bin_package() {
+        let workspace = tempfile::TempDir::new().unwrap();
+        let config = CreatePackageConfig {
+            package_path: "my-tool".to_string(),
+            package_type: PackageType::LibAndBin,
+            template: Template::Minimal,
+            add_to_workspace: false,
+            workspace_root: workspace.path().display().to_string(),
+        };
+
+        let result = RustProjectFactory.create_package(&config).unwrap();
+        assert_eq!(result.created_files.len(), 3);
+
+        let package = workspace.path().join("my-tool");
+        let cargo_toml = fs::read_to_string(package.join("Cargo.toml")).unwrap();
+        assert!(cargo_toml.contains("[lib]"));
+        assert!(cargo_toml.contains("[[bin]]"));
+
+        let lib = fs::read_to_string(package.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub fn greeting()"));
+        assert!(lib.contains("fn test_greeting()"));
+
+        let main = fs::read_to_string(package.join("src/main.rs")).unwrap();
+        assert!(main.contains("my_tool::greeting()"));
     }
 }
@@ -4,6 +4,7 @@
 
 use crate::constants;
 use mill_foundation::protocol::{EditLocation, EditPlan, EditType, TextEdit};
+use mill_lang_common::naming::{check_convention, validate_identifier, Language, NamingConvention};
 use mill_lang_common::{
     find_literal_occurrences, is_valid_code_literal_location,
     refactoring::edit_plan_builder::EditPlanBuilder, CodeRange, ExtractConstantAnalysis,
@@ -21,6 +22,8 @@ pub fn plan_extract_function(
     function_name: &str,
     file_path: &str,
 ) -> PluginResult<EditPlan> {
+    validate_identifier(function_name, Language::Rust).map_err(PluginApiError::invalid_input)?;
+    check_convention(function_name, NamingConvention::SnakeCase);
     let lines: Vec<&str> = source.lines().collect();
 
     if start_line as usize >= lines.len() || end_line as usize >= lines.len() {
@@ -128,6 +131,8 @@ pub fn plan_extract_variable(
     };
 
     let var_name = variable_name.unwrap_or_else(|| "extracted".to_string());
+    validate_identifier(&var_name, Language::Rust).map_err(PluginApiError::invalid_input)?;
+    check_convention(&var_name, NamingConvention::SnakeCase);
 
     // Get indentation
     let indent = LineExtractor::get_indentation_str(source, start_line);
@@ -604,6 +609,8 @@ pub fn plan_extract_constant(
     name: &str,
     file_path: &str,
 ) -> PluginResult<EditPlan> {
+    validate_identifier(name, Language::Rust).map_err(PluginApiError::invalid_input)?;
+    check_convention(name, NamingConvention::ScreamingSnakeCase);
     let analysis = analyze_extract_constant(source, line, character, file_path)?;
 
     // Rust needs explicit type annotation
@@ -727,6 +734,233 @@ pub fn plan_inline_variable(
     }
 }
 
+/// Plan reflowing a function signature that exceeds `max_width`
+///
+/// A signature whose parameter list sits on one line longer than `max_width`
+/// is rewritten to one parameter per line with a trailing comma, matching
+/// rustfmt's vertical layout. Signatures that already fit, or that already
+/// span multiple lines, produce a plan without edits.
+pub fn plan_reflow_signature(
+    source: &str,
+    function_name: &str,
+    max_width: usize,
+    file_path: &str,
+) -> PluginResult<EditPlan> {
+    let file = syn::parse_file(source).map_err(|e| crate::parser::syntax_error(&e, source))?;
+
+    let mut finder = SignatureFinder {
+        name: function_name,
+        found: None,
+    };
+    syn::visit::Visit::visit_file(&mut finder, &file);
+    let sig = finder.found.ok_or_else(|| {
+        PluginApiError::invalid_input(format!("Function '{}' not found", function_name))
+    })?;
+
+    let open = sig.paren_token.span.open().start();
+    let close = sig.paren_token.span.close().start();
+    let lines: Vec<&str> = source.lines().collect();
+    let line = lines[open.line - 1];
+
+    let mut edits = Vec::new();
+    if open.line == close.line
+        && line.trim_end().chars().count() > max_width
+        && !sig.inputs.is_empty()
+    {
+        let indent = LineExtractor::get_indentation_str(source, (open.line - 1) as u32);
+        let params: Vec<String> = sig
+            .inputs
+            .iter()
+            .map(|arg| {
+                let span = syn::spanned::Spanned::span(arg);
+                char_slice(line, span.start().column, span.end().column)
+            })
+            .collect();
+
+        let mut new_text = String::from("\n");
+        for param in &params {
+            new_text.push_str(&format!("{}    {},\n", indent, param));
+        }
+        new_text.push_str(&indent);
+
+        edits.push(TextEdit {
+            file_path: None,
+            edit_type: EditType::Replace,
+            location: EditLocation {
+                start_line: (open.line - 1) as u32,
+                start_column: (open.column + 1) as u32,
+                end_line: (close.line - 1) as u32,
+                end_column: close.column as u32,
+            },
+            original_text: char_slice(line, open.column + 1, close.column),
+            new_text,
+            priority: 100,
+            description: format!("Reflow signature of '{}'", function_name),
+        });
+    }
+
+    Ok(EditPlanBuilder::new(file_path, "reflow_signature")
+        .with_edits(edits)
+        .with_syntax_validation("Verify Rust syntax is valid after reflowing")
+        .with_intent_args(serde_json::json!({
+            "function_name": function_name,
+            "max_width": max_width
+        }))
+        .with_complexity(2)
+        .with_impact_area("formatting")
+        .build())
+}
+
+/// Finds the first function signature (free, method, or trait) with a given name
+struct SignatureFinder<'a> {
+    name: &'a str,
+    found: Option<syn::Signature>,
+}
+
+impl<'ast> syn::visit::Visit<'ast> for SignatureFinder<'_> {
+    fn visit_signature(&mut self, sig: &'ast syn::Signature) {
+        if self.found.is_none() && sig.ident == self.name {
+            self.found = Some(sig.clone());
+        }
+    }
+}
+
+/// Slice a line by character columns, as reported by proc-macro2 spans
+fn char_slice(line: &str, start: usize, end: usize) -> String {
+    line.chars()
+        .skip(start)
+        .take(end.saturating_sub(start))
+        .collect()
+}
+
+/// Plan sorting and grouping the `use` declarations at the top of a file
+///
+/// The leading run of `use` declarations is regrouped into `std`/`core`/`alloc`,
+/// external crates, and `crate`/`self`/`super` blocks separated by blank lines,
+/// each sorted by path. The run ends at the first line that is neither a `use`
+/// declaration nor blank, so imports behind `#[cfg(...)]` attributes and
+/// everything after them are left untouched.
+pub fn plan_organize_imports(source: &str, file_path: &str) -> PluginResult<EditPlan> {
+    let lines: Vec<&str> = source.lines().collect();
+
+    // Skip the file header: inner attributes, module docs, comments
+    let start = lines
+        .iter()
+        .position(|line| {
+            let trimmed = line.trim();
+            !(trimmed.is_empty() || trimmed.starts_with("//") || trimmed.starts_with("#!"))
+        })
+        .unwrap_or(lines.len());
+
+    let mut statements: Vec<String> = Vec::new();
+    let mut end = start;
+    let mut idx = start;
+    while idx < lines.len() {
+        let trimmed = lines[idx].trim();
+        if trimmed.is_empty() {
+            idx += 1;
+            continue;
+        }
+        if use_path(trimmed).is_none() {
+            break;
+        }
+        let first = idx;
+        while !strip_line_comment(lines[idx]).trim_end().ends_with(';') {
+            idx += 1;
+            if idx == lines.len() {
+                return Err(PluginApiError::parse(format!(
+                    "Unterminated use declaration at line {}",
+                    first + 1
+                )));
+            }
+        }
+        statements.push(lines[first..=idx].join("\n"));
+        idx += 1;
+        end = idx;
+    }
+
+    let mut edits = Vec::new();
+    if !statements.is_empty() {
+        let mut groups: [Vec<&String>; 3] = Default::default();
+        for statement in &statements {
+            groups[import_group(statement)].push(statement);
+        }
+        for group in &mut groups {
+            group.sort_by(|a, b| use_path(a).cmp(&use_path(b)).then_with(|| a.cmp(b)));
+        }
+        let organized = groups
+            .iter()
+            .filter(|group| !group.is_empty())
+            .map(|group| {
+                group
+                    .iter()
+                    .map(|s| s.as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let original = lines[start..end].join("\n");
+        if organized != original {
+            edits.push(TextEdit {
+                file_path: None,
+                edit_type: EditType::Replace,
+                location: EditLocation {
+                    start_line: start as u32,
+                    start_column: 0,
+                    end_line: (end - 1) as u32,
+                    end_column: lines[end - 1].chars().count() as u32,
+                },
+                original_text: original,
+                new_text: organized,
+                priority: 100,
+                description: "Organize imports".to_string(),
+            });
+        }
+    }
+
+    Ok(EditPlanBuilder::new(file_path, "organize_imports")
+        .with_edits(edits)
+        .with_syntax_validation("Verify Rust syntax is valid after organizing imports")
+        .with_complexity(1)
+        .with_impact_area("imports")
+        .build())
+}
+
+/// Path of a `use` declaration (after any visibility and leading `::`)
+fn use_path(statement: &str) -> Option<&str> {
+    let mut rest = statement.trim_start();
+    if let Some(after_pub) = rest.strip_prefix("pub") {
+        let after_pub = after_pub.trim_start();
+        rest = match after_pub.strip_prefix('(') {
+            Some(restricted) => &restricted[restricted.find(')')? + 1..],
+            None => after_pub,
+        }
+        .trim_start();
+    }
+    rest.strip_prefix("use ")
+        .map(|path| path.trim_start().trim_start_matches("::"))
+}
+
+/// 0 for the standard library, 1 for external crates, 2 for local paths
+fn import_group(statement: &str) -> usize {
+    let path = use_path(statement).unwrap_or_default();
+    let root = path
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .next()
+        .unwrap_or_default();
+    match root {
+        "std" | "core" | "alloc" => 0,
+        "crate" | "self" | "super" => 2,
+        _ => 1,
+    }
+}
+
+fn strip_line_comment(line: &str) -> &str {
+    line.find("//").map_or(line, |idx| &line[..idx])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1023,4 +1257,64 @@ let backup = "https://api.example.com";
         // Check that the inferred type matches the suffix
         assert!(plan.edits[0].new_text.contains("const TIMEOUT_MS: u64"));
     }
+
+    #[test]
+    fn test_plan_reflow_signature_long_function() {
+        let source = "impl Server {\n    pub fn configure(&mut self, host: String, port: u16, retries: Option<u32>, handler: Box<dyn Fn(u32) -> u32>) -> bool {\n        true\n    }\n}\n";
+        let plan = plan_reflow_signature(source, "configure", 100, "test.rs").unwrap();
+
+        assert_eq!(plan.edits.len(), 1);
+        let edit = &plan.edits[0];
+        assert_eq!(edit.location.start_line, 1);
+        assert_eq!(
+            edit.new_text,
+            "\n        &mut self,\n        host: String,\n        port: u16,\n        retries: Option<u32>,\n        handler: Box<dyn Fn(u32) -> u32>,\n    "
+        );
+
+        let line = source.lines().nth(1).unwrap();
+        let start = edit.location.start_column as usize;
+        let end = edit.location.end_column as usize;
+        let reflowed = format!("{}{}{}", &line[..start], edit.new_text, &line[end..]);
+        assert_eq!(
+            reflowed,
+            "    pub fn configure(\n        &mut self,\n        host: String,\n        port: u16,\n        retries: Option<u32>,\n        handler: Box<dyn Fn(u32) -> u32>,\n    ) -> bool {"
+        );
+    }
+
+    #[test]
+    fn test_plan_reflow_signature_short_function_untouched() {
+        let source = "fn add(a: u32, b: u32) -> u32 {\n    a + b\n}\n";
+        let plan = plan_reflow_signature(source, "add", 100, "test.rs").unwrap();
+        assert!(plan.edits.is_empty());
+
+        let missing = plan_reflow_signature(source, "subtract", 100, "test.rs");
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn test_plan_organize_imports_groups_and_sorts() {
+        let source = "//! Module docs\n\nuse crate::model::User;\nuse serde::Serialize;\nuse std::sync::Arc;\nuse super::helpers;\nuse anyhow::{\n    Context, Result,\n};\nuse std::collections::HashMap;\n\n#[cfg(test)]\nuse std::fmt;\n\nfn main() {}\n";
+        let plan = plan_organize_imports(source, "test.rs").unwrap();
+
+        assert_eq!(plan.edits.len(), 1);
+        let edit = &plan.edits[0];
+        assert_eq!(edit.location.start_line, 2);
+        assert_eq!(edit.location.end_line, 9);
+        assert_eq!(
+            edit.new_text,
+            "use std::collections::HashMap;\nuse std::sync::Arc;\n\nuse anyhow::{\n    Context, Result,\n};\nuse serde::Serialize;\n\nuse crate::model::User;\nuse super::helpers;"
+        );
+
+        // Applying the plan and organizing again yields no edits
+        let lines: Vec<&str> = source.lines().collect();
+        let organized = format!(
+            "{}\n{}\n{}\n",
+            lines[..2].join("\n"),
+            edit.new_text,
+            lines[10..].join("\n")
+        );
+        assert!(organized.contains("#[cfg(test)]\nuse std::fmt;"));
+        let again = plan_organize_imports(&organized, "test.rs").unwrap();
+        assert!(again.edits.is_empty());
+    }
 }
@@ -13,6 +13,10 @@ use tracing::debug;
 
 /// Add a new member to a workspace Cargo.toml
 ///
+/// The member is inserted at its sorted position in `members`, keeping the
+/// existing formatting and comments. Adding a member that is already listed
+/// returns the content unchanged.
+///
 /// # Arguments
 ///
 /// * `workspace_content` - Current workspace Cargo.toml content
@@ -70,28 +74,88 @@ pub fn add_workspace_member(
         .as_array_mut()
         .ok_or_else(|| PluginApiError::manifest("[workspace.members] is not an array"))?;
 
-    // Add new member if not already present
+    // Adding an existing member is a no-op
     let member_str = relative_path.to_string_lossy();
     let member_exists = members
         .iter()
         .any(|v| v.as_str() == Some(member_str.as_ref()));
 
-    if !member_exists {
-        members.push(member_str.as_ref());
-        debug!(
-            member = %member_str,
-            "Added new member to workspace"
-        );
-    } else {
+    if member_exists {
         debug!(
             member = %member_str,
             "Member already exists in workspace"
         );
+        return Ok(workspace_content.to_string());
     }
 
+    insert_sorted(members, member_str.as_ref());
+    debug!(
+        member = %member_str,
+        "Added new member to workspace"
+    );
+
     Ok(doc.to_string())
 }
 
+/// Insert `member` before the first entry that sorts after it
+///
+/// Multi-line arrays keep their one-entry-per-line layout, indented like the
+/// neighbouring entry; comments stay with the entries they belong to.
+/// Single-line arrays are reformatted as `["a", "b"]`.
+fn insert_sorted(members: &mut toml_edit::Array, member: &str) {
+    let position = members
+        .iter()
+        .position(|v| v.as_str().is_some_and(|existing| existing > member))
+        .unwrap_or(members.len());
+    let is_multiline = members.iter().any(|v| {
+        v.decor()
+            .prefix()
+            .and_then(|p| p.as_str())
+            .is_some_and(|p| p.contains('\n'))
+    });
+    let inserted_before = position < members.len();
+    let neighbor_prefix = members
+        .get(position)
+        .or_else(|| members.get(position.wrapping_sub(1)))
+        .and_then(|v| v.decor().prefix())
+        .and_then(|p| p.as_str())
+        .map(str::to_string);
+
+    members.insert(position, member);
+
+    let Some(neighbor_prefix) = neighbor_prefix.filter(|_| is_multiline) else {
+        members.fmt();
+        return;
+    };
+    let line_start = neighbor_prefix.rfind('\n').map_or(0, |i| i + 1);
+    let indent: String = neighbor_prefix[line_start..]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+
+    // Whatever followed the previous entry now follows the new one: a comment
+    // ending the previous line stays on that line, while comment lines above
+    // the next entry (or the closing bracket) stay with it
+    let following = if inserted_before {
+        neighbor_prefix
+    } else {
+        members.trailing().as_str().unwrap_or_default().to_string()
+    };
+    let (same_line, rest) = following.split_at(following.find('\n').unwrap_or(following.len()));
+    if inserted_before {
+        if let Some(next) = members.get_mut(position + 1) {
+            next.decor_mut().set_prefix(rest);
+        }
+    } else {
+        members.set_trailing(rest);
+    }
+    if let Some(inserted) = members.get_mut(position) {
+        inserted
+            .decor_mut()
+            .set_prefix(format!("{}\n{}", same_line, indent));
+    }
+}
+
 /// Add a path dependency to a Cargo.toml file
 ///
 /// # Arguments
@@ -165,6 +229,30 @@ pub fn add_path_dependency(
     Ok(doc.to_string())
 }
 
+/// Options for generating a workspace Cargo.toml
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceManifestOptions {
+    /// Feature resolver version written to `[workspace] resolver`
+    pub resolver: String,
+    /// Paths excluded from the workspace, absolute or relative to its root
+    pub exclude: Vec<String>,
+    /// `version` inherited by members through `[workspace.package]`
+    pub package_version: Option<String>,
+    /// `edition` inherited by members through `[workspace.package]`
+    pub package_edition: Option<String>,
+}
+
+impl Default for WorkspaceManifestOptions {
+    fn default() -> Self {
+        Self {
+            resolver: "2".to_string(),
+            exclude: Vec::new(),
+            package_version: None,
+            package_edition: None,
+        }
+    }
+}
+
 /// Generate a new workspace Cargo.toml with initial members
 ///
 /// # Arguments
@@ -191,6 +279,46 @@ pub fn add_path_dependency(
 pub fn generate_workspace_manifest(
     member_paths: &[&str],
     workspace_root: &Path,
+) -> PluginResult<String> {
+    generate_workspace_manifest_with_options(
+        member_paths,
+        workspace_root,
+        &WorkspaceManifestOptions::default(),
+    )
+}
+
+/// Generate a new workspace Cargo.toml with a resolver, excludes and
+/// inherited package metadata
+///
+/// The `[workspace.package]` table is only written when a version or
+/// edition is given.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let options = WorkspaceManifestOptions {
+///     exclude: vec!["examples/legacy".to_string()],
+///     package_edition: Some("2021".to_string()),
+///     ..Default::default()
+/// };
+/// let workspace_toml = generate_workspace_manifest_with_options(
+///     &["/workspace/crate1"],
+///     Path::new("/workspace"),
+///     &options,
+/// )?;
+/// // Result:
+/// // [workspace]
+/// // members = ["crate1"]
+/// // exclude = ["examples/legacy"]
+/// // resolver = "2"
+/// //
+/// // [workspace.package]
+/// // edition = "2021"
+/// ```
+pub fn generate_workspace_manifest_with_options(
+    member_paths: &[&str],
+    workspace_root: &Path,
+    options: &WorkspaceManifestOptions,
 ) -> PluginResult<String> {
     let mut members_relative = Vec::new();
 
@@ -209,7 +337,35 @@ pub fn generate_workspace_manifest(
     }
 
     lines.push("]".to_string());
-    lines.push("resolver = \"2\"".to_string());
+
+    if !options.exclude.is_empty() {
+        lines.push("exclude = [".to_string());
+        for excluded in &options.exclude {
+            let path = Path::new(excluded);
+            let relative_path = if path.is_absolute() {
+                pathdiff::diff_paths(path, workspace_root).ok_or_else(|| {
+                    PluginApiError::internal("Failed to calculate relative path for exclude")
+                })?
+            } else {
+                path.to_path_buf()
+            };
+            lines.push(format!("    \"{}\",", relative_path.to_string_lossy()));
+        }
+        lines.push("]".to_string());
+    }
+
+    lines.push(format!("resolver = \"{}\"", options.resolver));
+
+    if options.package_version.is_some() || options.package_edition.is_some() {
+        lines.push(String::new());
+        lines.push("[workspace.package]".to_string());
+        if let Some(version) = &options.package_version {
+            lines.push(format!("version = \"{}\"", version));
+        }
+        if let Some(edition) = &options.package_edition {
+            lines.push(format!("edition = \"{}\"", edition));
+        }
+    }
 
     Ok(lines.join("\n"))
 }
@@ -234,6 +390,70 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
 
+    #[test]
+    fn test_add_workspace_member_is_idempotent() {
+        let content =
+            "[workspace]\n# Crates of the project\nmembers = [\"crates/a\", \"crates/b\"]\n";
+
+        let result =
+            add_workspace_member(content, "/workspace/crates/b", &PathBuf::from("/workspace"))
+                .unwrap();
+
+        assert_eq!(result, content);
+    }
+
+    #[test]
+    fn test_add_workspace_member_inserts_sorted() {
+        let content = r#"[workspace]
+# Crates of the project
+members = [
+    "crates/alpha", # first
+    # The UI crate
+    "crates/gamma", # keep last
+]
+resolver = "2"
+"#;
+
+        let result = add_workspace_member(
+            content,
+            "/workspace/crates/beta",
+            &PathBuf::from("/workspace"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result,
+            r#"[workspace]
+# Crates of the project
+members = [
+    "crates/alpha", # first
+    "crates/beta",
+    # The UI crate
+    "crates/gamma", # keep last
+]
+resolver = "2"
+"#
+        );
+
+        let result = add_workspace_member(
+            content,
+            "/workspace/crates/zeta",
+            &PathBuf::from("/workspace"),
+        )
+        .unwrap();
+        assert!(
+            result.contains("    \"crates/gamma\", # keep last\n    \"crates/zeta\",\n]"),
+            "{}",
+            result
+        );
+
+        let single_line = "[workspace]\nmembers = [\"b\", \"d\"]\n";
+        let result =
+            add_workspace_member(single_line, "/workspace/c", &PathBuf::from("/workspace"))
+                .unwrap();
+        assert_eq!(result, "[workspace]\nmembers = [\"b\", \"c\", \"d\"]\n");
+    }
+
     #[test]
     fn test_add_path_dependency() {
         let content = r#"
@@ -268,6 +488,48 @@ version = "0.1.0"
         assert!(result.contains("members"));
         assert!(result.contains("crate1"));
         assert!(result.contains("crate2"));
-        assert!(result.contains("resolver"));
+        assert!(result.contains("resolver = \"2\""));
+        assert!(!result.contains("exclude"));
+        assert!(!result.contains("[workspace.package]"));
+        assert!(result.parse::<DocumentMut>().is_ok());
+    }
+
+    #[test]
+    fn test_generate_workspace_manifest_with_options() {
+        let options = WorkspaceManifestOptions {
+            resolver: "3".to_string(),
+            exclude: vec![
+                "/workspace/examples/legacy".to_string(),
+                "scratch".to_string(),
+            ],
+            package_version: Some("0.3.0".to_string()),
+            package_edition: Some("2024".to_string()),
+        };
+        let result = generate_workspace_manifest_with_options(
+            &["/workspace/crate1"],
+            &PathBuf::from("/workspace"),
+            &options,
+        )
+        .unwrap();
+
+        assert!(result.contains("resolver = \"3\""));
+        assert!(result.contains("\"examples/legacy\""));
+
+        let doc = result.parse::<DocumentMut>().unwrap();
+        let exclude: Vec<&str> = doc["workspace"]["exclude"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|v| v.as_str())
+            .collect();
+        assert_eq!(exclude, vec!["examples/legacy", "scratch"]);
+        assert_eq!(
+            doc["workspace"]["package"]["version"].as_str(),
+            Some("0.3.0")
+        );
+        assert_eq!(
+            doc["workspace"]["package"]["edition"].as_str(),
+            Some("2024")
+        );
     }
 }
@@ -5,7 +5,8 @@
 
 use async_trait::async_trait;
 use mill_plugin_api::workspace_support::WorkspaceSupport;
-use std::path::Path;
+use mill_plugin_api::{ManifestData, PluginApiError, PluginResult, WorkspaceEdit};
+use std::path::{Path, PathBuf};
 use toml_edit::DocumentMut;
 use tracing::debug;
 
@@ -304,6 +305,68 @@ impl WorkspaceSupport for RustWorkspaceSupport {
             }
         }
     }
+
+    async fn analyze_workspace_manifests(
+        &self,
+        root: &Path,
+    ) -> PluginResult<Vec<(PathBuf, ManifestData)>> {
+        let root_manifest = root.join("Cargo.toml");
+        let content = tokio::fs::read_to_string(&root_manifest)
+            .await
+            .map_err(|e| {
+                PluginApiError::manifest(format!(
+                    "Failed to read {}: {}",
+                    root_manifest.display(),
+                    e
+                ))
+            })?;
+
+        let excluded: Vec<PathBuf> = list_workspace_excludes_impl(&content)
+            .iter()
+            .map(|e| root.join(e))
+            .collect();
+
+        let mut manifest_paths: Vec<PathBuf> = list_workspace_members_impl(&content)
+            .map_err(PluginApiError::manifest)?
+            .iter()
+            .flat_map(|pattern| expand_member_pattern(root, pattern))
+            .filter(|dir| !excluded.contains(dir))
+            .map(|dir| dir.join("Cargo.toml"))
+            .filter(|manifest| manifest.is_file())
+            .collect();
+
+        // A root [package] is a workspace member too
+        if content
+            .parse::<DocumentMut>()
+            .is_ok_and(|doc| doc.contains_key("package"))
+        {
+            manifest_paths.push(root_manifest);
+        }
+
+        manifest_paths.sort();
+        manifest_paths.dedup();
+
+        let mut manifests = Vec::with_capacity(manifest_paths.len());
+        for path in manifest_paths {
+            let manifest = crate::manifest::load_cargo_toml(&path).await?;
+            manifests.push((path, manifest));
+        }
+
+        debug!(
+            members_count = manifests.len(),
+            "Analyzed workspace member manifests"
+        );
+        Ok(manifests)
+    }
+
+    async fn plan_move_file(
+        &self,
+        root: &Path,
+        old_path: &Path,
+        new_path: &Path,
+    ) -> PluginResult<WorkspaceEdit> {
+        crate::file_move::plan_move_file(root, old_path, new_path)
+    }
 }
 
 // Implementation functions that return Results for error handling
@@ -385,6 +448,88 @@ fn list_workspace_members_impl(content: &str) -> Result<Vec<String>, String> {
     Ok(Vec::new())
 }
 
+fn list_workspace_excludes_impl(content: &str) -> Vec<String> {
+    content
+        .parse::<DocumentMut>()
+        .ok()
+        .and_then(|doc| {
+            doc.get("workspace")
+                .and_then(|w| w.get("exclude"))
+                .and_then(|e| e.as_array())
+                .map(|exclude| {
+                    exclude
+                        .iter()
+                        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                        .collect()
+                })
+        })
+        .unwrap_or_default()
+}
+
+/// Expand a workspace member pattern (e.g. `crates/*`) into existing directories
+///
+/// Each path segment may use `*` and `?` wildcards; other segments are taken
+/// literally.
+fn expand_member_pattern(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let mut dirs = vec![root.to_path_buf()];
+
+    for segment in pattern.split('/').filter(|s| !s.is_empty() && *s != ".") {
+        if !segment.contains(['*', '?']) {
+            dirs = dirs
+                .into_iter()
+                .map(|dir| dir.join(segment))
+                .filter(|dir| dir.is_dir())
+                .collect();
+            continue;
+        }
+
+        let mut matches = Vec::new();
+        for dir in &dirs {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let name = entry.file_name();
+                if path.is_dir() && wildcard_match(segment, &name.to_string_lossy()) {
+                    matches.push(path);
+                }
+            }
+        }
+        dirs = matches;
+    }
+
+    dirs.sort();
+    dirs
+}
+
+/// Match `name` against a pattern where `*` matches any run of characters
+/// and `?` matches a single character
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            n = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 fn update_package_name_impl(content: &str, new_name: &str) -> Result<String, String> {
     let mut doc = content
         .parse::<DocumentMut>()
@@ -447,4 +592,83 @@ version = "0.1.0"
         let result = update_package_name_impl(content, "new_name").unwrap();
         assert!(result.contains("name = \"new_name\""));
     }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*", "anything"));
+        assert!(wildcard_match("mill-*", "mill-lang-rust"));
+        assert!(wildcard_match("a?c", "abc"));
+        assert!(!wildcard_match("mill-*", "other"));
+        assert!(!wildcard_match("a?c", "ac"));
+    }
+
+    #[tokio::test]
+    async fn test_analyze_workspace_manifests_expands_globs() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path();
+        let write = |path: &str, content: &str| {
+            let full = root.join(path);
+            std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+            std::fs::write(full, content).unwrap();
+        };
+        write(
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"crates/ignored\"]\n",
+        );
+        write(
+            "crates/alpha/Cargo.toml",
+            "[package]\nname = \"alpha\"\nversion = \"0.1.0\"\n",
+        );
+        write(
+            "crates/beta/Cargo.toml",
+            "[package]\nname = \"beta\"\nversion = \"0.2.0\"\n\n[dependencies]\nalpha = { path = \"../alpha\" }\n",
+        );
+        write(
+            "crates/ignored/Cargo.toml",
+            "[package]\nname = \"ignored\"\nversion = \"0.1.0\"\n",
+        );
+        std::fs::create_dir_all(root.join("crates/not-a-crate")).unwrap();
+
+        let manifests = RustWorkspaceSupport
+            .analyze_workspace_manifests(root)
+            .await
+            .unwrap();
+
+        let names: Vec<&str> = manifests.iter().map(|(_, m)| m.name.as_str()).collect();
+        assert_eq!(names, vec!["alpha", "beta"]);
+        assert_eq!(manifests[0].0, root.join("crates/alpha/Cargo.toml"));
+        assert_eq!(manifests[1].1.dependencies[0].name, "alpha");
+    }
+
+    #[tokio::test]
+    async fn test_dependents_of_lists_members_depending_on_package() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path();
+        let write = |path: &str, content: &str| {
+            let full = root.join(path);
+            std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+            std::fs::write(full, content).unwrap();
+        };
+        write(
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"a\", \"b\", \"c\"]\n",
+        );
+        write(
+            "a/Cargo.toml",
+            "[package]\nname = \"a\"\nversion = \"0.1.0\"\n",
+        );
+        write(
+            "b/Cargo.toml",
+            "[package]\nname = \"b\"\nversion = \"0.1.0\"\n\n[dependencies]\na = { path = \"../a\" }\n",
+        );
+        write(
+            "c/Cargo.toml",
+            "[package]\nname = \"c\"\nversion = \"0.1.0\"\n\n[dependencies]\nb = { path = \"../b\" }\n",
+        );
+
+        let support = RustWorkspaceSupport;
+        assert_eq!(support.dependents_of(root, "a").await.unwrap(), vec!["b"]);
+        assert_eq!(support.dependents_of(root, "b").await.unwrap(), vec!["c"]);
+        assert!(support.dependents_of(root, "c").await.unwrap().is_empty());
+    }
 }
@@ -0,0 +1,173 @@
+//! Integration tests for planning single-file moves with the Rust plugin
+
+use mill_lang_rust::workspace_support::RustWorkspaceSupport;
+use mill_plugin_api::workspace_support::WorkspaceSupport;
+use mill_plugin_api::{TextEdit, WorkspaceEdit};
+use mill_test_support::harness::TestWorkspace;
+use std::path::Path;
+
+/// Apply the edits of one file, last edit first
+fn apply(dir: &TestWorkspace, edit: &WorkspaceEdit, file: &str) -> String {
+    let path = dir.path().join(file);
+    let mut lines: Vec<String> = std::fs::read_to_string(&path)
+        .unwrap()
+        .split_inclusive('\n')
+        .map(String::from)
+        .collect();
+    let mut edits: Vec<&TextEdit> = edit
+        .changes
+        .get(&path.to_string_lossy().into_owned())
+        .map(|edits| edits.iter().collect())
+        .unwrap_or_default();
+    edits.sort_by_key(|e| std::cmp::Reverse((e.start_line, e.start_column)));
+
+    for e in edits {
+        let text: String = lines.concat();
+        let offset = |line: usize, column: usize| -> usize {
+            let before: usize = lines[..line - 1].iter().map(String::len).sum();
+            let in_line: usize = lines
+                .get(line - 1)
+                .map(|l| l.chars().take(column).map(char::len_utf8).sum())
+                .unwrap_or(0);
+            before + in_line
+        };
+        let start = offset(e.start_line, e.start_column);
+        let end = offset(e.end_line, e.end_column);
+        let updated = format!("{}{}{}", &text[..start], e.new_text, &text[end..]);
+        lines = updated.split_inclusive('\n').map(String::from).collect();
+    }
+    lines.concat()
+}
+
+#[tokio::test]
+async fn test_move_updates_importer() {
+    let dir = TestWorkspace::with_files(&[
+        (
+            "src/main.rs",
+            "mod utils;\n\nuse utils::helper;\n\nfn main() {\n    println!(\"{}\", helper());\n}\n",
+        ),
+        ("src/utils.rs", "pub fn helper() -> u32 {\n    42\n}\n"),
+    ]);
+
+    let edit = RustWorkspaceSupport
+        .plan_move_file(
+            dir.path(),
+            Path::new("src/utils.rs"),
+            Path::new("src/helpers.rs"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(edit.changes.len(), 1);
+    assert_eq!(
+        apply(&dir, &edit, "src/main.rs"),
+        "mod helpers;\n\nuse helpers::helper;\n\nfn main() {\n    println!(\"{}\", helper());\n}\n"
+    );
+}
+
+#[tokio::test]
+async fn test_move_to_other_module_updates_declarations_and_moved_file() {
+    let dir = TestWorkspace::with_files(&[
+        ("src/lib.rs", "pub mod net;\npub mod utils;\n"),
+        ("src/net/mod.rs", "pub mod client;\n"),
+        (
+            "src/net/client.rs",
+            "use crate::utils::helper;\n\npub fn connect() -> u32 {\n    helper()\n}\n",
+        ),
+        (
+            "src/utils.rs",
+            "use super::net::client;\n\npub fn helper() -> u32 {\n    42\n}\n",
+        ),
+    ]);
+
+    let edit = RustWorkspaceSupport
+        .plan_move_file(
+            dir.path(),
+            Path::new("src/utils.rs"),
+            Path::new("src/net/utils.rs"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(apply(&dir, &edit, "src/lib.rs"), "pub mod net;\n");
+    assert_eq!(
+        apply(&dir, &edit, "src/net/mod.rs"),
+        "pub mod client;\npub mod utils;\n"
+    );
+    assert_eq!(
+        apply(&dir, &edit, "src/net/client.rs"),
+        "use crate::net::utils::helper;\n\npub fn connect() -> u32 {\n    helper()\n}\n"
+    );
+    // The moved file keeps pointing at the same modules from its new place
+    assert_eq!(
+        apply(&dir, &edit, "src/utils.rs"),
+        "use crate::net::client;\n\npub fn helper() -> u32 {\n    42\n}\n"
+    );
+}
+
+#[tokio::test]
+async fn test_move_updates_qualified_calls() {
+    let dir = TestWorkspace::with_files(&[
+        (
+            "src/lib.rs",
+            "mod utils;\n\npub fn run() -> u32 {\n    utils::helper() + crate::utils::helper()\n}\n",
+        ),
+        ("src/utils.rs", "pub fn helper() -> u32 {\n    42\n}\n"),
+    ]);
+
+    let edit = RustWorkspaceSupport
+        .plan_move_file(
+            dir.path(),
+            Path::new("src/utils.rs"),
+            Path::new("src/helpers.rs"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        apply(&dir, &edit, "src/lib.rs"),
+        "mod helpers;\n\npub fn run() -> u32 {\n    helpers::helper() + crate::helpers::helper()\n}\n"
+    );
+}
+
+#[tokio::test]
+async fn test_move_updates_grouped_imports() {
+    let dir = TestWorkspace::with_files(&[
+        ("src/lib.rs", "pub mod app;\npub mod net;\npub mod utils;\n"),
+        ("src/net/mod.rs", ""),
+        (
+            "src/app.rs",
+            "use crate::{\n    net,\n    utils::{helper, Config},\n};\n",
+        ),
+        ("src/utils.rs", "pub struct Config;\n\npub fn helper() {}\n"),
+    ]);
+
+    let edit = RustWorkspaceSupport
+        .plan_move_file(
+            dir.path(),
+            Path::new("src/utils.rs"),
+            Path::new("src/net/utils.rs"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        apply(&dir, &edit, "src/app.rs"),
+        "use crate::{\n    net,\n    net::utils::{helper, Config},\n};\n"
+    );
+}
+
+#[tokio::test]
+async fn test_move_rejects_existing_destination() {
+    let dir = TestWorkspace::with_files(&[
+        ("src/lib.rs", "mod a;\nmod b;\n"),
+        ("src/a.rs", ""),
+        ("src/b.rs", ""),
+    ]);
+
+    let result = RustWorkspaceSupport
+        .plan_move_file(dir.path(), Path::new("src/a.rs"), Path::new("src/b.rs"))
+        .await;
+
+    assert!(result.is_err());
+}
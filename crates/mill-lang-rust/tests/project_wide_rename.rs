@@ -0,0 +1,138 @@
+//! Integration tests for project-wide symbol renames driven by the Rust plugin
+
+use mill_lang_rust::RustPlugin;
+use mill_plugin_api::{PluginApiError, PluginDiscovery, WorkspaceEdit};
+use mill_test_support::harness::TestWorkspace;
+use std::sync::Arc;
+
+fn discovery() -> PluginDiscovery {
+    let mut plugins = PluginDiscovery::new();
+    plugins.register(Arc::from(RustPlugin::new()));
+    plugins
+}
+
+fn edits_for<'a>(
+    edit: &'a WorkspaceEdit,
+    dir: &TestWorkspace,
+    file: &str,
+) -> &'a [mill_plugin_api::TextEdit] {
+    let key = dir.path().join(file).to_string_lossy().into_owned();
+    edit.changes.get(&key).map(Vec::as_slice).unwrap_or(&[])
+}
+
+#[tokio::test]
+async fn test_rename_function_referenced_from_second_file() {
+    let dir = TestWorkspace::with_files(&[
+        (
+            "src/lib.rs",
+            "pub mod util;\n\n/// Calls compute_total\npub fn compute_total(x: u32) -> u32 {\n    x * 2\n}\n",
+        ),
+        (
+            "src/util.rs",
+            "use crate::compute_total;\n\npub fn run() -> u32 {\n    let label = \"compute_total\";\n    compute_total(label.len() as u32)\n}\n",
+        ),
+    ]);
+    let plugins = discovery();
+    let provider = plugins
+        .refactoring_provider_for_file("src/lib.rs")
+        .expect("Rust plugin provides refactoring");
+
+    let edit = provider
+        .plan_rename_symbol_project_wide(dir.path(), "compute_total", "sum_total", &plugins)
+        .await
+        .unwrap();
+
+    let lib_edits = edits_for(&edit, &dir, "src/lib.rs");
+    assert_eq!(lib_edits.len(), 1, "doc comment must not be renamed");
+    assert_eq!((lib_edits[0].start_line, lib_edits[0].start_column), (4, 7));
+    assert_eq!(lib_edits[0].end_column, 7 + "compute_total".len());
+    assert_eq!(lib_edits[0].new_text, "sum_total");
+
+    let util_edits = edits_for(&edit, &dir, "src/util.rs");
+    let positions: Vec<_> = util_edits
+        .iter()
+        .map(|e| (e.start_line, e.start_column))
+        .collect();
+    assert_eq!(
+        positions,
+        vec![(1, 11), (5, 4)],
+        "string literal must not be renamed"
+    );
+}
+
+#[tokio::test]
+async fn test_rename_reports_collisions() {
+    let dir = TestWorkspace::with_files(&[
+        ("src/lib.rs", "pub fn old_name() {}\npub fn taken() {}\n"),
+        ("src/other.rs", "fn unrelated() {}\n"),
+    ]);
+    let plugins = discovery();
+    let provider = plugins.refactoring_provider_for_file("src/lib.rs").unwrap();
+
+    let err = provider
+        .plan_rename_symbol_project_wide(dir.path(), "old_name", "taken", &plugins)
+        .await
+        .unwrap_err();
+
+    match err {
+        PluginApiError::InvalidInput { message } => {
+            assert!(
+                message.contains("lib.rs:2:7"),
+                "unexpected message: {}",
+                message
+            );
+        }
+        other => panic!("expected InvalidInput, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_rename_skips_unrelated_files_and_ignored_directories() {
+    let dir = TestWorkspace::with_files(&[
+        ("src/lib.rs", "pub mod util;\n\npub fn compute_total() {}\n"),
+        (
+            "src/util.rs",
+            "pub fn run() {\n    crate::compute_total();\n}\n",
+        ),
+        (
+            "src/other.rs",
+            "fn helper() {\n    let compute_total = 1;\n    let _ = compute_total;\n}\n",
+        ),
+        ("target/debug/build/out.rs", "pub fn compute_total() {}\n"),
+        ("generated/mod.rs", "pub fn compute_total() {}\n"),
+        (".gitignore", "/generated\n"),
+    ]);
+    let plugins = discovery();
+    let provider = plugins.refactoring_provider_for_file("src/lib.rs").unwrap();
+
+    let edit = provider
+        .plan_rename_symbol_project_wide(dir.path(), "compute_total", "sum_total", &plugins)
+        .await
+        .unwrap();
+
+    assert_eq!(edits_for(&edit, &dir, "src/lib.rs").len(), 1);
+    assert_eq!(edits_for(&edit, &dir, "src/util.rs").len(), 1);
+    assert!(
+        edits_for(&edit, &dir, "src/other.rs").is_empty(),
+        "a local with the same name is not a reference"
+    );
+    assert!(edits_for(&edit, &dir, "target/debug/build/out.rs").is_empty());
+    assert!(edits_for(&edit, &dir, "generated/mod.rs").is_empty());
+}
+
+#[tokio::test]
+async fn test_rename_ignores_new_name_in_other_scopes() {
+    let dir = TestWorkspace::with_files(&[(
+        "src/lib.rs",
+        "pub fn old_name() {}\n\npub struct Counter;\n\nimpl Counter {\n    pub fn taken(&self) {}\n}\n",
+    )]);
+    let plugins = discovery();
+    let provider = plugins.refactoring_provider_for_file("src/lib.rs").unwrap();
+
+    let edit = provider
+        .plan_rename_symbol_project_wide(dir.path(), "old_name", "taken", &plugins)
+        .await
+        .unwrap();
+
+    assert_eq!(edits_for(&edit, &dir, "src/lib.rs").len(), 1);
+}
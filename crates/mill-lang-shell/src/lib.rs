@@ -57,10 +57,13 @@ impl ShellPlugin {
             metadata: LanguageMetadata {
                 name: "shell",
                 extensions: &["sh", "bash", "zsh"],
+                filenames: &[],
                 manifest_filename: "",
+                manifest_patterns: &[],
                 source_dir: ".",
                 entry_point: "",
                 module_separator: "/",
+                lsp: None,
             },
         }
     }
@@ -101,11 +104,13 @@ impl LanguagePlugin for ShellPlugin {
                         name: name.as_str().to_string(),
                         kind: SymbolKind::Function,
                         location: SourceLocation {
-                            line: line_idx + 1,
+                            line: line_idx,
                             column: name.start(),
                         },
                         end_location: None,
                         documentation: None,
+                        is_test: false,
+                        parent: None,
                     });
                     continue;
                 }
@@ -118,11 +123,13 @@ impl LanguagePlugin for ShellPlugin {
                         name: name.as_str().to_string(),
                         kind: SymbolKind::Variable,
                         location: SourceLocation {
-                            line: line_idx + 1,
+                            line: line_idx,
                             column: name.start(),
                         },
                         end_location: None,
                         documentation: Some("alias".to_string()),
+                        is_test: false,
+                        parent: None,
                     });
                     continue;
                 }
@@ -135,11 +142,13 @@ impl LanguagePlugin for ShellPlugin {
                         name: name.as_str().to_string(),
                         kind: SymbolKind::Variable,
                         location: SourceLocation {
-                            line: line_idx + 1,
+                            line: line_idx,
                             column: name.start(),
                         },
                         end_location: None,
                         documentation: None,
+                        is_test: false,
+                        parent: None,
                     });
                     continue;
                 }
@@ -159,6 +168,7 @@ impl LanguagePlugin for ShellPlugin {
                 "sources": sources,
             }),
             symbols,
+            diagnostics: Vec::new(),
         })
     }
 
@@ -215,6 +225,8 @@ build_all () {
         assert!(names.contains(&"setup"), "Should find setup function");
         assert!(names.contains(&"cleanup"), "Should find cleanup function");
         assert!(names.contains(&"build_all"), "Should find build_all function");
+        // Lines are 0-based
+        assert_eq!(parsed.symbols[0].location.line, 2);
     }
 
     #[tokio::test]
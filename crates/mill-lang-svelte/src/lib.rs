@@ -43,10 +43,13 @@ impl SveltePlugin {
             metadata: LanguageMetadata {
                 name: "svelte",
                 extensions: &["svelte"],
+                filenames: &[],
                 manifest_filename: "svelte.config.js",
+                manifest_patterns: &[],
                 source_dir: "src",
                 entry_point: "index.svelte",
                 module_separator: "/",
+                lsp: None,
             },
             path_alias_resolver: TypeScriptPathAliasResolver::new(),
             import_support: SvelteImportSupport::new(),
@@ -74,6 +77,7 @@ impl LanguagePlugin for SveltePlugin {
         Ok(ParsedSource {
             data: json!({ "language": "svelte" }),
             symbols: vec![],
+            diagnostics: Vec::new(),
         })
     }
 
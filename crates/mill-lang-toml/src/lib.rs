@@ -49,10 +49,13 @@ impl TomlLanguagePlugin {
             metadata: LanguageMetadata {
                 name: "toml",
                 extensions: &["toml"],
+                filenames: &[],
                 manifest_filename: "Cargo.toml",
+                manifest_patterns: &[],
                 source_dir: ".",
                 entry_point: "Cargo.toml",
                 module_separator: "/",
+                lsp: None,
             },
             import_support: TomlImportSupport::new(),
         }
@@ -83,6 +86,7 @@ impl LanguagePlugin for TomlLanguagePlugin {
                 "language": "toml",
             }),
             symbols: vec![],
+            diagnostics: Vec::new(),
         })
     }
 
@@ -38,6 +38,7 @@ use mill_plugin_api::{
     StandardFileDiscovery,
 };
 use std::path::Path;
+use std::time::Instant;
 
 #[derive(Default)]
 pub struct TypeScriptFileDiscovery;
@@ -79,13 +80,21 @@ impl LanguagePlugin for TypeScriptPlugin {
     impl_language_plugin_basics!();
 
     async fn parse(&self, source: &str) -> PluginResult<ParsedSource> {
+        // The AST tool extracts symbols while parsing, so `parse_ms` covers both
+        let started = Instant::now();
         let symbols = parser::extract_symbols(source).await?;
+        let parse_ms = started.elapsed().as_secs_f64() * 1000.0;
 
         Ok(ParsedSource {
             data: serde_json::json!(
-                { "language" : "typescript", "symbols_count" : symbols.len() }
+                {
+                    "language" : "typescript",
+                    "symbols_count" : symbols.len(),
+                    "timing" : { "parse_ms" : parse_ms }
+                }
             ),
             symbols,
+            diagnostics: Vec::new(),
         })
     }
 
@@ -303,6 +312,21 @@ impl mill_plugin_api::RefactoringProvider for TypeScriptPlugin {
     ) -> mill_plugin_api::PluginResult<mill_foundation::protocol::EditPlan> {
         refactoring::plan_symbol_delete(source, symbol_line, symbol_col, file_path)
     }
+
+    fn supports_extract_protocol(&self) -> bool {
+        true
+    }
+
+    async fn plan_extract_protocol(
+        &self,
+        source: &str,
+        type_name: &str,
+        protocol_name: &str,
+        methods: &[String],
+        file_path: &str,
+    ) -> mill_plugin_api::PluginResult<mill_foundation::protocol::EditPlan> {
+        refactoring::plan_extract_interface(source, type_name, protocol_name, methods, file_path)
+    }
 }
 
 impl mill_plugin_api::ImportAnalyzer for TypeScriptPlugin {
@@ -384,7 +408,22 @@ impl TypeScriptPlugin {
         module_to_find: &str,
         scope: mill_plugin_api::ScanScope,
     ) -> Vec<mill_plugin_api::ModuleReference> {
+        use mill_plugin_api::identifier_scanner::{find_comment_occurrences, LexicalSyntax};
         use mill_plugin_api::{ModuleReference, ReferenceKind, ScanScope};
+
+        if scope == ScanScope::Comments {
+            return find_comment_occurrences(content, module_to_find, LexicalSyntax::C_LIKE)
+                .into_iter()
+                .map(|occurrence| ModuleReference {
+                    line: occurrence.line,
+                    column: occurrence.column,
+                    length: module_to_find.chars().count(),
+                    text: module_to_find.to_string(),
+                    kind: ReferenceKind::Comment,
+                })
+                .collect();
+        }
+
         let mut references = Vec::new();
 
         for (line_num, line) in content.lines().enumerate() {
@@ -529,6 +568,26 @@ function тестфункция() {
         assert_eq!(refs.len(), 1);
     }
 
+    #[test]
+    fn test_scan_references_comments_scope() {
+        use mill_plugin_api::ReferenceKind;
+
+        let plugin = TypeScriptPlugin::new();
+        let scanner = plugin
+            .module_reference_scanner()
+            .expect("Should have scanner");
+        let content = "// Wraps utils/format
+import { format } from './utils/format';
+";
+
+        let comments = scanner
+            .scan_references(content, "utils/format", ScanScope::Comments)
+            .expect("Should scan");
+        let spans: Vec<_> = comments.iter().map(|r| (r.line, r.column)).collect();
+        assert_eq!(spans, vec![(1, 9)], "import declarations are not comments");
+        assert_eq!(comments[0].kind, ReferenceKind::Comment);
+    }
+
     #[tokio::test]
     async fn test_edge_parse_empty_file() {
         let plugin = TypeScriptPlugin::new();
@@ -1,7 +1,9 @@
 //! TypeScript LSP installer implementation
 
 use async_trait::async_trait;
-use mill_lang_common::lsp::{check_binary_in_path, install_npm_package};
+use mill_lang_common::lsp::{
+    binary_version, check_binary_in_path, install_npm_package, SystemCommandRunner,
+};
 use mill_plugin_api::{LspInstaller, PluginApiError, PluginResult};
 use std::path::{Path, PathBuf};
 use tracing::debug;
@@ -27,6 +29,34 @@ impl LspInstaller for TypeScriptLspInstaller {
         Ok(check_binary_in_path("typescript-language-server"))
     }
 
+    fn installed_version(&self) -> PluginResult<Option<String>> {
+        match self.check_installed()? {
+            Some(binary) => binary_version(&SystemCommandRunner, &binary).map_err(|e| {
+                PluginApiError::internal(format!(
+                    "Failed to query typescript-language-server version: {}",
+                    e
+                ))
+            }),
+            None => Ok(None),
+        }
+    }
+
+    fn install_hint(&self) -> Option<String> {
+        Some("Run `npm install -g typescript-language-server typescript` or `mill install-lsp typescript`".to_string())
+    }
+
+    async fn install_version(&self, version: &str) -> PluginResult<()> {
+        debug!("Installing typescript-language-server {} via npm", version);
+
+        install_npm_package(
+            &format!("typescript-language-server@{}", version),
+            "typescript-language-server",
+        )
+        .await
+        .map(|_| ())
+        .map_err(|e| PluginApiError::internal(format!("npm install failed: {}", e)))
+    }
+
     async fn install_lsp(&self, _cache_dir: &Path) -> PluginResult<PathBuf> {
         debug!("Installing typescript-language-server via npm");
 
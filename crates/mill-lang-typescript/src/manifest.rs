@@ -86,10 +86,7 @@ fn extract_dependencies(obj: &Map<String, Value>, field_name: &str) -> Vec<Depen
     if let Some(deps_obj) = obj.get(field_name).and_then(|v| v.as_object()) {
         for (name, value) in deps_obj.iter() {
             let source = parse_dependency_source(value);
-            deps.push(Dependency {
-                name: name.clone(),
-                source,
-            });
+            deps.push(Dependency::new(name.clone(), source));
         }
     }
 
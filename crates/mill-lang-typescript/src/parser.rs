@@ -245,6 +245,8 @@ async fn extract_symbols_ast(source: &str) -> Result<Vec<Symbol>, PluginApiError
                 column: s.location.end_column,
             }),
             documentation: s.documentation,
+            is_test: false,
+            parent: None,
         })
         .collect();
     Ok(symbols)
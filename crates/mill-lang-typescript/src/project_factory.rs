@@ -62,6 +62,13 @@ impl ProjectFactory for TypeScriptProjectFactory {
         write_project_file(&entry_file_path, &entry_content)?;
         created_files.push(entry_file_path.display().to_string());
 
+        // The binary of a combined package imports its library entry
+        if config.package_type == PackageType::LibAndBin {
+            let main_path = package_path.join("src/main.ts");
+            write_project_file(&main_path, LIB_AND_BIN_MAIN)?;
+            created_files.push(main_path.display().to_string());
+        }
+
         // Create baseline files (README, .gitignore, tests) for minimal template
         let baseline = create_baseline_files(&package_path, &package_name)?;
         created_files.extend(baseline);
@@ -128,7 +135,7 @@ fn create_directory_structure(package_path: &Path) -> PluginResult<()> {
 
 fn entry_file(package_type: PackageType) -> &'static str {
     match package_type {
-        PackageType::Library => "src/index.ts",
+        PackageType::Library | PackageType::LibAndBin => "src/index.ts",
         PackageType::Binary => "src/main.ts",
     }
 }
@@ -180,6 +187,35 @@ fn generate_package_json(package_name: &str, package_type: PackageType) -> Strin
     "@types/node": "^20.0.0"
   }}
 }}
+"#,
+            package_name,
+            package_name,
+            constants::DEFAULT_TS_VERSION
+        ),
+        PackageType::LibAndBin => format!(
+            r#"{{
+  "name": "{}",
+  "version": "0.1.0",
+  "description": "TODO: Add package description",
+  "main": "dist/index.js",
+  "types": "dist/index.d.ts",
+  "bin": {{
+    "{}": "dist/main.js"
+  }},
+  "scripts": {{
+    "build": "tsc",
+    "start": "node dist/main.js",
+    "test": "echo \"Error: no test specified\" && exit 1",
+    "lint": "eslint src --ext .ts"
+  }},
+  "keywords": [],
+  "author": "",
+  "license": "ISC",
+  "devDependencies": {{
+    "typescript": "{}",
+    "@types/node": "^20.0.0"
+  }}
+}}
 "#,
             package_name,
             package_name,
@@ -211,9 +247,17 @@ fn generate_tsconfig() -> String {
     .to_string()
 }
 
+/// `src/main.ts` of a combined package, using the library entry
+const LIB_AND_BIN_MAIN: &str = r#"#!/usr/bin/env node
+
+import { hello } from "./index";
+
+console.log(hello());
+"#;
+
 fn generate_entry_content(package_name: &str, package_type: PackageType) -> String {
     match package_type {
-        PackageType::Library => format!(
+        PackageType::Library | PackageType::LibAndBin => format!(
             r#"/**
  * {} library
  *
@@ -357,6 +401,7 @@ mod tests {
     fn test_entry_file() {
         assert_eq!(entry_file(PackageType::Library), "src/index.ts");
         assert_eq!(entry_file(PackageType::Binary), "src/main.ts");
+        assert_eq!(entry_file(PackageType::LibAndBin), "src/index.ts");
     }
 
     #[test]
@@ -1,5 +1,6 @@
 //! TypeScript/JavaScript specific refactoring logic.
 use mill_foundation::protocol::{EditPlan, EditType, TextEdit};
+use mill_lang_common::naming::{check_convention, validate_identifier, Language, NamingConvention};
 use mill_lang_common::refactoring::edit_plan_builder::EditPlanBuilder;
 use mill_lang_common::{
     find_literal_occurrences, is_escaped, is_valid_code_literal_location, CodeRange,
@@ -36,6 +37,9 @@ pub fn plan_extract_function(
     new_function_name: &str,
     file_path: &str,
 ) -> PluginResult<EditPlan> {
+    validate_identifier(new_function_name, Language::TypeScript)
+        .map_err(PluginApiError::invalid_input)?;
+    check_convention(new_function_name, NamingConvention::CamelCase);
     let range = CodeRange {
         start_line,
         start_col,
@@ -174,6 +178,8 @@ pub fn plan_extract_constant(
     name: &str,
     file_path: &str,
 ) -> PluginResult<EditPlan> {
+    validate_identifier(name, Language::TypeScript).map_err(PluginApiError::invalid_input)?;
+    check_convention(name, NamingConvention::ScreamingSnakeCase);
     let analysis = analyze_extract_constant(source, line, character, file_path)?;
     ast_extract_constant_ts_js(source, &analysis, name, file_path)
 }
@@ -226,6 +232,40 @@ pub fn plan_symbol_delete(
     ast_symbol_delete_ts_js(source, &analysis, file_path)
 }
 
+/// Plans an extract interface refactoring for TypeScript/JavaScript code.
+///
+/// Creates an edit plan that declares a new interface with the signatures of
+/// the selected methods of a class, placed just above the class, and adds it
+/// to the class's `implements` clause. Parameters with default values become
+/// optional parameters in the interface.
+///
+/// # Arguments
+/// * `source` - The TypeScript source code
+/// * `class_name` - Name of the class to extract from
+/// * `interface_name` - Name for the new interface
+/// * `methods` - Names of the instance methods to extract
+/// * `file_path` - Path to the file being refactored
+///
+/// # Returns
+/// Edit plan with the interface declaration and the `implements` clause
+pub fn plan_extract_interface(
+    source: &str,
+    class_name: &str,
+    interface_name: &str,
+    methods: &[String],
+    file_path: &str,
+) -> PluginResult<EditPlan> {
+    validate_identifier(interface_name, Language::TypeScript)
+        .map_err(PluginApiError::invalid_input)?;
+    check_convention(interface_name, NamingConvention::PascalCase);
+    if methods.is_empty() {
+        return Err(PluginApiError::invalid_input(
+            "At least one method is required to extract an interface",
+        ));
+    }
+    ast_extract_interface_ts_js(source, class_name, interface_name, methods, file_path)
+}
+
 fn ast_extract_function_ts_js(
     source: &str,
     range: &CodeRange,
@@ -346,6 +386,8 @@ fn ast_extract_variable_ts_js(
     }
 
     let var_name = variable_name.unwrap_or_else(|| analysis.suggested_name.clone());
+    validate_identifier(&var_name, Language::TypeScript).map_err(PluginApiError::invalid_input)?;
+    check_convention(&var_name, NamingConvention::CamelCase);
 
     let indent = LineExtractor::get_indentation_str(source, analysis.insertion_point.start_line);
 
@@ -1967,6 +2009,211 @@ fn ast_symbol_delete_ts_js(
         .build())
 }
 
+fn ast_extract_interface_ts_js(
+    source: &str,
+    class_name: &str,
+    interface_name: &str,
+    methods: &[String],
+    file_path: &str,
+) -> PluginResult<EditPlan> {
+    let (module, cm) = parse_module_with_source_map(source, file_path)?;
+    let (decl_span, decl, is_exported) = module
+        .body
+        .iter()
+        .find_map(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Class(decl))) if decl.ident.sym == class_name => {
+                Some((decl.class.span, decl, false))
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => match &export.decl {
+                Decl::Class(decl) if decl.ident.sym == class_name => {
+                    Some((export.span, decl, true))
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .ok_or_else(|| {
+            PluginApiError::invalid_input(format!("Class '{}' not found", class_name))
+        })?;
+    let class = &decl.class;
+
+    let snippet = |span: swc_common::Span| cm.span_to_snippet(span).unwrap_or_default();
+    let position = |pos: swc_common::BytePos| {
+        let loc = cm.lookup_char_pos(pos);
+        (loc.line.saturating_sub(1) as u32, loc.col_display as u32)
+    };
+
+    // Signatures in declaration order, so the interface reads like the class
+    let mut signatures = Vec::new();
+    let mut member_indent = None;
+    for member in &class.body {
+        let ClassMember::Method(method) = member else {
+            continue;
+        };
+        let name = match &method.key {
+            PropName::Ident(ident) => ident.sym.to_string(),
+            _ => continue,
+        };
+        if !methods.contains(&name) {
+            continue;
+        }
+        if method.is_static
+            || method.kind != MethodKind::Method
+            || matches!(
+                method.accessibility,
+                Some(Accessibility::Private | Accessibility::Protected)
+            )
+        {
+            return Err(PluginApiError::invalid_input(format!(
+                "'{}' is not a public instance method and cannot be part of an interface",
+                name
+            )));
+        }
+        let function = &method.function;
+        let params: Vec<String> = function
+            .params
+            .iter()
+            .map(|param| match &param.pat {
+                // `x: T = value` is an optional `x?: T` from the caller's side
+                Pat::Assign(assign) => match &*assign.left {
+                    Pat::Ident(binding) => format!(
+                        "{}?{}",
+                        binding.id.sym,
+                        binding
+                            .type_ann
+                            .as_ref()
+                            .map(|ann| format!(": {}", snippet(ann.type_ann.span())))
+                            .unwrap_or_default()
+                    ),
+                    left => snippet(left.span()),
+                },
+                pat => snippet(pat.span()),
+            })
+            .collect();
+        signatures.push((
+            name.clone(),
+            format!(
+                "{}{}{}({}){};",
+                name,
+                if method.is_optional { "?" } else { "" },
+                function
+                    .type_params
+                    .as_ref()
+                    .map(|tp| snippet(tp.span))
+                    .unwrap_or_default(),
+                params.join(", "),
+                function
+                    .return_type
+                    .as_ref()
+                    .map(|ann| format!(": {}", snippet(ann.type_ann.span())))
+                    .unwrap_or_default()
+            ),
+        ));
+        if member_indent.is_none() {
+            let (line, _) = position(method.span.lo);
+            member_indent = Some(line_indent(source, line));
+        }
+    }
+    let missing: Vec<&str> = methods
+        .iter()
+        .filter(|m| !signatures.iter().any(|(name, _)| name == *m))
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(PluginApiError::invalid_input(format!(
+            "Methods not found in class '{}': {}",
+            class_name,
+            missing.join(", ")
+        )));
+    }
+
+    let (decl_line, _) = position(decl_span.lo);
+    let indent = line_indent(source, decl_line);
+    let member_indent = member_indent.unwrap_or_else(|| format!("{}    ", indent));
+    let mut interface = format!(
+        "{}{}interface {} {{\n",
+        indent,
+        if is_exported { "export " } else { "" },
+        interface_name
+    );
+    for (_, signature) in &signatures {
+        interface.push_str(&format!("{}{}\n", member_indent, signature));
+    }
+    interface.push_str(&format!("{}}}\n\n", indent));
+
+    // The conformance goes after the last `implements` entry, or else after
+    // `extends Base<T>`, the type parameters or the class name
+    let (anchor, conformance) = match class.implements.last() {
+        Some(last) => (last.span.hi, format!(", {}", interface_name)),
+        None => {
+            let anchor = class
+                .super_type_params
+                .as_ref()
+                .map(|tp| tp.span.hi)
+                .or_else(|| class.super_class.as_ref().map(|sc| sc.span().hi))
+                .or_else(|| class.type_params.as_ref().map(|tp| tp.span.hi))
+                .unwrap_or(decl.ident.span.hi);
+            (anchor, format!(" implements {}", interface_name))
+        }
+    };
+    let (anchor_line, anchor_col) = position(anchor);
+
+    let edits = vec![
+        TextEdit {
+            file_path: None,
+            edit_type: EditType::Insert,
+            location: CodeRange {
+                start_line: decl_line,
+                start_col: 0,
+                end_line: decl_line,
+                end_col: 0,
+            }
+            .into(),
+            original_text: String::new(),
+            new_text: interface,
+            priority: 100,
+            description: format!("Create interface '{}'", interface_name),
+        },
+        TextEdit {
+            file_path: None,
+            edit_type: EditType::Insert,
+            location: CodeRange {
+                start_line: anchor_line,
+                start_col: anchor_col,
+                end_line: anchor_line,
+                end_col: anchor_col,
+            }
+            .into(),
+            original_text: String::new(),
+            new_text: conformance,
+            priority: 90,
+            description: format!("Implement '{}' on class '{}'", interface_name, class_name),
+        },
+    ];
+
+    Ok(EditPlanBuilder::new(file_path, "extract_interface")
+        .with_edits(edits)
+        .with_syntax_validation("Verify syntax is valid after extraction")
+        .with_type_check_validation()
+        .with_intent_args(serde_json::json!({
+            "class": class_name,
+            "interface": interface_name,
+            "methods": methods,
+        }))
+        .with_complexity(signatures.len().min(10) as u8)
+        .with_impact_area("interface_extraction")
+        .build())
+}
+
+/// Leading whitespace of a zero-based line
+fn line_indent(source: &str, line: u32) -> String {
+    source
+        .lines()
+        .nth(line as usize)
+        .map(|text| text[..text.len() - text.trim_start().len()].to_string())
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2142,6 +2389,70 @@ mod tests {
         assert!(!is_valid_number("0x"), "Should reject incomplete hex");
         assert!(!is_valid_number("0b"), "Should reject incomplete binary");
     }
+
+    /// Apply single-line insert edits, last position first
+    fn apply_inserts(source: &str, plan: &EditPlan) -> String {
+        let mut lines: Vec<String> = source.lines().map(String::from).collect();
+        let mut edits: Vec<&TextEdit> = plan.edits.iter().collect();
+        edits.sort_by_key(|e| (e.location.start_line, e.location.start_column));
+        for edit in edits.into_iter().rev() {
+            let line = &mut lines[edit.location.start_line as usize];
+            line.insert_str(edit.location.start_column as usize, &edit.new_text);
+        }
+        lines.join("\n") + "\n"
+    }
+
+    #[test]
+    fn test_plan_extract_interface_from_class() {
+        let source = r#"export class UserStore extends BaseStore<User> {
+    private cache = new Map<string, User>();
+
+    async load(id: string, fresh: boolean = false): Promise<User> {
+        return this.fetch(id, fresh);
+    }
+
+    count(): number {
+        return this.cache.size;
+    }
+
+    save<T extends User>(user: T, ...tags: string[]): void {
+        this.cache.set(user.id, user);
+    }
+}
+"#;
+        let methods = vec!["save".to_string(), "load".to_string()];
+        let plan = plan_extract_interface(source, "UserStore", "Repository", &methods, "store.ts")
+            .unwrap();
+
+        assert_eq!(plan.edits.len(), 2);
+        assert_eq!(
+            plan.edits[1].new_text, " implements Repository",
+            "conformance edit"
+        );
+        assert_eq!(
+            apply_inserts(source, &plan),
+            source.replacen(
+                "export class UserStore extends BaseStore<User> {",
+                "export interface Repository {\n    load(id: string, fresh?: boolean): Promise<User>;\n    save<T extends User>(user: T, ...tags: string[]): void;\n}\n\nexport class UserStore extends BaseStore<User> implements Repository {",
+                1
+            )
+        );
+    }
+
+    #[test]
+    fn test_plan_extract_interface_appends_to_implements() {
+        let source = "class Cat implements Pet {\n    speak(loud: boolean): string {\n        return loud ? \"MEOW\" : \"meow\";\n    }\n}\n";
+        let methods = vec!["speak".to_string()];
+        let plan = plan_extract_interface(source, "Cat", "Speaker", &methods, "cat.ts").unwrap();
+        assert_eq!(
+            apply_inserts(source, &plan),
+            "interface Speaker {\n    speak(loud: boolean): string;\n}\n\nclass Cat implements Pet, Speaker {\n    speak(loud: boolean): string {\n        return loud ? \"MEOW\" : \"meow\";\n    }\n}\n"
+        );
+
+        let missing = vec!["purr".to_string()];
+        assert!(plan_extract_interface(source, "Cat", "Speaker", &missing, "cat.ts").is_err());
+        assert!(plan_extract_interface(source, "Dog", "Speaker", &methods, "cat.ts").is_err());
+    }
 }
 
 #[cfg(test)]
@@ -49,10 +49,13 @@ impl YamlLanguagePlugin {
             metadata: LanguageMetadata {
                 name: "yaml",
                 extensions: &["yaml", "yml"],
+                filenames: &[],
                 manifest_filename: "package.json",
+                manifest_patterns: &[],
                 source_dir: ".",
                 entry_point: "main.yml",
                 module_separator: "/",
+                lsp: None,
             },
             import_support: YamlImportSupport::new(),
         }
@@ -83,6 +86,7 @@ impl LanguagePlugin for YamlLanguagePlugin {
                 "language": "yaml",
             }),
             symbols: vec![],
+            diagnostics: Vec::new(),
         })
     }
 
@@ -63,10 +63,13 @@ impl ZigPlugin {
             metadata: LanguageMetadata {
                 name: "zig",
                 extensions: &["zig"],
+                filenames: &[],
                 manifest_filename: "build.zig.zon",
+                manifest_patterns: &[],
                 source_dir: "src",
                 entry_point: "main.zig",
                 module_separator: "/",
+                lsp: None,
             },
             import_support: ZigImportSupport::new(),
         }
@@ -77,6 +80,53 @@ impl ZigPlugin {
     }
 }
 
+/// Symbols declared on one line: a function and/or a type or constant
+fn line_symbols(line_idx: usize, line: &str) -> Vec<Symbol> {
+    let symbol = |name: regex::Match, kind| Symbol {
+        name: name.as_str().to_string(),
+        kind,
+        location: SourceLocation {
+            line: line_idx,
+            column: name.start(),
+        },
+        end_location: None,
+        documentation: None,
+        is_test: false,
+        parent: None,
+    };
+    let mut symbols = Vec::new();
+
+    // Functions
+    if let Some(name) = FUNCTION_PATTERN.captures(line).and_then(|caps| caps.get(1)) {
+        symbols.push(symbol(name, SymbolKind::Function));
+    }
+
+    // Types (struct, enum, union, opaque), else constants
+    if let Some(caps) = TYPE_PATTERN.captures(line) {
+        if let Some(name) = caps.get(1) {
+            symbols.push(symbol(name, SymbolKind::Class));
+        }
+    } else if let Some(name) = CONST_PATTERN.captures(line).and_then(|caps| caps.get(1)) {
+        symbols.push(symbol(name, SymbolKind::Constant));
+    }
+
+    symbols
+}
+
+/// Symbols of `lines`, extracted one line at a time as the iterator is advanced
+fn symbols_from_lines<'a>(
+    lines: impl Iterator<Item = &'a str> + 'a,
+) -> impl Iterator<Item = Symbol> + 'a {
+    lines.enumerate().flat_map(|(line_idx, line)| {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with("//") {
+            Vec::new()
+        } else {
+            line_symbols(line_idx, line)
+        }
+    })
+}
+
 impl Default for ZigPlugin {
     fn default() -> Self {
         Self::new()
@@ -101,52 +151,7 @@ impl LanguagePlugin for ZigPlugin {
                 continue;
             }
 
-            // Functions
-            if let Some(caps) = FUNCTION_PATTERN.captures(line) {
-                if let Some(name) = caps.get(1) {
-                    symbols.push(Symbol {
-                        name: name.as_str().to_string(),
-                        kind: SymbolKind::Function,
-                        location: SourceLocation {
-                            line: line_idx + 1,
-                            column: name.start(),
-                        },
-                        end_location: None,
-                        documentation: None,
-                    });
-                    // Don't continue — line may also have @import
-                }
-            }
-
-            // Types (struct, enum, union, opaque)
-            if let Some(caps) = TYPE_PATTERN.captures(line) {
-                if let Some(name) = caps.get(1) {
-                    symbols.push(Symbol {
-                        name: name.as_str().to_string(),
-                        kind: SymbolKind::Class,
-                        location: SourceLocation {
-                            line: line_idx + 1,
-                            column: name.start(),
-                        },
-                        end_location: None,
-                        documentation: None,
-                    });
-                }
-            } else if let Some(caps) = CONST_PATTERN.captures(line) {
-                // Constants (only if not a type def)
-                if let Some(name) = caps.get(1) {
-                    symbols.push(Symbol {
-                        name: name.as_str().to_string(),
-                        kind: SymbolKind::Constant,
-                        location: SourceLocation {
-                            line: line_idx + 1,
-                            column: name.start(),
-                        },
-                        end_location: None,
-                        documentation: None,
-                    });
-                }
-            }
+            symbols.extend(line_symbols(line_idx, line));
 
             // Imports
             for caps in IMPORT_PATTERN.captures_iter(line) {
@@ -162,6 +167,7 @@ impl LanguagePlugin for ZigPlugin {
                 "imports": imports,
             }),
             symbols,
+            diagnostics: Vec::new(),
         })
     }
 
@@ -175,6 +181,10 @@ impl LanguagePlugin for ZigPlugin {
         Self::CAPABILITIES
     }
 
+    fn symbols_iter<'a>(&'a self, source: &'a str) -> Box<dyn Iterator<Item = Symbol> + 'a> {
+        Box::new(symbols_from_lines(source.lines()))
+    }
+
     fn import_rename_support(&self) -> Option<&dyn ImportRenameSupport> {
         Some(&self.import_support)
     }
@@ -251,6 +261,9 @@ export fn exported() void {}
         assert!(names.contains(&"main"));
         assert!(names.contains(&"helper"));
         assert!(names.contains(&"exported"));
+        // Lines are 0-based
+        let main = parsed.symbols.iter().find(|s| s.name == "main").unwrap();
+        assert_eq!(main.location.line, 3);
     }
 
     #[tokio::test]
@@ -352,4 +365,37 @@ const std = @import("std");
         assert!(new_content.contains(r#"@import("helpers.zig")"#));
         assert!(new_content.contains(r#"@import("std")"#));
     }
+
+    #[tokio::test]
+    async fn test_symbols_iter_matches_parse() {
+        let plugin = ZigPlugin::new();
+        let source = r#"
+const std = @import("std");
+const Point = struct { x: i32 };
+
+// fn commented() {}
+pub fn main() !void {}
+fn helper() void {}
+"#;
+
+        let parsed = plugin.parse(source).await.unwrap();
+        let streamed: Vec<Symbol> = plugin.symbols_iter(source).collect();
+        assert_eq!(streamed, parsed.symbols);
+    }
+
+    #[test]
+    fn test_symbols_iter_reads_only_what_is_taken() {
+        let lines_read = std::cell::Cell::new(0);
+        let source = "fn first() void {}\n// note\nfn second() void {}\n".repeat(100);
+        let lines = source
+            .lines()
+            .inspect(|_| lines_read.set(lines_read.get() + 1));
+
+        let first: Vec<Symbol> = symbols_from_lines(lines).take(2).collect();
+
+        assert_eq!(first[0].name, "first");
+        assert_eq!(first[1].name, "second");
+        // The comment is skipped, nothing after the second symbol is read
+        assert_eq!(lines_read.get(), 3);
+    }
 }
@@ -109,6 +109,22 @@ pub struct TextEdit {
     pub new_text: String,
 }
 
+/// A refactoring a [`RefactoringProvider`] can plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefactoringKind {
+    ExtractFunction,
+    ExtractVariable,
+    ExtractConstant,
+    InlineVariable,
+    Rename,
+    OrganizeImports,
+    SymbolMove,
+    SymbolDelete,
+    ReflowSignature,
+    ExtractProtocol,
+}
+
 /// Capability for providing refactoring operations
 ///
 /// This trait allows language plugins to provide language-specific refactoring
@@ -310,6 +326,191 @@ pub trait RefactoringProvider: Send + Sync {
         Err(crate::PluginApiError::not_supported("plan_symbol_delete"))
     }
 
+    /// Check if signature reflow is supported
+    fn supports_reflow_signature(&self) -> bool {
+        false
+    }
+
+    /// Plan reflowing a long function signature
+    ///
+    /// When the signature of `function_name` is wider than `max_width`, generates
+    /// an edit plan that puts one parameter per line with a trailing comma. The
+    /// plan has no edits when the signature already fits.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Source code content
+    /// * `function_name` - Name of the function whose signature to reflow
+    /// * `max_width` - Maximum line width in characters
+    /// * `file_path` - Path to the source file
+    async fn plan_reflow_signature(
+        &self,
+        _source: &str,
+        _function_name: &str,
+        _max_width: usize,
+        _file_path: &str,
+    ) -> PluginResult<mill_foundation::protocol::EditPlan> {
+        Err(crate::PluginApiError::not_supported(
+            "plan_reflow_signature",
+        ))
+    }
+
+    /// Check if organize imports is supported
+    fn supports_organize_imports(&self) -> bool {
+        false
+    }
+
+    /// Plan sorting and grouping the imports at the top of a file
+    ///
+    /// Reorders the leading import declarations into the language's canonical
+    /// groups. Imports inside conditional compilation blocks are left alone,
+    /// and a file whose imports are already organized yields a plan without
+    /// edits.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Source code content
+    /// * `file_path` - Path to the source file
+    async fn plan_organize_imports(
+        &self,
+        _source: &str,
+        _file_path: &str,
+    ) -> PluginResult<mill_foundation::protocol::EditPlan> {
+        Err(crate::PluginApiError::not_supported(
+            "plan_organize_imports",
+        ))
+    }
+
+    /// Check if extract protocol/interface refactoring is supported
+    fn supports_extract_protocol(&self) -> bool {
+        false
+    }
+
+    /// Plan extracting method signatures from a type into a new protocol
+    ///
+    /// Generates the language's protocol or interface declaration containing
+    /// the signatures of `methods`, copied with their parameter names, types
+    /// and return types, and adds a conformance to it on the declaration of
+    /// `type_name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Source code content
+    /// * `type_name` - Name of the class or struct to extract from
+    /// * `protocol_name` - Name for the new protocol/interface
+    /// * `methods` - Names of the methods whose signatures to extract
+    /// * `file_path` - Path to the source file
+    async fn plan_extract_protocol(
+        &self,
+        _source: &str,
+        _type_name: &str,
+        _protocol_name: &str,
+        _methods: &[String],
+        _file_path: &str,
+    ) -> PluginResult<mill_foundation::protocol::EditPlan> {
+        Err(crate::PluginApiError::not_supported(
+            "plan_extract_protocol",
+        ))
+    }
+
+    /// List the refactorings this provider can plan
+    ///
+    /// The default checks each `supports_*` method. Project-wide rename is
+    /// always listed since every provider inherits
+    /// [`plan_rename_symbol_project_wide`](Self::plan_rename_symbol_project_wide).
+    fn supported_refactorings(&self) -> Vec<RefactoringKind> {
+        [
+            (
+                RefactoringKind::ExtractFunction,
+                self.supports_extract_function(),
+            ),
+            (
+                RefactoringKind::ExtractVariable,
+                self.supports_extract_variable(),
+            ),
+            (
+                RefactoringKind::ExtractConstant,
+                self.supports_extract_constant(),
+            ),
+            (
+                RefactoringKind::InlineVariable,
+                self.supports_inline_variable(),
+            ),
+            (RefactoringKind::Rename, true),
+            (
+                RefactoringKind::OrganizeImports,
+                self.supports_organize_imports(),
+            ),
+            (RefactoringKind::SymbolMove, self.supports_symbol_move()),
+            (RefactoringKind::SymbolDelete, self.supports_symbol_delete()),
+            (
+                RefactoringKind::ReflowSignature,
+                self.supports_reflow_signature(),
+            ),
+            (
+                RefactoringKind::ExtractProtocol,
+                self.supports_extract_protocol(),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(kind, supported)| supported.then_some(kind))
+        .collect()
+    }
+
+    /// Plan a rename of a symbol across every source file in a project
+    ///
+    /// Scans the files under `root` handled by a plugin with refactoring support
+    /// and renames the symbol in files that declare it, import it by name,
+    /// glob-import a module, or use a qualified path ending in it. Files that
+    /// only mention an unrelated identifier of the same name are not touched,
+    /// and occurrences inside string literals and comments are left alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - Project root directory to scan
+    /// * `symbol_name` - Current name of the symbol
+    /// * `new_name` - New name for the symbol
+    /// * `plugins` - Plugin registry used to pick the syntax for each file
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidInput` if `new_name` is not a valid identifier or if a
+    /// file that would be edited already declares or imports `new_name` at
+    /// the top level, listing every conflict.
+    async fn plan_rename_symbol_project_wide(
+        &self,
+        root: &Path,
+        symbol_name: &str,
+        new_name: &str,
+        plugins: &crate::PluginDiscovery,
+    ) -> PluginResult<WorkspaceEdit> {
+        plan_project_wide_rename(root, symbol_name, new_name, plugins).await
+    }
+
+    /// Plan a project-wide rename of the symbol at a position
+    ///
+    /// Resolves the identifier at `line`/`character` (0-based, as in LSP) of
+    /// `file`, following an import alias back to the imported name, and
+    /// renames it like
+    /// [`plan_rename_symbol_project_wide`](Self::plan_rename_symbol_project_wide).
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidInput` if the position is not on an identifier in
+    /// code, if no file under `root` declares the symbol, or for the same
+    /// reasons as `plan_rename_symbol_project_wide`.
+    async fn plan_rename_symbol_at(
+        &self,
+        root: &Path,
+        file: &Path,
+        line: u32,
+        character: u32,
+        new_name: &str,
+        plugins: &crate::PluginDiscovery,
+    ) -> PluginResult<WorkspaceEdit> {
+        plan_project_wide_rename_at(root, file, line, character, new_name, plugins).await
+    }
+
     // ============================================================================
     // Legacy sync methods - DEPRECATED
     // These exist for backwards compatibility but should not be used in new code
@@ -328,6 +529,276 @@ pub trait RefactoringProvider: Send + Sync {
     }
 }
 
+/// Shared implementation of [`RefactoringProvider::plan_rename_symbol_project_wide`]
+pub async fn plan_project_wide_rename(
+    root: &Path,
+    symbol_name: &str,
+    new_name: &str,
+    plugins: &crate::PluginDiscovery,
+) -> PluginResult<WorkspaceEdit> {
+    plan_rename_in_files(root, symbol_name, new_name, plugins)
+        .await
+        .map(|(edit, _)| edit)
+}
+
+/// Shared implementation of [`RefactoringProvider::plan_rename_symbol_at`]
+pub async fn plan_project_wide_rename_at(
+    root: &Path,
+    file: &Path,
+    line: u32,
+    character: u32,
+    new_name: &str,
+    plugins: &crate::PluginDiscovery,
+) -> PluginResult<WorkspaceEdit> {
+    use crate::identifier_scanner::{identifier_at, LexicalSyntax};
+
+    let plugin = plugins.find_by_file(file).ok_or_else(|| {
+        crate::PluginApiError::not_supported(format!("No plugin handles {}", file.display()))
+    })?;
+    let content = tokio::fs::read_to_string(file).await.map_err(|e| {
+        crate::PluginApiError::internal(format!("Failed to read {}: {}", file.display(), e))
+    })?;
+
+    let syntax = LexicalSyntax::for_language(plugin.metadata().name);
+    let identifier = identifier_at(&content, line as usize + 1, character as usize, syntax)
+        .ok_or_else(|| {
+            crate::PluginApiError::invalid_input(format!(
+                "No identifier at {}:{}:{}",
+                file.display(),
+                line,
+                character
+            ))
+        })?;
+
+    // A local alias (`use a::b as c`, `import { b as c }`) renames the
+    // imported symbol, not the alias
+    let symbol_name = plugin
+        .analyze_detailed_imports(&content, Some(file))
+        .ok()
+        .and_then(|graph| {
+            graph
+                .imports
+                .into_iter()
+                .flat_map(|import| import.named_imports)
+                .find(|named| named.alias.as_deref() == Some(identifier.as_str()))
+                .map(|named| named.name)
+        })
+        .unwrap_or(identifier);
+
+    let (edit, declared) = plan_rename_in_files(root, &symbol_name, new_name, plugins).await?;
+    if !declared {
+        return Err(crate::PluginApiError::invalid_input(format!(
+            "No declaration of '{}' found under {}",
+            symbol_name,
+            root.display()
+        )));
+    }
+    Ok(edit)
+}
+
+/// Plan the rename across `root`, also reporting whether any edited file
+/// declares the symbol
+async fn plan_rename_in_files(
+    root: &Path,
+    symbol_name: &str,
+    new_name: &str,
+    plugins: &crate::PluginDiscovery,
+) -> PluginResult<(WorkspaceEdit, bool)> {
+    use crate::identifier_scanner::{
+        find_identifier_occurrences, is_valid_identifier, LexicalSyntax,
+    };
+
+    if !is_valid_identifier(symbol_name) || !is_valid_identifier(new_name) {
+        return Err(crate::PluginApiError::invalid_input(format!(
+            "Cannot rename '{}' to '{}': both names must be valid identifiers",
+            symbol_name, new_name
+        )));
+    }
+
+    let extensions: Vec<&str> = plugins
+        .all()
+        .iter()
+        .filter(|p| p.refactoring_provider().is_some())
+        .flat_map(|p| p.metadata().extensions.iter().copied())
+        .collect();
+    let mut files = StandardFileDiscovery::new(&extensions)
+        .with_ignored_dirs()
+        .find_source_files(root)
+        .await?;
+    files.sort();
+
+    let mut changes = std::collections::HashMap::new();
+    let mut conflicts = Vec::new();
+    let mut declared = false;
+
+    for file in files {
+        let Some(plugin) = file
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| plugins.find_by_extension(ext))
+        else {
+            continue;
+        };
+
+        let content = tokio::fs::read_to_string(&file).await.map_err(|e| {
+            crate::PluginApiError::internal(format!("Failed to read {}: {}", file.display(), e))
+        })?;
+        let syntax = LexicalSyntax::for_language(plugin.metadata().name);
+
+        let occurrences = find_identifier_occurrences(&content, symbol_name, syntax);
+        if occurrences.is_empty() {
+            continue;
+        }
+
+        let symbols = plugin
+            .parse_symbols_only(&content)
+            .await
+            .unwrap_or_default();
+        let imports = plugin
+            .analyze_detailed_imports(&content, Some(&file))
+            .map(|graph| graph.imports)
+            .unwrap_or_default();
+        if !references_symbol(&content, symbol_name, &occurrences, &symbols, &imports) {
+            continue;
+        }
+        declared |= symbols.iter().any(|s| s.name == symbol_name);
+
+        let new_name_occurrences = find_identifier_occurrences(&content, new_name, syntax);
+        conflicts.extend(
+            scope_conflicts(new_name, &new_name_occurrences, &symbols, &imports)
+                .into_iter()
+                .map(|o| format!("{}:{}:{}", file.display(), o.line, o.column)),
+        );
+
+        let symbol_len = symbol_name.chars().count();
+        let edits: Vec<TextEdit> = occurrences
+            .into_iter()
+            .map(|o| TextEdit {
+                start_line: o.line,
+                start_column: o.column,
+                end_line: o.line,
+                end_column: o.column + symbol_len,
+                new_text: new_name.to_string(),
+            })
+            .collect();
+        changes.insert(file.to_string_lossy().into_owned(), edits);
+    }
+
+    if !conflicts.is_empty() {
+        return Err(crate::PluginApiError::invalid_input(format!(
+            "Renaming '{}' to '{}' would collide with existing uses of '{}' at: {}",
+            symbol_name,
+            new_name,
+            new_name,
+            conflicts.join(", ")
+        )));
+    }
+
+    Ok((WorkspaceEdit { changes }, declared))
+}
+
+/// Whether a file's occurrences of `symbol_name` refer to the renamed symbol
+///
+/// True when the file declares the symbol, imports it (by name, or the
+/// module path ends in it), glob-imports a module that may provide it, or
+/// uses it through a `::` path or a `namespace.` qualifier bound by an import.
+fn references_symbol(
+    content: &str,
+    symbol_name: &str,
+    occurrences: &[crate::identifier_scanner::IdentifierOccurrence],
+    symbols: &[crate::Symbol],
+    imports: &[mill_foundation::protocol::ImportInfo],
+) -> bool {
+    if symbols.iter().any(|s| s.name == symbol_name) {
+        return true;
+    }
+
+    let imported = imports.iter().any(|import| {
+        import
+            .named_imports
+            .iter()
+            .any(|named| named.name == symbol_name || named.name == "*")
+            || last_path_segment(&import.module_path) == symbol_name
+            || import
+                .namespace_import
+                .as_deref()
+                .is_some_and(|ns| content.contains(&format!("{}::*", ns)))
+    });
+    if imported {
+        return true;
+    }
+
+    let namespaces: Vec<&str> = imports
+        .iter()
+        .flat_map(|import| [&import.namespace_import, &import.default_import])
+        .filter_map(|binding| binding.as_deref())
+        .collect();
+    let lines: Vec<&str> = content.lines().collect();
+    occurrences.iter().any(|o| {
+        let Some(line) = lines.get(o.line.saturating_sub(1)) else {
+            return false;
+        };
+        let prefix: String = line.chars().take(o.column).collect();
+        if prefix.ends_with("::") {
+            return true;
+        }
+        prefix.strip_suffix('.').is_some_and(|qualifier| {
+            namespaces
+                .iter()
+                .any(|ns| qualifier.trim_end().ends_with(ns))
+        })
+    })
+}
+
+/// Occurrences of `new_name` that a rename would collide with
+///
+/// Only top-level declarations and import bindings share the renamed
+/// symbol's scope; a method or local of the same name elsewhere in the file
+/// is not a conflict. Each conflict is reported at the occurrence on the
+/// declaration's line (symbol lines are 0-based), or at the first occurrence
+/// when the plugin does not report one there.
+fn scope_conflicts<'a>(
+    new_name: &str,
+    occurrences: &'a [crate::identifier_scanner::IdentifierOccurrence],
+    symbols: &[crate::Symbol],
+    imports: &[mill_foundation::protocol::ImportInfo],
+) -> Vec<&'a crate::identifier_scanner::IdentifierOccurrence> {
+    let mut conflicts: Vec<&crate::identifier_scanner::IdentifierOccurrence> = symbols
+        .iter()
+        .filter(|s| s.parent.is_none() && s.name == new_name)
+        .filter_map(|s| {
+            occurrences
+                .iter()
+                .find(|o| o.line == s.location.line + 1)
+                .or_else(|| occurrences.first())
+        })
+        .collect();
+
+    let imports_new_name = imports.iter().any(|import| {
+        import
+            .named_imports
+            .iter()
+            .any(|named| named.alias.as_deref().unwrap_or(&named.name) == new_name)
+            || import.namespace_import.as_deref() == Some(new_name)
+            || import.default_import.as_deref() == Some(new_name)
+    });
+    if imports_new_name {
+        conflicts.extend(occurrences.first());
+    }
+
+    conflicts.sort_by_key(|o| (o.line, o.column));
+    conflicts.dedup_by_key(|o| (o.line, o.column));
+    conflicts
+}
+
+/// Last segment of a module path written with `::`, `.` or `/` separators
+fn last_path_segment(module_path: &str) -> &str {
+    module_path
+        .rsplit([':', '.', '/'])
+        .next()
+        .unwrap_or(module_path)
+}
+
 // ============================================================================
 // Import Analyzer Capability
 // ============================================================================
@@ -530,6 +1001,56 @@ pub trait ManifestUpdater: Send + Sync {
     ) -> PluginResult<String> {
         Err(crate::PluginApiError::not_supported("add_path_dependency"))
     }
+
+    /// Remove a dependency from a manifest file
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest_content` - Current manifest file content
+    /// * `dep_name` - Name of the dependency to remove
+    ///
+    /// # Returns
+    ///
+    /// Updated manifest content without the dependency.
+    ///
+    /// # Default Implementation
+    ///
+    /// Returns NotSupported error.
+    async fn remove_dependency(
+        &self,
+        _manifest_content: &str,
+        _dep_name: &str,
+    ) -> PluginResult<String> {
+        Err(crate::PluginApiError::not_supported("remove_dependency"))
+    }
+
+    /// Remove dependencies that are never imported
+    ///
+    /// The caller gathers the modules imported across the package and the
+    /// plugin drops every dependency entry whose name is not among them.
+    /// Entries inherited from a workspace and build-time dependencies are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest_path` - Path to the manifest file
+    /// * `used_modules` - Module paths imported anywhere in the package
+    ///
+    /// # Returns
+    ///
+    /// Updated manifest content as a string, ready to be written to the file.
+    ///
+    /// # Default Implementation
+    ///
+    /// Returns NotSupported error.
+    async fn prune_unused_dependencies(
+        &self,
+        _manifest_path: &Path,
+        _used_modules: &[String],
+    ) -> PluginResult<String> {
+        Err(crate::PluginApiError::not_supported(
+            "prune_unused_dependencies",
+        ))
+    }
 }
 
 // ============================================================================
@@ -633,9 +1154,21 @@ pub trait FileDiscovery: Send + Sync {
     async fn find_source_files(&self, root_path: &Path) -> PluginResult<Vec<std::path::PathBuf>>;
 }
 
+/// Directories skipped by [`StandardFileDiscovery::with_ignored_dirs`]:
+/// build output, dependency caches and virtual environments
+const IGNORED_DIRS: &[&str] = &[
+    "target",
+    "node_modules",
+    "dist",
+    "build",
+    "__pycache__",
+    "venv",
+];
+
 /// Standard implementation of FileDiscovery using file extensions
 pub struct StandardFileDiscovery {
     extensions: Vec<String>,
+    skip_ignored_dirs: bool,
 }
 
 impl StandardFileDiscovery {
@@ -643,8 +1176,17 @@ impl StandardFileDiscovery {
     pub fn new(extensions: &[&str]) -> Self {
         Self {
             extensions: extensions.iter().map(|s| s.to_string()).collect(),
+            skip_ignored_dirs: false,
         }
     }
+
+    /// Skip hidden directories, the [`IGNORED_DIRS`] and any plain directory
+    /// name listed in the root `.gitignore` (glob and negated patterns are
+    /// not interpreted)
+    pub fn with_ignored_dirs(mut self) -> Self {
+        self.skip_ignored_dirs = true;
+        self
+    }
 }
 
 #[async_trait]
@@ -652,6 +1194,27 @@ impl FileDiscovery for StandardFileDiscovery {
     async fn find_source_files(&self, root_path: &Path) -> PluginResult<Vec<std::path::PathBuf>> {
         use tokio::fs;
 
+        let gitignored: Vec<String> = if self.skip_ignored_dirs {
+            fs::read_to_string(root_path.join(".gitignore"))
+                .await
+                .map(|content| {
+                    content
+                        .lines()
+                        .map(|line| line.trim().trim_matches('/'))
+                        .filter(|line| {
+                            !line.is_empty()
+                                && !line.starts_with('#')
+                                && !line.starts_with('!')
+                                && !line.contains(['*', '?', '[', '/'])
+                        })
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+
         let mut result = Vec::new();
         let mut queue = vec![root_path.to_path_buf()];
 
@@ -677,7 +1240,15 @@ impl FileDiscovery for StandardFileDiscovery {
                 })?;
 
                 if metadata.is_dir() {
-                    queue.push(path);
+                    let name = entry.file_name();
+                    let name = name.to_string_lossy();
+                    let ignored = self.skip_ignored_dirs
+                        && (name.starts_with('.')
+                            || IGNORED_DIRS.contains(&name.as_ref())
+                            || gitignored.iter().any(|g| *g == name));
+                    if !ignored {
+                        queue.push(path);
+                    }
                 } else if let Some(ext) = path.extension().and_then(|s| s.to_str()) {
                     if self.extensions.iter().any(|e| e == ext) {
                         result.push(path);
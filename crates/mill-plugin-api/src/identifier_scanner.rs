@@ -0,0 +1,594 @@
+//! Language-agnostic identifier scanning
+//!
+//! A small lexer that finds whole-identifier occurrences in source code while
+//! skipping string literals and comments. It is intentionally approximate: it
+//! knows the comment and string syntax of the supported languages but nothing
+//! about their grammar, which is enough to drive project-wide symbol renames.
+
+use serde::Serialize;
+
+/// Comment and string syntax of a language, as far as the scanner cares
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LexicalSyntax {
+    /// Line comment introducer (`//`, `#`, `--`)
+    pub line_comment: &'static str,
+    /// Whether `/* ... */` block comments exist
+    pub block_comments: bool,
+    /// Whether `'...'` delimits strings (false for Rust, where `'` starts char
+    /// literals and lifetimes)
+    pub single_quote_strings: bool,
+    /// Whether `` `...` `` delimits strings (template literals)
+    pub backtick_strings: bool,
+    /// Whether `"""` / `'''` triple-quoted strings exist
+    pub triple_quote_strings: bool,
+}
+
+impl LexicalSyntax {
+    /// C-family syntax: `//` and `/* */` comments, `"` and `'` strings
+    pub const C_LIKE: Self = Self {
+        line_comment: "//",
+        block_comments: true,
+        single_quote_strings: true,
+        backtick_strings: true,
+        triple_quote_strings: false,
+    };
+
+    /// Rust syntax: like C, but `'` is a char literal or lifetime
+    pub const RUST: Self = Self {
+        line_comment: "//",
+        block_comments: true,
+        single_quote_strings: false,
+        backtick_strings: false,
+        triple_quote_strings: false,
+    };
+
+    /// Python syntax: `#` comments and triple-quoted strings
+    pub const PYTHON: Self = Self {
+        line_comment: "#",
+        block_comments: false,
+        single_quote_strings: true,
+        backtick_strings: false,
+        triple_quote_strings: true,
+    };
+
+    /// Hash-comment syntax used by shell and config languages
+    pub const HASH: Self = Self {
+        line_comment: "#",
+        block_comments: false,
+        single_quote_strings: true,
+        backtick_strings: false,
+        triple_quote_strings: false,
+    };
+
+    /// Lua syntax: `--` comments
+    pub const LUA: Self = Self {
+        line_comment: "--",
+        block_comments: false,
+        single_quote_strings: true,
+        backtick_strings: false,
+        triple_quote_strings: false,
+    };
+
+    /// Pick the syntax for a language by its `LanguageMetadata::name`
+    pub fn for_language(name: &str) -> Self {
+        match name.to_ascii_lowercase().as_str() {
+            "rust" => Self::RUST,
+            "python" => Self::PYTHON,
+            "shell" | "toml" | "yaml" | "gitignore" => Self::HASH,
+            "lua" => Self::LUA,
+            _ => Self::C_LIKE,
+        }
+    }
+
+    /// Prefixes that start a comment running to the end of the line
+    pub fn line_comment_prefixes(&self) -> &[&'static str] {
+        std::slice::from_ref(&self.line_comment)
+    }
+
+    /// Opening and closing delimiters of block comments, if the language has them
+    pub fn block_comment_delimiters(&self) -> Option<(&'static str, &'static str)> {
+        self.block_comments.then_some(("/*", "*/"))
+    }
+}
+
+/// Tags that mark a task comment
+pub const TASK_TAGS: &[&str] = &["TODO", "FIXME", "HACK", "XXX"];
+
+/// A TODO-style marker found inside a comment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TaskComment {
+    /// Line number (1-indexed)
+    pub line: usize,
+    /// The tag that matched (`TODO`, `FIXME`, ...)
+    pub tag: String,
+    /// Text following the tag, with any `:` or `(owner)` stripped
+    pub message: String,
+}
+
+/// A whole-identifier occurrence in source code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdentifierOccurrence {
+    /// Line number (1-indexed)
+    pub line: usize,
+    /// Column in characters (0-indexed)
+    pub column: usize,
+}
+
+/// An identifier in code together with the brace depth it appears at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NestedIdentifier {
+    /// The identifier text
+    pub name: String,
+    /// Line number (1-indexed)
+    pub line: usize,
+    /// Column in characters (0-indexed)
+    pub column: usize,
+    /// Number of `{` opened and not yet closed before the identifier
+    pub depth: usize,
+}
+
+/// Find every occurrence of `identifier` as a whole identifier, ignoring any
+/// that appear inside string literals or comments
+pub fn find_identifier_occurrences(
+    content: &str,
+    identifier: &str,
+    syntax: LexicalSyntax,
+) -> Vec<IdentifierOccurrence> {
+    let target: Vec<char> = identifier.chars().collect();
+    let mut occurrences = Vec::new();
+    scan(content, syntax, |token| {
+        if let Token::Identifier { text, line, column } = token {
+            if text == target.as_slice() {
+                occurrences.push(IdentifierOccurrence { line, column });
+            }
+        }
+    });
+    occurrences
+}
+
+/// Identifier covering a position (1-indexed line, 0-indexed character
+/// column), or `None` when the position is not on an identifier in code,
+/// e.g. it falls inside a string literal or comment
+pub fn identifier_at(
+    content: &str,
+    line: usize,
+    column: usize,
+    syntax: LexicalSyntax,
+) -> Option<String> {
+    let mut found = None;
+    scan(content, syntax, |token| {
+        if let Token::Identifier {
+            text,
+            line: token_line,
+            column: token_column,
+        } = token
+        {
+            if token_line == line && (token_column..token_column + text.len()).contains(&column) {
+                found = Some(text.iter().collect());
+            }
+        }
+    });
+    found
+}
+
+/// List every identifier outside string literals and comments, with the
+/// `{ }` nesting depth it appears at
+pub fn find_nested_identifiers(content: &str, syntax: LexicalSyntax) -> Vec<NestedIdentifier> {
+    let mut identifiers = Vec::new();
+    let mut depth = 0usize;
+    scan(content, syntax, |token| match token {
+        Token::Identifier { text, line, column } => identifiers.push(NestedIdentifier {
+            name: text.iter().collect(),
+            line,
+            column,
+            depth,
+        }),
+        Token::Brace { open: true } => depth += 1,
+        Token::Brace { open: false } => depth = depth.saturating_sub(1),
+        Token::Comment { .. } => {}
+    });
+    identifiers
+}
+
+/// Find every whole-word occurrence of `needle` inside comments, such as a
+/// module path or type name mentioned in documentation
+pub fn find_comment_occurrences(
+    content: &str,
+    needle: &str,
+    syntax: LexicalSyntax,
+) -> Vec<IdentifierOccurrence> {
+    let target: Vec<char> = needle.chars().collect();
+    let mut occurrences = Vec::new();
+    if target.is_empty() {
+        return occurrences;
+    }
+    scan(content, syntax, |token| {
+        let Token::Comment { text, line, column } = token else {
+            return;
+        };
+        let (mut line, mut column) = (line, column);
+        for i in 0..text.len() {
+            let bounded_before = i == 0 || !is_identifier_char(text[i - 1]);
+            let bounded_after = text
+                .get(i + target.len())
+                .is_none_or(|&c| !is_identifier_char(c));
+            if text[i..].starts_with(&target) && bounded_before && bounded_after {
+                occurrences.push(IdentifierOccurrence { line, column });
+            }
+            if text[i] == '\n' {
+                line += 1;
+                column = 0;
+            } else {
+                column += 1;
+            }
+        }
+    });
+    occurrences
+}
+
+/// Find `TODO`, `FIXME`, `HACK` and `XXX` markers, looking only inside
+/// comments so the same words in strings or code are ignored
+pub fn find_task_comments(content: &str, syntax: LexicalSyntax) -> Vec<TaskComment> {
+    let mut tasks = Vec::new();
+    scan(content, syntax, |token| {
+        if let Token::Comment { text, line, .. } = token {
+            let text: String = text.iter().collect();
+            for (offset, comment_line) in text.lines().enumerate() {
+                if let Some((tag, message)) = parse_task(comment_line) {
+                    tasks.push(TaskComment {
+                        line: line + offset,
+                        tag: tag.to_string(),
+                        message,
+                    });
+                }
+            }
+        }
+    });
+    tasks
+}
+
+/// Match the first task tag on a comment line and return it with its message
+fn parse_task(comment_line: &str) -> Option<(&'static str, String)> {
+    let chars: Vec<char> = comment_line.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        if !is_identifier_char(chars[i]) {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < chars.len() && is_identifier_char(chars[i]) {
+            i += 1;
+        }
+        let word: String = chars[start..i].iter().collect();
+        let Some(tag) = TASK_TAGS.iter().find(|tag| **tag == word) else {
+            continue;
+        };
+
+        let mut rest: &[char] = &chars[i..];
+        // `TODO(owner): ...`
+        if rest.first() == Some(&'(') {
+            if let Some(close) = rest.iter().position(|&c| c == ')') {
+                rest = &rest[close + 1..];
+            }
+        }
+        let rest: String = rest.iter().collect();
+        let message = rest
+            .trim_start()
+            .trim_start_matches([':', '-'])
+            .trim()
+            .trim_end_matches("*/")
+            .trim_end()
+            .to_string();
+        return Some((tag, message));
+    }
+    None
+}
+
+/// A lexical item reported by [`scan`]
+enum Token<'a> {
+    /// An identifier outside strings and comments
+    Identifier {
+        text: &'a [char],
+        line: usize,
+        column: usize,
+    },
+    /// The body of a comment, without its delimiters
+    Comment {
+        text: &'a [char],
+        line: usize,
+        column: usize,
+    },
+    /// A `{` or `}` outside strings and comments
+    Brace { open: bool },
+}
+
+/// Walk `content`, skipping string literals and reporting identifiers,
+/// comments and braces to `visit`
+fn scan(content: &str, syntax: LexicalSyntax, mut visit: impl FnMut(Token<'_>)) {
+    let chars: Vec<char> = content.chars().collect();
+
+    let mut i = 0;
+    let mut line = 1;
+    let mut column = 0;
+
+    // Advance over `count` characters, keeping line/column in sync
+    let advance = |i: &mut usize, line: &mut usize, column: &mut usize, count: usize| {
+        for _ in 0..count {
+            if *i >= chars.len() {
+                return;
+            }
+            if chars[*i] == '\n' {
+                *line += 1;
+                *column = 0;
+            } else {
+                *column += 1;
+            }
+            *i += 1;
+        }
+    };
+
+    let starts_with = |at: usize, pattern: &str| -> bool {
+        pattern
+            .chars()
+            .enumerate()
+            .all(|(offset, c)| chars.get(at + offset) == Some(&c))
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        // Line comment
+        if let Some(prefix) = syntax
+            .line_comment_prefixes()
+            .iter()
+            .find(|prefix| starts_with(i, prefix))
+        {
+            advance(&mut i, &mut line, &mut column, prefix.chars().count());
+            let (start, comment_line, comment_column) = (i, line, column);
+            while i < chars.len() && chars[i] != '\n' {
+                advance(&mut i, &mut line, &mut column, 1);
+            }
+            visit(Token::Comment {
+                text: &chars[start..i],
+                line: comment_line,
+                column: comment_column,
+            });
+            continue;
+        }
+
+        // Block comment
+        if let Some((open, close)) = syntax
+            .block_comment_delimiters()
+            .filter(|(open, _)| starts_with(i, open))
+        {
+            advance(&mut i, &mut line, &mut column, open.chars().count());
+            let (start, comment_line, comment_column) = (i, line, column);
+            while i < chars.len() && !starts_with(i, close) {
+                advance(&mut i, &mut line, &mut column, 1);
+            }
+            visit(Token::Comment {
+                text: &chars[start..i],
+                line: comment_line,
+                column: comment_column,
+            });
+            advance(&mut i, &mut line, &mut column, close.chars().count());
+            continue;
+        }
+
+        // Triple-quoted string
+        if syntax.triple_quote_strings && (starts_with(i, "\"\"\"") || starts_with(i, "'''")) {
+            let delimiter: String = chars[i..i + 3].iter().collect();
+            advance(&mut i, &mut line, &mut column, 3);
+            while i < chars.len() && !starts_with(i, &delimiter) {
+                let step = if chars[i] == '\\' { 2 } else { 1 };
+                advance(&mut i, &mut line, &mut column, step);
+            }
+            advance(&mut i, &mut line, &mut column, 3);
+            continue;
+        }
+
+        // Single-line (or template) string
+        let is_quote = c == '"'
+            || (c == '\'' && syntax.single_quote_strings)
+            || (c == '`' && syntax.backtick_strings);
+        if is_quote {
+            advance(&mut i, &mut line, &mut column, 1);
+            while i < chars.len() && chars[i] != c {
+                let step = if chars[i] == '\\' { 2 } else { 1 };
+                advance(&mut i, &mut line, &mut column, step);
+            }
+            advance(&mut i, &mut line, &mut column, 1);
+            continue;
+        }
+
+        // Rust char literal ('x' or '\n'); a lone quote is a lifetime
+        if c == '\'' && !syntax.single_quote_strings {
+            let len = if chars.get(i + 1) == Some(&'\\') {
+                chars[i + 2..]
+                    .iter()
+                    .position(|&ch| ch == '\'')
+                    .map(|p| p + 3)
+            } else if chars.get(i + 2) == Some(&'\'') {
+                Some(3)
+            } else {
+                None
+            };
+            advance(&mut i, &mut line, &mut column, len.unwrap_or(1));
+            continue;
+        }
+
+        // Identifier
+        if is_identifier_start(c) {
+            let start = i;
+            let (start_line, start_column) = (line, column);
+            while i < chars.len() && is_identifier_char(chars[i]) {
+                advance(&mut i, &mut line, &mut column, 1);
+            }
+            visit(Token::Identifier {
+                text: &chars[start..i],
+                line: start_line,
+                column: start_column,
+            });
+            continue;
+        }
+
+        if c == '{' || c == '}' {
+            visit(Token::Brace { open: c == '{' });
+        }
+
+        advance(&mut i, &mut line, &mut column, 1);
+    }
+}
+
+/// Whether `name` is a syntactically valid identifier
+pub fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(is_identifier_start) && chars.all(is_identifier_char)
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '$'
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn positions(content: &str, ident: &str, syntax: LexicalSyntax) -> Vec<(usize, usize)> {
+        find_identifier_occurrences(content, ident, syntax)
+            .into_iter()
+            .map(|o| (o.line, o.column))
+            .collect()
+    }
+
+    #[test]
+    fn test_skips_strings_and_comments() {
+        let source =
+            "fn compute() {}\n// compute here\nlet s = \"compute\";\n/* compute */ compute();\n";
+        assert_eq!(
+            positions(source, "compute", LexicalSyntax::RUST),
+            vec![(1, 3), (4, 14)]
+        );
+    }
+
+    #[test]
+    fn test_identifier_at_position() {
+        let source = "let total = compute(x); // compute\n";
+        let syntax = LexicalSyntax::RUST;
+        assert_eq!(
+            identifier_at(source, 1, 14, syntax).as_deref(),
+            Some("compute")
+        );
+        assert_eq!(
+            identifier_at(source, 1, 12, syntax).as_deref(),
+            Some("compute")
+        );
+        assert_eq!(identifier_at(source, 1, 19, syntax), None);
+        assert_eq!(
+            identifier_at(source, 1, 28, syntax),
+            None,
+            "inside a comment"
+        );
+    }
+
+    #[test]
+    fn test_whole_identifiers_only() {
+        let source = "compute_all(); recompute(); compute();";
+        assert_eq!(
+            positions(source, "compute", LexicalSyntax::C_LIKE),
+            vec![(1, 28)]
+        );
+    }
+
+    #[test]
+    fn test_python_docstrings_and_hash_comments() {
+        let source =
+            "def helper():\n    \"\"\"helper docs\"\"\"\n    # helper\n    return helper\n";
+        assert_eq!(
+            positions(source, "helper", LexicalSyntax::PYTHON),
+            vec![(1, 4), (4, 11)]
+        );
+    }
+
+    #[test]
+    fn test_rust_lifetimes_and_chars() {
+        let source = "fn f<'a>(x: &'a str) -> char { let c = 'x'; x_len(x); 'x' }";
+        assert_eq!(
+            positions(source, "x", LexicalSyntax::RUST),
+            vec![(1, 9), (1, 50)]
+        );
+    }
+
+    #[test]
+    fn test_task_comments_only_in_comments() {
+        let source = "let s = \"TODO\";\n// TODO: fix\n/* FIXME(ana) handle\n   XXX empty */\nlet todo_list = 1;\n";
+        let tasks = find_task_comments(source, LexicalSyntax::RUST);
+        let found: Vec<_> = tasks
+            .iter()
+            .map(|t| (t.line, t.tag.as_str(), t.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (2, "TODO", "fix"),
+                (3, "FIXME", "handle"),
+                (4, "XXX", "empty")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_python_task_comments() {
+        let source = "msg = 'HACK'\n\"\"\"TODO in docstring\"\"\"\nx = 1  # HACK - temporary\n";
+        let tasks = find_task_comments(source, LexicalSyntax::PYTHON);
+        assert_eq!(tasks.len(), 1);
+        assert_eq!((tasks[0].line, tasks[0].tag.as_str()), (3, "HACK"));
+        assert_eq!(tasks[0].message, "temporary");
+    }
+
+    #[test]
+    fn test_comment_occurrences() {
+        let source =
+            "/// Wraps common::utils\nuse common::utils;\n/* see\n   common::utils_ext */\n";
+        let found: Vec<_> = find_comment_occurrences(source, "common::utils", LexicalSyntax::RUST)
+            .into_iter()
+            .map(|o| (o.line, o.column))
+            .collect();
+        assert_eq!(found, vec![(1, 10)]);
+    }
+
+    #[test]
+    fn test_nested_identifiers_ignore_braces_in_strings() {
+        let source = "fn a() {\n    let s = \"{\";\n    if x { y }\n}\n";
+        let found: Vec<_> = find_nested_identifiers(source, LexicalSyntax::RUST)
+            .into_iter()
+            .map(|ident| (ident.name, ident.line, ident.depth))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("fn".to_string(), 1, 0),
+                ("a".to_string(), 1, 0),
+                ("let".to_string(), 2, 1),
+                ("s".to_string(), 2, 1),
+                ("if".to_string(), 3, 1),
+                ("x".to_string(), 3, 1),
+                ("y".to_string(), 3, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_valid_identifier() {
+        assert!(is_valid_identifier("new_name"));
+        assert!(is_valid_identifier("_private"));
+        assert!(!is_valid_identifier("1abc"));
+        assert!(!is_valid_identifier("a-b"));
+        assert!(!is_valid_identifier(""));
+    }
+}
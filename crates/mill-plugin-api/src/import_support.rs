@@ -125,6 +125,21 @@ pub trait ImportMutationSupport: Send + Sync {
             line
         )))
     }
+
+    /// Remove exact-duplicate import statements, keeping the first occurrence
+    ///
+    /// A rename can map two different modules to the same name, leaving the
+    /// same import twice. The retained line keeps its comments.
+    ///
+    /// # Arguments
+    /// * `source` - Source code content
+    ///
+    /// # Returns
+    /// Updated content and the number of imports removed. The default
+    /// returns the content unchanged.
+    fn dedupe_imports(&self, source: &str) -> (String, usize) {
+        (source.to_string(), 0)
+    }
 }
 
 /// Advanced AST-based import operations
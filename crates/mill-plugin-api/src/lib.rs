@@ -24,29 +24,37 @@ use std::path::Path;
 // ============================================================================
 
 pub mod capabilities;
+pub mod identifier_scanner;
 pub mod import_support;
 pub mod language;
 pub mod lsp_installer;
+pub mod manifest_diff;
 pub mod metadata;
 pub mod path_alias_resolver;
 pub mod plugin_registry;
 pub mod project_factory;
 pub mod reference_detector;
+#[cfg(feature = "schemars")]
+pub mod schema;
 pub mod server;
 pub mod test_fixtures;
 pub mod workspace_support;
 
 // Re-exports
 pub use capabilities::{
-    ExtractParams, FileDiscovery, ImportAnalyzer, InlineParams, ManifestUpdater,
-    ModuleDeclarationSupport, ModuleLocator, ModuleReferenceScanner, RefactoringProvider,
-    StandardFileDiscovery, TextEdit, WorkspaceEdit,
+    plan_project_wide_rename, ExtractParams, FileDiscovery, ImportAnalyzer, InlineParams,
+    ManifestUpdater, ModuleDeclarationSupport, ModuleLocator, ModuleReferenceScanner,
+    RefactoringKind, RefactoringProvider, StandardFileDiscovery, TextEdit, WorkspaceEdit,
 };
 pub use import_support::{
     ImportAdvancedSupport, ImportMoveSupport, ImportMutationSupport, ImportParser,
     ImportRenameSupport,
 };
 pub use lsp_installer::LspInstaller;
+pub use manifest_diff::{
+    diff_manifests, normalize_dependency_name, DependencyChange, DependencyConflict,
+    DependencyListDiff, ManifestDiff,
+};
 pub use metadata::LanguageMetadata;
 pub use path_alias_resolver::PathAliasResolver;
 pub use plugin_registry::{iter_plugins, PluginDescriptor};
@@ -77,6 +85,8 @@ pub enum PluginApiError {
         message: String,
         /// Optional line and column information
         location: Option<SourceLocation>,
+        /// The offending source line with a `^` under the error column
+        snippet: Option<String>,
     },
 
     /// Failed to analyze manifest file
@@ -106,6 +116,7 @@ impl PluginApiError {
         Self::Parse {
             message: message.into(),
             location: None,
+            snippet: None,
         }
     }
 
@@ -114,6 +125,25 @@ impl PluginApiError {
         Self::Parse {
             message: message.into(),
             location: Some(SourceLocation { line, column }),
+            snippet: None,
+        }
+    }
+
+    /// Create a parse error pointing into `source`
+    ///
+    /// `line` is zero-based like symbol locations and `column` counts
+    /// characters. The snippet shows the offending line with a `^` under the
+    /// column; it is omitted when `line` is past the end of `source`.
+    pub fn parse_with_snippet(
+        message: impl Into<String>,
+        line: usize,
+        column: usize,
+        source: &str,
+    ) -> Self {
+        Self::Parse {
+            message: message.into(),
+            location: Some(SourceLocation { line, column }),
+            snippet: source_snippet(source, line, column),
         }
     }
 
@@ -146,14 +176,31 @@ impl PluginApiError {
     }
 }
 
+/// Render the zero-based `line` of `source` with a gutter and a caret under `column`
+fn source_snippet(source: &str, line: usize, column: usize) -> Option<String> {
+    let text = source.lines().nth(line)?;
+    let number = (line + 1).to_string();
+    let gutter = " ".repeat(number.len());
+    // Keep tabs so the caret lines up with the source line
+    let padding: String = text
+        .chars()
+        .take(column)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    Some(format!("{} | {}\n{} | {}^", number, text, gutter, padding))
+}
+
 // ============================================================================
 // Core Data Types
 // ============================================================================
 
 /// Location in source code (line and column)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SourceLocation {
+    /// 0-based line
     pub line: usize,
+    /// 0-based column
     pub column: usize,
 }
 
@@ -161,17 +208,61 @@ pub struct SourceLocation {
 ///
 /// This is a generic container for parsed AST data. Each language plugin
 /// can store its language-specific AST in the `data` field as JSON.
+///
+/// Plugins that measure their work add a `timing` object to `data` with
+/// `parse_ms` (fractional milliseconds). Only plugins that extract symbols
+/// in a pass of their own after parsing, like Rust, time it separately as
+/// `symbol_extraction_ms`; for plugins that extract symbols while parsing,
+/// like TypeScript and Python, `parse_ms` includes symbol extraction.
+///
+/// When a plugin's symbols come from a fallback path because the source did
+/// not parse cleanly, `data` carries `degraded: true`, and the recoverable
+/// errors are listed in `diagnostics` so callers still get best-effort
+/// symbols instead of an `Err`. `degraded: null` means the plugin could not
+/// check the source, e.g. because its native parser is not installed.
+///
+/// Plugins version the shape of `data` with a `schema_version` number (their
+/// crate's `AST_SCHEMA_VERSION`), so consumers can reject data they do not
+/// understand.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ParsedSource {
     /// Language-specific AST data (serialized as JSON for flexibility)
     pub data: Value,
 
     /// List of top-level symbols found in the source
     pub symbols: Vec<Symbol>,
+
+    /// Syntax errors the plugin recovered from while extracting `symbols`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl ParsedSource {
+    /// The `schema_version` the plugin stamped on `data`, if any
+    pub fn schema_version(&self) -> Option<u32> {
+        self.data
+            .get("schema_version")?
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+    }
+}
+
+/// A recoverable syntax error reported alongside a `ParsedSource`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ParseDiagnostic {
+    /// Description of the error
+    pub message: String,
+
+    /// Where the error was found, with a zero-based line like symbol
+    /// locations, when the parser reports one
+    pub location: Option<SourceLocation>,
 }
 
 /// A symbol in the source code (function, class, variable, etc.)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Symbol {
     /// Symbol name
     pub name: String,
@@ -187,10 +278,105 @@ pub struct Symbol {
 
     /// Optional documentation/comments
     pub documentation: Option<String>,
+
+    /// Whether the symbol is a test function (e.g., `#[test]` in Rust,
+    /// `test_*` functions in Python)
+    #[serde(default)]
+    pub is_test: bool,
+
+    /// Index of the enclosing symbol in the same list (e.g., the struct a
+    /// method's `impl` block belongs to), `None` for top-level symbols
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<usize>,
+}
+
+/// Keep only the symbols matching `keep`, remapping `parent` indices
+///
+/// A symbol whose parent is dropped becomes top-level.
+pub fn retain_symbols(symbols: &mut Vec<Symbol>, keep: impl Fn(&Symbol) -> bool) {
+    let mut new_index = Vec::with_capacity(symbols.len());
+    let mut kept = 0;
+    for symbol in symbols.iter() {
+        if keep(symbol) {
+            new_index.push(Some(kept));
+            kept += 1;
+        } else {
+            new_index.push(None);
+        }
+    }
+    let mut is_kept = new_index.iter().map(Option::is_some);
+    symbols.retain(|_| is_kept.next().unwrap_or(false));
+    for symbol in symbols.iter_mut() {
+        symbol.parent = symbol
+            .parent
+            .and_then(|parent| new_index.get(parent).copied().flatten());
+    }
+}
+
+/// A symbol with the symbols nested inside it, for outline views
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SymbolNode {
+    #[serde(flatten)]
+    pub symbol: Symbol,
+    pub children: Vec<SymbolNode>,
+}
+
+impl SymbolNode {
+    /// Nest a flat symbol list by each symbol's `parent` index
+    ///
+    /// Symbols keep their relative order. A `parent` that is out of range or
+    /// would form a loop is ignored and the symbol stays at the top level.
+    pub fn build_tree(symbols: Vec<Symbol>) -> Vec<SymbolNode> {
+        let count = symbols.len();
+        let is_valid_parent = |index: usize| {
+            let mut seen = vec![false; count];
+            let mut current = symbols[index].parent;
+            while let Some(parent) = current {
+                if parent >= count || parent == index || seen[parent] {
+                    return false;
+                }
+                seen[parent] = true;
+                current = symbols[parent].parent;
+            }
+            true
+        };
+        let parents: Vec<Option<usize>> = (0..count)
+            .map(|index| symbols[index].parent.filter(|_| is_valid_parent(index)))
+            .collect();
+
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); count];
+        let mut roots = Vec::new();
+        for (index, parent) in parents.iter().enumerate() {
+            match parent {
+                Some(parent) => children[*parent].push(index),
+                None => roots.push(index),
+            }
+        }
+
+        let mut slots: Vec<Option<Symbol>> = symbols.into_iter().map(Some).collect();
+        fn build(
+            index: usize,
+            slots: &mut [Option<Symbol>],
+            children: &[Vec<usize>],
+        ) -> SymbolNode {
+            SymbolNode {
+                symbol: slots[index].take().expect("each symbol is visited once"),
+                children: children[index]
+                    .iter()
+                    .map(|&child| build(child, slots, children))
+                    .collect(),
+            }
+        }
+        roots
+            .into_iter()
+            .map(|root| build(root, &mut slots, &children))
+            .collect()
+    }
 }
 
 /// Kind of symbol
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum SymbolKind {
     Function,
     Class,
@@ -304,7 +490,7 @@ impl PluginCapabilities {
 }
 
 /// Configuration for a Language Server Protocol (LSP) server.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LspConfig {
     /// The command to execute to start the LSP server (e.g., "rust-analyzer").
     pub command: &'static str,
@@ -321,6 +507,7 @@ impl LspConfig {
 
 /// Manifest file data (package.json, Cargo.toml, etc.)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ManifestData {
     /// Package/project name
     pub name: String,
@@ -338,18 +525,141 @@ pub struct ManifestData {
     pub raw_data: Value,
 }
 
+impl ManifestData {
+    /// Export the dependencies as a pip-style requirements list, one per line
+    ///
+    /// Exact versions (`==1.2` or `=1.2`) become `name==1.2`, path sources
+    /// the PEP 508 direct reference `name @ file:path`, and git sources
+    /// `name @ git+url[@rev]`. Other PEP 440 constraints (`>=0.27`) are
+    /// written as-is and marked `# unpinned`; constraints pip doesn't
+    /// understand, such as Cargo's bare `1.2` (meaning `^1.2`), only appear
+    /// in that comment. Dev dependencies follow the regular ones when
+    /// `include_dev` is set.
+    pub fn to_requirements(&self, include_dev: bool) -> String {
+        let dev = if include_dev {
+            self.dev_dependencies.as_slice()
+        } else {
+            &[]
+        };
+
+        let mut output = String::new();
+        for dependency in self.dependencies.iter().chain(dev) {
+            let name = if dependency.features.is_empty() {
+                dependency.name.clone()
+            } else {
+                format!("{}[{}]", dependency.name, dependency.features.join(","))
+            };
+            let line = match &dependency.source {
+                DependencySource::Version(version) => match exact_version(version) {
+                    Some(pinned) => format!("{}=={}", name, pinned),
+                    None if version == "*" || version.is_empty() => {
+                        format!("{}  # unpinned", name)
+                    }
+                    None if is_pep440_constraint(version) => {
+                        format!("{}{}  # unpinned", name, version)
+                    }
+                    None => format!("{}  # unpinned: {}", name, version),
+                },
+                DependencySource::Path(path) => format!("{} @ {}", name, file_url(path)),
+                DependencySource::Git { url, rev } => match rev {
+                    Some(rev) => format!("{} @ git+{}@{}", name, url, rev),
+                    None => format!("{} @ git+{}", name, url),
+                },
+            };
+            output.push_str(&line);
+            output.push('\n');
+        }
+        output
+    }
+}
+
+/// The version an explicit `==` or `=` constraint pins
+fn exact_version(constraint: &str) -> Option<&str> {
+    let version = constraint
+        .strip_prefix("==")
+        .or_else(|| constraint.strip_prefix('='))?
+        .trim();
+    let is_plain = version.starts_with(|c: char| c.is_ascii_digit())
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+'));
+    is_plain.then_some(version)
+}
+
+/// Whether every clause of `constraint` starts with a PEP 440 operator
+fn is_pep440_constraint(constraint: &str) -> bool {
+    constraint.split(',').all(|clause| {
+        ["~=", "==", "!=", "<=", ">=", "<", ">"]
+            .iter()
+            .any(|operator| clause.trim().starts_with(operator))
+    })
+}
+
+/// A `file:` URL for a local path, absolute paths as `file:///...`
+fn file_url(path: &str) -> String {
+    if path.starts_with("file:") {
+        return path.to_string();
+    }
+    let path = path.replace('\\', "/").replace(' ', "%20");
+    if path.starts_with('/') {
+        format!("file://{}", path)
+    } else {
+        format!("file:{}", path)
+    }
+}
+
 /// A dependency entry
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+///
+/// Dependencies order by name, then by source, so they can be kept in sorted
+/// sets when merging manifests.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Dependency {
     /// Dependency name
     pub name: String,
 
     /// Version specifier or path
     pub source: DependencySource,
+
+    /// Features enabled on the dependency (e.g., Cargo `features = [...]`)
+    #[serde(default)]
+    pub features: Vec<String>,
+
+    /// Whether the dependency is optional (only pulled in through a feature)
+    #[serde(default)]
+    pub optional: bool,
+
+    /// Whether the dependency's default features are enabled
+    #[serde(default = "default_true")]
+    pub default_features: bool,
+
+    /// Platform predicate the dependency is gated on (e.g., Cargo
+    /// `[target.'cfg(unix)'.dependencies]`), `None` for unconditional dependencies
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+}
+
+impl Dependency {
+    /// Create a dependency with no extra features and default features enabled
+    pub fn new(name: impl Into<String>, source: DependencySource) -> Self {
+        Self {
+            name: name.into(),
+            source,
+            features: Vec::new(),
+            optional: false,
+            default_features: true,
+            target: None,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
 }
 
 /// Where a dependency comes from
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum DependencySource {
     /// Registry version (e.g., "1.0.0", "^1.0", etc.)
     Version(String),
@@ -366,7 +676,7 @@ pub enum DependencySource {
 // ============================================================================
 
 /// Defines the scope of the import/reference scan
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ScanScope {
     /// Only find top-level `import`/`use` statements
     TopLevelOnly,
@@ -376,6 +686,11 @@ pub enum ScanScope {
     QualifiedPaths,
     /// Find all references, including string literals (requires confirmation)
     All,
+    /// Only find mentions inside comments (e.g. doc comments naming a module)
+    ///
+    /// This is the only scope that reports comment mentions; `All` leaves them
+    /// out so import rewrites never touch prose.
+    Comments,
 }
 
 /// Represents a found reference to a module within a source file
@@ -401,6 +716,8 @@ pub enum ReferenceKind {
     QualifiedPath,
     /// A reference inside a string literal
     StringLiteral,
+    /// A mention inside a comment
+    Comment,
 }
 
 // ============================================================================
@@ -468,6 +785,17 @@ pub trait LanguagePlugin: Send + Sync {
         })
     }
 
+    /// Extract symbols lazily
+    ///
+    /// Yields the same symbols as `parse(source).symbols`, in order. Plugins
+    /// that can extract symbols incrementally override this so consumers of
+    /// very large files can stop early without building the full list; the
+    /// default parses the whole source synchronously and yields from the
+    /// result, or nothing if parsing fails.
+    fn symbols_iter<'a>(&'a self, source: &'a str) -> Box<dyn Iterator<Item = Symbol> + 'a> {
+        Box::new(parse_symbols_blocking(self, source).into_iter())
+    }
+
     /// Get import parser if available
     fn import_parser(&self) -> Option<&dyn ImportParser> {
         None
@@ -523,6 +851,11 @@ pub trait LanguagePlugin: Send + Sync {
         None
     }
 
+    /// Get the LSP server declared in the plugin's metadata, if any
+    fn lsp_config(&self) -> Option<&LspConfig> {
+        self.metadata().lsp.as_ref()
+    }
+
     /// Get LSP installer if available
     fn lsp_installer(&self) -> Option<&dyn LspInstaller> {
         None
@@ -549,12 +882,32 @@ pub trait LanguagePlugin: Send + Sync {
             .collect())
     }
 
+    /// Extract only the symbols (functions, types, constants, ...) of `source`
+    ///
+    /// The default parses the whole source and discards `data`; plugins can
+    /// override this to skip building the language-specific AST JSON. The
+    /// symbol tools call this rather than `parse`, so an override must return
+    /// the same symbols `parse` would, including for sources with syntax errors.
+    async fn parse_symbols_only(&self, source: &str) -> PluginResult<Vec<Symbol>> {
+        Ok(self.parse(source).await?.symbols)
+    }
+
     fn handles_extension(&self, extension: &str) -> bool {
         self.metadata().extensions.contains(&extension)
     }
 
+    /// Whether this plugin handles `path`, by full file name or by extension
+    fn handles_file(&self, path: &Path) -> bool {
+        let metadata = self.metadata();
+        file_name_matches(path, metadata.filenames)
+            || file_extension(path).is_some_and(|ext| self.handles_extension(ext))
+    }
+
+    /// Whether `filename` is the manifest filename or matches one of the
+    /// manifest patterns
     fn handles_manifest(&self, filename: &str) -> bool {
-        self.metadata().manifest_filename == filename
+        let metadata = self.metadata();
+        metadata.manifest_filename == filename || metadata.matches_manifest_pattern(filename)
     }
 
     // ============================================================================
@@ -675,6 +1028,32 @@ pub trait LanguagePlugin: Send + Sync {
     }
 }
 
+/// Parse `source` with `plugin` from synchronous code and return its symbols
+///
+/// `parse` runs on a private runtime in a scoped thread, so this works both
+/// outside a runtime and from inside one without blocking its executor.
+fn parse_symbols_blocking<P: LanguagePlugin + ?Sized>(plugin: &P, source: &str) -> Vec<Symbol> {
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                let runtime = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .map_err(|e| {
+                        PluginApiError::internal(format!("Failed to start runtime: {}", e))
+                    })?;
+                runtime.block_on(plugin.parse(source))
+            })
+            .join()
+            .unwrap_or_else(|_| Err(PluginApiError::internal("Symbol extraction panicked")))
+    })
+    .map(|parsed| parsed.symbols)
+    .unwrap_or_else(|e| {
+        tracing::warn!(error = %e, "Failed to parse source for symbol iteration");
+        Vec::new()
+    })
+}
+
 // ============================================================================
 // Plugin Registry
 // ============================================================================
@@ -691,7 +1070,89 @@ pub trait LanguagePlugin: Send + Sync {
 /// This will be used by the main server to register and look up plugins
 /// based on file extensions.
 pub struct PluginDiscovery {
-    plugins: Vec<std::sync::Arc<dyn LanguagePlugin>>,
+    plugins: Vec<PluginEntry>,
+    /// Every plugin instantiated, built on the first call to `all()`
+    all: std::sync::OnceLock<Vec<std::sync::Arc<dyn LanguagePlugin>>>,
+}
+
+/// A registered plugin and its priority for overlapping extensions
+struct PluginEntry {
+    priority: i32,
+    kind: PluginKind,
+}
+
+/// The extension of `path`, if it is valid UTF-8
+fn file_extension(path: &Path) -> Option<&str> {
+    path.extension().and_then(|ext| ext.to_str())
+}
+
+/// Whether the file name of `path` is one of `filenames`
+fn file_name_matches(path: &Path, filenames: &[&str]) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| filenames.contains(&name))
+}
+
+/// A plugin either constructed up front or on first use
+enum PluginKind {
+    Eager(std::sync::Arc<dyn LanguagePlugin>),
+    Lazy(LazyPlugin),
+}
+
+/// A plugin constructed by its descriptor's factory on first use
+struct LazyPlugin {
+    extensions: &'static [&'static str],
+    filenames: &'static [&'static str],
+    manifest_filename: &'static str,
+    manifest_patterns: &'static [&'static str],
+    factory: fn() -> Box<dyn LanguagePlugin>,
+    instance: std::sync::OnceLock<std::sync::Arc<dyn LanguagePlugin>>,
+}
+
+impl PluginEntry {
+    fn handles_extension(&self, extension: &str) -> bool {
+        match &self.kind {
+            PluginKind::Eager(plugin) => plugin.handles_extension(extension),
+            PluginKind::Lazy(lazy) => lazy.extensions.contains(&extension),
+        }
+    }
+
+    /// Whether the plugin claims `path` by its full file name
+    fn handles_file_name(&self, path: &Path) -> bool {
+        let filenames = match &self.kind {
+            PluginKind::Eager(plugin) => plugin.metadata().filenames,
+            PluginKind::Lazy(lazy) => lazy.filenames,
+        };
+        file_name_matches(path, filenames)
+    }
+
+    fn handles_manifest(&self, filename: &str) -> bool {
+        match &self.kind {
+            PluginKind::Eager(plugin) => plugin.handles_manifest(filename),
+            PluginKind::Lazy(lazy) => {
+                lazy.manifest_filename == filename
+                    || metadata::matches_any_glob(lazy.manifest_patterns, filename)
+            }
+        }
+    }
+
+    /// The plugin's primary manifest filename
+    fn manifest_filename(&self) -> &'static str {
+        match &self.kind {
+            PluginKind::Eager(plugin) => plugin.metadata().manifest_filename,
+            PluginKind::Lazy(lazy) => lazy.manifest_filename,
+        }
+    }
+
+    /// Get the plugin, constructing it if this is its first use
+    fn plugin(&self) -> &std::sync::Arc<dyn LanguagePlugin> {
+        match &self.kind {
+            PluginKind::Eager(plugin) => plugin,
+            PluginKind::Lazy(lazy) => lazy
+                .instance
+                .get_or_init(|| std::sync::Arc::from((lazy.factory)())),
+        }
+    }
 }
 
 impl PluginDiscovery {
@@ -699,25 +1160,202 @@ impl PluginDiscovery {
     pub fn new() -> Self {
         Self {
             plugins: Vec::new(),
+            all: std::sync::OnceLock::new(),
         }
     }
 
-    /// Register a new language plugin
+    /// Register a new language plugin with the default priority (0)
     pub fn register(&mut self, plugin: std::sync::Arc<dyn LanguagePlugin>) {
-        self.plugins.push(plugin);
+        self.register_with_priority(plugin, 0);
     }
 
-    /// Find a plugin that handles the given file extension
+    /// Register a new language plugin with an explicit priority
+    ///
+    /// When several plugins handle the same extension, the one with the
+    /// highest priority is returned by `find_by_extension`.
+    pub fn register_with_priority(
+        &mut self,
+        plugin: std::sync::Arc<dyn LanguagePlugin>,
+        priority: i32,
+    ) {
+        self.plugins.push(PluginEntry {
+            priority,
+            kind: PluginKind::Eager(plugin),
+        });
+        self.all.take();
+    }
+
+    /// Register a plugin that is only constructed when first needed
+    ///
+    /// The descriptor's factory runs on the first `find_by_extension` or
+    /// `find_by_manifest` match (or on `all()`), and the instance is cached
+    /// for the lifetime of the registry.
+    pub fn register_lazy(&mut self, descriptor: &PluginDescriptor) {
+        self.plugins.push(PluginEntry {
+            priority: descriptor.priority,
+            kind: PluginKind::Lazy(LazyPlugin {
+                extensions: descriptor.extensions,
+                filenames: descriptor.filenames,
+                manifest_filename: descriptor.manifest_filename,
+                manifest_patterns: descriptor.manifest_patterns,
+                factory: descriptor.factory,
+                instance: std::sync::OnceLock::new(),
+            }),
+        });
+        self.all.take();
+    }
+
+    /// Find the plugin that handles the given file extension
+    ///
+    /// When several plugins handle it, the highest-priority one wins; ties go
+    /// to the plugin registered first.
     pub fn find_by_extension(&self, extension: &str) -> Option<&dyn LanguagePlugin> {
+        self.find_entry_by_extension(extension)
+            .map(|entry| entry.plugin().as_ref())
+    }
+
+    fn find_entry_by_extension(&self, extension: &str) -> Option<&PluginEntry> {
+        self.plugins
+            .iter()
+            .filter(|entry| entry.handles_extension(extension))
+            .min_by_key(|entry| std::cmp::Reverse(entry.priority))
+    }
+
+    /// Find the plugin that handles the file at `path`
+    ///
+    /// A plugin claiming the full file name (e.g., `Dockerfile`) wins over
+    /// one matching only the extension; within each group the usual priority
+    /// rules of `find_by_extension` apply.
+    pub fn find_by_file(&self, path: &Path) -> Option<&dyn LanguagePlugin> {
+        self.find_entry_by_file(path)
+            .map(|entry| entry.plugin().as_ref())
+    }
+
+    fn find_entry_by_file(&self, path: &Path) -> Option<&PluginEntry> {
+        self.plugins
+            .iter()
+            .filter(|entry| entry.handles_file_name(path))
+            .min_by_key(|entry| std::cmp::Reverse(entry.priority))
+            .or_else(|| file_extension(path).and_then(|ext| self.find_entry_by_extension(ext)))
+    }
+
+    /// Parse many files concurrently, each with the plugin `find_by_file` picks
+    ///
+    /// At most `concurrency` parses run at once (at least one). Results come
+    /// back in input order; a file no plugin handles gets a `NotSupported`
+    /// error in its slot.
+    pub async fn parse_many(
+        &self,
+        files: Vec<(std::path::PathBuf, String)>,
+        concurrency: usize,
+    ) -> Vec<(std::path::PathBuf, PluginResult<ParsedSource>)> {
+        let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(concurrency.max(1)));
+        let mut tasks = tokio::task::JoinSet::new();
+        let mut task_slots = std::collections::HashMap::new();
+        let mut paths = Vec::with_capacity(files.len());
+        let mut results: Vec<Option<PluginResult<ParsedSource>>> = Vec::with_capacity(files.len());
+
+        for (index, (path, source)) in files.into_iter().enumerate() {
+            let plugin = self
+                .find_entry_by_file(&path)
+                .map(|entry| entry.plugin().clone());
+            match plugin {
+                Some(plugin) => {
+                    results.push(None);
+                    let permit = semaphore
+                        .clone()
+                        .acquire_owned()
+                        .await
+                        .expect("parse semaphore is never closed");
+                    let handle = tasks.spawn(async move {
+                        let _permit = permit;
+                        plugin.parse(&source).await
+                    });
+                    task_slots.insert(handle.id(), index);
+                }
+                None => results.push(Some(Err(PluginApiError::not_supported(format!(
+                    "parsing {}: no plugin for this file type",
+                    path.display()
+                ))))),
+            }
+            paths.push(path);
+        }
+
+        while let Some(joined) = tasks.join_next_with_id().await {
+            let (id, result) = match joined {
+                Ok((id, result)) => (id, result),
+                Err(e) => (
+                    e.id(),
+                    Err(PluginApiError::internal(format!(
+                        "Parse task failed: {}",
+                        e
+                    ))),
+                ),
+            };
+            results[task_slots[&id]] = Some(result);
+        }
+
+        paths
+            .into_iter()
+            .zip(results)
+            .map(|(path, result)| (path, result.expect("every parse task reports back")))
+            .collect()
+    }
+
+    /// Find every plugin that handles the given file extension
+    ///
+    /// Candidates are ordered by descending priority, then registration order,
+    /// so the first one is what `find_by_extension` returns. Lazily registered
+    /// candidates are constructed.
+    pub fn find_all_by_extension(&self, extension: &str) -> Vec<&dyn LanguagePlugin> {
+        let mut candidates: Vec<&PluginEntry> = self
+            .plugins
+            .iter()
+            .filter(|entry| entry.handles_extension(extension))
+            .collect();
+        // Stable sort keeps registration order among equal priorities
+        candidates.sort_by_key(|entry| std::cmp::Reverse(entry.priority));
+        candidates
+            .into_iter()
+            .map(|entry| entry.plugin().as_ref())
+            .collect()
+    }
+
+    /// Find the first plugin that handles the given manifest filename
+    /// (e.g. `Cargo.toml`, `package.json`, `requirements-dev.txt`)
+    ///
+    /// A plugin whose primary manifest filename is exactly `filename` wins
+    /// over an earlier-registered plugin that only matches it by pattern.
+    pub fn find_by_manifest(&self, filename: &str) -> Option<&dyn LanguagePlugin> {
         self.plugins
             .iter()
-            .find(|p| p.handles_extension(extension))
-            .map(|arc| arc.as_ref())
+            .find(|entry| entry.manifest_filename() == filename)
+            .or_else(|| {
+                self.plugins
+                    .iter()
+                    .find(|entry| entry.handles_manifest(filename))
+            })
+            .map(|entry| entry.plugin().as_ref())
     }
 
-    /// Get all registered plugins
+    /// Get all registered plugins, in registration order
+    ///
+    /// Lazily registered plugins that have not been used yet are constructed.
+    /// Registration order depends on how self-registered plugins are linked,
+    /// so use `sorted_by_name` wherever the order is shown to users.
     pub fn all(&self) -> &[std::sync::Arc<dyn LanguagePlugin>] {
-        &self.plugins
+        self.all
+            .get_or_init(|| self.plugins.iter().map(|e| e.plugin().clone()).collect())
+    }
+
+    /// Get all registered plugins sorted by `metadata().name`
+    ///
+    /// Plugins sharing a name keep their registration order.
+    pub fn sorted_by_name(&self) -> Vec<&dyn LanguagePlugin> {
+        let mut plugins: Vec<&dyn LanguagePlugin> =
+            self.all().iter().map(|plugin| plugin.as_ref()).collect();
+        plugins.sort_by_key(|plugin| plugin.metadata().name);
+        plugins
     }
 
     /// Get the refactoring provider capability for a specific file
@@ -747,6 +1385,26 @@ impl PluginDiscovery {
         // Get capability from that specific plugin
         plugin.refactoring_provider()
     }
+
+    /// Get the manifest updater capability for a manifest file
+    ///
+    /// This looks up the plugin by the manifest's file name, then returns its
+    /// manifest updater capability.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest` - Manifest file name or path (e.g., "Cargo.toml", "crates/foo/Cargo.toml")
+    ///
+    /// # Returns
+    ///
+    /// The manifest updater for the manifest's language, or None if not supported
+    pub fn manifest_updater_for_manifest(&self, manifest: &str) -> Option<&dyn ManifestUpdater> {
+        let filename = std::path::Path::new(manifest)
+            .file_name()
+            .and_then(|name| name.to_str())?;
+
+        self.find_by_manifest(filename)?.manifest_updater()
+    }
 }
 
 impl Default for PluginDiscovery {
@@ -774,10 +1432,13 @@ mod tests {
                 metadata: LanguageMetadata {
                     name: "Mock",
                     extensions: &["mock"],
+                    filenames: &[],
                     manifest_filename: "mock.toml",
+                    manifest_patterns: &[],
                     source_dir: "src",
                     entry_point: "lib.mock",
                     module_separator: "::",
+                    lsp: None,
                 },
             }
         }
@@ -793,6 +1454,7 @@ mod tests {
             Ok(ParsedSource {
                 data: serde_json::json!({}),
                 symbols: vec![],
+                diagnostics: Vec::new(),
             })
         }
 
@@ -815,6 +1477,61 @@ mod tests {
         }
     }
 
+    fn symbol(name: &str, kind: SymbolKind, parent: Option<usize>) -> Symbol {
+        Symbol {
+            name: name.to_string(),
+            kind,
+            location: SourceLocation { line: 0, column: 0 },
+            end_location: None,
+            documentation: None,
+            is_test: false,
+            parent,
+        }
+    }
+
+    #[test]
+    fn test_build_tree_nests_methods_under_class() {
+        let symbols = vec![
+            symbol("Shape", SymbolKind::Class, None),
+            symbol("area", SymbolKind::Method, Some(0)),
+            symbol("main", SymbolKind::Function, None),
+            symbol("perimeter", SymbolKind::Method, Some(0)),
+            symbol("orphan", SymbolKind::Method, Some(42)),
+            symbol("looped", SymbolKind::Method, Some(5)),
+        ];
+
+        let tree = SymbolNode::build_tree(symbols);
+
+        let names = |nodes: &[SymbolNode]| -> Vec<String> {
+            nodes.iter().map(|n| n.symbol.name.clone()).collect()
+        };
+        assert_eq!(names(&tree), vec!["Shape", "main", "orphan", "looped"]);
+        assert_eq!(names(&tree[0].children), vec!["area", "perimeter"]);
+        assert!(tree[1].children.is_empty());
+    }
+
+    #[test]
+    fn test_retain_symbols_remaps_parents() {
+        let mut symbols = vec![
+            symbol("helper", SymbolKind::Function, None),
+            symbol("Shape", SymbolKind::Class, None),
+            symbol("area", SymbolKind::Method, Some(1)),
+            symbol("Point", SymbolKind::Struct, None),
+            symbol("norm", SymbolKind::Method, Some(3)),
+        ];
+
+        retain_symbols(&mut symbols, |s| s.name != "helper" && s.name != "Point");
+
+        let parents: Vec<(&str, Option<usize>)> = symbols
+            .iter()
+            .map(|s| (s.name.as_str(), s.parent))
+            .collect();
+        assert_eq!(
+            parents,
+            vec![("Shape", None), ("area", Some(0)), ("norm", None)]
+        );
+    }
+
     #[test]
     fn test_plugin_registry() {
         let mut registry = PluginDiscovery::new();
@@ -825,6 +1542,86 @@ mod tests {
         assert_eq!(plugin.unwrap().metadata().name, "Mock");
     }
 
+    #[test]
+    fn test_find_by_extension_prefers_higher_priority() {
+        fn header_plugin(name: &'static str) -> Arc<MockPlugin> {
+            let mut plugin = MockPlugin::new();
+            plugin.metadata.name = name;
+            plugin.metadata.extensions = &["h"];
+            Arc::new(plugin)
+        }
+
+        let mut registry = PluginDiscovery::new();
+        registry.register_with_priority(header_plugin("C"), 1);
+        registry.register_with_priority(header_plugin("C++"), 5);
+        registry.register(header_plugin("Objective-C"));
+
+        assert_eq!(
+            registry.find_by_extension("h").unwrap().metadata().name,
+            "C++"
+        );
+
+        let names: Vec<_> = registry
+            .find_all_by_extension("h")
+            .iter()
+            .map(|plugin| plugin.metadata().name)
+            .collect();
+        assert_eq!(names, vec!["C++", "C", "Objective-C"]);
+        assert!(registry.find_all_by_extension("rs").is_empty());
+
+        // Equal priorities fall back to registration order
+        let mut registry = PluginDiscovery::new();
+        registry.register(header_plugin("C"));
+        registry.register(header_plugin("C++"));
+        assert_eq!(
+            registry.find_by_extension("h").unwrap().metadata().name,
+            "C"
+        );
+    }
+
+    #[test]
+    fn test_lazy_plugin_constructed_on_first_use() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static CONSTRUCTED: AtomicUsize = AtomicUsize::new(0);
+
+        fn factory() -> Box<dyn LanguagePlugin> {
+            CONSTRUCTED.fetch_add(1, Ordering::SeqCst);
+            Box::new(MockPlugin::new())
+        }
+
+        let descriptor = PluginDescriptor {
+            name: "Mock",
+            extensions: &["mock"],
+            filenames: &[],
+            manifest_filename: "mock.toml",
+            manifest_patterns: &[],
+            capabilities: PluginCapabilities::none(),
+            factory,
+            lsp: None,
+            priority: 0,
+        };
+
+        let mut registry = PluginDiscovery::new();
+        registry.register_lazy(&descriptor);
+        assert_eq!(CONSTRUCTED.load(Ordering::SeqCst), 0);
+
+        assert!(registry.find_by_extension("rs").is_none());
+        assert_eq!(
+            CONSTRUCTED.load(Ordering::SeqCst),
+            0,
+            "unrelated extensions must not construct the plugin"
+        );
+
+        let plugin = registry.find_by_extension("mock").unwrap();
+        assert_eq!(plugin.metadata().name, "Mock");
+        assert_eq!(CONSTRUCTED.load(Ordering::SeqCst), 1);
+
+        registry.find_by_extension("mock").unwrap();
+        assert_eq!(registry.all().len(), 1);
+        assert_eq!(CONSTRUCTED.load(Ordering::SeqCst), 1, "instance is cached");
+    }
+
     #[test]
     fn test_refactoring_provider_for_file_routes_by_extension() {
         use crate::RefactoringProvider;
@@ -838,10 +1635,13 @@ mod tests {
                 static METADATA: LanguageMetadata = LanguageMetadata {
                     name: "rust-mock",
                     extensions: &["rs"],
+                    filenames: &[],
                     manifest_filename: "Cargo.toml",
+                    manifest_patterns: &[],
                     source_dir: "src",
                     entry_point: "lib.rs",
                     module_separator: "::",
+                    lsp: None,
                 };
                 &METADATA
             }
@@ -879,10 +1679,13 @@ mod tests {
                 static METADATA: LanguageMetadata = LanguageMetadata {
                     name: "typescript-mock",
                     extensions: &["ts", "tsx"],
+                    filenames: &[],
                     manifest_filename: "package.json",
+                    manifest_patterns: &[],
                     source_dir: "src",
                     entry_point: "index.ts",
                     module_separator: ".",
+                    lsp: None,
                 };
                 &METADATA
             }
@@ -943,6 +1746,445 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sorted_by_name_is_alphabetical() {
+        let mut registry = PluginDiscovery::new();
+        for name in ["zig", "python", "rust", "markdown"] {
+            let mut plugin = MockPlugin::new();
+            plugin.metadata.name = name;
+            registry.register(Arc::new(plugin));
+        }
+
+        let registered: Vec<_> = registry.all().iter().map(|p| p.metadata().name).collect();
+        assert_eq!(registered, ["zig", "python", "rust", "markdown"]);
+
+        let sorted: Vec<_> = registry
+            .sorted_by_name()
+            .iter()
+            .map(|p| p.metadata().name)
+            .collect();
+        assert_eq!(sorted, ["markdown", "python", "rust", "zig"]);
+    }
+
+    #[test]
+    fn test_find_by_file_matches_full_file_names() {
+        macro_rules! mock_plugin {
+            ($plugin:ident, $name:expr, $extensions:expr, $filenames:expr) => {
+                struct $plugin;
+
+                #[async_trait]
+                impl LanguagePlugin for $plugin {
+                    fn metadata(&self) -> &LanguageMetadata {
+                        static METADATA: LanguageMetadata = LanguageMetadata {
+                            name: $name,
+                            extensions: &$extensions,
+                            filenames: &$filenames,
+                            manifest_filename: "",
+                            manifest_patterns: &[],
+                            source_dir: ".",
+                            entry_point: "",
+                            module_separator: ".",
+                            lsp: None,
+                        };
+                        &METADATA
+                    }
+
+                    fn capabilities(&self) -> PluginCapabilities {
+                        PluginCapabilities::none()
+                    }
+
+                    fn as_any(&self) -> &dyn std::any::Any {
+                        self
+                    }
+
+                    async fn parse(&self, _: &str) -> PluginResult<ParsedSource> {
+                        unimplemented!()
+                    }
+
+                    async fn analyze_manifest(&self, _: &Path) -> PluginResult<ManifestData> {
+                        unimplemented!()
+                    }
+                }
+            };
+        }
+        mock_plugin!(
+            DockerMockPlugin,
+            "docker-mock",
+            ["dockerfile"],
+            ["Dockerfile"]
+        );
+        mock_plugin!(RustMockPlugin, "rust-mock", ["rs"], []);
+
+        let mut registry = PluginDiscovery::new();
+        registry.register(Arc::new(RustMockPlugin));
+        registry.register(Arc::new(DockerMockPlugin));
+
+        let docker = registry
+            .find_by_file(Path::new("deploy/Dockerfile"))
+            .unwrap();
+        assert_eq!(docker.metadata().name, "docker-mock");
+        assert!(docker.handles_file(Path::new("Dockerfile")));
+        assert!(docker.handles_file(Path::new("app.dockerfile")));
+        assert!(registry.find_by_file(Path::new("Dockerfile.dev")).is_none());
+        assert!(registry.find_by_file(Path::new("Makefile")).is_none());
+
+        // Extensions keep routing as before
+        let rust = registry.find_by_file(Path::new("src/lib.rs")).unwrap();
+        assert_eq!(rust.metadata().name, "rust-mock");
+        assert_eq!(
+            registry.find_by_extension("rs").unwrap().metadata().name,
+            "rust-mock"
+        );
+        assert!(!rust.handles_file(Path::new("Dockerfile")));
+    }
+
+    #[test]
+    fn test_find_by_manifest_routes_by_filename() {
+        struct RustMockPlugin;
+        struct SwiftMockPlugin;
+
+        static RUST_METADATA: LanguageMetadata = LanguageMetadata {
+            name: "rust-mock",
+            extensions: &["rs"],
+            filenames: &[],
+            manifest_filename: "Cargo.toml",
+            manifest_patterns: &[],
+            source_dir: "src",
+            entry_point: "lib.rs",
+            module_separator: "::",
+            lsp: Some(LspConfig::new("rust-analyzer", &[])),
+        };
+        static SWIFT_METADATA: LanguageMetadata = LanguageMetadata {
+            name: "swift-mock",
+            extensions: &["swift"],
+            filenames: &[],
+            manifest_filename: "Package.swift",
+            manifest_patterns: &[],
+            source_dir: "Sources",
+            entry_point: "main.swift",
+            module_separator: ".",
+            lsp: Some(LspConfig::new("sourcekit-lsp", &[])),
+        };
+
+        macro_rules! mock_plugin {
+            ($plugin:ident, $metadata:ident) => {
+                #[async_trait]
+                impl LanguagePlugin for $plugin {
+                    fn metadata(&self) -> &LanguageMetadata {
+                        &$metadata
+                    }
+
+                    fn capabilities(&self) -> PluginCapabilities {
+                        PluginCapabilities::none()
+                    }
+
+                    fn as_any(&self) -> &dyn std::any::Any {
+                        self
+                    }
+
+                    async fn parse(&self, _: &str) -> PluginResult<ParsedSource> {
+                        unimplemented!()
+                    }
+
+                    async fn analyze_manifest(&self, _: &Path) -> PluginResult<ManifestData> {
+                        unimplemented!()
+                    }
+
+                    fn manifest_updater(&self) -> Option<&dyn ManifestUpdater> {
+                        Some(self)
+                    }
+                }
+
+                #[async_trait]
+                impl ManifestUpdater for $plugin {
+                    async fn update_dependency(
+                        &self,
+                        _: &Path,
+                        _: &str,
+                        _: &str,
+                        _: Option<&str>,
+                    ) -> PluginResult<String> {
+                        unimplemented!()
+                    }
+
+                    fn generate_manifest(&self, _: &str, _: &[String]) -> String {
+                        $metadata.name.to_string()
+                    }
+                }
+            };
+        }
+        mock_plugin!(RustMockPlugin, RUST_METADATA);
+        mock_plugin!(SwiftMockPlugin, SWIFT_METADATA);
+
+        let mut registry = PluginDiscovery::new();
+        registry.register(Arc::new(RustMockPlugin));
+        registry.register(Arc::new(SwiftMockPlugin));
+
+        let rust = registry.find_by_manifest("Cargo.toml").unwrap();
+        assert_eq!(rust.metadata().name, "rust-mock");
+
+        let swift = registry.find_by_manifest("Package.swift").unwrap();
+        assert_eq!(swift.metadata().name, "swift-mock");
+
+        assert!(registry.find_by_manifest("pom.xml").is_none());
+
+        // The LSP to start comes straight from the metadata, no downcasting
+        assert_eq!(rust.lsp_config().unwrap().command, "rust-analyzer");
+        assert_eq!(swift.lsp_config().unwrap().command, "sourcekit-lsp");
+        assert!(MockPlugin::new().lsp_config().is_none());
+
+        let updater = registry
+            .manifest_updater_for_manifest("crates/foo/Cargo.toml")
+            .unwrap();
+        assert_eq!(updater.generate_manifest("foo", &[]), "rust-mock");
+        assert!(registry.manifest_updater_for_manifest("pom.xml").is_none());
+    }
+
+    #[test]
+    fn test_capabilities_path_alias_resolver_flag() {
+        assert!(PluginCapabilities::all().path_alias_resolver);
+        assert!(!PluginCapabilities::none().path_alias_resolver);
+        assert!(!PluginCapabilities::default().path_alias_resolver);
+
+        let caps = PluginCapabilities::none().with_path_alias_resolver();
+        assert!(caps.path_alias_resolver);
+        assert!(!caps.imports && !caps.workspace && !caps.project_factory);
+    }
+
+    #[test]
+    fn test_parsed_source_schema_version() {
+        let parsed = |data| ParsedSource {
+            data,
+            symbols: vec![],
+            diagnostics: Vec::new(),
+        };
+        assert_eq!(
+            parsed(serde_json::json!({"schema_version": 3})).schema_version(),
+            Some(3)
+        );
+        assert_eq!(
+            parsed(serde_json::json!({"type": "File"})).schema_version(),
+            None
+        );
+        assert_eq!(
+            parsed(serde_json::json!({"schema_version": "1"})).schema_version(),
+            None
+        );
+        assert_eq!(parsed(Value::Null).schema_version(), None);
+    }
+
+    #[tokio::test]
+    async fn test_parse_many_routes_by_extension() {
+        struct EchoPlugin(LanguageMetadata);
+
+        #[async_trait]
+        impl LanguagePlugin for EchoPlugin {
+            fn metadata(&self) -> &LanguageMetadata {
+                &self.0
+            }
+
+            async fn parse(&self, source: &str) -> PluginResult<ParsedSource> {
+                if source.is_empty() {
+                    return Err(PluginApiError::parse("empty source"));
+                }
+                Ok(ParsedSource {
+                    data: serde_json::json!({ "plugin": self.0.name, "source": source }),
+                    symbols: vec![],
+                    diagnostics: Vec::new(),
+                })
+            }
+
+            async fn analyze_manifest(&self, _path: &Path) -> PluginResult<ManifestData> {
+                unimplemented!()
+            }
+
+            fn capabilities(&self) -> PluginCapabilities {
+                PluginCapabilities::none()
+            }
+
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
+        let mut metadata = MockPlugin::new().metadata;
+        metadata.name = "rust-echo";
+        metadata.extensions = &["rs"];
+        let mut registry = PluginDiscovery::new();
+        registry.register(Arc::new(EchoPlugin(metadata)));
+
+        let files: Vec<(std::path::PathBuf, String)> = (0..20)
+            .map(|i| {
+                if i % 3 == 0 {
+                    (format!("file{}.unknown", i).into(), "???".to_string())
+                } else {
+                    (format!("file{}.rs", i).into(), format!("fn f{}() {{}}", i))
+                }
+            })
+            .chain([("empty.rs".into(), String::new())])
+            .collect();
+        let expected_paths: Vec<_> = files.iter().map(|(path, _)| path.clone()).collect();
+
+        let results = registry.parse_many(files, 4).await;
+
+        let paths: Vec<_> = results.iter().map(|(path, _)| path.clone()).collect();
+        assert_eq!(paths, expected_paths);
+        for (i, (path, result)) in results.iter().enumerate().take(20) {
+            if i % 3 == 0 {
+                assert!(
+                    matches!(result, Err(PluginApiError::NotSupported { .. })),
+                    "{}",
+                    path.display()
+                );
+            } else {
+                let parsed = result.as_ref().unwrap();
+                assert_eq!(parsed.data["plugin"], "rust-echo");
+                assert_eq!(parsed.data["source"], format!("fn f{}() {{}}", i));
+            }
+        }
+        // Plugin errors stay in their own slot
+        assert!(matches!(results[20].1, Err(PluginApiError::Parse { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_default_symbols_iter_yields_parsed_symbols() {
+        struct LinePlugin(LanguageMetadata);
+
+        #[async_trait]
+        impl LanguagePlugin for LinePlugin {
+            fn metadata(&self) -> &LanguageMetadata {
+                &self.0
+            }
+
+            async fn parse(&self, source: &str) -> PluginResult<ParsedSource> {
+                if source.is_empty() {
+                    return Err(PluginApiError::parse("empty source"));
+                }
+                // Yield to the runtime so parsing needs a working executor
+                tokio::task::yield_now().await;
+                let symbols = source
+                    .lines()
+                    .enumerate()
+                    .map(|(line, name)| Symbol {
+                        name: name.to_string(),
+                        kind: SymbolKind::Function,
+                        location: SourceLocation { line, column: 0 },
+                        end_location: None,
+                        documentation: None,
+                        is_test: false,
+                        parent: None,
+                    })
+                    .collect();
+                Ok(ParsedSource {
+                    data: serde_json::json!({}),
+                    symbols,
+                    diagnostics: Vec::new(),
+                })
+            }
+
+            async fn analyze_manifest(&self, _path: &Path) -> PluginResult<ManifestData> {
+                unimplemented!()
+            }
+
+            fn capabilities(&self) -> PluginCapabilities {
+                PluginCapabilities::none()
+            }
+
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
+        let plugin = LinePlugin(MockPlugin::new().metadata);
+        let source = "first\nsecond\nthird";
+
+        // Called from inside a current-thread runtime without deadlocking
+        let streamed: Vec<Symbol> = plugin.symbols_iter(source).collect();
+        assert_eq!(streamed, plugin.parse(source).await.unwrap().symbols);
+        assert_eq!(plugin.symbols_iter(source).nth(1).unwrap().name, "second");
+
+        // Parse errors yield no symbols
+        assert_eq!(plugin.symbols_iter("").count(), 0);
+    }
+
+    #[test]
+    fn test_find_by_manifest_matches_patterns() {
+        let mut python = MockPlugin::new();
+        python.metadata.name = "python-mock";
+        python.metadata.manifest_filename = "pyproject.toml";
+        python.metadata.manifest_patterns = &["requirements*.txt"];
+        assert!(python.handles_manifest("requirements-dev.txt"));
+        assert!(!python.handles_manifest("requirements.in"));
+
+        let mut registry = PluginDiscovery::new();
+        registry.register(Arc::new(python));
+
+        for filename in ["pyproject.toml", "requirements.txt", "requirements-dev.txt"] {
+            let plugin = registry.find_by_manifest(filename).unwrap();
+            assert_eq!(plugin.metadata().name, "python-mock", "{}", filename);
+        }
+        assert!(registry.find_by_manifest("requirements.in").is_none());
+
+        // Lazily registered plugins match patterns without being constructed
+        fn factory() -> Box<dyn LanguagePlugin> {
+            Box::new(MockPlugin::new())
+        }
+        let descriptor = PluginDescriptor {
+            name: "Mock",
+            extensions: &["mock"],
+            filenames: &[],
+            manifest_filename: "mock.toml",
+            manifest_patterns: &["*.mock.lock"],
+            capabilities: PluginCapabilities::none(),
+            factory,
+            lsp: None,
+            priority: 0,
+        };
+        registry.register_lazy(&descriptor);
+        assert_eq!(
+            registry
+                .find_by_manifest("deps.mock.lock")
+                .unwrap()
+                .metadata()
+                .name,
+            "Mock"
+        );
+    }
+
+    #[test]
+    fn test_find_by_manifest_prefers_exact_over_pattern() {
+        let mut catch_all = MockPlugin::new();
+        catch_all.metadata.name = "catch-all";
+        catch_all.metadata.manifest_filename = "catch-all.toml";
+        catch_all.metadata.manifest_patterns = &["*.toml"];
+
+        let mut cargo = MockPlugin::new();
+        cargo.metadata.name = "cargo";
+        cargo.metadata.manifest_filename = "Cargo.toml";
+
+        // The pattern plugin is registered first but the exact match wins
+        let mut registry = PluginDiscovery::new();
+        registry.register(Arc::new(catch_all));
+        registry.register(Arc::new(cargo));
+
+        assert_eq!(
+            registry
+                .find_by_manifest("Cargo.toml")
+                .unwrap()
+                .metadata()
+                .name,
+            "cargo"
+        );
+        assert_eq!(
+            registry
+                .find_by_manifest("Pipfile.toml")
+                .unwrap()
+                .metadata()
+                .name,
+            "catch-all"
+        );
+    }
+
     #[test]
     fn test_capability_discovery_pattern() {
         use crate::{ManifestUpdater, ModuleLocator};
@@ -956,10 +2198,13 @@ mod tests {
                 static METADATA: LanguageMetadata = LanguageMetadata {
                     name: "full-featured",
                     extensions: &["full"],
+                    filenames: &[],
                     manifest_filename: "manifest.toml",
+                    manifest_patterns: &[],
                     source_dir: "src",
                     entry_point: "lib.full",
                     module_separator: "::",
+                    lsp: None,
                 };
                 &METADATA
             }
@@ -1061,10 +2306,13 @@ mod tests {
                 static METADATA: LanguageMetadata = LanguageMetadata {
                     name: "minimal",
                     extensions: &["min"],
+                    filenames: &[],
                     manifest_filename: "manifest.toml",
+                    manifest_patterns: &[],
                     source_dir: "src",
                     entry_point: "lib.min",
                     module_separator: ".",
+                    lsp: None,
                 };
                 &METADATA
             }
@@ -1136,6 +2384,92 @@ mod tests {
             "Should return None when capability not present"
         );
     }
+
+    #[test]
+    fn test_dependencies_sort_by_name_then_source() {
+        let mut deps = [
+            Dependency::new("serde", DependencySource::Version("1.0".to_string())),
+            Dependency::new("anyhow", DependencySource::Version("1.0".to_string())),
+            Dependency::new("regex", DependencySource::Path("../regex".to_string())),
+            Dependency::new("regex", DependencySource::Version("1.10".to_string())),
+        ];
+        deps.sort();
+
+        let order: Vec<(&str, &DependencySource)> =
+            deps.iter().map(|d| (d.name.as_str(), &d.source)).collect();
+        assert_eq!(
+            order,
+            vec![
+                ("anyhow", &DependencySource::Version("1.0".to_string())),
+                ("regex", &DependencySource::Version("1.10".to_string())),
+                ("regex", &DependencySource::Path("../regex".to_string())),
+                ("serde", &DependencySource::Version("1.0".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dependencies_dedupe_in_btreeset() {
+        use std::collections::BTreeSet;
+
+        let serde = Dependency::new("serde", DependencySource::Version("1.0".to_string()));
+        let set: BTreeSet<Dependency> = [
+            serde.clone(),
+            Dependency::new("tokio", DependencySource::Version("1".to_string())),
+            serde.clone(),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.first(), Some(&serde));
+    }
+
+    #[test]
+    fn test_manifest_to_requirements() {
+        let mut requests = Dependency::new(
+            "requests",
+            DependencySource::Version("==2.31.0".to_string()),
+        );
+        requests.features = vec!["socks".to_string()];
+        let manifest = ManifestData {
+            name: "app".to_string(),
+            version: "0.1.0".to_string(),
+            dependencies: vec![
+                requests,
+                Dependency::new("httpx", DependencySource::Version(">=0.27".to_string())),
+                Dependency::new("serde", DependencySource::Version("1.0".to_string())),
+                Dependency::new("shared", DependencySource::Path("../shared".to_string())),
+                Dependency::new(
+                    "vendored",
+                    DependencySource::Path("/opt/my libs/vendored".to_string()),
+                ),
+                Dependency::new(
+                    "toolkit",
+                    DependencySource::Git {
+                        url: "https://github.com/example/toolkit".to_string(),
+                        rev: Some("v1.2".to_string()),
+                    },
+                ),
+            ],
+            dev_dependencies: vec![Dependency::new(
+                "pytest",
+                DependencySource::Version("=8.1.1".to_string()),
+            )],
+            raw_data: Value::Null,
+        };
+
+        assert_eq!(
+            manifest.to_requirements(false),
+            "requests[socks]==2.31.0\n\
+             httpx>=0.27  # unpinned\n\
+             serde  # unpinned: 1.0\n\
+             shared @ file:../shared\n\
+             vendored @ file:///opt/my%20libs/vendored\n\
+             toolkit @ git+https://github.com/example/toolkit@v1.2\n"
+        );
+        assert!(manifest.to_requirements(true).ends_with("pytest==8.1.1\n"));
+    }
 }
 
 // ============================================================================
@@ -1145,12 +2479,17 @@ mod tests {
 impl From<PluginApiError> for mill_foundation::errors::MillError {
     fn from(err: PluginApiError) -> Self {
         match err {
-            PluginApiError::Parse { message, location } => {
+            PluginApiError::Parse {
+                message,
+                location,
+                snippet,
+            } => {
                 let mut error = mill_foundation::errors::MillError::Parse {
                     message,
                     file: None,
                     line: None,
                     column: None,
+                    snippet,
                 };
 
                 // Add location information if present
@@ -1200,6 +2539,7 @@ impl From<mill_foundation::errors::MillError> for PluginApiError {
             MillError::Parse { message, .. } => PluginApiError::Parse {
                 message,
                 location: None,
+                snippet: None,
             },
             MillError::Manifest { message, .. } => PluginApiError::Manifest { message },
             MillError::NotSupported { operation, .. } => PluginApiError::NotSupported { operation },
@@ -1219,6 +2559,7 @@ impl From<mill_foundation::errors::MillError> for PluginApiError {
             MillError::Json { message, .. } => PluginApiError::Parse {
                 message,
                 location: None,
+                snippet: None,
             },
             MillError::Serialization { message, .. } => PluginApiError::Internal { message },
             MillError::PermissionDenied { operation, .. } => PluginApiError::Internal {
@@ -1231,6 +2572,7 @@ impl From<mill_foundation::errors::MillError> for PluginApiError {
             MillError::Ast { message, .. } => PluginApiError::Parse {
                 message,
                 location: None,
+                snippet: None,
             },
             MillError::UnsupportedSyntax { feature, .. } => {
                 PluginApiError::NotSupported { operation: feature }
@@ -1274,6 +2616,46 @@ mod error_conversion_tests {
         }
     }
 
+    #[test]
+    fn test_parse_error_snippet_points_at_column() {
+        let source = "fn ok() {}\n\nfn broken( {\n";
+        let err = PluginApiError::parse_with_snippet("expected `)`", 2, 11, source);
+
+        match &err {
+            PluginApiError::Parse {
+                location, snippet, ..
+            } => {
+                assert_eq!(
+                    location,
+                    &Some(SourceLocation {
+                        line: 2,
+                        column: 11
+                    })
+                );
+                assert_eq!(
+                    snippet.as_deref(),
+                    Some("3 | fn broken( {\n  |            ^")
+                );
+            }
+            _ => panic!("Expected Parse error"),
+        }
+
+        let mill_err: MillError = err.into();
+        assert!(matches!(
+            mill_err,
+            MillError::Parse {
+                snippet: Some(_),
+                ..
+            }
+        ));
+
+        let past_end = PluginApiError::parse_with_snippet("eof", 9, 0, source);
+        assert!(matches!(
+            past_end,
+            PluginApiError::Parse { snippet: None, .. }
+        ));
+    }
+
     #[test]
     fn test_parse_error_with_location_conversion() {
         let plugin_err = PluginApiError::parse_at("syntax error", 10, 5);
@@ -4,7 +4,7 @@
 //! Each plugin decides how to install its corresponding LSP server
 //! (direct download, package manager, etc.)
 
-use crate::PluginResult;
+use crate::{PluginApiError, PluginResult};
 use async_trait::async_trait;
 use std::path::{Path, PathBuf};
 
@@ -70,6 +70,36 @@ pub trait LspInstaller: Send + Sync {
     /// - `Err(...)` if check failed (permissions, etc.)
     fn check_installed(&self) -> PluginResult<Option<PathBuf>>;
 
+    /// Report the version of the installed LSP server
+    ///
+    /// Implementations typically run the binary with `--version` and parse
+    /// the output. The default implementation returns `Ok(None)`, meaning the
+    /// version is unknown (or the LSP is not installed).
+    fn installed_version(&self) -> PluginResult<Option<String>> {
+        Ok(None)
+    }
+
+    /// Tell the user how to install the LSP server by hand
+    ///
+    /// Shown by diagnostics such as `mill doctor` when `check_installed`
+    /// reports the server missing. The default implementation has no hint.
+    fn install_hint(&self) -> Option<String> {
+        None
+    }
+
+    /// Install a specific version of the LSP server
+    ///
+    /// What `version` means is up to the plugin: a package version for
+    /// npm/pip installs, a toolchain for rustup-managed rust-analyzer.
+    /// The default implementation reports the operation as not supported.
+    async fn install_version(&self, version: &str) -> PluginResult<()> {
+        Err(PluginApiError::not_supported(format!(
+            "Installing a specific {} version ({})",
+            self.lsp_name(),
+            version
+        )))
+    }
+
     /// Install the LSP server
     ///
     /// Implementations should:
@@ -0,0 +1,276 @@
+//! Dependency differences between two manifests
+//!
+//! Compares the dependency lists of two `ManifestData` values (for example a
+//! manifest before and after a pull request) and categorizes every change as
+//! added, removed, or changed. Dependency names are normalized before
+//! comparison so that spelling variants the package managers treat as equal
+//! (`serde_json` vs `serde-json`, `Django` vs `django`) are not reported as a
+//! removal plus an addition.
+//!
+//! The same normalization drives `ManifestData::conflicts`, which looks for
+//! inconsistencies within a single manifest.
+
+use crate::{Dependency, DependencySource, ManifestData};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+/// A dependency whose source (version, path, or git reference) changed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DependencyChange {
+    /// Dependency name as written in the new manifest
+    pub name: String,
+    /// Source in the old manifest
+    pub old_source: DependencySource,
+    /// Source in the new manifest
+    pub new_source: DependencySource,
+}
+
+/// Changes to one dependency list (normal or dev)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DependencyListDiff {
+    /// Dependencies only present in the new manifest
+    pub added: Vec<Dependency>,
+    /// Dependencies only present in the old manifest
+    pub removed: Vec<Dependency>,
+    /// Dependencies present in both with a different source
+    pub changed: Vec<DependencyChange>,
+}
+
+impl DependencyListDiff {
+    /// Whether the list is unchanged
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Dependency changes between two manifests
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ManifestDiff {
+    /// Changes to regular dependencies
+    pub dependencies: DependencyListDiff,
+    /// Changes to dev dependencies
+    pub dev_dependencies: DependencyListDiff,
+}
+
+impl ManifestDiff {
+    /// Whether neither dependency list changed
+    pub fn is_empty(&self) -> bool {
+        self.dependencies.is_empty() && self.dev_dependencies.is_empty()
+    }
+}
+
+/// A dependency declared more than once in a manifest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DependencyConflict {
+    /// Dependency name as first written in the manifest
+    pub name: String,
+    /// Every declared source, regular dependencies first
+    pub sources: Vec<DependencySource>,
+}
+
+impl ManifestData {
+    /// Find dependencies that are declared inconsistently
+    ///
+    /// Reports a name when it appears in both dependency lists with different
+    /// sources (for example a version in `dependencies` but a path in
+    /// `dev_dependencies`), or when it appears more than once in the same list.
+    /// Declaring the same source in both lists is not a conflict.
+    pub fn conflicts(&self) -> Vec<DependencyConflict> {
+        // normalized name -> (display name, sources, declared twice in one list)
+        let mut entries: BTreeMap<String, (String, Vec<DependencySource>, bool)> = BTreeMap::new();
+
+        for list in [&self.dependencies, &self.dev_dependencies] {
+            let mut seen_in_list = HashSet::new();
+            for dep in list {
+                let key = normalize_dependency_name(&dep.name);
+                let entry = entries
+                    .entry(key.clone())
+                    .or_insert_with(|| (dep.name.clone(), Vec::new(), false));
+                entry.1.push(dep.source.clone());
+                if !seen_in_list.insert(key) {
+                    entry.2 = true;
+                }
+            }
+        }
+
+        entries
+            .into_values()
+            .filter(|(_, sources, duplicated)| {
+                *duplicated || sources.iter().any(|source| source != &sources[0])
+            })
+            .map(|(name, sources, _)| DependencyConflict { name, sources })
+            .collect()
+    }
+}
+
+/// Compare the dependencies of two manifests
+pub fn diff_manifests(old: &ManifestData, new: &ManifestData) -> ManifestDiff {
+    ManifestDiff {
+        dependencies: diff_dependency_lists(&old.dependencies, &new.dependencies),
+        dev_dependencies: diff_dependency_lists(&old.dev_dependencies, &new.dev_dependencies),
+    }
+}
+
+/// Normalize a dependency name for comparison
+///
+/// Lowercases the name and treats `_`, `.`, and `-` as equivalent, which
+/// matches Cargo's and PEP 503's notion of name equality. npm names are
+/// already lowercase and unaffected in practice.
+pub fn normalize_dependency_name(name: &str) -> String {
+    name.trim()
+        .chars()
+        .map(|c| match c {
+            '_' | '.' => '-',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect()
+}
+
+fn diff_dependency_lists(old: &[Dependency], new: &[Dependency]) -> DependencyListDiff {
+    let index = |deps: &[Dependency]| -> BTreeMap<String, Dependency> {
+        deps.iter()
+            .map(|d| (normalize_dependency_name(&d.name), d.clone()))
+            .collect()
+    };
+    let old_by_name = index(old);
+    let new_by_name = index(new);
+
+    let mut diff = DependencyListDiff::default();
+
+    for (key, new_dep) in &new_by_name {
+        match old_by_name.get(key) {
+            None => diff.added.push(new_dep.clone()),
+            Some(old_dep) if old_dep.source != new_dep.source => {
+                diff.changed.push(DependencyChange {
+                    name: new_dep.name.clone(),
+                    old_source: old_dep.source.clone(),
+                    new_source: new_dep.source.clone(),
+                });
+            }
+            Some(_) => {}
+        }
+    }
+
+    diff.removed = old_by_name
+        .iter()
+        .filter(|(key, _)| !new_by_name.contains_key(*key))
+        .map(|(_, dep)| dep.clone())
+        .collect();
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dep(name: &str, version: &str) -> Dependency {
+        Dependency::new(name, DependencySource::Version(version.to_string()))
+    }
+
+    fn manifest(dependencies: Vec<Dependency>, dev_dependencies: Vec<Dependency>) -> ManifestData {
+        ManifestData {
+            name: "pkg".to_string(),
+            version: "0.1.0".to_string(),
+            dependencies,
+            dev_dependencies,
+            raw_data: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn test_diff_categorizes_added_removed_and_changed() {
+        let old = manifest(
+            vec![dep("serde", "1.0"), dep("regex", "1.9"), dep("toml", "0.8")],
+            vec![dep("tempfile", "3.0")],
+        );
+        let new = manifest(
+            vec![
+                dep("serde", "1.0"),
+                dep("regex", "1.10"),
+                dep("anyhow", "1.0"),
+            ],
+            vec![dep("tempfile", "3.0"), dep("proptest", "1.4")],
+        );
+
+        let diff = diff_manifests(&old, &new);
+
+        assert_eq!(diff.dependencies.added, vec![dep("anyhow", "1.0")]);
+        assert_eq!(diff.dependencies.removed, vec![dep("toml", "0.8")]);
+        assert_eq!(
+            diff.dependencies.changed,
+            vec![DependencyChange {
+                name: "regex".to_string(),
+                old_source: DependencySource::Version("1.9".to_string()),
+                new_source: DependencySource::Version("1.10".to_string()),
+            }]
+        );
+        assert_eq!(diff.dev_dependencies.added, vec![dep("proptest", "1.4")]);
+        assert!(diff.dev_dependencies.removed.is_empty());
+        assert!(diff.dev_dependencies.changed.is_empty());
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn test_diff_normalizes_names() {
+        let old = manifest(vec![dep("Django", "4.2"), dep("serde_json", "1.0")], vec![]);
+        let new = manifest(vec![dep("django", "4.2"), dep("serde-json", "1.0")], vec![]);
+
+        assert!(diff_manifests(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn test_diff_detects_source_kind_change() {
+        let old = manifest(vec![dep("utils", "1.0")], vec![]);
+        let new = manifest(
+            vec![Dependency::new(
+                "utils",
+                DependencySource::Path("../utils".to_string()),
+            )],
+            vec![],
+        );
+
+        let diff = diff_manifests(&old, &new);
+        assert_eq!(diff.dependencies.changed.len(), 1);
+        assert_eq!(
+            diff.dependencies.changed[0].new_source,
+            DependencySource::Path("../utils".to_string())
+        );
+    }
+
+    #[test]
+    fn test_conflicts_path_versus_version() {
+        let data = manifest(
+            vec![dep("serde", "1.0"), dep("utils", "1.0")],
+            vec![
+                dep("serde", "1.0"),
+                Dependency::new("utils", DependencySource::Path("../utils".to_string())),
+            ],
+        );
+
+        assert_eq!(
+            data.conflicts(),
+            vec![DependencyConflict {
+                name: "utils".to_string(),
+                sources: vec![
+                    DependencySource::Version("1.0".to_string()),
+                    DependencySource::Path("../utils".to_string()),
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_conflicts_duplicate_within_list() {
+        let data = manifest(vec![dep("regex", "1.9"), dep("Regex", "1.9")], vec![]);
+
+        let conflicts = data.conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].name, "regex");
+        assert_eq!(conflicts[0].sources.len(), 2);
+
+        assert!(manifest(vec![dep("regex", "1.9")], vec![])
+            .conflicts()
+            .is_empty());
+    }
+}
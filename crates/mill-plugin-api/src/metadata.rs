@@ -2,6 +2,8 @@
 //!
 //! Provides static metadata for language plugins.
 
+use crate::LspConfig;
+
 /// Static metadata about a programming language.
 ///
 /// This struct consolidates all language-specific constants in one place.
@@ -14,9 +16,19 @@ pub struct LanguageMetadata {
     /// File extensions this language handles (e.g., ["rs"], ["ts", "tsx"])
     pub extensions: &'static [&'static str],
 
+    /// Full file names this language handles regardless of extension
+    /// (e.g., ["Dockerfile", "Makefile"])
+    pub filenames: &'static [&'static str],
+
     /// Primary manifest filename (e.g., "Cargo.toml", "package.json")
     pub manifest_filename: &'static str,
 
+    /// Additional manifest filename globs (e.g., ["requirements*.txt"])
+    ///
+    /// `*` matches any run of characters and `?` a single character. An exact
+    /// `manifest_filename` match takes precedence over a pattern match.
+    pub manifest_patterns: &'static [&'static str],
+
     /// Default source directory (e.g., "src", "lib")
     pub source_dir: &'static str,
 
@@ -25,4 +37,70 @@ pub struct LanguageMetadata {
 
     /// Module path separator (e.g., "::" for Rust, "." for TypeScript)
     pub module_separator: &'static str,
+
+    /// Language server to start for this language, if any
+    pub lsp: Option<LspConfig>,
+}
+
+impl LanguageMetadata {
+    /// Whether `filename` matches one of the `manifest_patterns`
+    pub fn matches_manifest_pattern(&self, filename: &str) -> bool {
+        matches_any_glob(self.manifest_patterns, filename)
+    }
+}
+
+/// Whether `name` matches any of the `*`/`?` glob `patterns`
+pub(crate) fn matches_any_glob(patterns: &[&str], name: &str) -> bool {
+    patterns.iter().any(|pattern| glob_matches(pattern, name))
+}
+
+/// Match `name` against a glob where `*` is any run of characters and `?`
+/// is a single character
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Position after the last `*` and the name position it was tried at
+    let mut backtrack = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                p += 1;
+                backtrack = Some((p, n));
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` absorb one more character
+                Some((star_p, star_n)) => {
+                    p = star_p;
+                    n = star_n + 1;
+                    backtrack = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches("requirements*.txt", "requirements.txt"));
+        assert!(glob_matches("requirements*.txt", "requirements-dev.txt"));
+        assert!(glob_matches("*.lock", "poetry.lock"));
+        assert!(glob_matches("Cargo.?ock", "Cargo.lock"));
+        assert!(glob_matches("*", ""));
+
+        assert!(!glob_matches("requirements*.txt", "requirements.in"));
+        assert!(!glob_matches("requirements*.txt", "dev-requirements.txt"));
+        assert!(!glob_matches("Cargo.?ock", "Cargo.ock"));
+    }
 }
@@ -10,10 +10,17 @@ pub use inventory;
 pub struct PluginDescriptor {
     pub name: &'static str,
     pub extensions: &'static [&'static str],
+    /// Full file names handled regardless of extension, see `LanguageMetadata::filenames`
+    pub filenames: &'static [&'static str],
     pub manifest_filename: &'static str,
+    /// Additional manifest filename globs, see `LanguageMetadata::manifest_patterns`
+    pub manifest_patterns: &'static [&'static str],
     pub capabilities: PluginCapabilities,
     pub factory: fn() -> Box<dyn LanguagePlugin>,
     pub lsp: Option<LspConfig>,
+    /// Precedence when several plugins handle the same extension; the highest
+    /// priority wins and ties go to the plugin registered first
+    pub priority: i32,
 }
 
 // Collect all plugin descriptors into a static collection.
@@ -33,20 +40,48 @@ macro_rules! mill_plugin {
     (
         name: $name:expr,
         extensions: $extensions:expr,
+        $(filenames: [$($filename:expr),* $(,)?],)?
         manifest: $manifest:expr,
+        $(manifest_patterns: [$($pattern:expr),* $(,)?],)?
         capabilities: $capabilities:expr,
         factory: $factory:expr,
-        lsp: $lsp:expr
+        lsp: $lsp:expr,
+        priority: $priority:expr
     ) => {
         $crate::plugin_registry::inventory::submit! {
             $crate::plugin_registry::PluginDescriptor {
                 name: $name,
                 extensions: &$extensions,
+                filenames: &[$($($filename),*)?],
                 manifest_filename: $manifest,
+                manifest_patterns: &[$($($pattern),*)?],
                 capabilities: $capabilities,
                 factory: $factory,
                 lsp: $lsp,
+                priority: $priority,
             }
         }
     };
+    (
+        name: $name:expr,
+        extensions: $extensions:expr,
+        $(filenames: [$($filename:expr),* $(,)?],)?
+        manifest: $manifest:expr,
+        $(manifest_patterns: [$($pattern:expr),* $(,)?],)?
+        capabilities: $capabilities:expr,
+        factory: $factory:expr,
+        lsp: $lsp:expr
+    ) => {
+        $crate::mill_plugin! {
+            name: $name,
+            extensions: $extensions,
+            filenames: [$($($filename),*)?],
+            manifest: $manifest,
+            manifest_patterns: [$($($pattern),*)?],
+            capabilities: $capabilities,
+            factory: $factory,
+            lsp: $lsp,
+            priority: 0
+        }
+    };
 }
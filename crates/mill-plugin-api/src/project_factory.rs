@@ -50,6 +50,9 @@ pub enum PackageType {
     Library,
     /// Binary/executable package
     Binary,
+    /// Library plus an executable that depends on it (e.g., `src/lib.rs` and
+    /// `src/main.rs` in one Rust crate)
+    LibAndBin,
 }
 
 /// Template for package scaffolding
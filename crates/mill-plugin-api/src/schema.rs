@@ -0,0 +1,83 @@
+//! JSON Schemas for the plugin wire types
+//!
+//! Clients written in other languages can use these schemas to validate or
+//! generate bindings for the `ParsedSource`, `Symbol`, and `ManifestData`
+//! payloads in MCP responses. The schemas follow the serde encoding of each
+//! type (for example, `DependencySource` is externally tagged).
+
+use crate::{Dependency, DependencySource, ManifestData, ParsedSource, Symbol, SymbolKind};
+use serde_json::{json, Value};
+
+/// Schemas of the wire types, keyed by type name
+pub fn emit_schemas() -> Value {
+    json!({
+        "ParsedSource": schemars::schema_for!(ParsedSource),
+        "Symbol": schemars::schema_for!(Symbol),
+        "SymbolKind": schemars::schema_for!(SymbolKind),
+        "ManifestData": schemars::schema_for!(ManifestData),
+        "Dependency": schemars::schema_for!(Dependency),
+        "DependencySource": schemars::schema_for!(DependencySource),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbol_kind_schema_lists_all_variants() {
+        let schemas = emit_schemas();
+        let variants: Vec<&str> = schemas["SymbolKind"]["enum"]
+            .as_array()
+            .expect("SymbolKind is a string enum")
+            .iter()
+            .map(|variant| variant.as_str().unwrap())
+            .collect();
+
+        let all = [
+            SymbolKind::Function,
+            SymbolKind::Class,
+            SymbolKind::Struct,
+            SymbolKind::Enum,
+            SymbolKind::Interface,
+            SymbolKind::Variable,
+            SymbolKind::Constant,
+            SymbolKind::Module,
+            SymbolKind::Method,
+            SymbolKind::Field,
+            SymbolKind::Other,
+        ];
+        let encoded: Vec<Value> = all
+            .iter()
+            .map(|kind| serde_json::to_value(kind).unwrap())
+            .collect();
+        assert_eq!(variants.len(), all.len());
+        for kind in &encoded {
+            assert!(
+                variants.contains(&kind.as_str().unwrap()),
+                "{} missing",
+                kind
+            );
+        }
+    }
+
+    #[test]
+    fn test_schemas_round_trip() {
+        let schemas = emit_schemas();
+        let written = serde_json::to_string_pretty(&schemas).unwrap();
+        let read: Value = serde_json::from_str(&written).unwrap();
+        assert_eq!(read, schemas);
+
+        // Externally tagged, matching serde's encoding of the enum
+        let git = serde_json::to_value(DependencySource::Git {
+            url: "https://example.com/repo.git".to_string(),
+            rev: None,
+        })
+        .unwrap();
+        assert!(git.get("Git").is_some());
+        let source_schema = schemas["DependencySource"].to_string();
+        assert!(source_schema.contains("\"Git\""));
+        assert!(source_schema.contains("\"Version\""));
+        assert!(source_schema.contains("\"Path\""));
+    }
+}
@@ -197,6 +197,91 @@ pub trait WorkspaceSupport: Send + Sync {
         Err("generate_workspace_manifest not supported by this language".to_string())
     }
 
+    /// Parse the manifest of every package in a workspace
+    ///
+    /// Members are discovered from the workspace manifest at `root` (member
+    /// glob patterns such as `crates/*` are expanded) and each member's
+    /// manifest is parsed.
+    ///
+    /// # Arguments
+    /// * `root` - Workspace root directory
+    ///
+    /// # Returns
+    /// `(manifest_path, manifest)` pairs, sorted by path
+    ///
+    /// # Default Implementation
+    /// Returns an empty list. Languages with workspace support should override.
+    async fn analyze_workspace_manifests(
+        &self,
+        _root: &Path,
+    ) -> crate::PluginResult<Vec<(std::path::PathBuf, crate::ManifestData)>> {
+        Ok(Vec::new())
+    }
+
+    /// List the workspace members that depend on a package
+    ///
+    /// A member is a dependent when `package_name` appears among its runtime
+    /// or dev dependencies, whether as a path, version or git dependency.
+    /// Useful for impact analysis before renaming or moving a package.
+    ///
+    /// # Arguments
+    /// * `root` - Workspace root directory
+    /// * `package_name` - Name of the package whose dependents to find
+    ///
+    /// # Returns
+    /// Names of the dependent members, sorted
+    ///
+    /// # Default Implementation
+    /// Scans the manifests returned by `analyze_workspace_manifests`.
+    async fn dependents_of(
+        &self,
+        root: &Path,
+        package_name: &str,
+    ) -> crate::PluginResult<Vec<String>> {
+        let mut dependents: Vec<String> = self
+            .analyze_workspace_manifests(root)
+            .await?
+            .into_iter()
+            .map(|(_, manifest)| manifest)
+            .filter(|manifest| manifest.name != package_name)
+            .filter(|manifest| {
+                manifest
+                    .dependencies
+                    .iter()
+                    .chain(&manifest.dev_dependencies)
+                    .any(|dependency| dependency.name == package_name)
+            })
+            .map(|manifest| manifest.name)
+            .collect();
+        dependents.sort();
+        dependents.dedup();
+        Ok(dependents)
+    }
+
+    /// Plan the edits for moving a single source file
+    ///
+    /// Unlike `rewrite_file_references`, which rewrites one file's content,
+    /// this locates every file under `root` that imports the moved module and
+    /// returns the edits for all of them together with the edits to the moved
+    /// file's own imports. Edits are positioned against the files as they are
+    /// before the move; the moved file's edits are keyed by `old_path`.
+    ///
+    /// # Arguments
+    /// * `root` - Project root directory
+    /// * `old_path` - Current path of the file
+    /// * `new_path` - Path the file is moved to
+    ///
+    /// # Default Implementation
+    /// Returns NotSupported error. Languages with module-based imports should override.
+    async fn plan_move_file(
+        &self,
+        _root: &Path,
+        _old_path: &Path,
+        _new_path: &Path,
+    ) -> crate::PluginResult<crate::WorkspaceEdit> {
+        Err(crate::PluginApiError::not_supported("plan_move_file"))
+    }
+
     // ========================================================================
     // Consolidation Post-Processing (Language-specific file structure fixes)
     // ========================================================================
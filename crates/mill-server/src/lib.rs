@@ -57,11 +57,16 @@ impl std::fmt::Debug for ServerOptions {
     }
 }
 
+/// How long `ServerHandle::shutdown` waits for the operation worker to drain
+const WORKER_SHUTDOWN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
 /// Handle to a running server
 pub struct ServerHandle {
     shutdown_tx: oneshot::Sender<()>,
     _config: AppConfig,
     _dispatcher: Arc<PluginDispatcher>,
+    worker_shutdown: tokio::sync::watch::Sender<bool>,
+    worker: tokio::task::JoinHandle<()>,
 }
 
 /// Bootstrap the server with given options
@@ -77,7 +82,7 @@ pub async fn bootstrap(options: ServerOptions) -> ServerResult<ServerHandle> {
     let project_root = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
 
     // Use the app_state_factory to create services and app_state
-    use mill_services::services::app_state_factory::create_services_bundle;
+    use mill_services::services::app_state_factory::create_services_bundle_without_worker;
     #[cfg(feature = "mcp-proxy")]
     use mill_services::services::app_state_factory::register_mcp_proxy_if_enabled;
 
@@ -99,7 +104,7 @@ pub async fn bootstrap(options: ServerOptions) -> ServerResult<ServerHandle> {
         mill_services::services::registry_builder::build_language_plugin_registry(vec![])
     });
 
-    let services = create_services_bundle(
+    let services = create_services_bundle_without_worker(
         &project_root,
         cache_settings,
         plugin_manager.clone(),
@@ -110,6 +115,15 @@ pub async fn bootstrap(options: ServerOptions) -> ServerResult<ServerHandle> {
 
     let workspace_manager = Arc::new(mill_workspaces::WorkspaceManager::new());
 
+    // Start the only worker draining the operation queue, stopped by `shutdown`
+    let (worker_shutdown, worker_shutdown_rx) = tokio::sync::watch::channel(false);
+    let worker = spawn_operation_worker_with_shutdown(
+        services.operation_queue.clone(),
+        project_root.clone(),
+        plugin_registry.clone(),
+        worker_shutdown_rx,
+    );
+
     // Create application state
     let app_state = Arc::new(AppState {
         ast_service: services.ast_service,
@@ -137,6 +151,8 @@ pub async fn bootstrap(options: ServerOptions) -> ServerResult<ServerHandle> {
         shutdown_tx,
         _config: options.config,
         _dispatcher: dispatcher,
+        worker_shutdown,
+        worker,
     })
 }
 
@@ -193,7 +209,7 @@ pub async fn create_dispatcher_with_workspace(
     let project_root = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
 
     // Use the app_state_factory to create services
-    use mill_services::services::app_state_factory::create_services_bundle;
+    use mill_services::services::app_state_factory::create_services_bundle_without_worker;
     #[cfg(feature = "mcp-proxy")]
     use mill_services::services::app_state_factory::register_mcp_proxy_if_enabled;
 
@@ -209,7 +225,7 @@ pub async fn create_dispatcher_with_workspace(
     #[cfg(feature = "mcp-proxy")]
     register_mcp_proxy_if_enabled(&plugin_manager, config.external_mcp.as_ref()).await?;
 
-    let services = create_services_bundle(
+    let services = create_services_bundle_without_worker(
         &project_root,
         cache_settings,
         plugin_manager.clone(),
@@ -218,8 +234,12 @@ pub async fn create_dispatcher_with_workspace(
     )
     .await;
 
-    // Start background processor for operation queue
-    spawn_operation_worker(services.operation_queue.clone(), project_root.clone());
+    // Start the only worker draining the operation queue
+    spawn_operation_worker(
+        services.operation_queue.clone(),
+        project_root.clone(),
+        plugin_registry.clone(),
+    );
 
     // Create application state
     let app_state = Arc::new(AppState {
@@ -270,6 +290,17 @@ impl ServerHandle {
             tracing::warn!("Server already shut down");
         }
 
+        // Stop the operation worker and let it drain queued operations
+        let _ = self.worker_shutdown.send(true);
+        match tokio::time::timeout(WORKER_SHUTDOWN_TIMEOUT, self.worker).await {
+            Ok(Ok(())) => tracing::debug!("Operation worker drained"),
+            Ok(Err(e)) => tracing::warn!(error = %e, "Operation worker failed during shutdown"),
+            Err(_) => tracing::warn!(
+                timeout = ?WORKER_SHUTDOWN_TIMEOUT,
+                "Timed out waiting for operation worker to drain"
+            ),
+        }
+
         // In a real implementation, this would:
         // 1. Stop accepting new connections
         // 2. Finish processing existing requests
@@ -282,6 +313,19 @@ impl ServerHandle {
 }
 
 /// Convert path to absolute and verify it's within project root
+///
+/// The returned path is the fully resolved location that a read or write
+/// through `path` would touch, and it is guaranteed to lie inside the
+/// canonical project root:
+///
+/// - The existing portion of the path is canonicalized, so symlinked
+///   directories (e.g. `project/link -> /tmp`) resolve to their real target.
+/// - Each component past the existing portion is re-checked with
+///   `symlink_metadata`. A dangling symlink there would be followed when the
+///   file is created, so its target is resolved the same way and must also
+///   stay inside the root.
+/// - `..` in a part of the path that does not exist yet (including inside a
+///   dangling symlink's target) is rejected rather than resolved lexically.
 async fn validate_path(
     project_root: &std::path::Path,
     path: &std::path::Path,
@@ -302,22 +346,47 @@ async fn validate_path(
         project_root.join(path)
     };
 
-    // Try to canonicalize the full path if it exists
-    // We use fs::metadata as a way to check existence async
-    let canonical = if fs::metadata(&abs_path).await.is_ok() {
-        fs::canonicalize(&abs_path).await.map_err(|e| {
-            ServerError::invalid_request(format!(
-                "Path canonicalization failed for {:?}: {}",
-                abs_path, e
-            ))
-        })?
-    } else {
+    let canonical = resolve_path(abs_path).await?;
+
+    // Verify containment within project root
+    if !canonical.starts_with(&canonical_root) {
+        return Err(ServerError::permission_denied(format!(
+            "Path traversal detected: {:?} escapes project root {:?}",
+            path, project_root
+        )));
+    }
+
+    Ok(canonical)
+}
+
+/// Maximum number of dangling symlinks followed while resolving one path
+const MAX_SYMLINK_HOPS: usize = 40;
+
+/// Resolve an absolute path that may not exist yet, following symlinks
+///
+/// Existing paths are simply canonicalized. Otherwise the first existing
+/// ancestor is canonicalized and the remaining components are re-added one at
+/// a time; if one of them is a (dangling) symlink, resolution restarts from
+/// its target.
+async fn resolve_path(mut abs_path: PathBuf) -> ServerResult<PathBuf> {
+    use tokio::fs;
+
+    for _ in 0..MAX_SYMLINK_HOPS {
+        // We use fs::metadata as a way to check existence async
+        if fs::metadata(&abs_path).await.is_ok() {
+            return fs::canonicalize(&abs_path).await.map_err(|e| {
+                ServerError::invalid_request(format!(
+                    "Path canonicalization failed for {:?}: {}",
+                    abs_path, e
+                ))
+            });
+        }
+
         // Path doesn't exist - find first existing ancestor and build from there
         let mut current = abs_path.clone();
         let mut components_to_add = Vec::new();
 
         // Walk up until we find an existing directory
-        // Loop bound: preventing infinite loop if root is missing (though unlikely if project_root exists)
         loop {
             if fs::metadata(&current).await.is_ok() {
                 break;
@@ -341,6 +410,7 @@ async fn validate_path(
                 )));
             }
         }
+        components_to_add.reverse();
 
         // Canonicalize the existing ancestor
         let mut canonical = fs::canonicalize(&current).await.map_err(|e| {
@@ -350,30 +420,74 @@ async fn validate_path(
             ))
         })?;
 
-        // Add back the non-existing components
-        for component in components_to_add.iter().rev() {
-            canonical = canonical.join(component);
+        // Add back the non-existing components, stopping at a dangling symlink
+        let mut redirect = None;
+        for (index, component) in components_to_add.iter().enumerate() {
+            canonical.push(component);
+
+            let is_symlink = fs::symlink_metadata(&canonical)
+                .await
+                .map(|meta| meta.file_type().is_symlink())
+                .unwrap_or(false);
+            if is_symlink {
+                let target = fs::read_link(&canonical).await.map_err(|e| {
+                    ServerError::invalid_request(format!(
+                        "Failed to read symlink {:?}: {}",
+                        canonical, e
+                    ))
+                })?;
+                let mut next = canonical
+                    .parent()
+                    .map(|parent| parent.join(&target))
+                    .unwrap_or(target);
+                next.extend(&components_to_add[index + 1..]);
+                redirect = Some(next);
+                break;
+            }
         }
 
-        canonical
-    };
-
-    // Verify containment within project root
-    if !canonical.starts_with(&canonical_root) {
-        return Err(ServerError::permission_denied(format!(
-            "Path traversal detected: {:?} escapes project root {:?}",
-            path, project_root
-        )));
+        match redirect {
+            Some(next) => abs_path = next,
+            None => return Ok(canonical),
+        }
     }
 
-    Ok(canonical)
+    Err(ServerError::invalid_request(format!(
+        "Too many levels of symbolic links in {:?}",
+        abs_path
+    )))
 }
 
 /// Spawn a worker to process file operations in the background
+///
+/// Rename operations also rewrite references inside the moved file using the
+/// language plugin registered for its extension. When the operation carries a
+/// `rename_info` parameter, importers elsewhere in the project are rewritten
+/// too, via follow-up `Write` operations queued behind the rename.
+///
+/// The worker runs for the lifetime of the process; use
+/// [`spawn_operation_worker_with_shutdown`] to be able to stop it.
 pub fn spawn_operation_worker(
     queue: Arc<mill_services::services::OperationQueue>,
     project_root: PathBuf,
-) {
+    plugins: Arc<mill_plugin_api::PluginDiscovery>,
+) -> tokio::task::JoinHandle<()> {
+    // The sender is dropped right away, so the worker never shuts down
+    let (_never, shutdown) = tokio::sync::watch::channel(false);
+    spawn_operation_worker_with_shutdown(queue, project_root, plugins, shutdown)
+}
+
+/// Spawn an operation worker that stops when `shutdown` becomes `true`
+///
+/// On shutdown the queue is closed to new operations and everything already
+/// pending is executed before the returned task completes.
+pub fn spawn_operation_worker_with_shutdown(
+    queue: Arc<mill_services::services::OperationQueue>,
+    project_root: PathBuf,
+    plugins: Arc<mill_plugin_api::PluginDiscovery>,
+    shutdown: tokio::sync::watch::Receiver<bool>,
+) -> tokio::task::JoinHandle<()> {
+    let follow_up_queue = queue.clone();
     tokio::spawn(async move {
         use mill_services::services::OperationType;
         use serde_json::Value;
@@ -382,122 +496,300 @@ pub fn spawn_operation_worker(
         use tokio::io::AsyncWriteExt;
 
         queue
-            .process_with(move |op, stats| {
-                let project_root = project_root.clone();
-                async move {
-                    tracing::debug!(
-                        operation_id = %op.id,
-                        operation_type = ?op.operation_type,
-                        file_path = %op.file_path.display(),
-                        "Executing queued operation"
-                    );
-
-                    // Security check: Validate path before any operation
-                    let valid_path = match validate_path(&project_root, &op.file_path).await {
-                        Ok(p) => p,
-                        Err(e) => {
-                            let mut stats_guard = stats.lock().await;
-                            stats_guard.failed_operations += 1;
-                            tracing::error!(
-                                operation_id = %op.id,
-                                error = %e,
-                                "Security check failed: Path traversal prevented"
-                            );
-                            return Err(e);
-                        }
-                    };
-
-                    // Use valid_path instead of op.file_path for subsequent operations
-                    let result = match op.operation_type {
-                        OperationType::CreateDir => {
-                            fs::create_dir_all(&valid_path).await.map_err(|e| {
-                                ServerError::internal(format!("Failed to create directory: {}", e))
-                            })?;
-                            Ok(Value::Null)
-                        }
-                        OperationType::CreateFile | OperationType::Write => {
-                            let content = op
-                                .params
-                                .get("content")
-                                .and_then(|v| v.as_str())
-                                .unwrap_or("");
-
-                            // Write and explicitly sync to disk to avoid caching issues
-                            let mut file = fs::File::create(&valid_path).await.map_err(|e| {
-                                ServerError::internal(format!("Failed to create file: {}", e))
-                            })?;
-
-                            file.write_all(content.as_bytes()).await.map_err(|e| {
-                                ServerError::internal(format!("Failed to write content: {}", e))
-                            })?;
-
-                            // CRITICAL: Sync file to disk BEFORE updating stats
-                            file.sync_all().await.map_err(|e| {
-                                ServerError::internal(format!("Failed to sync file: {}", e))
-                            })?;
-
-                            Ok(Value::Null)
-                        }
-                        OperationType::Delete => {
-                            if valid_path.exists() {
-                                fs::remove_file(&valid_path).await.map_err(|e| {
-                                    ServerError::internal(format!("Failed to delete file: {}", e))
+            .process_until_shutdown(
+                move |op, stats| {
+                    let project_root = project_root.clone();
+                    let plugins = plugins.clone();
+                    let follow_up_queue = follow_up_queue.clone();
+                    async move {
+                        tracing::debug!(
+                            operation_id = %op.id,
+                            operation_type = ?op.operation_type,
+                            file_path = %op.file_path.display(),
+                            "Executing queued operation"
+                        );
+
+                        // Security check: Validate path before any operation
+                        let valid_path = match validate_path(&project_root, &op.file_path).await {
+                            Ok(p) => p,
+                            Err(e) => {
+                                let mut stats_guard = stats.lock().await;
+                                stats_guard.failed_operations += 1;
+                                tracing::error!(
+                                    operation_id = %op.id,
+                                    error = %e,
+                                    "Security check failed: Path traversal prevented"
+                                );
+                                return Err(e);
+                            }
+                        };
+
+                        // Use valid_path instead of op.file_path for subsequent operations
+                        let result = match op.operation_type {
+                            OperationType::CreateDir => {
+                                fs::create_dir_all(&valid_path).await.map_err(|e| {
+                                    ServerError::internal(format!(
+                                        "Failed to create directory: {}",
+                                        e
+                                    ))
                                 })?;
+                                Ok(Value::Null)
+                            }
+                            OperationType::CreateFile | OperationType::Write => {
+                                let content = op
+                                    .params
+                                    .get("content")
+                                    .and_then(|v| v.as_str())
+                                    .unwrap_or("");
+
+                                // Write and explicitly sync to disk to avoid caching issues
+                                let mut file =
+                                    fs::File::create(&valid_path).await.map_err(|e| {
+                                        ServerError::internal(format!(
+                                            "Failed to create file: {}",
+                                            e
+                                        ))
+                                    })?;
+
+                                file.write_all(content.as_bytes()).await.map_err(|e| {
+                                    ServerError::internal(format!("Failed to write content: {}", e))
+                                })?;
+
+                                // CRITICAL: Sync file to disk BEFORE updating stats
+                                file.sync_all().await.map_err(|e| {
+                                    ServerError::internal(format!("Failed to sync file: {}", e))
+                                })?;
+
+                                Ok(Value::Null)
+                            }
+                            OperationType::Delete => {
+                                if valid_path.exists() {
+                                    fs::remove_file(&valid_path).await.map_err(|e| {
+                                        ServerError::internal(format!(
+                                            "Failed to delete file: {}",
+                                            e
+                                        ))
+                                    })?;
+                                }
+                                Ok(Value::Null)
+                            }
+                            OperationType::Rename => {
+                                let new_path_str = op
+                                    .params
+                                    .get("new_path")
+                                    .and_then(|v| v.as_str())
+                                    .ok_or_else(|| {
+                                        ServerError::internal(
+                                            "Missing 'new_path' parameter for Rename",
+                                        )
+                                    })?;
+                                let new_path = Path::new(new_path_str);
+
+                                // Also validate new_path
+                                let valid_new_path = validate_path(&project_root, new_path).await?;
+
+                                fs::rename(&valid_path, &valid_new_path)
+                                    .await
+                                    .map_err(|e| {
+                                        ServerError::internal(format!(
+                                            "Failed to rename file: {}",
+                                            e
+                                        ))
+                                    })?;
+
+                                rewrite_references_after_rename(
+                                    &plugins,
+                                    &follow_up_queue,
+                                    &project_root,
+                                    &op,
+                                    &valid_path,
+                                    &valid_new_path,
+                                )
+                                .await?;
+                                Ok(Value::Null)
+                            }
+                            OperationType::Copy => {
+                                copy_file(&project_root, &valid_path, &op.params).await
+                            }
+                            OperationType::Read
+                            | OperationType::Format
+                            | OperationType::Refactor => {
+                                tracing::trace!(
+                                    operation_type = ?op.operation_type,
+                                    path = %valid_path.display(),
+                                    "Operation queued"
+                                );
+                                Ok(Value::Null)
+                            }
+                            _ => Err(ServerError::internal(format!(
+                                "Unsupported operation type in worker: {:?}",
+                                op.operation_type
+                            ))),
+                        };
+
+                        // Update stats AFTER all I/O is complete (including sync_all)
+                        let mut stats_guard = stats.lock().await;
+                        match &result {
+                            Ok(_) => {
+                                stats_guard.completed_operations += 1;
+                                tracing::info!(
+                                    operation_id = %op.id,
+                                    operation_type = ?op.operation_type,
+                                    completed = stats_guard.completed_operations,
+                                    "Operation executed successfully"
+                                );
+                            }
+                            Err(e) => {
+                                stats_guard.failed_operations += 1;
+                                tracing::error!(
+                                    operation_id = %op.id,
+                                    operation_type = ?op.operation_type,
+                                    error = %e,
+                                    failed = stats_guard.failed_operations,
+                                    "Operation execution failed"
+                                );
                             }
-                            Ok(Value::Null)
-                        }
-                        OperationType::Rename => {
-                            let new_path_str = op
-                                .params
-                                .get("new_path")
-                                .and_then(|v| v.as_str())
-                                .ok_or_else(|| {
-                                ServerError::internal("Missing 'new_path' parameter for Rename")
-                            })?;
-                            let new_path = Path::new(new_path_str);
-
-                            // Also validate new_path
-                            let valid_new_path = validate_path(&project_root, new_path).await?;
-
-                            fs::rename(&valid_path, valid_new_path).await.map_err(|e| {
-                                ServerError::internal(format!("Failed to rename file: {}", e))
-                            })?;
-                            Ok(Value::Null)
-                        }
-                        _ => Err(ServerError::internal(format!(
-                            "Unsupported operation type in worker: {:?}",
-                            op.operation_type
-                        ))),
-                    };
-
-                    // Update stats AFTER all I/O is complete (including sync_all)
-                    let mut stats_guard = stats.lock().await;
-                    match &result {
-                        Ok(_) => {
-                            stats_guard.completed_operations += 1;
-                            tracing::info!(
-                                operation_id = %op.id,
-                                operation_type = ?op.operation_type,
-                                completed = stats_guard.completed_operations,
-                                "Operation executed successfully"
-                            );
-                        }
-                        Err(e) => {
-                            stats_guard.failed_operations += 1;
-                            tracing::error!(
-                                operation_id = %op.id,
-                                operation_type = ?op.operation_type,
-                                error = %e,
-                                failed = stats_guard.failed_operations,
-                                "Operation execution failed"
-                            );
                         }
-                    }
-                    drop(stats_guard); // Explicitly release lock
+                        drop(stats_guard); // Explicitly release lock
 
-                    result
-                }
-            })
+                        result
+                    }
+                },
+                shutdown,
+            )
             .await;
-    });
+    })
+}
+
+/// Copy a file for a queued `Copy` operation
+///
+/// The destination comes from the `new_path` parameter and is validated
+/// against the project root like the source. An existing destination is only
+/// replaced when the `overwrite` parameter is `true`.
+async fn copy_file(
+    project_root: &std::path::Path,
+    source: &std::path::Path,
+    params: &serde_json::Value,
+) -> ServerResult<serde_json::Value> {
+    let new_path = params
+        .get("new_path")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| ServerError::invalid_request("Missing 'new_path' parameter for Copy"))?;
+    let destination = validate_path(project_root, std::path::Path::new(new_path)).await?;
+    let overwrite = params
+        .get("overwrite")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    mill_services::services::app_state_factory::copy_file(source, &destination, overwrite).await?;
+    Ok(serde_json::Value::Null)
+}
+
+/// Rewrite references affected by a file move performed by the worker
+///
+/// The moved file itself is rewritten in place. Importers are only scanned
+/// when the operation provides `rename_info`, and their updates are queued as
+/// `Write` operations so they go through the same validation as any other write.
+/// Once the queue is closed for shutdown the move still succeeds, but the
+/// remaining importers are left unchanged.
+async fn rewrite_references_after_rename(
+    plugins: &mill_plugin_api::PluginDiscovery,
+    queue: &mill_services::services::OperationQueue,
+    project_root: &std::path::Path,
+    op: &mill_services::services::FileOperation,
+    old_path: &std::path::Path,
+    new_path: &std::path::Path,
+) -> ServerResult<()> {
+    use mill_plugin_api::{FileDiscovery, StandardFileDiscovery};
+    use mill_services::services::{FileOperation, OperationType};
+    use tokio::fs;
+
+    let Some(plugin) = new_path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(|ext| plugins.find_by_extension(ext))
+    else {
+        return Ok(());
+    };
+
+    let canonical_root = fs::canonicalize(project_root)
+        .await
+        .unwrap_or_else(|_| project_root.to_path_buf());
+    let rename_info = op.params.get("rename_info");
+
+    let content = fs::read_to_string(new_path)
+        .await
+        .map_err(|e| ServerError::internal(format!("Failed to read moved file: {}", e)))?;
+    if let Some((updated, changes)) = plugin.rewrite_file_references(
+        &content,
+        old_path,
+        new_path,
+        new_path,
+        &canonical_root,
+        rename_info,
+    ) {
+        if changes > 0 && updated != content {
+            fs::write(new_path, updated)
+                .await
+                .map_err(|e| ServerError::internal(format!("Failed to write moved file: {}", e)))?;
+            tracing::debug!(
+                file_path = %new_path.display(),
+                changes,
+                "Rewrote references in moved file"
+            );
+        }
+    }
+
+    if rename_info.is_none() {
+        return Ok(());
+    }
+
+    let importers = StandardFileDiscovery::new(plugin.metadata().extensions)
+        .find_source_files(&canonical_root)
+        .await
+        .map_err(|e| ServerError::internal(format!("Failed to discover importers: {}", e)))?;
+
+    for importer in importers.into_iter().filter(|path| path != new_path) {
+        let Ok(content) = fs::read_to_string(&importer).await else {
+            continue;
+        };
+        let Some((updated, changes)) = plugin.rewrite_file_references(
+            &content,
+            old_path,
+            new_path,
+            &importer,
+            &canonical_root,
+            rename_info,
+        ) else {
+            continue;
+        };
+        if changes == 0 || updated == content {
+            continue;
+        }
+
+        tracing::debug!(
+            file_path = %importer.display(),
+            changes,
+            "Queueing importer rewrite after rename"
+        );
+        let rewrite = FileOperation::new(
+            op.tool_name.clone(),
+            OperationType::Write,
+            importer,
+            serde_json::json!({ "content": updated }),
+        );
+        if let Err(e) = queue.enqueue(rewrite).await {
+            if queue.is_closed() {
+                tracing::warn!(
+                    operation_id = %op.id,
+                    file_path = %new_path.display(),
+                    "Operation queue closed, importer references not rewritten after rename"
+                );
+                return Ok(());
+            }
+            return Err(e);
+        }
+    }
+
+    Ok(())
 }
@@ -107,6 +107,28 @@ fn spawn_test_worker(queue: Arc<OperationQueue>) {
                             ))
                         })
                     }
+                    OperationType::Copy => {
+                        let new_path_str = op
+                            .params
+                            .get("new_path")
+                            .and_then(|v| v.as_str())
+                            .ok_or_else(|| {
+                                mill_foundation::errors::MillError::invalid_request(
+                                    "Copy operation missing new_path".to_string(),
+                                )
+                            })?;
+                        fs::copy(&op.file_path, new_path_str)
+                            .await
+                            .map(|_| ())
+                            .map_err(|e| {
+                                mill_foundation::errors::MillError::internal(format!(
+                                    "Failed to copy file {} to {}: {}",
+                                    op.file_path.display(),
+                                    new_path_str,
+                                    e
+                                ))
+                            })
+                    }
                     OperationType::Read
                     | OperationType::Format
                     | OperationType::Refactor
@@ -1,4 +1,8 @@
-use crate::spawn_operation_worker;
+// Tests report setup failures through `expect`
+#![allow(clippy::expect_used)]
+
+use crate::{spawn_operation_worker, spawn_operation_worker_with_shutdown};
+use mill_plugin_api::PluginDiscovery;
 use mill_services::services::coordination::lock_manager::LockManager;
 use mill_services::services::coordination::operation_queue::{
     FileOperation, OperationQueue, OperationType,
@@ -9,9 +13,16 @@ use std::sync::Arc;
 use std::time::Duration;
 use tempfile::TempDir;
 
+/// Wait until the worker has processed everything queued so far
+async fn wait_for_worker(queue: &OperationQueue) {
+    tokio::time::timeout(Duration::from_secs(5), queue.wait_until_idle())
+        .await
+        .expect("worker should drain the queue");
+}
+
 #[tokio::test]
 async fn test_worker_path_traversal_prevention() {
-    let temp_dir = TempDir::new().unwrap();
+    let temp_dir = TempDir::new().expect("create temp dir");
     let root = temp_dir.path().to_path_buf();
 
     // Setup queue
@@ -19,7 +30,11 @@ async fn test_worker_path_traversal_prevention() {
     let queue = Arc::new(OperationQueue::new(lock_manager));
 
     // Spawn worker
-    spawn_operation_worker(queue.clone(), root.clone());
+    spawn_operation_worker(
+        queue.clone(),
+        root.clone(),
+        Arc::new(PluginDiscovery::new()),
+    );
 
     // 1. Attempt traversal with ../
     // Note: We use a relative path that tries to escape.
@@ -31,11 +46,9 @@ async fn test_worker_path_traversal_prevention() {
         json!({"content": "hacked"}),
     );
 
-    queue.enqueue(op).await.unwrap();
+    queue.enqueue(op).await.expect("enqueue operation");
 
-    // Wait for processing
-    // We use a simple sleep here as the worker runs in background
-    tokio::time::sleep(Duration::from_millis(100)).await;
+    wait_for_worker(&queue).await;
 
     let stats = queue.get_stats().await;
     assert_eq!(
@@ -66,17 +79,21 @@ async fn test_worker_path_traversal_prevention() {
 
 #[tokio::test]
 async fn test_worker_absolute_path_traversal() {
-    let temp_dir = TempDir::new().unwrap();
+    let temp_dir = TempDir::new().expect("create temp dir");
     let root = temp_dir.path().to_path_buf();
 
     let lock_manager = Arc::new(LockManager::new());
     let queue = Arc::new(OperationQueue::new(lock_manager));
 
-    spawn_operation_worker(queue.clone(), root.clone());
+    spawn_operation_worker(
+        queue.clone(),
+        root.clone(),
+        Arc::new(PluginDiscovery::new()),
+    );
 
     // Attempt absolute path outside root
     // We create a separate temp dir to target
-    let other_temp = TempDir::new().unwrap();
+    let other_temp = TempDir::new().expect("create temp dir");
     let target_path = other_temp.path().join("hacked.txt");
 
     let op = FileOperation::new(
@@ -86,9 +103,9 @@ async fn test_worker_absolute_path_traversal() {
         json!({"content": "hacked"}),
     );
 
-    queue.enqueue(op).await.unwrap();
+    queue.enqueue(op).await.expect("enqueue operation");
 
-    tokio::time::sleep(Duration::from_millis(100)).await;
+    wait_for_worker(&queue).await;
 
     let stats = queue.get_stats().await;
     assert_eq!(
@@ -104,13 +121,17 @@ async fn test_worker_absolute_path_traversal() {
 
 #[tokio::test]
 async fn test_worker_valid_operation() {
-    let temp_dir = TempDir::new().unwrap();
+    let temp_dir = TempDir::new().expect("create temp dir");
     let root = temp_dir.path().to_path_buf();
 
     let lock_manager = Arc::new(LockManager::new());
     let queue = Arc::new(OperationQueue::new(lock_manager));
 
-    spawn_operation_worker(queue.clone(), root.clone());
+    spawn_operation_worker(
+        queue.clone(),
+        root.clone(),
+        Arc::new(PluginDiscovery::new()),
+    );
 
     let op = FileOperation::new(
         "good_tool".to_string(),
@@ -119,15 +140,348 @@ async fn test_worker_valid_operation() {
         json!({"content": "safe"}),
     );
 
-    queue.enqueue(op).await.unwrap();
+    queue.enqueue(op).await.expect("enqueue operation");
 
-    tokio::time::sleep(Duration::from_millis(100)).await;
+    wait_for_worker(&queue).await;
 
     let stats = queue.get_stats().await;
     assert_eq!(stats.completed_operations, 1);
 
     let inside_path = root.join("inside.txt");
     assert!(inside_path.exists());
-    let content = std::fs::read_to_string(inside_path).unwrap();
+    let content = std::fs::read_to_string(inside_path).expect("read inside.txt");
     assert_eq!(content, "safe");
 }
+
+#[tokio::test]
+async fn test_worker_rename_rewrites_references() {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    let root = temp_dir.path().to_path_buf();
+    std::fs::create_dir_all(root.join("src")).expect("create src");
+    std::fs::write(
+        root.join("src/lib.rs"),
+        "pub mod utils;\n\npub fn run() {\n    utils::a();\n}\n",
+    )
+    .expect("write src/lib.rs");
+    std::fs::write(
+        root.join("src/utils.rs"),
+        "pub fn a() {}\n\npub fn b() {\n    crate::utils::a();\n}\n",
+    )
+    .expect("write src/utils.rs");
+
+    let mut plugins = PluginDiscovery::new();
+    for plugin in mill_plugin_bundle::all_plugins() {
+        plugins.register(plugin);
+    }
+
+    let lock_manager = Arc::new(LockManager::new());
+    let queue = Arc::new(OperationQueue::new(lock_manager));
+
+    spawn_operation_worker(queue.clone(), root.clone(), Arc::new(plugins));
+
+    let op = FileOperation::new(
+        "rename_file".to_string(),
+        OperationType::Rename,
+        PathBuf::from("src/utils.rs"),
+        json!({
+            "new_path": root.join("src/helpers.rs").to_string_lossy(),
+            "rename_info": {}
+        }),
+    );
+
+    queue.enqueue(op).await.expect("enqueue operation");
+
+    wait_for_worker(&queue).await;
+
+    let stats = queue.get_stats().await;
+    assert_eq!(stats.failed_operations, 0);
+
+    assert!(!root.join("src/utils.rs").exists());
+    let moved = std::fs::read_to_string(root.join("src/helpers.rs")).expect("read src/helpers.rs");
+    assert!(
+        moved.contains("crate::helpers::a()"),
+        "moved file should reference its new module path: {}",
+        moved
+    );
+    assert!(!moved.contains("crate::utils::"));
+
+    // rename_info was provided, so the importer is rewritten by a follow-up write
+    let lib = std::fs::read_to_string(root.join("src/lib.rs")).expect("read src/lib.rs");
+    assert!(
+        lib.contains("pub mod helpers;"),
+        "unexpected lib.rs: {}",
+        lib
+    );
+    assert!(lib.contains("helpers::a();"));
+    assert_eq!(stats.completed_operations, 2);
+}
+
+#[tokio::test]
+async fn test_worker_rename_succeeds_when_queue_is_closed() {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    let root = temp_dir.path().to_path_buf();
+    std::fs::create_dir_all(root.join("src")).expect("create src");
+    let lib = "pub mod utils;\n\npub fn run() {\n    utils::a();\n}\n";
+    std::fs::write(root.join("src/lib.rs"), lib).expect("write src/lib.rs");
+    std::fs::write(root.join("src/utils.rs"), "pub fn a() {}\n").expect("write src/utils.rs");
+
+    let mut plugins = PluginDiscovery::new();
+    for plugin in mill_plugin_bundle::all_plugins() {
+        plugins.register(plugin);
+    }
+
+    let lock_manager = Arc::new(LockManager::new());
+    let queue = Arc::new(OperationQueue::new(lock_manager));
+
+    queue
+        .enqueue(FileOperation::new(
+            "rename_file".to_string(),
+            OperationType::Rename,
+            PathBuf::from("src/utils.rs"),
+            json!({
+                "new_path": root.join("src/helpers.rs").to_string_lossy(),
+                "rename_info": {}
+            }),
+        ))
+        .await
+        .expect("enqueue operation");
+    // As during the shutdown drain: pending work runs, new work is refused
+    queue.close();
+
+    spawn_operation_worker(queue.clone(), root.clone(), Arc::new(plugins));
+    wait_for_worker(&queue).await;
+
+    let stats = queue.get_stats().await;
+    assert_eq!(stats.failed_operations, 0);
+    assert_eq!(stats.completed_operations, 1);
+    assert!(!root.join("src/utils.rs").exists());
+    assert!(root.join("src/helpers.rs").exists());
+    assert_eq!(
+        std::fs::read_to_string(root.join("src/lib.rs")).expect("read src/lib.rs"),
+        lib
+    );
+}
+
+#[tokio::test]
+async fn test_worker_copy_and_rejected_overwrite() {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    let root = temp_dir.path().to_path_buf();
+    std::fs::write(root.join("template.txt"), "template").expect("write template.txt");
+    std::fs::write(root.join("existing.txt"), "keep me").expect("write existing.txt");
+
+    let lock_manager = Arc::new(LockManager::new());
+    let queue = Arc::new(OperationQueue::new(lock_manager));
+
+    spawn_operation_worker(
+        queue.clone(),
+        root.clone(),
+        Arc::new(PluginDiscovery::new()),
+    );
+
+    queue
+        .enqueue(FileOperation::new(
+            "copy_file".to_string(),
+            OperationType::Copy,
+            PathBuf::from("template.txt"),
+            json!({"new_path": "copy.txt"}),
+        ))
+        .await
+        .expect("enqueue operation");
+    queue
+        .enqueue(FileOperation::new(
+            "copy_file".to_string(),
+            OperationType::Copy,
+            PathBuf::from("template.txt"),
+            json!({"new_path": "existing.txt"}),
+        ))
+        .await
+        .expect("enqueue operation");
+
+    wait_for_worker(&queue).await;
+
+    let stats = queue.get_stats().await;
+    assert_eq!(stats.completed_operations, 1);
+    assert_eq!(stats.failed_operations, 1);
+
+    assert_eq!(
+        std::fs::read_to_string(root.join("copy.txt")).expect("read copy.txt"),
+        "template"
+    );
+    assert_eq!(
+        std::fs::read_to_string(root.join("existing.txt")).expect("read existing.txt"),
+        "keep me",
+        "existing file must not be overwritten without 'overwrite'"
+    );
+    assert!(root.join("template.txt").exists());
+}
+
+#[tokio::test]
+async fn test_worker_copy_destination_traversal() {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    let root = temp_dir.path().to_path_buf();
+    std::fs::write(root.join("template.txt"), "template").expect("write template.txt");
+    let other_temp = TempDir::new().expect("create temp dir");
+    let target_path = other_temp.path().join("copied.txt");
+
+    let lock_manager = Arc::new(LockManager::new());
+    let queue = Arc::new(OperationQueue::new(lock_manager));
+
+    spawn_operation_worker(
+        queue.clone(),
+        root.clone(),
+        Arc::new(PluginDiscovery::new()),
+    );
+
+    queue
+        .enqueue(FileOperation::new(
+            "copy_file".to_string(),
+            OperationType::Copy,
+            PathBuf::from("template.txt"),
+            json!({"new_path": target_path.to_string_lossy(), "overwrite": true}),
+        ))
+        .await
+        .expect("enqueue operation");
+
+    wait_for_worker(&queue).await;
+
+    let stats = queue.get_stats().await;
+    assert_eq!(stats.failed_operations, 1);
+    assert!(!target_path.exists());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_worker_write_through_symlink_escape() {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    let root = temp_dir.path().to_path_buf();
+    let outside = TempDir::new().expect("create temp dir");
+
+    // project/escape -> <outside dir>, project/dangling -> <outside>/new.txt
+    std::os::unix::fs::symlink(outside.path(), root.join("escape")).expect("create symlink");
+    std::os::unix::fs::symlink(outside.path().join("new.txt"), root.join("dangling"))
+        .expect("create symlink");
+
+    let lock_manager = Arc::new(LockManager::new());
+    let queue = Arc::new(OperationQueue::new(lock_manager));
+
+    spawn_operation_worker(
+        queue.clone(),
+        root.clone(),
+        Arc::new(PluginDiscovery::new()),
+    );
+
+    for path in ["escape/hacked.txt", "escape/nested/hacked.txt", "dangling"] {
+        queue
+            .enqueue(FileOperation::new(
+                "malicious_tool".to_string(),
+                OperationType::Write,
+                PathBuf::from(path),
+                json!({"content": "hacked"}),
+            ))
+            .await
+            .expect("enqueue operation");
+    }
+
+    wait_for_worker(&queue).await;
+
+    let stats = queue.get_stats().await;
+    assert_eq!(stats.failed_operations, 3);
+    assert_eq!(stats.completed_operations, 0);
+    assert!(!outside.path().join("hacked.txt").exists());
+    assert!(!outside.path().join("new.txt").exists());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_worker_write_through_internal_dangling_symlink() {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    let root = temp_dir.path().to_path_buf();
+    std::fs::create_dir_all(root.join("data")).expect("create directory");
+    std::os::unix::fs::symlink(root.join("data/target.txt"), root.join("alias.txt"))
+        .expect("create symlink");
+
+    let lock_manager = Arc::new(LockManager::new());
+    let queue = Arc::new(OperationQueue::new(lock_manager));
+
+    spawn_operation_worker(
+        queue.clone(),
+        root.clone(),
+        Arc::new(PluginDiscovery::new()),
+    );
+
+    queue
+        .enqueue(FileOperation::new(
+            "good_tool".to_string(),
+            OperationType::Write,
+            PathBuf::from("alias.txt"),
+            json!({"content": "inside"}),
+        ))
+        .await
+        .expect("enqueue operation");
+
+    wait_for_worker(&queue).await;
+
+    let stats = queue.get_stats().await;
+    assert_eq!(stats.completed_operations, 1);
+    assert_eq!(
+        std::fs::read_to_string(root.join("data/target.txt")).expect("read data/target.txt"),
+        "inside"
+    );
+}
+
+#[tokio::test]
+async fn test_worker_drains_queue_on_shutdown() {
+    let temp_dir = TempDir::new().expect("create temp dir");
+    let root = temp_dir.path().to_path_buf();
+
+    let lock_manager = Arc::new(LockManager::new());
+    let queue = Arc::new(OperationQueue::new(lock_manager));
+
+    let (shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+    let worker = spawn_operation_worker_with_shutdown(
+        queue.clone(),
+        root.clone(),
+        Arc::new(PluginDiscovery::new()),
+        shutdown_rx,
+    );
+
+    for i in 0..5 {
+        queue
+            .enqueue(FileOperation::new(
+                "write_file".to_string(),
+                OperationType::Write,
+                PathBuf::from(format!("file_{}.txt", i)),
+                json!({"content": format!("content {}", i)}),
+            ))
+            .await
+            .expect("enqueue operation");
+    }
+
+    shutdown_tx.send(true).expect("signal shutdown");
+    tokio::time::timeout(Duration::from_secs(5), worker)
+        .await
+        .expect("worker should finish after draining")
+        .expect("worker task should not panic");
+
+    let stats = queue.get_stats().await;
+    assert_eq!(stats.completed_operations, 5);
+    assert_eq!(stats.pending_operations, 0);
+    for i in 0..5 {
+        assert_eq!(
+            std::fs::read_to_string(root.join(format!("file_{}.txt", i)))
+                .expect("read drained file"),
+            format!("content {}", i)
+        );
+    }
+
+    // The queue no longer accepts work once shut down
+    let rejected = queue
+        .enqueue(FileOperation::new(
+            "write_file".to_string(),
+            OperationType::Write,
+            PathBuf::from("late.txt"),
+            json!({"content": "late"}),
+        ))
+        .await;
+    assert!(rejected.is_err());
+}
@@ -1,8 +1,14 @@
 //! Shared test utilities
 
+// Each test binary compiles this module and uses only some of its helpers
+#![allow(dead_code)]
+
 use mill_handlers::handlers::plugin_dispatcher::AppState;
+use mill_handlers::handlers::tools::ToolHandlerContext;
+use mill_plugin_api::LanguagePlugin;
 use std::sync::Arc;
 use tempfile::TempDir;
+use tokio::sync::Mutex;
 
 pub async fn create_test_app_state() -> (Arc<AppState>, TempDir) {
     use mill_plugin_system::PluginManager;
@@ -45,3 +51,58 @@ pub async fn create_test_app_state() -> (Arc<AppState>, TempDir) {
 
     (app_state, temp_dir)
 }
+
+/// Context for a project whose LSP is switched off, so code intelligence
+/// must come from the language plugins
+pub async fn context_without_lsp(project_root: &std::path::Path) -> ToolHandlerContext {
+    use mill_plugin_system::PluginManager;
+    use mill_services::services::app_state_factory::create_services_bundle;
+    use mill_workspaces::WorkspaceManager;
+
+    let plugin_manager = Arc::new(PluginManager::new());
+    let config = mill_config::AppConfig::default();
+    let plugins: Vec<Arc<dyn LanguagePlugin>> = mill_plugin_bundle::all_plugins();
+    let plugin_registry =
+        mill_services::services::registry_builder::build_language_plugin_registry(plugins);
+
+    let services = create_services_bundle(
+        &project_root.to_path_buf(),
+        mill_ast::CacheSettings::default(),
+        plugin_manager.clone(),
+        &config,
+        plugin_registry.clone(),
+    )
+    .await;
+
+    let app_state = Arc::new(AppState {
+        ast_service: services.ast_service,
+        file_service: services.file_service,
+        planner: services.planner,
+        workflow_executor: services.workflow_executor,
+        project_root: project_root.to_path_buf(),
+        lock_manager: services.lock_manager,
+        operation_queue: services.operation_queue,
+        start_time: std::time::Instant::now(),
+        workspace_manager: Arc::new(WorkspaceManager::new()),
+        language_plugins: mill_handlers::LanguagePluginRegistry::from_registry(plugin_registry),
+        lsp_mode: mill_config::config::LspMode::Off,
+    });
+
+    ToolHandlerContext {
+        user_id: None,
+        app_state,
+        plugin_manager,
+        lsp_adapter: Arc::new(Mutex::new(None)),
+    }
+}
+
+/// Writes `(relative path, content)` files into a fresh temporary directory
+pub fn write_project(files: &[(&str, &str)]) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    for (path, content) in files {
+        let full = dir.path().join(path);
+        std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+        std::fs::write(full, content).unwrap();
+    }
+    dir
+}
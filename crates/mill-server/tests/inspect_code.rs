@@ -0,0 +1,100 @@
+//! Integration tests for inspect_code's plugin fallback when the LSP is off
+
+mod common;
+
+use common::{context_without_lsp, write_project};
+use mill_foundation::core::model::mcp::ToolCall;
+use mill_handlers::handlers::tools::ToolHandler;
+use mill_handlers::handlers::InspectHandler;
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn test_inspect_code_hover_and_definition_without_lsp() {
+    let dir = write_project(&[(
+        "src/lib.rs",
+        "/// Adds up the order lines.\npub fn compute_total() -> u32 {\n    let base = 40;\n    base + 2\n}\n\npub fn report() -> u32 {\n    compute_total()\n}\n",
+    )]);
+    let root = dir.path().canonicalize().unwrap();
+    let context = context_without_lsp(&root).await.to_api_context().await;
+    let lib = root.join("src/lib.rs");
+
+    // Hover on a line inside the function body
+    let hover: Value = InspectHandler::new()
+        .handle_tool_call(
+            &context,
+            &ToolCall {
+                name: "inspect_code".to_string(),
+                arguments: Some(json!({
+                    "filePath": lib.to_str().unwrap(),
+                    "line": 2,
+                    "character": 8,
+                    "include": ["typeInfo"],
+                })),
+            },
+        )
+        .await
+        .unwrap();
+    let markdown = hover["content"]["typeInfo"]["contents"]["value"]
+        .as_str()
+        .unwrap_or_else(|| panic!("no hover: {}", hover));
+    assert!(markdown.contains("function compute_total"), "{}", markdown);
+    assert!(
+        markdown.contains("Adds up the order lines."),
+        "{}",
+        markdown
+    );
+
+    // Definition of the call inside `report`
+    let definition: Value = InspectHandler::new()
+        .handle_tool_call(
+            &context,
+            &ToolCall {
+                name: "inspect_code".to_string(),
+                arguments: Some(json!({
+                    "filePath": lib.to_str().unwrap(),
+                    "line": 7,
+                    "character": 6,
+                    "include": ["definition"],
+                })),
+            },
+        )
+        .await
+        .unwrap();
+    let items = definition["content"]["definition"]["items"]
+        .as_array()
+        .unwrap_or_else(|| panic!("no definition: {}", definition));
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["range"]["start"]["line"], 0);
+}
+
+#[tokio::test]
+async fn test_inspect_code_definition_uses_zero_based_lines_for_lua() {
+    let dir = write_project(&[(
+        "main.lua",
+        "local function helper()\n    return 1\nend\n\nlocal x = helper()\n",
+    )]);
+    let root = dir.path().canonicalize().unwrap();
+    let context = context_without_lsp(&root).await.to_api_context().await;
+    let main = root.join("main.lua");
+
+    let definition: Value = InspectHandler::new()
+        .handle_tool_call(
+            &context,
+            &ToolCall {
+                name: "inspect_code".to_string(),
+                arguments: Some(json!({
+                    "filePath": main.to_str().unwrap(),
+                    "line": 4,
+                    "character": 12,
+                    "include": ["definition"],
+                })),
+            },
+        )
+        .await
+        .unwrap();
+    let items = definition["content"]["definition"]["items"]
+        .as_array()
+        .unwrap_or_else(|| panic!("no definition: {}", definition));
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["range"]["start"]["line"], 0);
+}
@@ -105,6 +105,7 @@ async fn test_priority_ordering() {
         params: json!({"content": "test"}),
         created_at: Instant::now(),
         priority: 5, // Write priority
+        request_id: None,
     };
     queue.enqueue(op1).await.unwrap();
 
@@ -116,6 +117,7 @@ async fn test_priority_ordering() {
         params: json!({"old": "foo", "new": "bar"}),
         created_at: Instant::now(),
         priority: 1, // Refactor priority
+        request_id: None,
     };
     queue.enqueue(op2).await.unwrap();
 
@@ -127,6 +129,7 @@ async fn test_priority_ordering() {
         params: json!({}),
         created_at: Instant::now(),
         priority: 10, // Format priority
+        request_id: None,
     };
     queue.enqueue(op3).await.unwrap();
 
@@ -161,6 +164,7 @@ async fn test_operation_stats() {
         params: json!({}),
         created_at: Instant::now(),
         priority: 5,
+        request_id: None,
     };
     queue.enqueue(op).await.unwrap();
 
@@ -189,6 +193,7 @@ async fn test_transaction_creation() {
         params: json!({"edit": "rename foo to bar"}),
         created_at: Instant::now(),
         priority: 1,
+        request_id: None,
     };
     transaction.add_operation(op1);
 
@@ -200,6 +205,7 @@ async fn test_transaction_creation() {
         params: json!({"edit": "rename foo to bar"}),
         created_at: Instant::now(),
         priority: 1,
+        request_id: None,
     };
     transaction.add_operation(op2);
 
@@ -228,6 +234,7 @@ async fn test_clear_queue() {
             params: json!({}),
             created_at: Instant::now(),
             priority: 5,
+            request_id: None,
         };
         queue.enqueue(op).await.unwrap();
     }
@@ -0,0 +1,50 @@
+//! Integration tests for parse errors reaching the CLI with their source snippet
+
+mod common;
+
+use common::{context_without_lsp, write_project};
+use mill_client::formatting::Formatter;
+use mill_client::websocket::MCPResponse;
+use mill_foundation::errors::ErrorResponse;
+use mill_handlers::handlers::tools::dispatch_to_language_plugin;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_plugin_parse_error_snippet_reaches_cli_output() {
+    let dir = write_project(&[("src/broken.rs", "fn ok() {}\n\nfn broken( {\n")]);
+    let root = dir.path().canonicalize().unwrap();
+    let context = context_without_lsp(&root).await.to_api_context().await;
+    let file = root.join("src/broken.rs");
+
+    let err = dispatch_to_language_plugin(&context, file.to_str().unwrap(), |plugin, content| {
+        Box::pin(async move { plugin.list_functions(&content).await })
+    })
+    .await
+    .unwrap_err();
+
+    // Serialize the error the way the transports do
+    let error = ErrorResponse::from(err);
+    let response: MCPResponse = serde_json::from_value(json!({
+        "id": "1",
+        "error": {
+            "code": -1,
+            "message": error.message,
+            "data": serde_json::to_value(&error).unwrap(),
+        }
+    }))
+    .unwrap();
+
+    let output = Formatter::with_settings(false, false)
+        .mcp_response(&response)
+        .unwrap();
+    let caret = output
+        .split("3 | fn broken( {\n")
+        .nth(1)
+        .and_then(|rest| rest.lines().next())
+        .unwrap_or_else(|| panic!("no snippet: {}", output));
+    assert!(
+        caret.starts_with("  | ") && caret.ends_with('^'),
+        "{}",
+        output
+    );
+}
@@ -0,0 +1,103 @@
+//! Integration tests for the project_symbols index
+
+mod common;
+
+use common::write_project;
+use mill_ast::AstCache;
+use mill_plugin_api::{PluginDiscovery, SymbolKind};
+use mill_server::handlers::tools::project_symbols::{ProjectSymbolsHandler, ProjectSymbolsOptions};
+
+fn discovery() -> PluginDiscovery {
+    let mut plugins = PluginDiscovery::new();
+    for plugin in mill_plugin_bundle::all_plugins() {
+        plugins.register(plugin);
+    }
+    plugins
+}
+
+fn names(
+    index: &mill_server::handlers::tools::project_symbols::ProjectSymbols,
+    file: &str,
+) -> Vec<String> {
+    let mut names: Vec<String> = index.files[file].iter().map(|s| s.name.clone()).collect();
+    names.sort();
+    names
+}
+
+#[tokio::test]
+async fn test_project_symbols_indexes_every_language() {
+    let dir = write_project(&[
+        (
+            "src/lib.rs",
+            "pub struct Config {\n    pub name: String,\n}\n\npub fn load() -> Config {\n    Config { name: String::new() }\n}\n",
+        ),
+        (
+            "scripts/tool.py",
+            "class Runner:\n    pass\n\n\ndef main():\n    return Runner()\n",
+        ),
+        ("ignored/skip.rs", "pub fn hidden() {}\n"),
+        (".gitignore", "ignored/\n"),
+        ("README.txt", "not source\n"),
+    ]);
+    let plugins = discovery();
+    let handler = ProjectSymbolsHandler::new();
+    let cache = AstCache::new();
+
+    let index = handler
+        .collect(
+            &plugins,
+            &cache,
+            dir.path(),
+            dir.path(),
+            &ProjectSymbolsOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert!(names(&index, "src/lib.rs").contains(&"Config".to_string()));
+    assert!(names(&index, "src/lib.rs").contains(&"load".to_string()));
+    assert!(names(&index, "scripts/tool.py").contains(&"Runner".to_string()));
+    assert!(names(&index, "scripts/tool.py").contains(&"main".to_string()));
+    assert!(!index.files.contains_key("ignored/skip.rs"));
+    assert!(!index.files.contains_key("README.txt"));
+    assert!(index.skipped.is_empty());
+
+    // A second run is served from the cache and yields the same index
+    let again = handler
+        .collect(
+            &plugins,
+            &cache,
+            dir.path(),
+            dir.path(),
+            &ProjectSymbolsOptions::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(again.files, index.files);
+}
+
+#[tokio::test]
+async fn test_project_symbols_kind_filter_and_size_limit() {
+    let dir = write_project(&[
+        ("src/lib.rs", "pub struct Config;\n\npub fn load() {}\n"),
+        (
+            "src/big.rs",
+            &format!("pub fn big() {{}}\n{}", "// padding\n".repeat(100)),
+        ),
+    ]);
+    let plugins = discovery();
+    let options = ProjectSymbolsOptions {
+        kinds: Some(vec![SymbolKind::Function]),
+        max_file_bytes: 200,
+        ..Default::default()
+    };
+
+    let index = ProjectSymbolsHandler::new()
+        .collect(&plugins, &AstCache::new(), dir.path(), dir.path(), &options)
+        .await
+        .unwrap();
+
+    assert_eq!(names(&index, "src/lib.rs"), vec!["load"]);
+    assert_eq!(index.skipped.len(), 1);
+    assert_eq!(index.skipped[0].path, "src/big.rs");
+}
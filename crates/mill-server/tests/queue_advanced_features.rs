@@ -26,6 +26,7 @@ async fn test_monitoring_api() {
             params: json!({"test": i}),
             created_at: Instant::now(),
             priority: 5,
+            request_id: None,
         };
         operation_queue.enqueue(op).await.unwrap();
     }
@@ -78,6 +79,7 @@ async fn test_operation_batching() {
             params: json!({"data": i}),
             created_at: Instant::now(),
             priority: 5,
+            request_id: None,
         };
         queue.enqueue(op).await.unwrap();
     }
@@ -155,6 +157,7 @@ async fn test_batch_with_priority() {
         params: json!({}),
         created_at: Instant::now(),
         priority: 10, // Low priority
+        request_id: None,
     };
 
     let op2 = FileOperation {
@@ -165,6 +168,7 @@ async fn test_batch_with_priority() {
         params: json!({}),
         created_at: Instant::now(),
         priority: 1, // High priority
+        request_id: None,
     };
 
     queue.enqueue(op1).await.unwrap();
@@ -0,0 +1,143 @@
+//! Integration tests for the rename_symbol tool's plugin fallback
+
+mod common;
+
+use common::{context_without_lsp, write_project};
+use mill_foundation::core::model::mcp::ToolCall;
+use mill_handlers::handlers::tools::ToolHandler;
+use mill_handlers::handlers::RenameSymbolHandler;
+use serde_json::{json, Value};
+
+fn rename_call(file_path: &str, line: u32, character: u32, dry_run: bool) -> ToolCall {
+    ToolCall {
+        name: "rename_symbol".to_string(),
+        arguments: Some(json!({
+            "filePath": file_path,
+            "line": line,
+            "character": character,
+            "newName": "sum_total",
+            "options": { "dryRun": dry_run },
+        })),
+    }
+}
+
+#[tokio::test]
+async fn test_rename_symbol_falls_back_to_plugin_without_lsp() {
+    let dir = write_project(&[
+        (
+            "src/lib.rs",
+            "pub mod report;\n\npub fn compute_total() -> u32 {\n    42\n}\n",
+        ),
+        (
+            "src/report.rs",
+            "use crate::compute_total;\n\npub fn print() {\n    println!(\"{}\", compute_total());\n}\n",
+        ),
+    ]);
+    let root = dir.path().canonicalize().unwrap();
+    let context = context_without_lsp(&root).await.to_api_context().await;
+    let handler = RenameSymbolHandler::new();
+    let lib = root.join("src/lib.rs");
+
+    // Preview: both files are planned, nothing is written
+    let preview: Value = handler
+        .handle_tool_call(&context, &rename_call(lib.to_str().unwrap(), 2, 7, true))
+        .await
+        .unwrap();
+    assert_eq!(preview["content"]["status"], "preview");
+    assert_eq!(
+        preview["content"]["filesChanged"].as_array().unwrap().len(),
+        2,
+        "{}",
+        preview
+    );
+    assert!(std::fs::read_to_string(&lib)
+        .unwrap()
+        .contains("compute_total"));
+
+    // Execution applies the plugin's edits to every file
+    let applied: Value = handler
+        .handle_tool_call(&context, &rename_call(lib.to_str().unwrap(), 2, 7, false))
+        .await
+        .unwrap();
+    assert_eq!(applied["content"]["status"], "success", "{}", applied);
+    assert_eq!(
+        std::fs::read_to_string(&lib).unwrap(),
+        "pub mod report;\n\npub fn sum_total() -> u32 {\n    42\n}\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(root.join("src/report.rs")).unwrap(),
+        "use crate::sum_total;\n\npub fn print() {\n    println!(\"{}\", sum_total());\n}\n"
+    );
+}
+
+#[tokio::test]
+async fn test_rename_symbol_rejects_file_outside_project() {
+    let dir = write_project(&[("src/lib.rs", "pub fn compute_total() {}\n")]);
+    let outside = write_project(&[("other.rs", "pub fn compute_total() {}\n")]);
+    let root = dir.path().canonicalize().unwrap();
+    let context = context_without_lsp(&root).await.to_api_context().await;
+
+    let other = outside.path().canonicalize().unwrap().join("other.rs");
+    let result = RenameSymbolHandler::new()
+        .handle_tool_call(&context, &rename_call(other.to_str().unwrap(), 0, 7, false))
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        std::fs::read_to_string(&other).unwrap(),
+        "pub fn compute_total() {}\n"
+    );
+}
+
+#[tokio::test]
+async fn test_rename_symbol_resolves_declaration_through_alias() {
+    let dir = write_project(&[
+        (
+            "src/lib.rs",
+            "pub mod report;\n\npub fn compute_total() -> u32 {\n    42\n}\n",
+        ),
+        (
+            "src/report.rs",
+            "use crate::compute_total as total;\n\npub fn print() -> u32 {\n    total()\n}\n",
+        ),
+    ]);
+    let root = dir.path().canonicalize().unwrap();
+    let context = context_without_lsp(&root).await.to_api_context().await;
+    let report = root.join("src/report.rs");
+
+    // Cursor on the alias at the call site
+    let applied: Value = RenameSymbolHandler::new()
+        .handle_tool_call(
+            &context,
+            &rename_call(report.to_str().unwrap(), 3, 4, false),
+        )
+        .await
+        .unwrap();
+    assert_eq!(applied["content"]["status"], "success", "{}", applied);
+    assert_eq!(
+        std::fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+        "pub mod report;\n\npub fn sum_total() -> u32 {\n    42\n}\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(&report).unwrap(),
+        "use crate::sum_total as total;\n\npub fn print() -> u32 {\n    total()\n}\n"
+    );
+}
+
+#[tokio::test]
+async fn test_rename_symbol_requires_a_project_declaration() {
+    let dir = write_project(&[(
+        "src/lib.rs",
+        "use std::collections::HashMap;\n\npub fn names() -> HashMap<u32, u32> {\n    HashMap::new()\n}\n",
+    )]);
+    let root = dir.path().canonicalize().unwrap();
+    let context = context_without_lsp(&root).await.to_api_context().await;
+    let lib = root.join("src/lib.rs");
+
+    let result = RenameSymbolHandler::new()
+        .handle_tool_call(&context, &rename_call(lib.to_str().unwrap(), 3, 4, false))
+        .await;
+
+    assert!(result.is_err(), "HashMap is declared outside the project");
+    assert!(std::fs::read_to_string(&lib).unwrap().contains("HashMap"));
+}
@@ -0,0 +1,111 @@
+//! Integration tests for request ID propagation through logging spans
+
+mod common;
+
+use mill_foundation::core::model::mcp::{McpMessage, McpRequest};
+use mill_handlers::handlers::plugin_dispatcher::PluginDispatcher;
+use mill_plugin_system::PluginManager;
+use serde_json::json;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tracing_subscriber::fmt::MakeWriter;
+
+/// Log sink shared between the subscriber and the test
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedLogs {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for CapturedLogs {
+    type Writer = CapturedLogs;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+/// Value of `field=` in the first log line inside span `span_name`
+fn span_field(logs: &str, span_name: &str, field: &str) -> Option<String> {
+    let prefix = format!("{}{{", span_name);
+    let needle = format!("{}=", field);
+    logs.lines()
+        .filter_map(|line| line.find(&prefix).map(|start| &line[start..]))
+        .filter_map(|span| {
+            span.find(&needle)
+                .map(|start| &span[start + needle.len()..])
+        })
+        .map(|value| {
+            value
+                .split([' ', '}'])
+                .next()
+                .unwrap_or_default()
+                .to_string()
+        })
+        .next()
+}
+
+// The default current-thread runtime keeps the worker on this thread, so the
+// thread-local subscriber sees its spans too
+#[tokio::test]
+async fn test_request_id_reaches_queued_operation_span() {
+    let logs = CapturedLogs::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(logs.clone())
+        .with_ansi(false)
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let (app_state, _temp_dir) = common::create_test_app_state().await;
+    let root = app_state.project_root.clone();
+    let dispatcher = PluginDispatcher::new(app_state, Arc::new(PluginManager::new()));
+
+    // A non-dry-run find/replace writes through the operation queue
+    std::fs::write(root.join("notes.txt"), "hello world\n").unwrap();
+    let request = McpMessage::Request(McpRequest {
+        jsonrpc: "2.0".to_string(),
+        id: Some(json!(1)),
+        method: "tools/call".to_string(),
+        params: Some(json!({
+            "name": "workspace",
+            "arguments": {
+                "action": "find_replace",
+                "params": { "pattern": "hello", "replacement": "goodbye" },
+                "options": { "dryRun": false },
+            },
+        })),
+    });
+    dispatcher
+        .dispatch(request, &mill_transport::SessionInfo::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(root.join("notes.txt")).unwrap(),
+        "goodbye world\n"
+    );
+
+    let logs = logs.contents();
+    let request_id = span_field(&logs, "dispatch", "request_id")
+        .unwrap_or_else(|| panic!("no dispatch span in logs:\n{}", logs));
+    assert!(uuid::Uuid::parse_str(&request_id).is_ok(), "{}", request_id);
+    assert_eq!(
+        span_field(&logs, "queued_operation", "request_id").as_deref(),
+        Some(request_id.as_str()),
+        "worker span should carry the dispatcher's request id:\n{}",
+        logs
+    );
+}
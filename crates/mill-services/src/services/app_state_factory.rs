@@ -23,6 +23,8 @@ pub struct ServicesBundle {
 
 /// Create services bundle with default configuration
 ///
+/// Also spawns the worker that drains the bundle's operation queue.
+///
 /// # Parameters
 /// - `plugin_registry`: Pre-built plugin registry (injected by the application layer)
 pub async fn create_services_bundle(
@@ -31,6 +33,36 @@ pub async fn create_services_bundle(
     plugin_manager: Arc<mill_plugin_system::PluginManager>,
     config: &mill_config::AppConfig,
     plugin_registry: Arc<mill_plugin_api::PluginDiscovery>,
+) -> ServicesBundle {
+    let services = create_services_bundle_without_worker(
+        project_root,
+        cache_settings,
+        plugin_manager.clone(),
+        config,
+        plugin_registry,
+    )
+    .await;
+
+    // Spawn operation queue worker to process file operations
+    spawn_operation_worker(
+        services.operation_queue.clone(),
+        plugin_manager,
+        services.file_service.clone(),
+    );
+
+    services
+}
+
+/// Create services bundle without starting an operation queue worker
+///
+/// For callers that drain `operation_queue` with their own worker: two
+/// workers on the same queue would race for its operations.
+pub async fn create_services_bundle_without_worker(
+    project_root: &PathBuf,
+    cache_settings: mill_ast::CacheSettings,
+    plugin_manager: Arc<mill_plugin_system::PluginManager>,
+    config: &mill_config::AppConfig,
+    plugin_registry: Arc<mill_plugin_api::PluginDiscovery>,
 ) -> ServicesBundle {
     // Plugin registry is now injected by the caller (dependency injection)
 
@@ -42,9 +74,6 @@ pub async fn create_services_bundle(
     let lock_manager = Arc::new(LockManager::new());
     let operation_queue = Arc::new(OperationQueue::new(lock_manager.clone()));
 
-    // Spawn operation queue worker to process file operations
-    spawn_operation_worker(operation_queue.clone(), plugin_manager.clone());
-
     let file_service = Arc::new(FileService::new(
         project_root,
         ast_cache.clone(),
@@ -67,9 +96,12 @@ pub async fn create_services_bundle(
 }
 
 /// Spawn background worker to process file operations from the queue
+///
+/// `file_service` confines copy sources and destinations to the project root.
 fn spawn_operation_worker(
     queue: Arc<OperationQueue>,
     plugin_manager: Arc<mill_plugin_system::PluginManager>,
+    file_service: Arc<FileService>,
 ) {
     use tokio::fs;
 
@@ -78,6 +110,7 @@ fn spawn_operation_worker(
         queue
             .process_with(move |op, stats| {
                 let plugin_manager = plugin_manager.clone();
+                let file_service = file_service.clone();
                 async move {
                     tracing::info!(
                         op_type = ?op.operation_type,
@@ -162,6 +195,7 @@ fn spawn_operation_worker(
                                 ))
                             })
                         }
+                        OperationType::Copy => copy_queued_file(&file_service, &op).await,
                         OperationType::UpdateDependency => {
                             use mill_plugin_system::protocol::PluginRequest;
                             let request =
@@ -208,6 +242,82 @@ fn spawn_operation_worker(
     });
 }
 
+/// Copy `op.file_path` to the `new_path` param, both confined to the project root
+async fn copy_queued_file(
+    file_service: &FileService,
+    op: &crate::services::coordination::operation_queue::FileOperation,
+) -> mill_foundation::errors::MillResult<()> {
+    let new_path = op
+        .params
+        .get("new_path")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| {
+            mill_foundation::errors::MillError::invalid_request("Copy operation missing new_path")
+        })?;
+    let overwrite = op
+        .params
+        .get("overwrite")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let source = file_service.to_absolute_path_checked(&op.file_path)?;
+    let destination = file_service.to_absolute_path_checked(std::path::Path::new(new_path))?;
+    copy_file(&source, &destination, overwrite).await
+}
+
+/// Copy `source` to `destination`, both already confined to the project root
+///
+/// Without `overwrite`, an existing destination is an error; the check and
+/// the creation are one atomic `create_new` open.
+pub async fn copy_file(
+    source: &std::path::Path,
+    destination: &std::path::Path,
+    overwrite: bool,
+) -> mill_foundation::errors::MillResult<()> {
+    use mill_foundation::errors::MillError;
+    use tokio::fs;
+    use tokio::io::AsyncWriteExt;
+
+    let content = fs::read(source).await.map_err(|e| {
+        MillError::internal(format!(
+            "Failed to read copy source {}: {}",
+            source.display(),
+            e
+        ))
+    })?;
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .create_new(!overwrite)
+        .open(destination)
+        .await
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::AlreadyExists => {
+                MillError::already_exists(destination.display().to_string())
+            }
+            _ => MillError::internal(format!(
+                "Failed to copy file {} to {}: {}",
+                source.display(),
+                destination.display(),
+                e
+            )),
+        })?;
+    file.write_all(&content).await.map_err(|e| {
+        MillError::internal(format!(
+            "Failed to write content to {}: {}",
+            destination.display(),
+            e
+        ))
+    })?;
+    file.sync_all().await.map_err(|e| {
+        MillError::internal(format!(
+            "Failed to sync file {}: {}",
+            destination.display(),
+            e
+        ))
+    })
+}
+
 /// Register MCP proxy plugin if feature is enabled
 #[cfg(feature = "mcp-proxy")]
 pub async fn register_mcp_proxy_if_enabled(
@@ -243,3 +353,58 @@ pub async fn register_mcp_proxy_if_enabled(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::coordination::operation_queue::FileOperation;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_worker_copy_stays_in_project_root() {
+        let root = tempfile::TempDir::new().unwrap();
+        let outside = tempfile::TempDir::new().unwrap();
+        std::fs::write(root.path().join("template.txt"), "template").unwrap();
+        std::fs::write(root.path().join("existing.txt"), "keep me").unwrap();
+        let escaped = outside.path().join("copied.txt");
+
+        let services = create_services_bundle(
+            &root.path().to_path_buf(),
+            mill_ast::CacheSettings::default(),
+            Arc::new(mill_plugin_system::PluginManager::new()),
+            &mill_config::AppConfig::default(),
+            Arc::new(mill_plugin_api::PluginDiscovery::new()),
+        )
+        .await;
+        let queue = services.operation_queue;
+
+        for new_path in [
+            escaped.to_string_lossy().to_string(),
+            "existing.txt".to_string(),
+            "copy.txt".to_string(),
+        ] {
+            queue
+                .enqueue(FileOperation::new(
+                    "copy_file".to_string(),
+                    OperationType::Copy,
+                    root.path().join("template.txt"),
+                    json!({ "new_path": new_path }),
+                ))
+                .await
+                .unwrap();
+        }
+        queue.wait_until_idle().await;
+
+        let stats = queue.get_stats().await;
+        assert_eq!(stats.failed_operations, 2);
+        assert!(!escaped.exists());
+        assert_eq!(
+            std::fs::read_to_string(root.path().join("existing.txt")).unwrap(),
+            "keep me"
+        );
+        assert_eq!(
+            std::fs::read_to_string(root.path().join("copy.txt")).unwrap(),
+            "template"
+        );
+    }
+}
@@ -1,6 +1,8 @@
 //! Service for managing import updates across the codebase
 
-use mill_ast::{find_project_files, update_imports_for_rename, ImportPathResolver};
+use mill_ast::{
+    find_project_files, update_imports_for_rename, ImportPathResolver, ReferenceScanCache,
+};
 use mill_foundation::errors::MillError as ServerError;
 use mill_foundation::protocol::DependencyUpdate;
 
@@ -17,6 +19,8 @@ pub struct ImportService {
     project_root: PathBuf,
     /// Language plugin registry for multi-language support
     plugin_registry: Arc<PluginDiscovery>,
+    /// Module reference scans, reused across renames while files are unchanged
+    reference_cache: Arc<ReferenceScanCache>,
 }
 
 impl ImportService {
@@ -30,6 +34,7 @@ impl ImportService {
         Self {
             project_root: project_root.as_ref().to_path_buf(),
             plugin_registry,
+            reference_cache: Arc::new(ReferenceScanCache::new()),
         }
     }
 
@@ -81,6 +86,7 @@ impl ImportService {
             rename_info,
             dry_run,
             scan_scope,
+            &self.reference_cache,
         )
         .await
         .map_err(|e| ServerError::internal(format!("Failed to update imports: {}", e)))?;
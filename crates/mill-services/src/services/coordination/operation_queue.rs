@@ -7,15 +7,37 @@ type ServerResult<T> = Result<T, ServerError>;
 use serde_json::Value;
 use std::collections::VecDeque;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::{Mutex, Notify};
+use tokio::sync::{watch, Mutex, Notify};
 use tokio::time::timeout;
-use tracing::{debug, error, warn};
+use tracing::{debug, error, info_span, warn, Instrument};
 
 /// Warning timeout for lock acquisition (30 seconds)
 const LOCK_ACQUISITION_WARNING_TIMEOUT: Duration = Duration::from_secs(30);
 
+tokio::task_local! {
+    /// ID of the MCP request the current task is serving
+    static REQUEST_ID: String;
+}
+
+/// Run `future` with `request_id` as the current request ID
+///
+/// Operations created inside the future with [`FileOperation::new`] record
+/// the ID, so the worker can log them under the originating request.
+pub async fn with_request_id<F>(request_id: String, future: F) -> F::Output
+where
+    F: std::future::Future,
+{
+    REQUEST_ID.scope(request_id, future).await
+}
+
+/// ID of the request the current task is serving, if any
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(|id| id.clone()).ok()
+}
+
 /// Type of file operation
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OperationType {
@@ -23,6 +45,7 @@ pub enum OperationType {
     Write,
     Delete,
     Rename,
+    Copy,
     Format,
     Refactor,
     CreateDir,
@@ -38,6 +61,7 @@ impl OperationType {
             OperationType::Write
                 | OperationType::Delete
                 | OperationType::Rename
+                | OperationType::Copy
                 | OperationType::Format
                 | OperationType::Refactor
                 | OperationType::CreateDir
@@ -66,6 +90,8 @@ pub struct FileOperation {
     pub params: Value,
     pub created_at: Instant,
     pub priority: u8, // 0 = highest priority
+    /// ID of the MCP request that queued this operation
+    pub request_id: Option<String>,
 }
 
 impl FileOperation {
@@ -84,6 +110,7 @@ impl FileOperation {
             params,
             created_at: Instant::now(),
             priority: 5, // Default medium priority
+            request_id: current_request_id(),
         }
     }
 
@@ -110,6 +137,15 @@ pub struct QueueStats {
     pub max_wait_time: Duration,
 }
 
+/// Span the worker runs an operation's handler in
+fn operation_span(operation: &FileOperation) -> tracing::Span {
+    info_span!(
+        "queued_operation",
+        operation_id = %operation.id,
+        request_id = operation.request_id.as_deref().map(tracing::field::display),
+    )
+}
+
 /// Manages a queue of file operations
 pub struct OperationQueue {
     /// Pending operations queue
@@ -124,6 +160,8 @@ pub struct OperationQueue {
     max_queue_size: usize,
     /// Operation timeout
     operation_timeout: Duration,
+    /// Set once the queue stops accepting new operations
+    closed: AtomicBool,
 }
 
 #[derive(Debug)]
@@ -151,6 +189,7 @@ impl OperationQueue {
             })),
             max_queue_size: 1000,
             operation_timeout: Duration::from_secs(300), // 5 minutes
+            closed: AtomicBool::new(false),
         }
     }
 
@@ -158,6 +197,12 @@ impl OperationQueue {
     pub async fn enqueue(&self, operation: FileOperation) -> ServerResult<String> {
         let mut queue = self.queue.lock().await;
 
+        // Checked under the queue lock: the shutdown drain dequeues through the
+        // same lock after closing, so an operation accepted here is drained
+        if self.is_closed() {
+            return Err(ServerError::runtime("Operation queue is shut down"));
+        }
+
         // Check queue size limit
         if queue.len() >= self.max_queue_size {
             return Err(ServerError::runtime("Operation queue is full"));
@@ -216,137 +261,187 @@ impl OperationQueue {
     ///
     /// The handler receives both the operation and the stats object, and is responsible
     /// for incrementing completed_operations/failed_operations AFTER all I/O completes.
-    pub async fn process_with<F, Fut>(&self, mut handler: F)
+    pub async fn process_with<F, Fut>(&self, handler: F)
     where
         F: FnMut(FileOperation, Arc<Mutex<QueueStatsInternal>>) -> Fut + Send + 'static,
         Fut: std::future::Future<Output = ServerResult<Value>> + Send,
     {
+        // The sender is dropped right away, which means "never shut down"
+        let (_never, shutdown) = watch::channel(false);
+        self.process_until_shutdown(handler, shutdown).await
+    }
+
+    /// Process operations until `shutdown` becomes `true`, then drain
+    ///
+    /// On shutdown the queue is closed, so further `enqueue` calls fail, and
+    /// every operation still pending is handed to the handler before this
+    /// returns. Dropping the sender without signalling leaves the loop running.
+    pub async fn process_until_shutdown<F, Fut>(
+        &self,
+        mut handler: F,
+        mut shutdown: watch::Receiver<bool>,
+    ) where
+        F: FnMut(FileOperation, Arc<Mutex<QueueStatsInternal>>) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = ServerResult<Value>> + Send,
+    {
+        let shutdown_requested = async move {
+            if shutdown.wait_for(|&stop| stop).await.is_err() {
+                std::future::pending::<()>().await;
+            }
+        };
+        tokio::pin!(shutdown_requested);
+
         loop {
-            if let Some(operation) = self.wait_for_operation().await {
-                let wait_time = operation.age();
-                let file_path = operation.file_path.clone();
-                let lock_type = operation.operation_type.lock_type();
+            tokio::select! {
+                // `wait_for_operation` only awaits before it pops an
+                // operation, so losing this race never drops one
+                Some(operation) = self.wait_for_operation() => {
+                    self.process_operation(operation, &mut handler).await;
+                }
+                _ = &mut shutdown_requested => break,
+            }
+        }
+
+        self.close();
+        debug!("Operation queue closed, draining pending operations");
+        while let Some(operation) = self.dequeue().await {
+            self.process_operation(operation, &mut handler).await;
+        }
+    }
+
+    /// Stop accepting new operations
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether the queue has stopped accepting new operations
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst)
+    }
+
+    /// Lock the operation's file, batch same-file writes, and run the handler
+    async fn process_operation<F, Fut>(&self, operation: FileOperation, handler: &mut F)
+    where
+        F: FnMut(FileOperation, Arc<Mutex<QueueStatsInternal>>) -> Fut,
+        Fut: std::future::Future<Output = ServerResult<Value>>,
+    {
+        let wait_time = operation.age();
+        let file_path = operation.file_path.clone();
+        let lock_type = operation.operation_type.lock_type();
+
+        // Update wait time stats
+        {
+            let mut stats = self.stats.lock().await;
+            stats.total_wait_time += wait_time;
+            if wait_time > stats.max_wait_time {
+                stats.max_wait_time = wait_time;
+            }
+        }
+
+        // Check if operation has timed out
+        if wait_time > self.operation_timeout {
+            warn!(operation_id = %operation.id, wait_time = ?wait_time, "Operation timed out");
+            let mut stats = self.stats.lock().await;
+            stats.failed_operations += 1;
+            return;
+        }
 
-                // Update wait time stats
+        // Acquire lock for the file
+        debug!(lock_type = ?lock_type, file_path = %file_path.display(), "Acquiring lock");
+        let file_lock = self.lock_manager.get_lock(&file_path).await;
+
+        // Acquire the appropriate lock and process immediately
+        match lock_type {
+            LockType::Read => {
+                // Try to acquire read lock with timeout warning
+                let _guard = match timeout(LOCK_ACQUISITION_WARNING_TIMEOUT, file_lock.read()).await
                 {
-                    let mut stats = self.stats.lock().await;
-                    stats.total_wait_time += wait_time;
-                    if wait_time > stats.max_wait_time {
-                        stats.max_wait_time = wait_time;
+                    Ok(guard) => guard,
+                    Err(_) => {
+                        warn!(
+                            "Potential stall detected: Operation {} waiting >30s for read lock on {}",
+                            operation.id, file_path.display()
+                        );
+                        // Continue waiting for the lock (don't cancel)
+                        file_lock.read().await
                     }
+                };
+                // Process the operation
+                debug!(
+                    "Processing operation {}: {}",
+                    operation.id, operation.tool_name
+                );
+                // Handler is now responsible for updating stats after all I/O completes
+                let stats = self.stats.clone();
+                let span = operation_span(&operation);
+                if let Err(e) = handler(operation, stats).instrument(span).await {
+                    error!(error = %e, "Handler returned error");
                 }
+            }
+            LockType::Write => {
+                // Batch processing: collect all operations for the same file
+                let mut batched_operations = vec![operation];
 
-                // Check if operation has timed out
-                if wait_time > self.operation_timeout {
-                    warn!(operation_id = %operation.id, wait_time = ?wait_time, "Operation timed out");
-                    let mut stats = self.stats.lock().await;
-                    stats.failed_operations += 1;
-                    continue;
-                }
-
-                // Acquire lock for the file
-                debug!(lock_type = ?lock_type, file_path = %file_path.display(), "Acquiring lock");
-                let file_lock = self.lock_manager.get_lock(&file_path).await;
-
-                // Acquire the appropriate lock and process immediately
-                match lock_type {
-                    LockType::Read => {
-                        // Try to acquire read lock with timeout warning
-                        let _guard = match timeout(
-                            LOCK_ACQUISITION_WARNING_TIMEOUT,
-                            file_lock.read(),
-                        )
-                        .await
-                        {
-                            Ok(guard) => guard,
-                            Err(_) => {
-                                warn!(
-                                    "Potential stall detected: Operation {} waiting >30s for read lock on {}",
-                                    operation.id, file_path.display()
+                // Look for other operations targeting the same file
+                {
+                    let mut queue = self.queue.lock().await;
+                    let mut i = 0;
+                    while i < queue.len() {
+                        if queue[i].file_path == file_path {
+                            // Remove and add to batch
+                            if let Some(op) = queue.remove(i) {
+                                debug!(
+                                    "Batching operation {} for file {}",
+                                    op.id,
+                                    file_path.display()
                                 );
-                                // Continue waiting for the lock (don't cancel)
-                                file_lock.read().await
+                                batched_operations.push(op);
+                            } else {
+                                // Index became invalid, skip and continue
+                                warn!("Failed to remove operation at index {}", i);
+                                i += 1;
                             }
-                        };
-                        // Process the operation
-                        debug!(
-                            "Processing operation {}: {}",
-                            operation.id, operation.tool_name
-                        );
-                        // Handler is now responsible for updating stats after all I/O completes
-                        let stats = self.stats.clone();
-                        if let Err(e) = handler(operation, stats).await {
-                            error!(error = %e, "Handler returned error");
+                        } else {
+                            i += 1;
                         }
                     }
-                    LockType::Write => {
-                        // Batch processing: collect all operations for the same file
-                        let mut batched_operations = vec![operation];
-
-                        // Look for other operations targeting the same file
-                        {
-                            let mut queue = self.queue.lock().await;
-                            let mut i = 0;
-                            while i < queue.len() {
-                                if queue[i].file_path == file_path {
-                                    // Remove and add to batch
-                                    if let Some(op) = queue.remove(i) {
-                                        debug!(
-                                            "Batching operation {} for file {}",
-                                            op.id,
-                                            file_path.display()
-                                        );
-                                        batched_operations.push(op);
-                                    } else {
-                                        // Index became invalid, skip and continue
-                                        warn!("Failed to remove operation at index {}", i);
-                                        i += 1;
-                                    }
-                                } else {
-                                    i += 1;
-                                }
-                            }
-                        }
+                }
 
-                        // Process all batched operations under the same write lock
-                        // Try to acquire write lock with timeout warning
-                        let _guard = match timeout(
-                            LOCK_ACQUISITION_WARNING_TIMEOUT,
-                            file_lock.write(),
-                        )
-                        .await
-                        {
-                            Ok(guard) => guard,
-                            Err(_) => {
-                                warn!(
-                                    "Potential stall detected: {} batched operations waiting >30s for write lock on {}",
-                                    batched_operations.len(), file_path.display()
-                                );
-                                // Continue waiting for the lock (don't cancel)
-                                file_lock.write().await
-                            }
-                        };
-                        debug!(
-                            "Processing {} batched operations for file {}",
-                            batched_operations.len(),
-                            file_path.display()
+                // Process all batched operations under the same write lock
+                // Try to acquire write lock with timeout warning
+                let _guard = match timeout(LOCK_ACQUISITION_WARNING_TIMEOUT, file_lock.write())
+                    .await
+                {
+                    Ok(guard) => guard,
+                    Err(_) => {
+                        warn!(
+                            "Potential stall detected: {} batched operations waiting >30s for write lock on {}",
+                            batched_operations.len(), file_path.display()
                         );
-
-                        // Handler is now responsible for updating stats after all I/O completes
-                        let stats = self.stats.clone();
-                        for batched_op in batched_operations {
-                            debug!(
-                                "Processing operation {}: {}",
-                                batched_op.id, batched_op.tool_name
-                            );
-                            if let Err(e) = handler(batched_op, stats.clone()).await {
-                                error!(error = %e, "Handler returned error");
-                            }
-                        }
+                        // Continue waiting for the lock (don't cancel)
+                        file_lock.write().await
                     }
                 };
+                debug!(
+                    "Processing {} batched operations for file {}",
+                    batched_operations.len(),
+                    file_path.display()
+                );
+
+                // Handler is now responsible for updating stats after all I/O completes
+                let stats = self.stats.clone();
+                for batched_op in batched_operations {
+                    debug!(
+                        "Processing operation {}: {}",
+                        batched_op.id, batched_op.tool_name
+                    );
+                    let span = operation_span(&batched_op);
+                    if let Err(e) = handler(batched_op, stats.clone()).instrument(span).await {
+                        error!(error = %e, "Handler returned error");
+                    }
+                }
             }
-        }
+        };
     }
 
     /// Get current queue size
@@ -492,6 +587,67 @@ mod tests {
         assert_eq!(dequeued.unwrap().id, id);
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_operations_accepted_during_shutdown_are_drained() {
+        let lock_manager = Arc::new(LockManager::new());
+        let queue = Arc::new(OperationQueue::new(lock_manager));
+        let processed = Arc::new(Mutex::new(Vec::new()));
+
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let worker = {
+            let queue = queue.clone();
+            let processed = processed.clone();
+            tokio::spawn(async move {
+                queue
+                    .process_until_shutdown(
+                        move |op, _stats| {
+                            let processed = processed.clone();
+                            async move {
+                                processed.lock().await.push(op.id);
+                                Ok(Value::Null)
+                            }
+                        },
+                        shutdown_rx,
+                    )
+                    .await;
+            })
+        };
+
+        let producers: Vec<_> = (0..8)
+            .map(|task| {
+                let queue = queue.clone();
+                tokio::spawn(async move {
+                    let mut accepted = Vec::new();
+                    for i in 0..50 {
+                        let op = FileOperation::new(
+                            "test_tool".to_string(),
+                            OperationType::Read,
+                            PathBuf::from(format!("/file_{}_{}.txt", task, i)),
+                            Value::Null,
+                        );
+                        if let Ok(id) = queue.enqueue(op).await {
+                            accepted.push(id);
+                        }
+                        tokio::task::yield_now().await;
+                    }
+                    accepted
+                })
+            })
+            .collect();
+
+        shutdown_tx.send(true).expect("worker is listening");
+        let mut accepted = Vec::new();
+        for producer in producers {
+            accepted.extend(producer.await.expect("producer task"));
+        }
+        worker.await.expect("worker task");
+
+        let mut processed = processed.lock().await.clone();
+        accepted.sort();
+        processed.sort();
+        assert_eq!(accepted, processed);
+    }
+
     #[tokio::test]
     async fn test_priority_ordering() {
         let lock_manager = Arc::new(LockManager::new());
@@ -118,4 +118,9 @@ impl FileService {
             &self.project_root,
         )
     }
+
+    /// AST cache shared with the handlers, invalidated after edits
+    pub fn ast_cache(&self) -> &Arc<AstCache> {
+        &self.ast_cache
+    }
 }
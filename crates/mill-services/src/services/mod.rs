@@ -38,7 +38,7 @@ pub use self::ast::ast_service::DefaultAstService;
 pub use self::ast::import_service::ImportService;
 pub use self::coordination::lock_manager::{LockManager, LockType};
 pub use self::coordination::operation_queue::{
-    FileOperation, OperationQueue, OperationType, QueueStats,
+    current_request_id, with_request_id, FileOperation, OperationQueue, OperationType, QueueStats,
 };
 pub use self::coordination::workflow_executor::{self, WorkflowExecutor};
 pub use self::filesystem::file_service::{self, FileService};
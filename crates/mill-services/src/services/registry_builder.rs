@@ -11,6 +11,7 @@
 //! `mill_plugin!` macro, and this builder collects them into the
 //! `PluginRegistry`.
 
+use mill_plugin_api::iter_plugins;
 use mill_plugin_api::LanguagePlugin;
 use mill_plugin_api::PluginDiscovery;
 use std::sync::Arc;
@@ -38,8 +39,12 @@ pub fn build_language_plugin_registry(
 
     for plugin in plugins {
         let name = plugin.metadata().name;
-        debug!(plugin_name = %name, "Registering plugin");
-        registry.register(plugin);
+        // Priority comes from the plugin's self-registered descriptor
+        let priority = iter_plugins()
+            .find(|descriptor| descriptor.name == name)
+            .map_or(0, |descriptor| descriptor.priority);
+        debug!(plugin_name = %name, priority, "Registering plugin");
+        registry.register_with_priority(plugin, priority);
         plugin_count += 1;
     }
 
@@ -98,10 +103,13 @@ mod tests {
                     mill_plugin_api::LanguageMetadata {
                         name: "mock",
                         extensions: &["mock"],
+                        filenames: &[],
                         manifest_filename: "Mockfile",
+                        manifest_patterns: &[],
                         source_dir: "src",
                         entry_point: "lib.rs",
                         module_separator: "::",
+                        lsp: None,
                     };
                 &METADATA
             }
@@ -114,6 +122,7 @@ mod tests {
                 Ok(ParsedSource {
                     data: serde_json::Value::Null,
                     symbols: vec![],
+                    diagnostics: Vec::new(),
                 })
             }
 
@@ -139,4 +148,17 @@ mod tests {
         assert!(!registry.all().is_empty());
         assert!(registry.find_by_extension("mock").is_some());
     }
+
+    #[cfg(feature = "lang-python")]
+    #[test]
+    fn test_requirements_variants_route_to_python() {
+        let plugins: Vec<Arc<dyn LanguagePlugin>> =
+            vec![Arc::from(mill_lang_python::PythonPlugin::new())];
+        let registry = build_language_plugin_registry(plugins);
+
+        for filename in ["pyproject.toml", "requirements.txt", "requirements-dev.txt"] {
+            let plugin = registry.find_by_manifest(filename).unwrap();
+            assert_eq!(plugin.metadata().name, "python", "{}", filename);
+        }
+    }
 }
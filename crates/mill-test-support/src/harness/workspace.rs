@@ -16,6 +16,15 @@ impl TestWorkspace {
         }
     }
 
+    /// Creates a workspace containing the given `(relative path, content)` files.
+    pub fn with_files(files: &[(&str, &str)]) -> Self {
+        let workspace = Self::new();
+        for (path, content) in files {
+            workspace.create_file(path, content);
+        }
+        workspace
+    }
+
     /// Returns the root path of the workspace.
     pub fn path(&self) -> &Path {
         self.temp_dir.path()